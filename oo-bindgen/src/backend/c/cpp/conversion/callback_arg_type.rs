@@ -10,6 +10,9 @@ impl CppCallbackArgType for CallbackArgument {
         match self {
             CallbackArgument::Basic(x) => x.core_cpp_type(),
             CallbackArgument::String(_) => "const char*".to_string(),
+            CallbackArgument::Bytes(x) => const_ref(x.core_cpp_type()),
+            CallbackArgument::Union(x) => const_ref(x.core_cpp_type()),
+            CallbackArgument::Optional(x) => x.core_cpp_type(),
             CallbackArgument::Iterator(x) => mut_ref(x.core_cpp_type()),
             CallbackArgument::Class(x) => mut_ref(x.core_cpp_type()),
             CallbackArgument::Struct(x) => const_ref(x.core_cpp_type()),
@@ -26,18 +26,61 @@ impl CoreCppType for BasicType {
     fn core_cpp_type(&self) -> String {
         match self {
             BasicType::Primitive(x) => x.core_cpp_type(),
+            BasicType::Duration(DurationType::FloatSeconds) => {
+                "std::chrono::duration<double>".to_string()
+            }
             BasicType::Duration(_) => "std::chrono::steady_clock::duration".to_string(),
+            BasicType::Timestamp(_) => "std::chrono::system_clock::time_point".to_string(),
             BasicType::Enum(x) => x.core_cpp_type(),
+            BasicType::Flags(x) => x.core_cpp_type(),
         }
     }
 }
 
+impl CoreCppType for Optional<BasicType> {
+    fn core_cpp_type(&self) -> String {
+        format!("std::optional<{}>", self.inner.core_cpp_type())
+    }
+}
+
+impl CoreCppType for ValueRef {
+    fn core_cpp_type(&self) -> String {
+        let inner = match self {
+            ValueRef::String(x) => x.core_cpp_type(),
+            ValueRef::Enum(x) => x.core_cpp_type(),
+            ValueRef::Duration(x) => BasicType::Duration(*x).core_cpp_type(),
+        };
+        format!("std::optional<{inner}>")
+    }
+}
+
 impl CoreCppType for StringType {
     fn core_cpp_type(&self) -> String {
         "std::string".to_string()
     }
 }
 
+impl CoreCppType for BytesType {
+    fn core_cpp_type(&self) -> String {
+        "std::vector<uint8_t>".to_string()
+    }
+}
+
+impl CoreCppType for ListItemType {
+    fn core_cpp_type(&self) -> String {
+        match self {
+            ListItemType::Basic(x) => x.core_cpp_type(),
+            ListItemType::Struct(x) => x.core_cpp_type(),
+        }
+    }
+}
+
+impl CoreCppType for ListHandle {
+    fn core_cpp_type(&self) -> String {
+        format!("std::vector<{}>", self.item_type.core_cpp_type())
+    }
+}
+
 impl<T, D> CoreCppType for Struct<T, D>
 where
     D: DocReference,
@@ -81,6 +124,24 @@ where
     }
 }
 
+impl<D> CoreCppType for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn core_cpp_type(&self) -> String {
+        self.name.camel_case()
+    }
+}
+
+impl<D> CoreCppType for Handle<Flags<D>>
+where
+    D: DocReference,
+{
+    fn core_cpp_type(&self) -> String {
+        self.name.camel_case()
+    }
+}
+
 impl<D> CoreCppType for EnumVariant<D>
 where
     D: DocReference,
@@ -90,6 +151,15 @@ where
     }
 }
 
+impl<D> CoreCppType for BaseException<D>
+where
+    D: DocReference,
+{
+    fn core_cpp_type(&self) -> String {
+        self.name.camel_case()
+    }
+}
+
 impl<D> CoreCppType for ErrorType<D>
 where
     D: DocReference,
@@ -122,6 +192,10 @@ impl CoreCppType for IteratorItemType {
         match self {
             IteratorItemType::Struct(x) => x.core_cpp_type(),
             IteratorItemType::Primitive(x) => x.core_cpp_type(),
+            IteratorItemType::String(x) => x.core_cpp_type(),
+            IteratorItemType::Enum(x) => x.core_cpp_type(),
+            IteratorItemType::Duration(x) => BasicType::Duration(*x).core_cpp_type(),
+            IteratorItemType::Class(x) => x.core_cpp_type(),
         }
     }
 }
@@ -183,15 +257,37 @@ where
     D: DocReference,
 {
     fn core_cpp_type(&self) -> String {
-        let inner = match &self.item_type {
+        format!("std::vector<{}>", self.item_type.core_cpp_type())
+    }
+}
+
+impl<D> CoreCppType for Handle<Map<D>>
+where
+    D: DocReference,
+{
+    fn core_cpp_type(&self) -> String {
+        format!(
+            "std::map<{}, {}>",
+            self.key_type.core_cpp_type(),
+            self.value_type.core_cpp_type()
+        )
+    }
+}
+
+impl CoreCppType for FunctionArgument {
+    fn core_cpp_type(&self) -> String {
+        match self {
             FunctionArgument::Basic(x) => x.core_cpp_type(),
             FunctionArgument::String(x) => x.core_cpp_type(),
+            FunctionArgument::Bytes(x) => x.core_cpp_type(),
+            FunctionArgument::Union(x) => x.core_cpp_type(),
+            FunctionArgument::Optional(x) => x.core_cpp_type(),
             FunctionArgument::Collection(x) => x.core_cpp_type(),
+            FunctionArgument::Map(x) => x.core_cpp_type(),
             FunctionArgument::Struct(x) => x.core_cpp_type(),
             FunctionArgument::StructRef(x) => x.inner.core_cpp_type(),
             FunctionArgument::ClassRef(x) => x.core_cpp_type(),
             FunctionArgument::Interface(x) => x.core_cpp_type(),
-        };
-        format!("std::vector<{inner}>")
+        }
     }
 }
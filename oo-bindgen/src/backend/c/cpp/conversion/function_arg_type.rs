@@ -28,18 +28,43 @@ impl CppFunctionArgType for StringType {
     }
 }
 
+impl CppFunctionArgType for BytesType {
+    fn get_cpp_function_arg_type(&self) -> String {
+        const_ref(self.core_cpp_type())
+    }
+}
+
+impl CppFunctionArgType for ListHandle {
+    fn get_cpp_function_arg_type(&self) -> String {
+        const_ref(self.core_cpp_type())
+    }
+}
+
 impl CppFunctionArgType for BasicType {
     fn get_cpp_function_arg_type(&self) -> String {
         self.core_cpp_type()
     }
 }
 
+impl CppFunctionArgType for Optional<BasicType> {
+    fn get_cpp_function_arg_type(&self) -> String {
+        self.core_cpp_type()
+    }
+}
+
+impl CppFunctionArgType for UnionHandle {
+    fn get_cpp_function_arg_type(&self) -> String {
+        const_ref(self.core_cpp_type())
+    }
+}
+
 impl CppFunctionArgType for Handle<Interface<Unvalidated>> {
     fn get_cpp_function_arg_type(&self) -> String {
         match self.mode {
             InterfaceCategory::Synchronous => mut_ref(self.core_cpp_type()),
             InterfaceCategory::Asynchronous => unique_ptr(self.core_cpp_type()),
             InterfaceCategory::Future => unique_ptr(self.core_cpp_type()),
+            InterfaceCategory::Stream => unique_ptr(self.core_cpp_type()),
         }
     }
 }
@@ -61,7 +86,11 @@ impl CppFunctionArgType for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.get_cpp_function_arg_type(),
             FunctionArgument::String(x) => x.get_cpp_function_arg_type(),
+            FunctionArgument::Bytes(x) => x.get_cpp_function_arg_type(),
+            FunctionArgument::Union(x) => x.get_cpp_function_arg_type(),
+            FunctionArgument::Optional(x) => x.get_cpp_function_arg_type(),
             FunctionArgument::Collection(x) => const_ref(x.core_cpp_type()),
+            FunctionArgument::Map(x) => const_ref(x.core_cpp_type()),
             FunctionArgument::Struct(x) => x.get_cpp_function_arg_type(),
             FunctionArgument::StructRef(x) => const_ref(x.inner.core_cpp_type()),
             FunctionArgument::ClassRef(x) => mut_ref(x.core_cpp_type()),
@@ -75,8 +104,11 @@ impl CppFunctionArgType for FunctionArgStructField {
         match self {
             Self::Basic(x) => x.get_cpp_function_arg_type(),
             Self::String(x) => x.get_cpp_function_arg_type(),
+            Self::Bytes(x) => x.get_cpp_function_arg_type(),
+            Self::Optional(x) => x.get_cpp_function_arg_type(),
             Self::Interface(x) => x.inner.get_cpp_function_arg_type(),
             Self::Struct(x) => x.get_cpp_function_arg_type(),
+            Self::List(x) => x.get_cpp_function_arg_type(),
         }
     }
 }
@@ -99,6 +131,8 @@ impl CppFunctionArgType for CallbackArgStructField {
             Self::Iterator(x) => x.core_cpp_type(),
             Self::Struct(x) => const_ref(x.core_cpp_type()),
             Self::String(x) => x.core_cpp_type(),
+            Self::Bytes(x) => x.core_cpp_type(),
+            Self::Optional(x) => x.core_cpp_type(),
         }
     }
 }
@@ -109,6 +143,9 @@ impl CppFunctionArgType for UniversalStructField {
             Self::Basic(x) => x.get_cpp_function_arg_type(),
             Self::Struct(x) => x.get_cpp_function_arg_type(),
             Self::String(x) => x.get_cpp_function_arg_type(),
+            Self::Bytes(x) => x.get_cpp_function_arg_type(),
+            Self::Optional(x) => x.get_cpp_function_arg_type(),
+            Self::List(x) => x.get_cpp_function_arg_type(),
         }
     }
 }
@@ -14,10 +14,14 @@ impl CppFunctionReturnType for OptionalReturnType<FunctionReturnValue, Validated
             Some(t) => match t {
                 FunctionReturnValue::Basic(x) => x.core_cpp_type(),
                 FunctionReturnValue::String(x) => x.core_cpp_type(),
+                FunctionReturnValue::Bytes(x) => x.core_cpp_type(),
+                FunctionReturnValue::Union(x) => x.core_cpp_type(),
                 FunctionReturnValue::ClassRef(x) => x.core_cpp_type(),
+                FunctionReturnValue::Iterator(x) => x.core_cpp_type(),
                 FunctionReturnValue::Struct(x) => x.core_cpp_type(),
                 FunctionReturnValue::StructRef(x) => pointer(x.untyped().core_cpp_type()),
                 FunctionReturnValue::PrimitiveRef(x) => pointer(x.inner.core_cpp_type()),
+                FunctionReturnValue::ValueRef(x) => x.core_cpp_type(),
             },
         }
     }
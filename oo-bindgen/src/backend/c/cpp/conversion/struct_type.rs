@@ -13,19 +13,40 @@ impl CppStructType for BasicType {
     }
 }
 
+impl CppStructType for Optional<BasicType> {
+    fn struct_member_type(&self) -> String {
+        self.core_cpp_type()
+    }
+}
+
 impl CppStructType for StringType {
     fn struct_member_type(&self) -> String {
         self.core_cpp_type()
     }
 }
 
+impl CppStructType for BytesType {
+    fn struct_member_type(&self) -> String {
+        self.core_cpp_type()
+    }
+}
+
+impl CppStructType for ListHandle {
+    fn struct_member_type(&self) -> String {
+        self.core_cpp_type()
+    }
+}
+
 impl CppStructType for FunctionArgStructField {
     fn struct_member_type(&self) -> String {
         match self {
             FunctionArgStructField::Basic(x) => x.struct_member_type(),
             FunctionArgStructField::String(x) => x.struct_member_type(),
+            FunctionArgStructField::Bytes(x) => x.struct_member_type(),
+            FunctionArgStructField::Optional(x) => x.struct_member_type(),
             FunctionArgStructField::Interface(x) => unique_ptr(x.inner.core_cpp_type()),
             FunctionArgStructField::Struct(x) => x.core_cpp_type(),
+            FunctionArgStructField::List(x) => x.struct_member_type(),
         }
     }
 }
@@ -48,6 +69,8 @@ impl CppStructType for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => mut_ref(x.core_cpp_type()),
             CallbackArgStructField::Struct(x) => x.core_cpp_type(),
             CallbackArgStructField::String(x) => x.core_cpp_type(),
+            CallbackArgStructField::Bytes(x) => x.core_cpp_type(),
+            CallbackArgStructField::Optional(x) => x.core_cpp_type(),
         }
     }
 }
@@ -58,6 +81,9 @@ impl CppStructType for UniversalStructField {
             UniversalStructField::Basic(x) => x.struct_member_type(),
             UniversalStructField::Struct(x) => x.core_cpp_type(),
             UniversalStructField::String(x) => x.struct_member_type(),
+            UniversalStructField::Bytes(x) => x.struct_member_type(),
+            UniversalStructField::Optional(x) => x.struct_member_type(),
+            UniversalStructField::List(x) => x.struct_member_type(),
         }
     }
 }
@@ -10,6 +10,18 @@ impl ToCpp for DurationType {
         match self {
             DurationType::Milliseconds => format!("::convert::from_milli_sec_u64({expr})"),
             DurationType::Seconds => format!("::convert::from_sec_u64({expr})"),
+            DurationType::Microseconds => format!("::convert::from_micro_sec_u64({expr})"),
+            DurationType::Nanoseconds => format!("::convert::from_nano_sec_u64({expr})"),
+            DurationType::SignedMilliseconds => format!("::convert::from_milli_sec_i64({expr})"),
+            DurationType::FloatSeconds => format!("::convert::from_sec_f64({expr})"),
+        }
+    }
+}
+
+impl ToCpp for TimestampType {
+    fn to_cpp(&self, expr: String) -> String {
+        match self {
+            TimestampType::Milliseconds => format!("::convert::from_epoch_milli_sec_i64({expr})"),
         }
     }
 }
@@ -23,6 +35,29 @@ where
     }
 }
 
+impl<D> ToCpp for Handle<Flags<D>>
+where
+    D: DocReference,
+{
+    fn to_cpp(&self, expr: String) -> String {
+        // flags of the same width share the same C type, so a cast is used instead of an overload
+        format!(
+            "static_cast<::{}::{}>({expr})",
+            self.settings.c_ffi_prefix,
+            self.name.camel_case()
+        )
+    }
+}
+
+impl<D> ToCpp for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn to_cpp(&self, expr: String) -> String {
+        format!("::convert::to_cpp({expr})")
+    }
+}
+
 impl ToCpp for Primitive {
     fn to_cpp(&self, expr: String) -> String {
         match self {
@@ -46,17 +81,35 @@ impl ToCpp for BasicType {
         match self {
             Self::Primitive(x) => x.to_cpp(expr),
             Self::Duration(x) => x.to_cpp(expr),
+            Self::Timestamp(x) => x.to_cpp(expr),
             Self::Enum(x) => x.to_cpp(expr),
+            Self::Flags(x) => x.to_cpp(expr),
         }
     }
 }
 
+impl ToCpp for Optional<BasicType> {
+    fn to_cpp(&self, expr: String) -> String {
+        format!(
+            "::convert::to_cpp_optional({}, [](auto x) {{ return {}; }})",
+            expr,
+            self.inner.to_cpp("x".to_string())
+        )
+    }
+}
+
 impl ToCpp for StringType {
     fn to_cpp(&self, expr: String) -> String {
         format!("std::string({expr})")
     }
 }
 
+impl ToCpp for BytesType {
+    fn to_cpp(&self, expr: String) -> String {
+        format!("::convert::to_cpp({expr})")
+    }
+}
+
 impl ToCpp for ClassDeclarationHandle {
     fn to_cpp(&self, expr: String) -> String {
         format!("::convert::to_cpp({expr})")
@@ -32,6 +32,9 @@ impl ToNativeCallbackArgument for CallbackArgument {
         match self {
             CallbackArgument::Basic(x) => x.to_cpp_callback_argument(expr),
             CallbackArgument::String(_) => expr,
+            CallbackArgument::Bytes(x) => x.to_cpp(expr),
+            CallbackArgument::Union(x) => x.to_cpp(expr),
+            CallbackArgument::Optional(x) => x.to_cpp(expr),
             CallbackArgument::Iterator(x) => x.to_cpp_struct_field(expr),
             CallbackArgument::Class(x) => x.to_cpp_struct_field(expr),
             CallbackArgument::Struct(x) => x.to_cpp_struct_field(expr),
@@ -42,6 +45,9 @@ impl ToNativeCallbackArgument for CallbackArgument {
         match self {
             CallbackArgument::Basic(_) => false,
             CallbackArgument::String(_) => false,
+            CallbackArgument::Bytes(_) => false,
+            CallbackArgument::Union(_) => false,
+            CallbackArgument::Optional(_) => false,
             CallbackArgument::Iterator(_) => true,
             CallbackArgument::Class(_) => true,
             CallbackArgument::Struct(_) => false,
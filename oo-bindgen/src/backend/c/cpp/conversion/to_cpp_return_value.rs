@@ -1,5 +1,5 @@
 use crate::backend::c::cpp::conversion::ToCpp;
-use crate::model::FunctionReturnValue;
+use crate::model::{FunctionReturnValue, ValueRef};
 
 pub(crate) trait ToCppReturnValue {
     fn to_cpp_return_value(&self, expr: String) -> String;
@@ -11,9 +11,14 @@ impl ToCppReturnValue for FunctionReturnValue {
         match self {
             FunctionReturnValue::Basic(x) => x.to_cpp(expr),
             FunctionReturnValue::String(x) => x.to_cpp(expr),
+            FunctionReturnValue::Bytes(x) => x.to_cpp(expr),
+            FunctionReturnValue::Union(x) => x.to_cpp(expr),
             FunctionReturnValue::ClassRef(_) => {
                 format!("::convert::to_cpp({expr})")
             }
+            FunctionReturnValue::Iterator(_) => {
+                format!("::convert::to_cpp({expr})")
+            }
             FunctionReturnValue::Struct(_) => {
                 format!("::convert::to_cpp({expr})")
             }
@@ -25,6 +30,14 @@ impl ToCppReturnValue for FunctionReturnValue {
                 // point to a primitive same in C++
                 expr
             }
+            FunctionReturnValue::ValueRef(x) => {
+                let convert = match x {
+                    ValueRef::String(x) => x.to_cpp("x".to_string()),
+                    ValueRef::Enum(x) => x.to_cpp("*x".to_string()),
+                    ValueRef::Duration(x) => x.to_cpp("*x".to_string()),
+                };
+                format!("::convert::to_cpp_optional_ref({expr}, [](const auto* x) {{ return {convert}; }})")
+            }
         }
     }
 
@@ -32,10 +45,14 @@ impl ToCppReturnValue for FunctionReturnValue {
         match self {
             FunctionReturnValue::Basic(_) => true,
             FunctionReturnValue::String(_) => true,
+            FunctionReturnValue::Bytes(_) => true,
+            FunctionReturnValue::Union(_) => true,
             FunctionReturnValue::ClassRef(_) => false,
+            FunctionReturnValue::Iterator(_) => true,
             FunctionReturnValue::Struct(_) => true,
             FunctionReturnValue::StructRef(_) => false,
             FunctionReturnValue::PrimitiveRef(_) => false,
+            FunctionReturnValue::ValueRef(_) => true,
         }
     }
 }
@@ -28,6 +28,16 @@ where
     }
 }
 
+impl ToCppStructField for ListHandle {
+    fn to_cpp_struct_field(&self, expr: String) -> String {
+        let convert = match &self.item_type {
+            ListItemType::Basic(x) => x.to_cpp("x".to_string()),
+            ListItemType::Struct(x) => x.to_cpp_struct_field("x".to_string()),
+        };
+        format!("::convert::to_cpp_list({expr}, [](const auto& x) {{ return {convert}; }})")
+    }
+}
+
 impl ToCppStructField for ClassDeclarationHandle {
     fn to_cpp_struct_field(&self, expr: String) -> String {
         self.to_cpp(expr)
@@ -61,6 +71,8 @@ impl ToCppStructField for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.to_cpp_struct_field(expr),
             CallbackArgStructField::Struct(x) => x.to_cpp_struct_field(expr),
             CallbackArgStructField::String(x) => x.to_cpp(expr),
+            CallbackArgStructField::Bytes(x) => x.to_cpp(expr),
+            CallbackArgStructField::Optional(x) => x.to_cpp(expr),
         }
     }
 }
@@ -71,6 +83,9 @@ impl ToCppStructField for UniversalStructField {
             UniversalStructField::Basic(x) => x.to_cpp(expr),
             UniversalStructField::Struct(x) => x.to_cpp_struct_field(expr),
             UniversalStructField::String(x) => x.to_cpp(expr),
+            UniversalStructField::Bytes(x) => x.to_cpp(expr),
+            UniversalStructField::Optional(x) => x.to_cpp(expr),
+            UniversalStructField::List(x) => x.to_cpp_struct_field(expr),
         }
     }
 }
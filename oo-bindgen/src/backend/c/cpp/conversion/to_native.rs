@@ -1,3 +1,4 @@
+use crate::backend::c::ctype::CType;
 use crate::model::*;
 
 /// Some types have a C++ -> C conversion that is context independent
@@ -5,11 +6,29 @@ pub(crate) trait ToNative {
     fn to_native(&self, expr: String) -> String;
 }
 
+impl ToNative for UnionHandle {
+    fn to_native(&self, expr: String) -> String {
+        format!("::convert::to_native({expr})")
+    }
+}
+
 impl ToNative for DurationType {
     fn to_native(&self, expr: String) -> String {
         match self {
             DurationType::Milliseconds => format!("::convert::to_milli_sec_u64({expr})"),
             DurationType::Seconds => format!("::convert::to_sec_u64({expr})"),
+            DurationType::Microseconds => format!("::convert::to_micro_sec_u64({expr})"),
+            DurationType::Nanoseconds => format!("::convert::to_nano_sec_u64({expr})"),
+            DurationType::SignedMilliseconds => format!("::convert::to_milli_sec_i64({expr})"),
+            DurationType::FloatSeconds => format!("::convert::to_sec_f64({expr})"),
+        }
+    }
+}
+
+impl ToNative for TimestampType {
+    fn to_native(&self, expr: String) -> String {
+        match self {
+            TimestampType::Milliseconds => format!("::convert::to_epoch_milli_sec_i64({expr})"),
         }
     }
 }
@@ -20,6 +39,12 @@ impl ToNative for Handle<Enum<Unvalidated>> {
     }
 }
 
+impl ToNative for FlagsHandle {
+    fn to_native(&self, expr: String) -> String {
+        format!("static_cast<{}>({expr})", self.to_c_type())
+    }
+}
+
 impl ToNative for Primitive {
     fn to_native(&self, expr: String) -> String {
         match self {
@@ -43,13 +68,32 @@ impl ToNative for BasicType {
         match self {
             Self::Primitive(x) => x.to_native(expr),
             Self::Duration(t) => t.to_native(expr),
+            Self::Timestamp(t) => t.to_native(expr),
             Self::Enum(t) => t.to_native(expr),
+            Self::Flags(t) => t.to_native(expr),
         }
     }
 }
 
+impl ToNative for Optional<BasicType> {
+    fn to_native(&self, expr: String) -> String {
+        format!(
+            "::convert::to_native_optional<{}>({}, [](auto x) {{ return {}; }})",
+            self.to_c_type(),
+            expr,
+            self.inner.to_native("x".to_string())
+        )
+    }
+}
+
 impl ToNative for StringType {
     fn to_native(&self, expr: String) -> String {
         format!("{expr}.c_str()")
     }
 }
+
+impl ToNative for BytesType {
+    fn to_native(&self, expr: String) -> String {
+        format!("::convert::to_native({expr})")
+    }
+}
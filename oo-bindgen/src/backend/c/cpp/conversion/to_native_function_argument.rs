@@ -21,9 +21,13 @@ impl ToNativeFunctionArgument for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.to_native(expr),
             FunctionArgument::String(x) => x.to_native(expr),
+            FunctionArgument::Bytes(x) => x.to_native(expr),
+            FunctionArgument::Union(x) => x.to_native(expr),
+            FunctionArgument::Optional(x) => x.to_native(expr),
             FunctionArgument::Collection(x) => {
                 format!("{}({})", x.collection_class.core_cpp_type(), expr)
             }
+            FunctionArgument::Map(x) => format!("{}({})", x.map_class.core_cpp_type(), expr),
             FunctionArgument::Struct(_) => {
                 format!("::convert::to_native({expr})")
             }
@@ -40,7 +44,7 @@ impl ToNativeFunctionArgument for FunctionArgument {
                 InterfaceCategory::Asynchronous => {
                     format!("::convert::to_native(std::move({expr}))")
                 }
-                InterfaceCategory::Future => {
+                InterfaceCategory::Future | InterfaceCategory::Stream => {
                     format!("::convert::to_native(std::move({expr}))")
                 }
             },
@@ -51,10 +55,17 @@ impl ToNativeFunctionArgument for FunctionArgument {
         match self {
             FunctionArgument::Basic(_) => None,
             FunctionArgument::String(_) => None,
+            FunctionArgument::Bytes(_) => None,
+            FunctionArgument::Union(_) => None,
+            FunctionArgument::Optional(_) => None,
             FunctionArgument::Collection(x) => {
                 let friend_class = x.collection_class.friend_class();
                 Some(Box::new(move |e| format!("{friend_class}::get({e})")))
             }
+            FunctionArgument::Map(x) => {
+                let friend_class = x.map_class.friend_class();
+                Some(Box::new(move |e| format!("{friend_class}::get({e})")))
+            }
             FunctionArgument::Struct(_) => None,
             FunctionArgument::StructRef(_) => Some(Box::new(|e| format!("&{e}"))),
             FunctionArgument::ClassRef(_) => None,
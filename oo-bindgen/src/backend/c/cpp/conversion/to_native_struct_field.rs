@@ -1,4 +1,5 @@
 use crate::backend::c::cpp::conversion::ToNative;
+use crate::backend::c::ctype::CType;
 use crate::model::*;
 
 pub(crate) trait ToNativeStructField {
@@ -21,6 +22,35 @@ impl ToNativeStructField for StringType {
     }
 }
 
+impl ToNativeStructField for BytesType {
+    fn to_native_struct_field(&self, expr: String) -> String {
+        // same as strings, the C++ vector outlives the function call
+        self.to_native(expr)
+    }
+
+    fn requires_move(&self) -> bool {
+        false
+    }
+}
+
+impl ToNativeStructField for ListHandle {
+    fn to_native_struct_field(&self, expr: String) -> String {
+        // the native items are released by the ListScope of the function wrapper
+        let convert = match &self.item_type {
+            ListItemType::Basic(x) => x.to_native("x".to_string()),
+            ListItemType::Struct(x) => x.to_native_struct_field("x".to_string()),
+        };
+        format!(
+            "::convert::to_native_list<{}>({expr}, [](const auto& x) {{ return {convert}; }})",
+            self.to_c_type()
+        )
+    }
+
+    fn requires_move(&self) -> bool {
+        false
+    }
+}
+
 impl<T, D> ToNativeStructField for Handle<Struct<T, D>>
 where
     D: DocReference,
@@ -78,11 +108,24 @@ impl ToNativeStructField for BasicType {
     }
 }
 
+impl ToNativeStructField for Optional<BasicType> {
+    fn to_native_struct_field(&self, expr: String) -> String {
+        self.to_native(expr)
+    }
+
+    fn requires_move(&self) -> bool {
+        false
+    }
+}
+
 impl ToNativeStructField for FunctionArgStructField {
     fn to_native_struct_field(&self, expr: String) -> String {
         match self {
             FunctionArgStructField::Basic(x) => x.to_native_struct_field(expr),
             FunctionArgStructField::String(x) => x.to_native_struct_field(expr),
+            FunctionArgStructField::Bytes(x) => x.to_native_struct_field(expr),
+            FunctionArgStructField::Optional(x) => x.to_native_struct_field(expr),
+            FunctionArgStructField::List(x) => x.to_native_struct_field(expr),
             FunctionArgStructField::Interface(x) => x.inner.to_native_struct_field(expr),
             FunctionArgStructField::Struct(x) => match x {
                 UniversalOr::Specific(x) => x.to_native_struct_field(expr),
@@ -95,6 +138,9 @@ impl ToNativeStructField for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.requires_move(),
             FunctionArgStructField::String(x) => x.requires_move(),
+            FunctionArgStructField::Bytes(x) => x.requires_move(),
+            FunctionArgStructField::Optional(x) => x.requires_move(),
+            FunctionArgStructField::List(x) => x.requires_move(),
             FunctionArgStructField::Interface(x) => x.inner.requires_move(),
             FunctionArgStructField::Struct(x) => match x {
                 UniversalOr::Specific(x) => x.requires_move(),
@@ -110,6 +156,9 @@ impl ToNativeStructField for UniversalStructField {
             UniversalStructField::Basic(x) => x.to_native_struct_field(expr),
             UniversalStructField::Struct(x) => x.to_native_struct_field(expr),
             UniversalStructField::String(x) => x.to_native_struct_field(expr),
+            UniversalStructField::Bytes(x) => x.to_native_struct_field(expr),
+            UniversalStructField::Optional(x) => x.to_native_struct_field(expr),
+            UniversalStructField::List(x) => x.to_native_struct_field(expr),
         }
     }
 
@@ -118,6 +167,9 @@ impl ToNativeStructField for UniversalStructField {
             UniversalStructField::Basic(x) => x.requires_move(),
             UniversalStructField::Struct(x) => x.requires_move(),
             UniversalStructField::String(x) => x.requires_move(),
+            UniversalStructField::Bytes(x) => x.requires_move(),
+            UniversalStructField::Optional(x) => x.requires_move(),
+            UniversalStructField::List(x) => x.requires_move(),
         }
     }
 }
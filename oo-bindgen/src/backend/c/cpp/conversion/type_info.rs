@@ -25,6 +25,7 @@ where
             InterfaceCategory::Synchronous => PassBy::MutRef,
             InterfaceCategory::Asynchronous => PassBy::Move,
             InterfaceCategory::Future => PassBy::Move,
+            InterfaceCategory::Stream => PassBy::Move,
         }
     }
 }
@@ -52,7 +53,9 @@ impl TypeInfo for BasicType {
         match self {
             BasicType::Primitive(x) => x.pass_by(),
             BasicType::Duration(_) => PassBy::Copy,
+            BasicType::Timestamp(_) => PassBy::Copy,
             BasicType::Enum(_) => PassBy::Copy,
+            BasicType::Flags(_) => PassBy::Copy,
         }
     }
 }
@@ -90,6 +93,9 @@ impl TypeInfo for UniversalStructField {
             UniversalStructField::Basic(x) => x.pass_by(),
             UniversalStructField::Struct(x) => x.pass_by(),
             UniversalStructField::String(x) => x.pass_by(),
+            UniversalStructField::Bytes(x) => x.pass_by(),
+            UniversalStructField::Optional(x) => x.pass_by(),
+            UniversalStructField::List(x) => x.pass_by(),
         }
     }
 }
@@ -127,6 +133,8 @@ impl TypeInfo for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.pass_by(),
             CallbackArgStructField::Struct(x) => x.pass_by(),
             CallbackArgStructField::String(x) => x.pass_by(),
+            CallbackArgStructField::Bytes(x) => x.pass_by(),
+            CallbackArgStructField::Optional(x) => x.pass_by(),
         }
     }
 }
@@ -137,13 +145,43 @@ impl TypeInfo for StringType {
     }
 }
 
+impl TypeInfo for Optional<BasicType> {
+    fn pass_by(&self) -> PassBy {
+        PassBy::Copy
+    }
+}
+
+impl TypeInfo for BytesType {
+    fn pass_by(&self) -> PassBy {
+        PassBy::ConstRef
+    }
+}
+
+impl TypeInfo for ListHandle {
+    fn pass_by(&self) -> PassBy {
+        PassBy::ConstRef
+    }
+}
+
+impl<D> TypeInfo for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn pass_by(&self) -> PassBy {
+        PassBy::ConstRef
+    }
+}
+
 impl TypeInfo for FunctionArgStructField {
     fn pass_by(&self) -> PassBy {
         match self {
             FunctionArgStructField::Basic(x) => x.pass_by(),
             FunctionArgStructField::String(x) => x.pass_by(),
+            FunctionArgStructField::Bytes(x) => x.pass_by(),
+            FunctionArgStructField::Optional(x) => x.pass_by(),
             FunctionArgStructField::Interface(x) => x.inner.pass_by(),
             FunctionArgStructField::Struct(x) => x.pass_by(),
+            FunctionArgStructField::List(x) => x.pass_by(),
         }
     }
 }
@@ -157,6 +195,15 @@ where
     }
 }
 
+impl<D> TypeInfo for Handle<Map<D>>
+where
+    D: DocReference,
+{
+    fn pass_by(&self) -> PassBy {
+        PassBy::MutRef
+    }
+}
+
 impl<T> TypeInfo for TypedStructDeclaration<T> {
     fn pass_by(&self) -> PassBy {
         PassBy::ConstRef
@@ -168,7 +215,11 @@ impl TypeInfo for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.pass_by(),
             FunctionArgument::String(x) => x.pass_by(),
+            FunctionArgument::Bytes(x) => x.pass_by(),
+            FunctionArgument::Union(x) => x.pass_by(),
+            FunctionArgument::Optional(x) => x.pass_by(),
             FunctionArgument::Collection(x) => x.pass_by(),
+            FunctionArgument::Map(x) => x.pass_by(),
             FunctionArgument::Struct(x) => x.pass_by(),
             FunctionArgument::StructRef(x) => x.pass_by(),
             FunctionArgument::ClassRef(x) => x.pass_by(),
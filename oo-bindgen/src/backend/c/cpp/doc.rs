@@ -36,6 +36,13 @@ pub(crate) fn print_cpp_future_method_docs(
     print_cpp_function_docs(f, &method.native_function, true, true)
 }
 
+pub(crate) fn print_cpp_stream_method_docs(
+    f: &mut dyn Printer,
+    method: &StreamMethod<Validated>,
+) -> FormattingResult<()> {
+    print_cpp_function_docs(f, &method.native_function, true, true)
+}
+
 pub(crate) fn print_cpp_constructor_docs(
     f: &mut dyn Printer,
     constructor: &ClassConstructor<Validated>,
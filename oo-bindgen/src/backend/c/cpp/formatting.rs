@@ -1,3 +1,4 @@
+use crate::backend::c::cpp::conversion::*;
 use crate::backend::*;
 use crate::model::*;
 
@@ -17,6 +18,43 @@ pub(crate) fn std_move<S: Into<String>>(expr: S) -> String {
     format!("std::move({})", expr.into())
 }
 
+pub(crate) fn get_default_value(default: &ValidatedDefaultValue) -> String {
+    match default {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => match x {
+            NumberValue::U8(x) => x.to_string(),
+            NumberValue::S8(x) => x.to_string(),
+            NumberValue::U16(x) => x.to_string(),
+            NumberValue::S16(x) => x.to_string(),
+            NumberValue::U32(x) => x.to_string(),
+            NumberValue::S32(x) => x.to_string(),
+            NumberValue::U64(x) => x.to_string(),
+            NumberValue::S64(x) => x.to_string(),
+            NumberValue::Float(x) => format!("{x}f"),
+            NumberValue::Double(x) => x.to_string(),
+        },
+        ValidatedDefaultValue::Duration(DurationType::Microseconds, x) => {
+            format!("std::chrono::microseconds({})", x.as_micros())
+        }
+        ValidatedDefaultValue::Duration(DurationType::Nanoseconds, x) => {
+            format!("std::chrono::nanoseconds({})", x.as_nanos())
+        }
+        ValidatedDefaultValue::Duration(_, x) => {
+            format!("std::chrono::milliseconds({})", x.as_millis())
+        }
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!("{}::{}", x.core_cpp_type(), variant)
+        }
+        ValidatedDefaultValue::String(x) => {
+            format!("\"{x}\"")
+        }
+        ValidatedDefaultValue::DefaultStruct(st, ct, c_name) => match ct {
+            InitializerType::Normal => format!("{}()", st.core_cpp_type()),
+            InitializerType::Static => format!("{}::{}()", st.core_cpp_type(), c_name),
+        },
+    }
+}
+
 pub(crate) fn namespace<F>(f: &mut dyn Printer, namespace: &str, cb: F) -> FormattingResult<()>
 where
     F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
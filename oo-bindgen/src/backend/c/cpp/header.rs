@@ -22,7 +22,14 @@ pub(crate) fn generate_header(lib: &Library, path: &Path) -> FormattingResult<()
     f.writeln("#include <cstdint>")?;
     f.writeln("#include <stdexcept>")?;
     f.writeln("#include <chrono>")?;
+    f.writeln("#include <condition_variable>")?;
+    f.writeln("#include <deque>")?;
+    f.writeln("#include <iterator>")?;
+    f.writeln("#include <map>")?;
     f.writeln("#include <memory>")?;
+    f.writeln("#include <mutex>")?;
+    f.writeln("#include <optional>")?;
+    f.writeln("#include <variant>")?;
     f.writeln("#include <vector>")?;
     f.newline()?;
 
@@ -76,6 +83,8 @@ fn print_header_namespace_contents(lib: &Library, f: &mut dyn Printer) -> Format
         match &statement {
             Statement::Constants(x) => print_constants(f, x)?,
             Statement::EnumDefinition(x) => print_enum(f, x)?,
+            Statement::FlagsDefinition(x) => print_flags(f, x)?,
+            Statement::BaseException(x) => print_base_exception(f, x)?,
             Statement::ErrorType(x) => print_exception(f, x)?,
             Statement::StructDeclaration(x) => print_struct_decl(f, x)?,
             Statement::StructDefinition(x) => match x {
@@ -87,6 +96,10 @@ fn print_header_namespace_contents(lib: &Library, f: &mut dyn Printer) -> Format
             Statement::InterfaceDefinition(x) => {
                 print_interface(f, x.untyped())?;
 
+                if let InterfaceType::Stream(x) = x {
+                    print_stream_iterator(f, x)?;
+                }
+
                 if let Some(callback) = x.untyped().get_functional_callback() {
                     if !documented_functional_ns {
                         documented_functional_ns = true;
@@ -98,6 +111,7 @@ fn print_header_namespace_contents(lib: &Library, f: &mut dyn Printer) -> Format
                     f.newline()?;
                 }
             }
+            Statement::UnionDefinition(x) => print_union(f, x)?,
             Statement::ClassDeclaration(x) => {
                 match x.class_type {
                     ClassType::Normal => print_class_decl(f, x)?,
@@ -105,6 +119,8 @@ fn print_header_namespace_contents(lib: &Library, f: &mut dyn Printer) -> Format
                     // collections are mapped to Vec<T> in C++ and therefore
                     // have no opaque declaration in the header
                     ClassType::Collection => {}
+                    // likewise, maps are mapped to std::map<K, V>
+                    ClassType::Map => {}
                 }
             }
             Statement::ClassDefinition(x) => print_class_definition(f, x)?,
@@ -113,6 +129,12 @@ fn print_header_namespace_contents(lib: &Library, f: &mut dyn Printer) -> Format
             Statement::CollectionDeclaration(_) => {
                 // collections are just vectors in C++
             }
+            Statement::MapDeclaration(_) => {
+                // maps are just std::map in C++
+            }
+            Statement::ListDeclaration(_) => {
+                // lists are just vectors in C++
+            }
             Statement::FunctionDefinition(_) => {
                 // not used in C++
             }
@@ -198,6 +220,7 @@ fn write_functional_interface_helpers(
         InterfaceCategory::Synchronous => true,
         InterfaceCategory::Asynchronous => false,
         InterfaceCategory::Future => false,
+        InterfaceCategory::Stream => false,
     };
 
     let return_type = if is_synchronous {
@@ -239,7 +262,11 @@ fn print_iterator_definition(
     f: &mut dyn Printer,
     iter: &Handle<AbstractIterator<Validated>>,
 ) -> FormattingResult<()> {
-    let iterator = include_str!("snippet/iterator.hpp");
+    let iterator = if iter.is_owned() {
+        include_str!("snippet/owned_iterator.hpp")
+    } else {
+        include_str!("snippet/iterator.hpp")
+    };
     for line in iterator.lines() {
         let substituted = line
             .replace("<name>", &iter.core_cpp_type())
@@ -250,6 +277,46 @@ fn print_iterator_definition(
     f.newline()
 }
 
+fn print_stream_iterator(
+    f: &mut dyn Printer,
+    stream: &StreamInterface<Validated>,
+) -> FormattingResult<()> {
+    let settings = &stream.interface.settings.stream;
+    // items are copied into the queue, so strings are stored as std::string
+    let item_type = match &stream.item_type {
+        CallbackArgument::String(x) => x.core_cpp_type(),
+        CallbackArgument::Struct(x) => x.core_cpp_type(),
+        x => x.get_cpp_callback_arg_type(),
+    };
+    for line in include_str!("snippet/stream_iterator.hpp").lines() {
+        let substituted = line
+            .replace("<name>", &stream.interface.core_cpp_type())
+            .replace("<item_type>", &item_type)
+            .replace(
+                "<item_arg_type>",
+                &stream.item_type.get_cpp_callback_arg_type(),
+            )
+            .replace("<error_type>", &stream.error_type.inner.core_cpp_type())
+            .replace("<exception>", &stream.error_type.core_cpp_type())
+            .replace(
+                "<item_callback>",
+                settings.item_callback_method_name.as_ref(),
+            )
+            .replace("<item_param>", settings.item_parameter_name.as_ref())
+            .replace(
+                "<complete_callback>",
+                settings.complete_callback_method_name.as_ref(),
+            )
+            .replace(
+                "<failure_callback>",
+                settings.failure_callback_method_name.as_ref(),
+            )
+            .replace("<error_param>", settings.failure_parameter_name.as_ref());
+        f.writeln(&substituted)?;
+    }
+    f.newline()
+}
+
 fn print_class_decl(f: &mut dyn Printer, handle: &ClassDeclarationHandle) -> FormattingResult<()> {
     f.writeln(&format!("class {};", handle.core_cpp_type()))?;
     f.newline()
@@ -288,15 +355,58 @@ fn print_constants(
     f: &mut dyn Printer,
     set: &Handle<ConstantSet<Validated>>,
 ) -> FormattingResult<()> {
-    fn get_value(v: ConstantValue) -> String {
+    fn get_value(v: &ConstantValue) -> String {
         match v {
-            ConstantValue::U8(v, Representation::Hex) => format!("0x{v:02X}"),
+            ConstantValue::Primitive(x, rep) => {
+                // binary literals require C++14
+                let rep = match rep {
+                    Representation::Binary => Representation::Hex,
+                    x => *x,
+                };
+                match x {
+                    PrimitiveValue::Bool(x) => x.to_string(),
+                    PrimitiveValue::U8(_)
+                    | PrimitiveValue::U16(_)
+                    | PrimitiveValue::U32(_)
+                    | PrimitiveValue::U64(_) => {
+                        format!("{}u", format_integer(integer_value(x).unwrap(), rep))
+                    }
+                    PrimitiveValue::S8(_) | PrimitiveValue::S16(_) | PrimitiveValue::S32(_) => {
+                        format_integer(integer_value(x).unwrap(), rep)
+                    }
+                    PrimitiveValue::S64(_) => {
+                        format!("{}ll", format_integer(integer_value(x).unwrap(), rep))
+                    }
+                    PrimitiveValue::Float(x) => format!("{x:?}f"),
+                    PrimitiveValue::Double(x) => format!("{x:?}"),
+                }
+            }
+            ConstantValue::Duration(x) => x.to_constant_cpp(),
+            ConstantValue::String(x) => quoted_string(x),
+            ConstantValue::Enum(x) => x.to_constant_cpp(),
         }
     }
 
-    fn get_type(v: ConstantValue) -> &'static str {
+    fn get_type(v: &ConstantValue) -> String {
         match v {
-            ConstantValue::U8(_, _) => "uint8_t",
+            ConstantValue::Primitive(x, _) => Primitive::from(*x).core_cpp_type(),
+            ConstantValue::Duration(DurationValue::Milliseconds(_)) => {
+                "std::chrono::milliseconds".to_string()
+            }
+            ConstantValue::Duration(DurationValue::Seconds(_)) => {
+                "std::chrono::seconds".to_string()
+            }
+            ConstantValue::Duration(DurationValue::Microseconds(_)) => {
+                "std::chrono::microseconds".to_string()
+            }
+            ConstantValue::Duration(DurationValue::Nanoseconds(_)) => {
+                "std::chrono::nanoseconds".to_string()
+            }
+            ConstantValue::Duration(DurationValue::SignedMilliseconds(_)) => {
+                "std::chrono::milliseconds".to_string()
+            }
+            ConstantValue::String(_) => "char const*".to_string(),
+            ConstantValue::Enum(x) => x.handle.core_cpp_type(),
         }
     }
 
@@ -307,9 +417,9 @@ fn print_constants(
             print_commented_cpp_doc(f, &value.doc)?;
             f.writeln(&format!(
                 "constexpr {} {} = {};",
-                get_type(value.value),
+                get_type(&value.value),
                 value.core_cpp_type(),
-                get_value(value.value)
+                get_value(&value.value)
             ))?;
         }
         Ok(())
@@ -345,14 +455,93 @@ fn print_enum(f: &mut dyn Printer, e: &Handle<Enum<Validated>>) -> FormattingRes
     f.newline()
 }
 
+fn print_flags(f: &mut dyn Printer, e: &Handle<Flags<Validated>>) -> FormattingResult<()> {
+    let name = e.core_cpp_type();
+    let int_type = Primitive::from(e.flags_type).core_cpp_type();
+    let all = e.bits.iter().fold(0, |acc, bit| acc | bit.value);
+    let cast = |expr: &str| format!("static_cast<{int_type}>({expr})");
+
+    print_commented_cpp_doc(f, &e.doc)?;
+    f.writeln(&format!("enum class {name} : {int_type} {{"))?;
+    indented(f, |f| {
+        for bit in &e.bits {
+            print_commented_cpp_doc(f, &bit.doc)?;
+            f.writeln(&format!("{} = 0x{:X}u,", bit.name, bit.value))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("};")?;
+    f.newline()?;
+
+    for (op, doc) in [
+        ("|", "union of the bits of two sets"),
+        ("&", "intersection of the bits of two sets"),
+        ("^", "bits set in exactly one of two sets"),
+    ] {
+        f.writeln(&format!("/// @brief {doc}"))?;
+        f.writeln(&format!(
+            "constexpr {name} operator{op}({name} lhs, {name} rhs) {{ return static_cast<{name}>({} {op} {}); }}",
+            cast("lhs"),
+            cast("rhs")
+        ))?;
+        f.writeln(&format!("/// @brief assign the {doc}"))?;
+        f.writeln(&format!(
+            "inline {name}& operator{op}=({name}& lhs, {name} rhs) {{ return lhs = lhs {op} rhs; }}"
+        ))?;
+    }
+    f.writeln("/// @brief complement of the defined bits of a set")?;
+    f.writeln(&format!(
+        "constexpr {name} operator~({name} value) {{ return static_cast<{name}>(~{} & 0x{all:X}u); }}",
+        cast("value")
+    ))?;
+    f.writeln("/// @brief check if any bit is set")?;
+    f.writeln(&format!(
+        "constexpr bool any({name} value) {{ return {} != 0; }}",
+        cast("value")
+    ))?;
+    f.newline()
+}
+
+fn print_base_exception(
+    f: &mut dyn Printer,
+    e: &Handle<BaseException<Validated>>,
+) -> FormattingResult<()> {
+    let parent = match &e.parent {
+        None => "std::logic_error".to_string(),
+        Some(x) => x.core_cpp_type(),
+    };
+    print_commented_cpp_doc(f, &e.doc)?;
+    f.writeln(&format!(
+        "class {} : public {} {{",
+        e.core_cpp_type(),
+        parent
+    ))?;
+    f.writeln("protected:")?;
+    indented(f, |f| {
+        f.writeln("/// @brief construct the exception with a message")?;
+        f.writeln(&format!(
+            "explicit {}(const std::string& what) : {}(what) {{}}",
+            e.core_cpp_type(),
+            parent
+        ))
+    })?;
+    f.writeln("};")?;
+    f.newline()
+}
+
 fn print_exception(f: &mut dyn Printer, e: &ErrorType<Validated>) -> FormattingResult<()> {
+    let parent = match &e.base {
+        None => "std::logic_error".to_string(),
+        Some(x) => x.core_cpp_type(),
+    };
     f.writeln(&format!(
         "/// @brief Exception type corresponding to the underlying error enum #{}",
         e.inner.core_cpp_type()
     ))?;
     f.writeln(&format!(
-        "class {} : public std::logic_error {{",
-        e.core_cpp_type()
+        "class {} : public {} {{",
+        e.core_cpp_type(),
+        parent
     ))?;
     f.writeln("public:")?;
     indented(f, |f| {
@@ -360,13 +549,106 @@ fn print_exception(f: &mut dyn Printer, e: &ErrorType<Validated>) -> FormattingR
         f.writeln(&format!("{} error;", e.inner.core_cpp_type()))?;
         f.writeln("/// @brief construct the exception with an instance of the enum")?;
         f.writeln(&format!(
-            "{}({} error) : std::logic_error(to_string(error)), error(error) {{}}",
+            "{}({} error) : {}(to_string(error)), error(error) {{}}",
             e.core_cpp_type(),
-            e.inner.core_cpp_type()
+            e.inner.core_cpp_type(),
+            parent
         ))?;
+        if e.has_detail() {
+            let mut params = vec![format!("{} error", e.inner.core_cpp_type())];
+            let mut initializers = vec!["error(error)".to_string()];
+            if e.message {
+                params.push("std::string detail".to_string());
+                initializers.push("detail(detail)".to_string());
+            }
+            if let Some(payload) = &e.payload {
+                params.push(format!(
+                    "std::optional<{}> payload",
+                    payload.core_cpp_type()
+                ));
+                initializers.push("payload(payload)".to_string());
+            }
+            let what = if e.message {
+                "detail.empty() ? std::string(to_string(error)) : std::string(to_string(error)) + \": \" + detail"
+            } else {
+                "to_string(error)"
+            };
+            f.writeln("/// @brief construct the exception with an instance of the enum and the detail that accompanied it")?;
+            f.writeln(&format!(
+                "{}({}) : {}({}), {} {{}}",
+                e.core_cpp_type(),
+                params.join(", "),
+                parent,
+                what,
+                initializers.join(", ")
+            ))?;
+        }
+        if e.message {
+            f.writeln(
+                "/// @brief detailed message of the error, empty if no message was provided",
+            )?;
+            f.writeln("std::string detail;")?;
+        }
+        if let Some(payload) = &e.payload {
+            f.writeln("/// @brief payload of the error, if one was provided")?;
+            f.writeln(&format!(
+                "std::optional<{}> payload;",
+                payload.core_cpp_type()
+            ))?;
+        }
         Ok(())
     })?;
     f.writeln("};")?;
+    f.newline()?;
+
+    for (x, _) in e.variant_exceptions() {
+        f.writeln(&format!(
+            "/// @brief Exception thrown in place of #{} when the error is #{}::{}",
+            e.core_cpp_type(),
+            e.inner.core_cpp_type(),
+            x.variant
+        ))?;
+        f.writeln(&format!(
+            "class {} : public {} {{",
+            x.exception_name.camel_case(),
+            e.core_cpp_type()
+        ))?;
+        f.writeln("public:")?;
+        indented(f, |f| {
+            f.writeln(&format!(
+                "using {}::{};",
+                e.core_cpp_type(),
+                e.core_cpp_type()
+            ))
+        })?;
+        f.writeln("};")?;
+        f.newline()?;
+    }
+
+    Ok(())
+}
+
+fn print_union(f: &mut dyn Printer, handle: &Handle<Union<Validated>>) -> FormattingResult<()> {
+    print_commented_cpp_doc(f, &handle.doc)?;
+    f.writeln("///")?;
+    f.writeln("/// alternatives in order of their index:")?;
+    for variant in handle.variants.iter() {
+        f.writeln(&format!(
+            "/// - {}: {}",
+            variant.name,
+            variant.value.core_cpp_type()
+        ))?;
+    }
+    let types: Vec<String> = handle
+        .variants
+        .iter()
+        .map(|x| x.value.core_cpp_type())
+        .collect();
+    f.writeln(&format!(
+        "using {} = std::variant<{}>;",
+        handle.core_cpp_type(),
+        types.join(", ")
+    ))?;
     f.newline()
 }
 
@@ -467,7 +749,10 @@ impl ToConstantCpp for DurationValue {
     fn to_constant_cpp(&self) -> String {
         match self {
             DurationValue::Milliseconds(x) => format!("std::chrono::milliseconds({x})"),
-            DurationValue::Seconds(x) => format!("std::chrono::Duration::seconds({x})"),
+            DurationValue::Seconds(x) => format!("std::chrono::seconds({x})"),
+            DurationValue::Microseconds(x) => format!("std::chrono::microseconds({x})"),
+            DurationValue::Nanoseconds(x) => format!("std::chrono::nanoseconds({x})"),
+            DurationValue::SignedMilliseconds(x) => format!("std::chrono::milliseconds({x})"),
         }
     }
 }
@@ -624,10 +909,7 @@ fn get_default_value_doc(default: &ValidatedDefaultValue) -> String {
     match default {
         ValidatedDefaultValue::Bool(x) => format!("@p {x}"),
         ValidatedDefaultValue::Number(x) => x.to_string(),
-        ValidatedDefaultValue::Duration(DurationType::Milliseconds, x) => {
-            format!("{}ms", x.as_millis())
-        }
-        ValidatedDefaultValue::Duration(DurationType::Seconds, x) => format!("{}s", x.as_secs()),
+        ValidatedDefaultValue::Duration(t, x) => t.get_value_abbreviation(*x),
         ValidatedDefaultValue::Enum(x, variant) => format!("{}::{}", x.core_cpp_type(), variant),
         ValidatedDefaultValue::String(x) => format!("\"{x}\""),
         ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
@@ -741,6 +1023,11 @@ fn print_class_definition(
             print_method(f, method)?;
         }
 
+        for method in &handle.property_accessors(None) {
+            f.newline()?;
+            print_method(f, method)?;
+        }
+
         for method in &handle.static_methods {
             f.newline()?;
             print_static_method(f, method)?;
@@ -749,6 +1036,14 @@ fn print_class_definition(
         for method in &handle.future_methods {
             f.newline()?;
             print_future_method(f, method)?;
+            if let Some(cancel) = method.cancel_method() {
+                print_static_method(f, &cancel)?;
+            }
+        }
+
+        for method in &handle.stream_methods {
+            f.newline()?;
+            print_stream_method(f, method)?;
         }
 
         Ok(())
@@ -810,6 +1105,26 @@ fn print_future_method(
     f.newline()
 }
 
+fn print_stream_method(
+    f: &mut dyn Printer,
+    method: &StreamMethod<Validated>,
+) -> FormattingResult<()> {
+    let args: String = cpp_arguments(method.native_function.arguments.iter().skip(1));
+
+    print_cpp_stream_method_docs(f, method)?;
+    f.writeln(&format!(
+        "{} {}({});",
+        method
+            .native_function
+            .return_type
+            .get_cpp_function_return_type(),
+        method.name,
+        args
+    ))?;
+
+    f.newline()
+}
+
 fn print_static_class(
     f: &mut dyn Printer,
     handle: &Handle<StaticClass<Validated>>,
@@ -844,11 +1159,15 @@ where
     T: Iterator<Item = &'a Arg<FunctionArgument, Validated>>,
 {
     iter.map(|p| {
-        format!(
+        let arg = format!(
             "{} {}",
             p.arg_type.get_cpp_function_arg_type(),
             p.core_cpp_type(),
-        )
+        );
+        match &p.default {
+            Some(x) => format!("{arg} = {}", get_default_value(x)),
+            None => arg,
+        }
     })
     .collect::<Vec<String>>()
     .join(", ")
@@ -28,6 +28,15 @@ pub(crate) fn generate_cpp_file(lib: &Library, path: &Path) -> FormattingResult<
         for line in include_str!("snippet/convert_time.cpp").lines() {
             f.writeln(line)?;
         }
+        for line in include_str!("snippet/convert_bytes.cpp").lines() {
+            f.writeln(line)?;
+        }
+        for line in include_str!("snippet/convert_optional.cpp").lines() {
+            f.writeln(line)?;
+        }
+        for line in include_str!("snippet/convert_list.cpp").lines() {
+            f.writeln(line)?;
+        }
         f.newline()?;
 
         // emit the conversions in statement order as some conversions reference other conversions
@@ -57,8 +66,12 @@ pub(crate) fn generate_cpp_file(lib: &Library, path: &Path) -> FormattingResult<
 
 fn write_collection_class_definitions(lib: &Library, f: &mut dyn Printer) -> FormattingResult<()> {
     for col in lib.collections() {
-        write_collection_class_definition(f, col)?;
-        write_collection_class_friend(f, col)?;
+        write_collection_class_definition(f, &col.collection_class, col.core_cpp_type())?;
+        write_collection_class_friend(f, &col.collection_class)?;
+    }
+    for map in lib.maps() {
+        write_collection_class_definition(f, &map.map_class, map.core_cpp_type())?;
+        write_collection_class_friend(f, &map.map_class)?;
     }
     Ok(())
 }
@@ -70,6 +83,9 @@ fn write_collection_class_implementations(
     for col in lib.collections() {
         write_collection_class_implementation(f, col)?;
     }
+    for map in lib.maps() {
+        write_map_class_implementation(f, map)?;
+    }
     Ok(())
 }
 
@@ -111,28 +127,55 @@ fn write_collection_class_implementation(
     f.newline()
 }
 
+fn write_map_class_implementation(
+    f: &mut dyn Printer,
+    map: &Handle<Map<Validated>>,
+) -> FormattingResult<()> {
+    let cpp_type = map.map_class.core_cpp_type();
+
+    // write the constructor
+    f.writeln(&format!(
+        "{}::{}({} values) : self(fn::{}())",
+        cpp_type,
+        cpp_type,
+        const_ref(map.core_cpp_type()),
+        map.create_func.name
+    ))?;
+    blocked(f, |f| {
+        f.writeln("for(const auto& x : values)")?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "fn::{}(*this, x.first, x.second);",
+                map.insert_func.name
+            ))
+        })
+    })?;
+    f.newline()?;
+
+    // write the destructor
+    f.writeln(&format!("{cpp_type}::~{cpp_type}()"))?;
+    blocked(f, |f| {
+        f.writeln(&format!("fn::{}(*this);", map.delete_func.name))
+    })?;
+    f.newline()
+}
+
 fn write_collection_class_definition(
     f: &mut dyn Printer,
-    col: &Handle<Collection<Validated>>,
+    class: &ClassDeclarationHandle,
+    values_type: String,
 ) -> FormattingResult<()> {
-    let cpp_type = col.collection_class.core_cpp_type();
-    let c_type = col.collection_class.to_c_type();
+    let cpp_type = class.core_cpp_type();
+    let c_type = class.to_c_type();
     f.writeln(&format!("class {cpp_type}"))?;
     f.writeln("{")?;
     indented(f, |f| {
-        f.writeln(&format!(
-            "friend class {};",
-            col.collection_class.friend_class()
-        ))?;
+        f.writeln(&format!("friend class {};", class.friend_class()))?;
         f.writeln(&format!("{c_type}* self;"))
     })?;
     f.writeln("public:")?;
     indented(f, |f| {
-        f.writeln(&format!(
-            "{}({} values);",
-            cpp_type,
-            const_ref(col.core_cpp_type())
-        ))?;
+        f.writeln(&format!("{}({} values);", cpp_type, const_ref(values_type)))?;
         f.writeln(&format!("~{cpp_type}();"))
     })?;
     f.writeln("};")?;
@@ -141,17 +184,17 @@ fn write_collection_class_definition(
 
 fn write_collection_class_friend(
     f: &mut dyn Printer,
-    col: &Handle<Collection<Validated>>,
+    class: &ClassDeclarationHandle,
 ) -> FormattingResult<()> {
-    let c_type = col.collection_class.to_c_type();
-    f.writeln(&format!("class {}", col.collection_class.friend_class()))?;
+    let c_type = class.to_c_type();
+    f.writeln(&format!("class {}", class.friend_class()))?;
     f.writeln("{")?;
     f.writeln("public:")?;
     indented(f, |f| {
         f.writeln(&format!(
             "static {}* get({}& value)",
             c_type,
-            col.collection_class.core_cpp_type()
+            class.core_cpp_type()
         ))?;
         blocked(f, |f| f.writeln("return value.self;"))?;
         Ok(())
@@ -194,7 +237,7 @@ fn write_function_wrappers(lib: &Library, f: &mut dyn Printer) -> FormattingResu
     f.writeln("// We don't convert the return type here as there are nuances that require it to be converted at the call site")?;
     namespace(f, "fn", |f| {
         for func in lib.functions() {
-            write_function_wrapper(f, func)?;
+            write_function_wrapper(lib, f, func)?;
         }
         Ok(())
     })?;
@@ -250,9 +293,14 @@ fn write_iterator_methods(
 
         f.newline()?;
 
-        f.writeln(&format!(
-            "this->current = {c_next}(reinterpret_cast<{c_class_type}*>(this->iter));"
-        ))?;
+        let next = format!("{c_next}(reinterpret_cast<{c_class_type}*>(this->iter))");
+        match it.item_type {
+            // strings are returned as const pointers
+            IteratorItemType::String(_) => {
+                f.writeln(&format!("this->current = const_cast<char*>({next});"))?
+            }
+            _ => f.writeln(&format!("this->current = {next};"))?,
+        }
         f.writeln("return this->current;")?;
 
         Ok(())
@@ -273,6 +321,22 @@ fn write_iterator_methods(
             IteratorItemType::Primitive(_) => f.writeln(&format!(
                 "return *reinterpret_cast<{c_value_type}>(this->current);"
             )),
+            IteratorItemType::String(x) => f.writeln(&format!(
+                "return {};",
+                x.to_cpp(format!("reinterpret_cast<{c_value_type}>(this->current)"))
+            )),
+            IteratorItemType::Enum(ref x) => f.writeln(&format!(
+                "return {};",
+                x.to_cpp(format!("*reinterpret_cast<{c_value_type}*>(this->current)"))
+            )),
+            IteratorItemType::Duration(x) => f.writeln(&format!(
+                "return {};",
+                x.to_cpp(format!("*reinterpret_cast<{c_value_type}*>(this->current)"))
+            )),
+            IteratorItemType::Class(ref x) => f.writeln(&format!(
+                "return {};",
+                x.to_cpp(format!("reinterpret_cast<{c_value_type}>(this->current)"))
+            )),
             IteratorItemType::Struct(_) => f.writeln(&format!(
                 "return ::convert::to_cpp(*reinterpret_cast<{c_value_type}*>(this->current));"
             )),
@@ -281,9 +345,62 @@ fn write_iterator_methods(
 
     f.newline()?;
 
+    if let Some(destroy) = &it.destroy_function {
+        write_owned_iterator_methods(f, it, destroy)?;
+    }
+
     Ok(())
 }
 
+fn write_owned_iterator_methods(
+    f: &mut dyn Printer,
+    it: &Handle<AbstractIterator<Validated>>,
+    destroy: &Handle<Function<Validated>>,
+) -> FormattingResult<()> {
+    let c_class_type = it.iter_class.to_c_type();
+    let cpp_class_type = it.iter_class.core_cpp_type();
+    let c_destroy = destroy.to_c_type();
+
+    f.writeln(&format!(
+        "{cpp_class_type}::{cpp_class_type}({cpp_class_type}&& other) noexcept : iter(other.iter), current(other.current), owned(other.owned)"
+    ))?;
+    blocked(f, |f| {
+        f.writeln("other.iter = nullptr;")?;
+        f.writeln("other.current = nullptr;")?;
+        f.writeln("other.owned = false;")
+    })?;
+
+    f.newline()?;
+
+    f.writeln(&format!("{cpp_class_type}::~{cpp_class_type}()"))?;
+    blocked(f, |f| {
+        f.writeln("if(this->owned && this->iter)")?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "{c_destroy}(reinterpret_cast<{c_class_type}*>(this->iter));"
+            ))
+        })
+    })?;
+
+    f.newline()?;
+
+    f.writeln(&format!(
+        "{cpp_class_type}::input_iterator {cpp_class_type}::begin()"
+    ))?;
+    blocked(f, |f| {
+        f.writeln("return input_iterator(this->next() ? this : nullptr);")
+    })?;
+
+    f.newline()?;
+
+    f.writeln(&format!(
+        "{cpp_class_type}::input_iterator {cpp_class_type}::end()"
+    ))?;
+    blocked(f, |f| f.writeln("return input_iterator(nullptr);"))?;
+
+    f.newline()
+}
+
 fn write_static_class_method(
     f: &mut dyn Printer,
     class: &Handle<StaticClass<Validated>>,
@@ -359,37 +476,6 @@ where
     Ok(())
 }
 
-fn get_default_value(default: &ValidatedDefaultValue) -> String {
-    match default {
-        ValidatedDefaultValue::Bool(x) => x.to_string(),
-        ValidatedDefaultValue::Number(x) => match x {
-            NumberValue::U8(x) => x.to_string(),
-            NumberValue::S8(x) => x.to_string(),
-            NumberValue::U16(x) => x.to_string(),
-            NumberValue::S16(x) => x.to_string(),
-            NumberValue::U32(x) => x.to_string(),
-            NumberValue::S32(x) => x.to_string(),
-            NumberValue::U64(x) => x.to_string(),
-            NumberValue::S64(x) => x.to_string(),
-            NumberValue::Float(x) => format!("{x}f"),
-            NumberValue::Double(x) => x.to_string(),
-        },
-        ValidatedDefaultValue::Duration(_, x) => {
-            format!("std::chrono::milliseconds({})", x.as_millis())
-        }
-        ValidatedDefaultValue::Enum(x, variant) => {
-            format!("{}::{}", x.core_cpp_type(), variant)
-        }
-        ValidatedDefaultValue::String(x) => {
-            format!("\"{x}\"")
-        }
-        ValidatedDefaultValue::DefaultStruct(st, ct, c_name) => match ct {
-            InitializerType::Normal => format!("{}()", st.name().camel_case()),
-            InitializerType::Static => format!("{}()", c_name.camel_case()),
-        },
-    }
-}
-
 fn write_struct_constructor<T>(
     f: &mut dyn Printer,
     st: &Handle<Struct<T, Validated>>,
@@ -514,9 +600,22 @@ fn write_class_implementation(
         write_class_method_impl(f, handle, method)?;
     }
 
+    // write the property accessors
+    for method in &handle.property_accessors(None) {
+        write_class_method_impl(f, handle, method)?;
+    }
+
     // write the async methods
     for method in &handle.future_methods {
         write_class_future_method_impl(f, handle, method)?;
+        if let Some(cancel) = method.cancel_method() {
+            write_class_static_method_impl(f, handle, &cancel)?;
+        }
+    }
+
+    // write the stream methods
+    for method in &handle.stream_methods {
+        write_class_stream_method_impl(f, handle, method)?;
     }
 
     f.newline()
@@ -582,6 +681,14 @@ fn write_class_future_method_impl(
     write_class_method_impl_generic(f, handle, &method.name, &method.native_function)
 }
 
+fn write_class_stream_method_impl(
+    f: &mut dyn Printer,
+    handle: &Handle<Class<Validated>>,
+    method: &StreamMethod<Validated>,
+) -> FormattingResult<()> {
+    write_class_method_impl_generic(f, handle, &method.name, &method.native_function)
+}
+
 fn write_class_method_impl_generic(
     f: &mut dyn Printer,
     handle: &Handle<Class<Validated>>,
@@ -670,7 +777,25 @@ fn cpp_function_arg_invocation(args: &[Arg<FunctionArgument, Validated>]) -> Str
 
 const RETURN_VALUE: &str = "_oo_bindgen_return_value";
 
+/// true if converting the argument allocates the native items of a list
+fn requires_list_scope(lib: &Library, arg: &FunctionArgument) -> bool {
+    match arg {
+        FunctionArgument::Struct(UniversalOr::Specific(x)) => x.contains_list(),
+        FunctionArgument::Struct(UniversalOr::Universal(x)) => x.contains_list(),
+        FunctionArgument::StructRef(x) => lib
+            .structs()
+            .any(|s| s.name() == &x.inner.name && s.contains_list()),
+        FunctionArgument::Union(x) => x.contains_list(),
+        FunctionArgument::Collection(x) => requires_list_scope(lib, &x.item_type),
+        FunctionArgument::Map(x) => {
+            requires_list_scope(lib, &x.key_type) || requires_list_scope(lib, &x.value_type)
+        }
+        _ => false,
+    }
+}
+
 fn write_function_wrapper(
+    lib: &Library,
     f: &mut dyn Printer,
     func: &Handle<Function<Validated>>,
 ) -> FormattingResult<()> {
@@ -688,11 +813,50 @@ fn write_function_wrapper(
         );
         f.writeln(&format!("if(_error != {c_success_variant})"))?;
         blocked(f, |f| {
-            f.writeln(&format!(
-                "throw {}({});",
-                err.exception_name.camel_case(),
-                err.inner.to_cpp("_error".to_string())
-            ))
+            let mut args = vec![err.inner.to_cpp("_error".to_string())];
+            if err.message {
+                f.writeln(&format!(
+                    "const auto _detail = {}();",
+                    err.last_message_function_name()
+                ))?;
+                args.push("_detail ? std::string(_detail) : std::string()".to_string());
+            }
+            if let Some(payload) = &err.payload {
+                f.writeln(&format!(
+                    "const auto _payload = {}();",
+                    err.last_payload_function_name()
+                ))?;
+                args.push(format!(
+                    "_payload ? std::optional<{}>(::convert::to_cpp(*_payload)) : std::nullopt",
+                    payload.core_cpp_type()
+                ));
+            }
+            let args = args.join(", ");
+            if err.variant_exceptions.is_empty() {
+                return f.writeln(&format!("throw {}({});", err.core_cpp_type(), args));
+            }
+            f.writeln("switch(_error)")?;
+            blocked(f, |f| {
+                for (x, _) in err.variant_exceptions() {
+                    f.writeln(&format!(
+                        "case {}_{}_{}:",
+                        err.inner.settings.c_ffi_prefix.capital_snake_case(),
+                        err.inner.name.capital_snake_case(),
+                        x.variant.capital_snake_case(),
+                    ))?;
+                    indented(f, |f| {
+                        f.writeln(&format!(
+                            "throw {}({});",
+                            x.exception_name.camel_case(),
+                            args
+                        ))
+                    })?;
+                }
+                f.writeln("default:")?;
+                indented(f, |f| {
+                    f.writeln(&format!("throw {}({});", err.core_cpp_type(), args))
+                })
+            })
         })
     }
 
@@ -757,6 +921,14 @@ fn write_function_wrapper(
 
     blocked(f, |f| {
         let c_func_name = func.to_c_type();
+        // native items of lists in the arguments must outlive the native call
+        if func
+            .arguments
+            .iter()
+            .any(|arg| requires_list_scope(lib, &arg.arg_type))
+        {
+            f.writeln("::convert::ListScope _list_scope;")?;
+        }
         write_shadowed_conversions(f, func)?;
         match func.error_type.get() {
             None => match &func.return_type.get_value() {
@@ -824,6 +996,10 @@ fn write_conversions(
         Statement::InterfaceDefinition(x) => {
             write_cpp_interface_to_native_conversion(f, x.untyped())
         }
+        Statement::UnionDefinition(x) => {
+            write_union_to_native_conversion(f, x)?;
+            write_union_to_cpp_conversion(f, x)
+        }
         Statement::ClassDefinition(x) => write_class_construct_helper(f, x),
         Statement::IteratorDeclaration(x) => {
             write_iterator_construct_helper(f, x)?;
@@ -852,7 +1028,27 @@ fn write_iterator_construct_helper(
             handle.friend_class()
         ))
     })?;
-    f.newline()
+    f.newline()?;
+
+    if handle.is_owned() {
+        let signature = format!(
+            "::{}::{} to_cpp({}* self)",
+            handle.settings.c_ffi_prefix,
+            cpp_type,
+            handle.iter_class.to_c_type()
+        );
+        f.writeln(&signature)?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "return ::{}::{}::init_owned(self);",
+                handle.settings.c_ffi_prefix,
+                handle.friend_class()
+            ))
+        })?;
+        f.newline()?;
+    }
+
+    Ok(())
 }
 
 fn write_iterator_to_native_helper(
@@ -975,9 +1171,25 @@ fn write_iterator_friend_class(
             handle.iter_class.to_c_type()
         ))?;
         blocked(f, |f| {
-            f.writeln(&format!("return {}(value);", handle.core_cpp_type()))
+            if handle.is_owned() {
+                // iterators passed to callbacks are only borrowed
+                f.writeln(&format!("return {}(value, false);", handle.core_cpp_type()))
+            } else {
+                f.writeln(&format!("return {}(value);", handle.core_cpp_type()))
+            }
         })?;
         f.newline()?;
+        if handle.is_owned() {
+            f.writeln(&format!(
+                "static {} init_owned({}* value)",
+                handle.core_cpp_type(),
+                handle.iter_class.to_c_type()
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!("return {}(value, true);", handle.core_cpp_type()))
+            })?;
+            f.newline()?;
+        }
         f.writeln(&format!(
             "static {}* get(const {}& value)",
             c_type,
@@ -1190,6 +1402,95 @@ fn write_enum_to_cpp_conversion(
     f.newline()
 }
 
+fn union_tag_value(handle: &Handle<Union<Validated>>, variant: &UnionVariant<Validated>) -> String {
+    format!(
+        "{}_{}_{}",
+        handle.settings.c_ffi_prefix.capital_snake_case(),
+        handle.tag_name().capital_snake_case(),
+        variant.name.capital_snake_case()
+    )
+}
+
+fn write_union_to_native_conversion(
+    f: &mut dyn Printer,
+    handle: &Handle<Union<Validated>>,
+) -> FormattingResult<()> {
+    let cpp_type = format!(
+        "::{}::{}",
+        handle.settings.c_ffi_prefix,
+        handle.core_cpp_type()
+    );
+    f.writeln(&format!(
+        "{} to_native(const {}& value)",
+        handle.to_c_type(),
+        cpp_type
+    ))?;
+    f.writeln("{")?;
+    indented(f, |f| {
+        f.writeln(&format!("{} result{{}};", handle.to_c_type()))?;
+        // variants are accessed by index as several of them may hold the same struct type
+        f.writeln("switch(value.index())")?;
+        f.writeln("{")?;
+        indented(f, |f| {
+            for (index, v) in handle.variants.iter().enumerate() {
+                f.writeln(&format!("case {index}:"))?;
+                indented(f, |f| {
+                    f.writeln(&format!("result.tag = {};", union_tag_value(handle, v)))?;
+                    f.writeln(&format!(
+                        "result.value.{} = ::convert::to_native(std::get<{}>(value));",
+                        v.name, index
+                    ))?;
+                    f.writeln("break;")
+                })?;
+            }
+            f.writeln("default: throw std::invalid_argument(\"bad union conversion\");")?;
+            Ok(())
+        })?;
+        f.writeln("}")?;
+        f.writeln("return result;")
+    })?;
+    f.writeln("}")?;
+    f.newline()
+}
+
+fn write_union_to_cpp_conversion(
+    f: &mut dyn Printer,
+    handle: &Handle<Union<Validated>>,
+) -> FormattingResult<()> {
+    let cpp_type = format!(
+        "::{}::{}",
+        handle.settings.c_ffi_prefix,
+        handle.core_cpp_type()
+    );
+    f.writeln(&format!(
+        "{} to_cpp(const {}& value)",
+        cpp_type,
+        handle.to_c_type()
+    ))?;
+    f.writeln("{")?;
+    indented(f, |f| {
+        f.writeln("switch(value.tag)")?;
+        f.writeln("{")?;
+        indented(f, |f| {
+            for (index, v) in handle.variants.iter().enumerate() {
+                f.writeln(&format!(
+                    "case {}: return {}(std::in_place_index<{}>, ::convert::to_cpp(value.value.{}));",
+                    union_tag_value(handle, v),
+                    cpp_type,
+                    index,
+                    v.name
+                ))?;
+            }
+            f.writeln("default: throw std::invalid_argument(\"bad union conversion\");")?;
+            Ok(())
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")?;
+
+    f.newline()
+}
+
 fn write_callback_function(
     f: &mut dyn Printer,
     interface: &Handle<Interface<Validated>>,
@@ -1279,6 +1580,7 @@ fn write_cpp_interface_to_native_conversion(
         InterfaceCategory::Synchronous => mut_ref(cpp_type.clone()),
         InterfaceCategory::Asynchronous => unique_ptr(cpp_type.clone()),
         InterfaceCategory::Future => unique_ptr(cpp_type.clone()),
+        InterfaceCategory::Stream => unique_ptr(cpp_type.clone()),
     };
     f.writeln(&format!("{c_type} to_native({argument_type} value)",))?;
     blocked(f, |f| {
@@ -1291,7 +1593,9 @@ fn write_cpp_interface_to_native_conversion(
                 InterfaceCategory::Synchronous => {
                     f.writeln("[](void*){}, // nothing to free")?;
                 }
-                InterfaceCategory::Asynchronous | InterfaceCategory::Future => {
+                InterfaceCategory::Asynchronous
+                | InterfaceCategory::Future
+                | InterfaceCategory::Stream => {
                     f.writeln(&format!(
                         "[](void* ctx) {{ delete reinterpret_cast<{cpp_type}*>(ctx); }},"
                     ))?;
@@ -1301,7 +1605,9 @@ fn write_cpp_interface_to_native_conversion(
                 InterfaceCategory::Synchronous => {
                     f.writeln("&value // the pointer will outlive the callbacks")?;
                 }
-                InterfaceCategory::Asynchronous | InterfaceCategory::Future => {
+                InterfaceCategory::Asynchronous
+                | InterfaceCategory::Future
+                | InterfaceCategory::Stream => {
                     f.writeln("value.release()")?;
                 }
             }
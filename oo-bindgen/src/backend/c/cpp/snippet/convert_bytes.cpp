@@ -0,0 +1,12 @@
+
+std::vector<uint8_t> to_cpp(const oo_bindgen_byte_slice_t& value) {
+    if(value.value == nullptr) {
+        return std::vector<uint8_t>();
+    }
+    return std::vector<uint8_t>(value.value, value.value + value.len);
+}
+
+oo_bindgen_byte_slice_t to_native(const std::vector<uint8_t>& value) {
+    return { value.data(), value.size() };
+}
+
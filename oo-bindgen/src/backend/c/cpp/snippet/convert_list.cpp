@@ -0,0 +1,43 @@
+
+// native items of the lists converted on this thread, released by the ListScope active when they were allocated
+static std::vector<std::shared_ptr<void>>& list_storage() {
+    thread_local std::vector<std::shared_ptr<void>> storage;
+    return storage;
+}
+
+// releases the native items of the lists allocated during its lifetime
+class ListScope final {
+    const size_t size;
+public:
+    ListScope() : size(list_storage().size()) {}
+    ~ListScope() {
+        auto& storage = list_storage();
+        storage.erase(storage.begin() + size, storage.end());
+    }
+    ListScope(const ListScope&) = delete;
+    ListScope& operator=(const ListScope&) = delete;
+};
+
+template <class L, class T, class F>
+L to_native_list(const std::vector<T>& values, F convert) {
+    using N = decltype(convert(values.front()));
+    if(values.empty()) {
+        return L { nullptr, 0 };
+    }
+    std::shared_ptr<N> items(new N[values.size()], std::default_delete<N[]>());
+    for(size_t i = 0; i < values.size(); ++i) {
+        items.get()[i] = convert(values[i]);
+    }
+    list_storage().push_back(items);
+    return L { items.get(), values.size() };
+}
+
+template <class L, class F>
+auto to_cpp_list(const L& list, F convert) -> std::vector<decltype(convert(*list.items))> {
+    std::vector<decltype(convert(*list.items))> values;
+    values.reserve(list.count);
+    for(size_t i = 0; i < list.count; ++i) {
+        values.push_back(convert(list.items[i]));
+    }
+    return values;
+}
@@ -0,0 +1,27 @@
+
+template <class N, class T, class F>
+N to_native_optional(const std::optional<T>& value, F convert) {
+    N result{};
+    if(value) {
+        result.has_value = true;
+        result.value = convert(*value);
+    }
+    return result;
+}
+
+template <class N, class F>
+auto to_cpp_optional(const N& value, F convert) -> std::optional<decltype(convert(value.value))> {
+    if(value.has_value) {
+        return convert(value.value);
+    }
+    return std::nullopt;
+}
+
+
+template <class T, class F>
+auto to_cpp_optional_ref(const T* value, F convert) -> std::optional<decltype(convert(value))> {
+    if(value) {
+        return convert(value);
+    }
+    return std::nullopt;
+}
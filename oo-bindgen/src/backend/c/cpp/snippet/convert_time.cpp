@@ -15,3 +15,43 @@ uint64_t to_milli_sec_u64(std::chrono::steady_clock::duration value) {
     return std::chrono::duration_cast<std::chrono::milliseconds>(value).count();
 }
 
+
+std::chrono::steady_clock::duration from_micro_sec_u64(uint64_t value) {
+    return std::chrono::microseconds(value);
+}
+
+std::chrono::steady_clock::duration from_nano_sec_u64(uint64_t value) {
+    return std::chrono::nanoseconds(value);
+}
+
+uint64_t to_micro_sec_u64(std::chrono::steady_clock::duration value) {
+    return std::chrono::duration_cast<std::chrono::microseconds>(value).count();
+}
+
+uint64_t to_nano_sec_u64(std::chrono::steady_clock::duration value) {
+    return std::chrono::duration_cast<std::chrono::nanoseconds>(value).count();
+}
+
+std::chrono::steady_clock::duration from_milli_sec_i64(int64_t value) {
+    return std::chrono::milliseconds(value);
+}
+
+int64_t to_milli_sec_i64(std::chrono::steady_clock::duration value) {
+    return std::chrono::duration_cast<std::chrono::milliseconds>(value).count();
+}
+
+std::chrono::duration<double> from_sec_f64(double value) {
+    return std::chrono::duration<double>(value);
+}
+
+double to_sec_f64(std::chrono::duration<double> value) {
+    return value.count();
+}
+
+std::chrono::system_clock::time_point from_epoch_milli_sec_i64(int64_t value) {
+    return std::chrono::system_clock::time_point(std::chrono::duration_cast<std::chrono::system_clock::duration>(std::chrono::milliseconds(value)));
+}
+
+int64_t to_epoch_milli_sec_i64(std::chrono::system_clock::time_point value) {
+    return std::chrono::duration_cast<std::chrono::milliseconds>(value.time_since_epoch()).count();
+}
@@ -0,0 +1,104 @@
+/// @brief Iterator over @ref <iter_type> instances that may be returned from functions
+///
+/// When returned from a function, the iterator owns the underlying native iterator and releases it when destroyed.
+///
+/// Values can be extracted with the same pattern as other iterators:
+///
+/// while(iter.next()) {
+///   <iter_type> value = iter.get();
+/// }
+///
+/// or with a range-based for loop:
+///
+/// for(auto value : iter) {
+///   ...
+/// }
+///
+/// @warning The underlying sequence can only be traversed once, regardless of the pattern used.
+///
+/// Calling get() when no value is available will throw std::logic_error
+class <name> final {
+
+    friend class Cpp<name>Friend;
+
+    // underlying opaque c type
+    void* iter;
+    // pointer to the last retrieved c value
+    void* current;
+    // true if the underlying c type is destroyed with this instance
+    bool owned;
+
+    // internal constructor
+    <name>(void* iter, bool owned) : iter(iter), current(nullptr), owned(owned) {}
+
+    <name>() = delete; // no default construction
+    <name>(const <name>&) = delete; // no copies
+    <name>& operator=(const <name>&) = delete; // no self-assignment
+    <name>& operator=(<name>&&) = delete; // no move self-assignment
+
+public:
+
+    /// @brief Single-pass input iterator used by range-based for loops
+    class input_iterator final {
+
+        friend class <name>;
+
+        // iterator being traversed or nullptr when the end has been reached
+        <name>* parent;
+
+        input_iterator(<name>* parent) : parent(parent) {}
+
+    public:
+
+        using iterator_category = std::input_iterator_tag;
+        using value_type = <iter_type>;
+        using difference_type = std::ptrdiff_t;
+        using pointer = const <iter_type>*;
+        using reference = <iter_type>;
+
+        /// @brief retrieve the current value
+        /// @return current value of the iterator
+        <iter_type> operator*() const { return parent->get(); }
+
+        /// @brief move to the next value
+        /// @return reference to this iterator
+        input_iterator& operator++()
+        {
+            if(!parent->next()) {
+                parent = nullptr;
+            }
+            return *this;
+        }
+
+        /// @brief compare two iterators
+        /// @return true if both iterators are at the same position
+        bool operator==(const input_iterator& other) const { return parent == other.parent; }
+
+        /// @brief compare two iterators
+        /// @return true if the iterators are at different positions
+        bool operator!=(const input_iterator& other) const { return parent != other.parent; }
+    };
+
+    /// @brief move constructor for the iterator
+    <name>(<name>&& other) noexcept;
+
+    /// @brief destroy the underlying native iterator if it is owned
+    ~<name>();
+
+    /// @brief move the iterator to the next value
+    /// @return true if another value is available
+    bool next();
+
+    /// @brief retrieve the current value
+    /// @return current value of the iterator
+    /// @throws std::logic_error if the last call to next() returns false or next() has never been called
+    <iter_type> get();
+
+    /// @brief begin traversing the values by retrieving the first one
+    /// @return input iterator positioned on the first value, or equal to end() if there are no values
+    input_iterator begin();
+
+    /// @brief end of the traversal
+    /// @return input iterator marking the end of the values
+    input_iterator end();
+};
@@ -0,0 +1,111 @@
+/// @brief Blocking iterator over the items delivered to a @ref <name>
+///
+/// Pass the interface returned by handler() to a method producing the stream, then extract the items:
+///
+/// while(iter.next()) {
+///   <item_type> value = iter.get();
+/// }
+///
+/// next() blocks until an item is available or the stream ends. When the stream fails,
+/// next() throws @ref <exception> once all the items received before the failure have been consumed.
+class <name>Iterator final {
+
+    // state shared with the handler which may outlive the iterator
+    struct State {
+        std::mutex mutex;
+        std::condition_variable cond;
+        std::deque<<item_type>> items;
+        bool complete = false;
+        bool dropped = false;
+        std::optional<<error_type>> error;
+    };
+
+    // implementation of the interface that queues the items
+    class Handler final : public <name> {
+        std::shared_ptr<State> state;
+
+    public:
+        explicit Handler(std::shared_ptr<State> state) : state(std::move(state)) {}
+
+        ~Handler() override
+        {
+            std::lock_guard<std::mutex> lock(state->mutex);
+            state->dropped = true;
+            state->cond.notify_all();
+        }
+
+        void <item_callback>(<item_arg_type> <item_param>) override
+        {
+            std::lock_guard<std::mutex> lock(state->mutex);
+            state->items.push_back(<item_type>(<item_param>));
+            state->cond.notify_all();
+        }
+
+        void <complete_callback>() override
+        {
+            std::lock_guard<std::mutex> lock(state->mutex);
+            state->complete = true;
+            state->cond.notify_all();
+        }
+
+        void <failure_callback>(<error_type> <error_param>) override
+        {
+            std::lock_guard<std::mutex> lock(state->mutex);
+            state->error = <error_param>;
+            state->cond.notify_all();
+        }
+    };
+
+    std::shared_ptr<State> state;
+    // last retrieved item
+    std::optional<<item_type>> current;
+
+public:
+
+    /// @brief construct an iterator waiting for a stream
+    <name>Iterator() : state(std::make_shared<State>()) {}
+
+    <name>Iterator(const <name>Iterator&) = delete; // no copies
+    <name>Iterator& operator=(const <name>Iterator&) = delete; // no self-assignment
+
+    /// @brief create the interface to pass to the method producing the stream
+    /// @return implementation of @ref <name> feeding this iterator
+    std::unique_ptr<<name>> handler()
+    {
+        return std::make_unique<Handler>(state);
+    }
+
+    /// @brief wait for the next item of the stream
+    /// @return true if another item is available, false if the stream completed
+    /// @throws <exception> if the stream failed
+    /// @throws std::logic_error if the stream was dropped before it ended
+    bool next()
+    {
+        std::unique_lock<std::mutex> lock(state->mutex);
+        state->cond.wait(lock, [this]() { return !state->items.empty() || state->complete || state->error || state->dropped; });
+        if(!state->items.empty()) {
+            current.emplace(std::move(state->items.front()));
+            state->items.pop_front();
+            return true;
+        }
+        current.reset();
+        if(state->error) {
+            throw <exception>(*state->error);
+        }
+        if(!state->complete) {
+            throw std::logic_error("stream dropped before it ended");
+        }
+        return false;
+    }
+
+    /// @brief retrieve the current item
+    /// @return current item of the stream
+    /// @throws std::logic_error if the last call to next() returns false or next() has never been called
+    <item_type> get()
+    {
+        if(!current) {
+            throw std::logic_error("no item available");
+        }
+        return *current;
+    }
+};
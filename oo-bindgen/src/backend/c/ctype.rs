@@ -1,5 +1,21 @@
 use crate::model::*;
 
+/// C type used for all byte buffers, shared across every library generated by oo-bindgen
+pub(crate) const BYTE_SLICE_C_TYPE: &str = "oo_bindgen_byte_slice_t";
+
+/// C type used for optional primitive values, shared across every library generated by oo-bindgen
+pub(crate) fn optional_primitive_c_type(x: Primitive) -> String {
+    format!("oo_bindgen_optional_{}_t", x.get_c_rust_type())
+}
+
+/// C type used for optional values of an enum
+pub(crate) fn optional_enum_c_type<D>(x: &Handle<Enum<D>>) -> String
+where
+    D: DocReference,
+{
+    format!("{}_optional_{}_t", x.settings.c_ffi_prefix, x.name)
+}
+
 pub(crate) trait CType {
     fn to_c_type(&self) -> String;
 }
@@ -33,6 +49,33 @@ impl CType for StringType {
     }
 }
 
+impl CType for BytesType {
+    fn to_c_type(&self) -> String {
+        BYTE_SLICE_C_TYPE.to_string()
+    }
+}
+
+impl CType for Optional<BasicType> {
+    fn to_c_type(&self) -> String {
+        match &self.inner {
+            BasicType::Primitive(x) => optional_primitive_c_type(*x),
+            BasicType::Duration(x) => optional_primitive_c_type(x.primitive()),
+            BasicType::Timestamp(x) => optional_primitive_c_type(x.primitive()),
+            BasicType::Enum(x) => optional_enum_c_type(x),
+            BasicType::Flags(x) => optional_primitive_c_type(x.flags_type.into()),
+        }
+    }
+}
+
+impl<D> CType for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn to_c_type(&self) -> String {
+        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name)
+    }
+}
+
 impl<D> CType for Handle<AbstractIterator<D>>
 where
     D: DocReference,
@@ -47,6 +90,9 @@ impl CType for CallbackArgument {
         match self {
             CallbackArgument::Basic(x) => x.to_c_type(),
             CallbackArgument::String(x) => x.to_c_type(),
+            CallbackArgument::Bytes(x) => x.to_c_type(),
+            CallbackArgument::Union(x) => x.to_c_type(),
+            CallbackArgument::Optional(x) => x.to_c_type(),
             CallbackArgument::Iterator(x) => x.to_c_type(),
             CallbackArgument::Struct(x) => x.to_c_type(),
             CallbackArgument::Class(x) => pointer(x).to_c_type(),
@@ -59,10 +105,25 @@ impl CType for FunctionReturnValue {
         match self {
             FunctionReturnValue::Basic(x) => x.to_c_type(),
             FunctionReturnValue::String(x) => x.to_c_type(),
+            FunctionReturnValue::Bytes(x) => x.to_c_type(),
+            FunctionReturnValue::Union(x) => x.to_c_type(),
             FunctionReturnValue::ClassRef(x) => pointer(x).to_c_type(),
+            FunctionReturnValue::Iterator(x) => x.to_c_type(),
             FunctionReturnValue::Struct(x) => x.to_c_type(),
             FunctionReturnValue::StructRef(x) => pointer(x.untyped()).to_c_type(),
             FunctionReturnValue::PrimitiveRef(x) => pointer(&x.inner).to_c_type(),
+            FunctionReturnValue::ValueRef(x) => x.to_c_type(),
+        }
+    }
+}
+
+impl CType for ValueRef {
+    fn to_c_type(&self) -> String {
+        match self {
+            // strings are already nullable pointers
+            ValueRef::String(x) => x.to_c_type(),
+            ValueRef::Enum(x) => pointer(x).to_c_type(),
+            ValueRef::Duration(x) => pointer(&x.primitive()).to_c_type(),
         }
     }
 }
@@ -114,6 +175,15 @@ where
     }
 }
 
+impl<D> CType for Handle<Flags<D>>
+where
+    D: DocReference,
+{
+    fn to_c_type(&self) -> String {
+        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name)
+    }
+}
+
 impl CType for ClassDeclarationHandle {
     fn to_c_type(&self) -> String {
         format!("{}_{}_t", self.settings.c_ffi_prefix, self.name)
@@ -138,11 +208,39 @@ where
     }
 }
 
+impl<D> CType for Handle<Map<D>>
+where
+    D: DocReference,
+{
+    fn to_c_type(&self) -> String {
+        self.map_class.to_c_type()
+    }
+}
+
+impl CType for ListItemType {
+    fn to_c_type(&self) -> String {
+        match self {
+            Self::Basic(x) => x.to_c_type(),
+            Self::Struct(x) => x.to_c_type(),
+        }
+    }
+}
+
+impl CType for ListHandle {
+    fn to_c_type(&self) -> String {
+        format!("{}_{}_t", self.settings.c_ffi_prefix, self.name)
+    }
+}
+
 impl CType for IteratorItemType {
     fn to_c_type(&self) -> String {
         match self {
             IteratorItemType::Struct(x) => x.to_c_type(),
             IteratorItemType::Primitive(x) => pointer(x).to_c_type(),
+            IteratorItemType::String(x) => x.to_c_type(),
+            IteratorItemType::Enum(x) => x.to_c_type(),
+            IteratorItemType::Duration(x) => x.primitive().to_c_type(),
+            IteratorItemType::Class(x) => pointer(x).to_c_type(),
         }
     }
 }
@@ -178,8 +276,10 @@ impl CType for BasicType {
     fn to_c_type(&self) -> String {
         match self {
             Self::Primitive(x) => x.to_c_type(),
-            Self::Duration(_) => "uint64_t".to_string(),
+            Self::Duration(x) => x.primitive().to_c_type(),
+            Self::Timestamp(x) => x.primitive().to_c_type(),
             Self::Enum(handle) => handle.to_c_type(),
+            Self::Flags(handle) => handle.to_c_type(),
         }
     }
 }
@@ -198,8 +298,11 @@ impl CType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.to_c_type(),
             FunctionArgStructField::String(x) => x.to_c_type(),
+            FunctionArgStructField::Bytes(x) => x.to_c_type(),
+            FunctionArgStructField::Optional(x) => x.to_c_type(),
             FunctionArgStructField::Interface(x) => x.inner.to_c_type(),
             FunctionArgStructField::Struct(x) => x.to_c_type(),
+            FunctionArgStructField::List(x) => x.to_c_type(),
         }
     }
 }
@@ -222,6 +325,8 @@ impl CType for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => pointer(x).to_c_type(),
             CallbackArgStructField::Struct(x) => x.to_c_type(),
             CallbackArgStructField::String(x) => x.to_c_type(),
+            CallbackArgStructField::Bytes(x) => x.to_c_type(),
+            CallbackArgStructField::Optional(x) => x.to_c_type(),
         }
     }
 }
@@ -232,6 +337,9 @@ impl CType for UniversalStructField {
             UniversalStructField::Basic(x) => x.to_c_type(),
             UniversalStructField::Struct(x) => x.to_c_type(),
             UniversalStructField::String(x) => x.to_c_type(),
+            UniversalStructField::Bytes(x) => x.to_c_type(),
+            UniversalStructField::Optional(x) => x.to_c_type(),
+            UniversalStructField::List(x) => x.to_c_type(),
         }
     }
 }
@@ -241,7 +349,11 @@ impl CType for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.to_c_type(),
             FunctionArgument::String(x) => x.to_c_type(),
+            FunctionArgument::Bytes(x) => x.to_c_type(),
+            FunctionArgument::Union(x) => x.to_c_type(),
+            FunctionArgument::Optional(x) => x.to_c_type(),
             FunctionArgument::Collection(x) => pointer(x).to_c_type(),
+            FunctionArgument::Map(x) => pointer(x).to_c_type(),
             FunctionArgument::Struct(x) => x.to_c_type(),
             FunctionArgument::StructRef(x) => pointer(&x.inner).to_c_type(),
             FunctionArgument::ClassRef(x) => pointer(x).to_c_type(),
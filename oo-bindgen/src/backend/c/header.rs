@@ -46,9 +46,13 @@ pub(crate) fn generate_c_header(lib: &Library, path: &Path) -> FormattingResult<
 
         // Standard includes needed
         f.writeln("#include <stdbool.h>")?;
+        f.writeln("#include <stddef.h>")?;
         f.writeln("#include <stdint.h>")?;
         f.newline()?;
 
+        write_layout_assert_macros(f, &uppercase_name)?;
+        f.newline()?;
+
         doxygen(f, |f| {
             // Doxygen needs the @file tag
             f.writeln(&format!(
@@ -78,6 +82,12 @@ pub(crate) fn generate_c_header(lib: &Library, path: &Path) -> FormattingResult<
         })?;
         f.newline()?;
 
+        write_byte_slice_definition(f)?;
+        f.newline()?;
+
+        write_optional_primitive_definitions(f)?;
+        f.newline()?;
+
         // Iterate through each statement and print them
         for statement in lib.statements() {
             match statement {
@@ -93,9 +103,14 @@ pub(crate) fn generate_c_header(lib: &Library, path: &Path) -> FormattingResult<
                     StructType::Universal(x) => write_struct_definition(f, x)?,
                 },
                 Statement::EnumDefinition(handle) => write_enum_definition(f, handle)?,
+                Statement::FlagsDefinition(handle) => write_flags_definition(f, handle)?,
+                Statement::UnionDefinition(handle) => write_union_definition(f, handle)?,
+                Statement::ErrorType(handle) => write_error_detail_functions(f, handle)?,
+                Statement::ListDeclaration(handle) => write_list_definition(f, handle)?,
                 Statement::ClassDeclaration(handle) => write_class_declaration(f, handle, lib)?,
                 Statement::FunctionDefinition(handle) => write_function(f, handle)?,
                 Statement::InterfaceDefinition(handle) => write_interface(f, handle.untyped())?,
+                Statement::ClassDefinition(handle) => write_interface_adapters(f, handle)?,
                 _ => (),
             }
             f.newline()?;
@@ -105,24 +120,142 @@ pub(crate) fn generate_c_header(lib: &Library, path: &Path) -> FormattingResult<
     })
 }
 
+fn write_byte_slice_definition(f: &mut dyn Printer) -> FormattingResult<()> {
+    // the type is shared by all the libraries, so guard against multiple definitions
+    f.writeln("#ifndef OO_BINDGEN_BYTE_SLICE_DEFINED")?;
+    f.writeln("#define OO_BINDGEN_BYTE_SLICE_DEFINED")?;
+    doxygen(f, |f| {
+        f.writeln("@brief Borrowed sequence of bytes")?;
+        f.newline()?;
+        f.writeln("The memory is borrowed and must be copied if it needs to outlive the call in which it was received")
+    })?;
+    f.writeln(&format!("typedef struct {BYTE_SLICE_C_TYPE}"))?;
+    f.writeln("{")?;
+    indented(f, |f| {
+        doxygen(f, |f| f.writeln("@brief Pointer to the first byte"))?;
+        f.writeln("const uint8_t* value;")?;
+        doxygen(f, |f| f.writeln("@brief Number of bytes"))?;
+        f.writeln("size_t len;")
+    })?;
+    f.writeln(&format!("}} {BYTE_SLICE_C_TYPE};"))?;
+    f.writeln("#endif")
+}
+
+fn write_optional_definition(
+    f: &mut dyn Printer,
+    name: &str,
+    value_type: &str,
+    brief: &str,
+) -> FormattingResult<()> {
+    doxygen(f, |f| f.writeln(&format!("@brief {brief}")))?;
+    f.writeln(&format!("typedef struct {name}"))?;
+    f.writeln("{")?;
+    indented(f, |f| {
+        doxygen(f, |f| f.writeln("@brief true if the value is present"))?;
+        f.writeln("bool has_value;")?;
+        doxygen(f, |f| {
+            f.writeln("@brief Value, only valid if has_value is true")
+        })?;
+        f.writeln(&format!("{value_type} value;"))
+    })?;
+    f.writeln(&format!("}} {name};"))
+}
+
+fn write_optional_primitive_definitions(f: &mut dyn Printer) -> FormattingResult<()> {
+    const PRIMITIVES: [Primitive; 11] = [
+        Primitive::Bool,
+        Primitive::U8,
+        Primitive::S8,
+        Primitive::U16,
+        Primitive::S16,
+        Primitive::U32,
+        Primitive::S32,
+        Primitive::U64,
+        Primitive::S64,
+        Primitive::Float,
+        Primitive::Double,
+    ];
+
+    // the types are shared by all the libraries, so guard against multiple definitions
+    f.writeln("#ifndef OO_BINDGEN_OPTIONAL_DEFINED")?;
+    f.writeln("#define OO_BINDGEN_OPTIONAL_DEFINED")?;
+    for primitive in PRIMITIVES {
+        write_optional_definition(
+            f,
+            &optional_primitive_c_type(primitive),
+            &primitive.to_c_type(),
+            &format!("Optional {} value", primitive.to_c_type()),
+        )?;
+        f.newline()?;
+    }
+    f.writeln("#endif")
+}
+
 fn write_constants_definition(
     f: &mut dyn Printer,
     handle: &Handle<ConstantSet<Validated>>,
 ) -> FormattingResult<()> {
-    fn get_constant_value(value: ConstantValue) -> String {
+    fn get_integer_value(macro_name: &str, value: &PrimitiveValue, rep: Representation) -> String {
+        // C has no binary literals
+        let rep = match rep {
+            Representation::Binary => Representation::Hex,
+            x => x,
+        };
+        format!(
+            "{macro_name}({})",
+            format_integer(integer_value(value).unwrap(), rep)
+        )
+    }
+
+    fn get_constant_value(value: &ConstantValue) -> String {
         match value {
-            ConstantValue::U8(value, Representation::Hex) => format!("0x{value:02X?}"),
+            ConstantValue::Primitive(x, rep) => match x {
+                PrimitiveValue::Bool(x) => x.to_string(),
+                PrimitiveValue::U8(_) => get_integer_value("UINT8_C", x, *rep),
+                PrimitiveValue::S8(_) => get_integer_value("INT8_C", x, *rep),
+                PrimitiveValue::U16(_) => get_integer_value("UINT16_C", x, *rep),
+                PrimitiveValue::S16(_) => get_integer_value("INT16_C", x, *rep),
+                PrimitiveValue::U32(_) => get_integer_value("UINT32_C", x, *rep),
+                PrimitiveValue::S32(_) => get_integer_value("INT32_C", x, *rep),
+                PrimitiveValue::U64(_) => get_integer_value("UINT64_C", x, *rep),
+                PrimitiveValue::S64(_) => get_integer_value("INT64_C", x, *rep),
+                PrimitiveValue::Float(x) => format!("{x:?}f"),
+                PrimitiveValue::Double(x) => format!("{x:?}"),
+            },
+            ConstantValue::Duration(x) => match x {
+                DurationValue::Milliseconds(x)
+                | DurationValue::Seconds(x)
+                | DurationValue::Microseconds(x)
+                | DurationValue::Nanoseconds(x) => format!("UINT64_C({x})"),
+                DurationValue::SignedMilliseconds(x) => format!("INT64_C({x})"),
+            },
+            ConstantValue::String(x) => quoted_string(x),
+            ConstantValue::Enum(x) => format!(
+                "{}_{}_{}",
+                x.handle.settings.c_ffi_prefix.capital_snake_case(),
+                x.handle.name.capital_snake_case(),
+                x.variant.name.capital_snake_case()
+            ),
         }
     }
 
     for item in &handle.values {
-        doxygen(f, |f| doxygen_print(f, &item.doc))?;
+        doxygen(f, |f| {
+            doxygen_print(f, &item.doc)?;
+            if let ConstantValue::Duration(x) = item.value {
+                f.writeln(&format!(
+                    "@note The unit is {}",
+                    DurationType::from(x).unit()
+                ))?;
+            }
+            Ok(())
+        })?;
         f.writeln(&format!(
             "#define {}_{}_{} {}",
             handle.settings.c_ffi_prefix.capital_snake_case(),
             handle.name.capital_snake_case(),
             item.name.capital_snake_case(),
-            get_constant_value(item.value)
+            get_constant_value(&item.value)
         ))?;
     }
     Ok(())
@@ -133,7 +266,7 @@ fn write_struct_definition<T>(
     handle: &Handle<Struct<T, Validated>>,
 ) -> FormattingResult<()>
 where
-    T: StructFieldType + TypeExtractor + CType,
+    T: StructFieldType + TypeExtractor + CType + TypeLayout,
 {
     let doc = match handle.visibility {
         Visibility::Public => handle.doc.clone(),
@@ -153,8 +286,15 @@ where
             doxygen(f, |f| {
                 doxygen_print(f, &element.doc)?;
 
-                if let Some(t) = &element.field_type.get_duration_type() {
-                    f.writeln(&format!("@note The unit is {}", t.unit()))?;
+                if let Some(unit) = element.field_type.get_basic_type().and_then(|x| x.unit()) {
+                    f.writeln(&format!("@note The unit is {unit}"))?;
+                }
+                if let Some(clamping) = element
+                    .field_type
+                    .get_basic_type()
+                    .and_then(|x| x.clamping())
+                {
+                    f.writeln(&format!("@note {clamping}"))?;
                 }
 
                 Ok(())
@@ -169,6 +309,8 @@ where
     })?;
     f.writeln(&format!("}} {};", handle.to_c_type()))?;
 
+    write_layout_asserts(f, handle)?;
+
     // user should never try to initialize opaque structs, so don't suggest this is OK
     if handle.visibility != Visibility::Private {
         f.newline()?;
@@ -181,6 +323,62 @@ where
     Ok(())
 }
 
+fn write_layout_assert_macros(f: &mut dyn Printer, uppercase_name: &str) -> FormattingResult<()> {
+    f.writeln(
+        "// the layout of the structs is asserted against the Rust definitions on 64-bit platforms",
+    )?;
+    f.writeln("#if UINTPTR_MAX == UINT64_MAX")?;
+    f.writeln("#if defined(__cplusplus)")?;
+    f.writeln(&format!(
+        "#define {uppercase_name}_LAYOUT_ASSERT(condition) static_assert(condition, #condition)"
+    ))?;
+    f.writeln(&format!(
+        "#define {uppercase_name}_ALIGNOF(type) alignof(type)"
+    ))?;
+    f.writeln("#elif defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L")?;
+    f.writeln(&format!(
+        "#define {uppercase_name}_LAYOUT_ASSERT(condition) _Static_assert(condition, #condition)"
+    ))?;
+    f.writeln(&format!(
+        "#define {uppercase_name}_ALIGNOF(type) _Alignof(type)"
+    ))?;
+    f.writeln("#endif")?;
+    f.writeln("#endif")
+}
+
+fn write_layout_asserts<T>(
+    f: &mut dyn Printer,
+    handle: &Handle<Struct<T, Validated>>,
+) -> FormattingResult<()>
+where
+    T: StructFieldType + TypeLayout,
+{
+    // C doesn't allow empty structs
+    if handle.fields.is_empty() {
+        return Ok(());
+    }
+
+    let uppercase_name = handle.settings().c_ffi_prefix.to_uppercase();
+    let struct_name = handle.to_c_type();
+    let layout = struct_layout(handle);
+
+    f.writeln(&format!("#ifdef {uppercase_name}_LAYOUT_ASSERT"))?;
+    f.writeln(&format!(
+        "{uppercase_name}_LAYOUT_ASSERT(sizeof({struct_name}) == {});",
+        layout.layout.size
+    ))?;
+    f.writeln(&format!(
+        "{uppercase_name}_LAYOUT_ASSERT({uppercase_name}_ALIGNOF({struct_name}) == {});",
+        layout.layout.align
+    ))?;
+    for (field, offset) in &layout.offsets {
+        f.writeln(&format!(
+            "{uppercase_name}_LAYOUT_ASSERT(offsetof({struct_name}, {field}) == {offset});"
+        ))?;
+    }
+    f.writeln("#endif")
+}
+
 fn get_default_value(default: &ValidatedDefaultValue) -> String {
     match default {
         ValidatedDefaultValue::Bool(x) => x.to_string(),
@@ -221,10 +419,7 @@ fn get_default_value_doc(default: &ValidatedDefaultValue) -> String {
     match default {
         ValidatedDefaultValue::Bool(x) => format!("@p {x}"),
         ValidatedDefaultValue::Number(x) => x.to_string(),
-        ValidatedDefaultValue::Duration(DurationType::Milliseconds, x) => {
-            format!("{}ms", x.as_millis())
-        }
-        ValidatedDefaultValue::Duration(DurationType::Seconds, x) => format!("{}s", x.as_secs()),
+        ValidatedDefaultValue::Duration(t, x) => t.get_value_abbreviation(*x),
         ValidatedDefaultValue::Enum(x, variant) => {
             format!(
                 "@ref {}_{}_{}",
@@ -316,6 +511,49 @@ where
     Ok(())
 }
 
+fn write_flags_definition(
+    f: &mut dyn Printer,
+    handle: &Handle<Flags<Validated>>,
+) -> FormattingResult<()> {
+    let primitive = Primitive::from(handle.flags_type);
+    let literal_macro = match handle.flags_type {
+        FlagsType::U8 => "UINT8_C",
+        FlagsType::U16 => "UINT16_C",
+        FlagsType::U32 => "UINT32_C",
+        FlagsType::U64 => "UINT64_C",
+    };
+
+    doxygen(f, |f| {
+        doxygen_print(f, &handle.doc)?;
+        f.writeln(&format!(
+            "@note Combine the {}_{}_* bits with the bitwise OR operator",
+            handle.settings.c_ffi_prefix.capital_snake_case(),
+            handle.name.capital_snake_case()
+        ))
+    })?;
+    f.writeln(&format!(
+        "typedef {} {};",
+        primitive.to_c_type(),
+        handle.to_c_type()
+    ))?;
+
+    f.newline()?;
+
+    for bit in &handle.bits {
+        doxygen(f, |f| doxygen_print(f, &bit.doc))?;
+        f.writeln(&format!(
+            "#define {}_{}_{} {}({})",
+            handle.settings.c_ffi_prefix.capital_snake_case(),
+            handle.name.capital_snake_case(),
+            bit.name.capital_snake_case(),
+            literal_macro,
+            format_integer(bit.value.into(), Representation::Hex)
+        ))?;
+    }
+
+    Ok(())
+}
+
 fn write_enum_definition(
     f: &mut dyn Printer,
     handle: &Handle<Enum<Validated>>,
@@ -370,12 +608,183 @@ fn write_enum_definition(
                 handle.name
             ))
         })
-    })
+    })?;
+
+    f.newline()?;
+
+    write_optional_definition(
+        f,
+        &optional_enum_c_type(handle),
+        &handle.to_c_type(),
+        &format!("Optional @ref {} value", handle.to_c_type()),
+    )
+}
+
+fn write_list_definition(f: &mut dyn Printer, handle: &ListHandle) -> FormattingResult<()> {
+    let item_type = handle.item_type.to_c_type();
+    doxygen(f, |f| {
+        f.writeln(&format!("@brief Borrowed list of {item_type} values"))?;
+        f.newline()?;
+        if let Some(unit) = handle.item_type.get_basic_type().and_then(|x| x.unit()) {
+            f.writeln(&format!("@note The unit of the items is {unit}"))?;
+            f.newline()?;
+        }
+        f.writeln("The memory is borrowed and must be copied if it needs to outlive the call in which it was received")
+    })?;
+    f.writeln(&format!("typedef struct {}", handle.to_c_type()))?;
+    f.writeln("{")?;
+    indented(f, |f| {
+        doxygen(f, |f| f.writeln("@brief Pointer to the first item"))?;
+        f.writeln(&format!("const {item_type}* items;"))?;
+        doxygen(f, |f| f.writeln("@brief Number of items"))?;
+        f.writeln("size_t count;")
+    })?;
+    f.writeln(&format!("}} {};", handle.to_c_type()))
+}
+
+fn write_error_detail_functions(
+    f: &mut dyn Printer,
+    handle: &ErrorType<Validated>,
+) -> FormattingResult<()> {
+    let error_type = handle.inner.to_c_type();
+
+    if handle.message {
+        doxygen(f, |f| {
+            f.writeln(&format!(
+                "@brief Retrieve the message of the last @ref {error_type} returned on the calling thread"
+            ))?;
+            f.newline()?;
+            f.writeln("The string remains valid until another error of this type is returned on the same thread")?;
+            f.newline()?;
+            f.writeln("@return message of the error or NULL if the error didn't have a message")
+        })?;
+        f.writeln(&format!(
+            "const char* {}();",
+            handle.last_message_function_name()
+        ))?;
+    }
+
+    if let Some(payload) = &handle.payload {
+        if handle.message {
+            f.newline()?;
+        }
+        doxygen(f, |f| {
+            f.writeln(&format!(
+                "@brief Retrieve the payload of the last @ref {error_type} returned on the calling thread"
+            ))?;
+            f.newline()?;
+            f.writeln("The struct remains valid until another error of this type is returned on the same thread")?;
+            f.newline()?;
+            f.writeln("@return payload of the error or NULL if the error didn't have a payload")
+        })?;
+        f.writeln(&format!(
+            "const {}* {}();",
+            payload.to_c_type(),
+            handle.last_payload_function_name()
+        ))?;
+    }
+
+    Ok(())
+}
+
+fn write_union_definition(
+    f: &mut dyn Printer,
+    handle: &Handle<Union<Validated>>,
+) -> FormattingResult<()> {
+    let tag_type = format!("{}_{}_t", handle.settings.c_ffi_prefix, handle.tag_name());
+    let tag_value = |variant: &UnionVariant<Validated>| {
+        format!(
+            "{}_{}_{}",
+            handle.settings.c_ffi_prefix.capital_snake_case(),
+            handle.tag_name().capital_snake_case(),
+            variant.name.capital_snake_case()
+        )
+    };
+
+    doxygen(f, |f| {
+        f.writeln(&format!(
+            "@brief Identifies the active variant of @ref {}",
+            handle.to_c_type()
+        ))
+    })?;
+    f.writeln(&format!("typedef enum {tag_type}"))?;
+    f.writeln("{")?;
+    indented(f, |f| {
+        for (index, variant) in handle.variants.iter().enumerate() {
+            doxygen(f, |f| doxygen_print(f, &variant.doc))?;
+            f.writeln(&format!("{} = {},", tag_value(variant), index))?;
+        }
+        Ok(())
+    })?;
+    f.writeln(&format!("}} {tag_type};"))?;
+
+    f.newline()?;
+
+    doxygen(f, |f| doxygen_print(f, &handle.doc))?;
+    f.writeln(&format!("typedef struct {}", handle.to_c_type()))?;
+    f.writeln("{")?;
+    indented(f, |f| {
+        doxygen(f, |f| {
+            f.writeln("@brief Identifies which member of the value union is active")
+        })?;
+        f.writeln(&format!("{tag_type} tag;"))?;
+        doxygen(f, |f| f.writeln("@brief Payload of the active variant"))?;
+        f.writeln("union")?;
+        f.writeln("{")?;
+        indented(f, |f| {
+            for variant in &handle.variants {
+                doxygen(f, |f| doxygen_print(f, &variant.doc))?;
+                f.writeln(&format!("{} {};", variant.value.to_c_type(), variant.name))?;
+            }
+            Ok(())
+        })?;
+        f.writeln("} value;")
+    })?;
+    f.writeln(&format!("}} {};", handle.to_c_type()))?;
+
+    for variant in &handle.variants {
+        f.newline()?;
+        doxygen(f, |f| {
+            f.writeln(&format!(
+                "@brief Construct a @ref {} holding the @ref {} variant",
+                handle.to_c_type(),
+                tag_value(variant)
+            ))?;
+            f.writeln("@param value Payload of the variant")?;
+            f.writeln(&format!(
+                "@returns New instance of @ref {}",
+                handle.to_c_type()
+            ))
+        })?;
+        f.writeln(&format!(
+            "static {} {}_{}_init_{}({} value)",
+            handle.to_c_type(),
+            handle.settings.c_ffi_prefix,
+            handle.name,
+            variant.name,
+            variant.value.to_c_type()
+        ))?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "{} _return_value = {{ {} }};",
+                handle.to_c_type(),
+                tag_value(variant)
+            ))?;
+            f.writeln(&format!("_return_value.value.{} = value;", variant.name))?;
+            f.writeln("return _return_value;")
+        })?;
+    }
+
+    Ok(())
 }
 
 fn get_type_description(item: &IteratorItemType) -> String {
     match item {
         IteratorItemType::Primitive(x) => x.to_c_type(),
+        IteratorItemType::String(x) => x.to_c_type(),
+        IteratorItemType::Enum(x) => x.name.to_string(),
+        IteratorItemType::Duration(x) => x.primitive().to_c_type(),
+        IteratorItemType::Class(x) => x.name.to_string(),
         IteratorItemType::Struct(x) => x.name().to_string(),
     }
 }
@@ -390,21 +799,29 @@ fn write_class_declaration(
         doxygen(f, |f| doxygen_print(f, &class.doc))?;
     }
     if let Some(iterator) = lib.iterators().find(|x| x.name() == &handle.name) {
-        doxygen(f, |f| {
-            doxygen_print(
-                f,
-                &brief(&format!(
-                    "Iterator of {}",
-                    get_type_description(&iterator.item_type)
-                )),
-            )
-        })?;
+        let doc = brief(&format!(
+            "Iterator of {}",
+            get_type_description(&iterator.item_type)
+        ));
+        let doc = match &iterator.destroy_function {
+            None => doc,
+            Some(destroy) => doc.warning(&format!(
+                "Iterators returned from functions must be released with {}_{}()",
+                lib.settings.c_ffi_prefix, destroy.name
+            )),
+        };
+        doxygen(f, |f| doxygen_print(f, &doc))?;
     }
     if let Some(collection) = lib.collections().find(|x| x.name() == &handle.name) {
         doxygen(f, |f| {
             doxygen_print(f, &brief(&format!("Collection of {}", collection.name())))
         })?;
     }
+    if let Some(map) = lib.maps().find(|x| x.name() == &handle.name) {
+        doxygen(f, |f| {
+            doxygen_print(f, &brief(&format!("Map of {}", map.name())))
+        })?;
+    }
 
     f.writeln(&format!(
         "typedef struct {} {};",
@@ -425,8 +842,11 @@ fn write_function_docs(
         for param in &handle.arguments {
             f.writeln(&format!("@param {} ", param.name))?;
             docstring_print(f, &param.doc)?;
-            if let FunctionArgument::Basic(BasicType::Duration(mapping)) = param.arg_type {
-                f.write(&format!(" ({})", mapping.unit()))?;
+            if let Some(unit) = param.arg_type.get_basic_type().and_then(|x| x.unit()) {
+                f.write(&format!(" ({unit})"))?;
+            }
+            if let Some(clamping) = param.arg_type.get_basic_type().and_then(|x| x.clamping()) {
+                f.write(&format!(". {clamping}"))?;
             }
         }
 
@@ -439,8 +859,8 @@ fn write_function_docs(
             SignatureType::NoErrorWithReturn(ret, doc) => {
                 f.writeln("@return ")?;
                 docstring_print(f, &doc)?;
-                if let FunctionReturnValue::Basic(BasicType::Duration(mapping)) = ret {
-                    f.write(&format!(" ({})", mapping.unit()))?;
+                if let Some(unit) = ret.get_basic_type().and_then(|x| x.unit()) {
+                    f.write(&format!(" ({unit})"))?;
                 }
             }
             SignatureType::ErrorNoReturn(_) => {
@@ -449,8 +869,8 @@ fn write_function_docs(
             SignatureType::ErrorWithReturn(_, ret, doc) => {
                 f.writeln("@param out ")?;
                 docstring_print(f, &doc)?;
-                if let FunctionReturnValue::Basic(BasicType::Duration(mapping)) = ret {
-                    f.write(&format!(" ({})", mapping.unit()))?;
+                if let Some(unit) = ret.get_basic_type().and_then(|x| x.unit()) {
+                    f.write(&format!(" ({unit})"))?;
                 }
                 write_error_return_doc(f)?;
             }
@@ -495,8 +915,8 @@ fn write_function(
         if let Some(x) = &handle.return_type.get_value() {
             if !handle.arguments.is_empty() {
                 f.write(", ")?;
-                f.write(&format!("{}* out", x.to_c_type()))?;
             }
+            f.write(&format!("{}* out", x.to_c_type()))?;
         }
     }
 
@@ -579,3 +999,94 @@ fn write_interface(
 
     Ok(())
 }
+
+fn write_interface_adapters(
+    f: &mut dyn Printer,
+    handle: &Handle<Class<Validated>>,
+) -> FormattingResult<()> {
+    let prefix = &handle.settings.c_ffi_prefix;
+    let class_type = handle.declaration.to_c_type();
+    let ctx_variable_name = &handle.settings.interface.context_variable_name;
+
+    for (interface, last) in handle.interfaces.iter().with_last() {
+        let interface_type = interface.to_c_type();
+
+        // forward each callback to the native function of the method
+        for (cb, method) in handle.interface_methods(interface) {
+            let function = &method.native_function;
+            let params = cb
+                .arguments
+                .iter()
+                .map(|arg| format!("{} {}", arg.arg_type.to_c_type(), arg.name))
+                .chain(std::iter::once(format!("void* {ctx_variable_name}")))
+                .collect::<Vec<String>>()
+                .join(", ");
+            let args = std::iter::once(format!("({class_type}*){ctx_variable_name}"))
+                .chain(cb.arguments.iter().map(|arg| arg.name.to_string()))
+                .collect::<Vec<String>>()
+                .join(", ");
+            let call = format!("{}_{}({})", prefix, function.name, args);
+
+            f.writeln(&format!(
+                "static {} {}_{}_{}_{}({})",
+                cb.return_type.to_c_type(),
+                prefix,
+                handle.name(),
+                interface.name,
+                cb.name,
+                params
+            ))?;
+            blocked(f, |f| {
+                if cb.return_type.is_none() {
+                    f.writeln(&format!("{call};"))
+                } else {
+                    f.writeln(&format!("return {call};"))
+                }
+            })?;
+            f.newline()?;
+        }
+
+        doxygen(f, |f| {
+            f.writeln(&format!(
+                "@brief Create a @ref {interface_type} that forwards each callback to the methods of a @ref {class_type}"
+            ))?;
+            f.newline()?;
+            f.writeln("@note The interface does not take ownership of the instance, which must outlive it")?;
+            f.newline()?;
+            f.writeln("@param instance Instance invoked by the callbacks")?;
+            f.writeln(&format!("@returns New instance of @ref {interface_type}"))
+        })?;
+        f.writeln(&format!(
+            "static {} {}_{}_as_{}({}* instance)",
+            interface_type,
+            prefix,
+            handle.name(),
+            interface.name,
+            class_type
+        ))?;
+        blocked(f, |f| {
+            f.writeln(&format!("{interface_type} _return_value = {{"))?;
+            indented(f, |f| {
+                for (cb, _) in handle.interface_methods(interface) {
+                    f.writeln(&format!(
+                        "{}_{}_{}_{},",
+                        prefix,
+                        handle.name(),
+                        interface.name,
+                        cb.name
+                    ))?;
+                }
+                f.writeln("NULL,")?;
+                f.writeln("instance")
+            })?;
+            f.writeln("};")?;
+            f.writeln("return _return_value;")
+        })?;
+
+        if !last {
+            f.newline()?;
+        }
+    }
+
+    Ok(())
+}
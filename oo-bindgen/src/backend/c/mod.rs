@@ -292,13 +292,13 @@ fn generate_cmake_config(
     f.writeln("get_property(languages GLOBAL PROPERTY ENABLED_LANGUAGES)")?;
     f.writeln("if(\"CXX\" IN_LIST languages)")?;
     indented(&mut f, |f| {
-        f.writeln("set(CMAKE_CXX_STANDARD 11)")?;
+        f.writeln("set(CMAKE_CXX_STANDARD 17)")?;
         f.writeln(&format!(
             "add_library({}_cpp OBJECT EXCLUDE_FROM_ALL ${{prefix}}/src/{}.cpp)",
             lib.settings.name, lib.settings.name
         ))?;
         f.writeln(&format!(
-            "target_compile_features({} INTERFACE cxx_std_14)",
+            "target_compile_features({} INTERFACE cxx_std_17)",
             lib.settings.name
         ))?;
         f.writeln(&format!(
@@ -0,0 +1,197 @@
+use crate::model::*;
+
+/// Size and alignment of a type in the C ABI of a 64-bit platform
+///
+/// Primitives are aligned to their size and pointers are 8 bytes wide. The generated code
+/// only asserts layouts on 64-bit platforms where this holds.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) struct Layout {
+    pub(crate) size: usize,
+    pub(crate) align: usize,
+}
+
+/// Layout of a struct and the offset of each of its fields
+pub(crate) struct StructLayout {
+    pub(crate) layout: Layout,
+    pub(crate) offsets: Vec<(Name, usize)>,
+}
+
+impl Layout {
+    const POINTER: Layout = Layout::scalar(8);
+
+    const fn scalar(size: usize) -> Self {
+        Self { size, align: size }
+    }
+
+    /// layout of a C struct with fields of the provided layouts and the offset of each field
+    fn of_fields<I: Iterator<Item = Layout>>(fields: I) -> (Self, Vec<usize>) {
+        fn round_up(value: usize, align: usize) -> usize {
+            value.div_ceil(align) * align
+        }
+
+        let mut offsets = Vec::new();
+        let mut size = 0;
+        let mut align = 1;
+        for field in fields {
+            let offset = round_up(size, field.align);
+            offsets.push(offset);
+            size = offset + field.size;
+            align = align.max(field.align);
+        }
+
+        (
+            Self {
+                size: round_up(size, align),
+                align,
+            },
+            offsets,
+        )
+    }
+}
+
+pub(crate) trait TypeLayout {
+    fn layout(&self) -> Layout;
+}
+
+/// Compute the layout of a struct and the offset of each of its fields
+pub(crate) fn struct_layout<T, D>(st: &Struct<T, D>) -> StructLayout
+where
+    T: StructFieldType + TypeLayout,
+    D: DocReference,
+{
+    let (layout, offsets) = Layout::of_fields(st.fields.iter().map(|x| x.field_type.layout()));
+    StructLayout {
+        layout,
+        offsets: st
+            .fields
+            .iter()
+            .map(|x| x.name.clone())
+            .zip(offsets)
+            .collect(),
+    }
+}
+
+impl<T, D> TypeLayout for Struct<T, D>
+where
+    T: StructFieldType + TypeLayout,
+    D: DocReference,
+{
+    fn layout(&self) -> Layout {
+        struct_layout(self).layout
+    }
+}
+
+impl<T> TypeLayout for UniversalOr<T>
+where
+    T: StructFieldType + TypeLayout,
+{
+    fn layout(&self) -> Layout {
+        match self {
+            UniversalOr::Specific(x) => x.layout(),
+            UniversalOr::Universal(x) => x.layout(),
+        }
+    }
+}
+
+impl TypeLayout for Primitive {
+    fn layout(&self) -> Layout {
+        match self {
+            Primitive::Bool | Primitive::U8 | Primitive::S8 => Layout::scalar(1),
+            Primitive::U16 | Primitive::S16 => Layout::scalar(2),
+            Primitive::U32 | Primitive::S32 | Primitive::Float => Layout::scalar(4),
+            Primitive::U64 | Primitive::S64 | Primitive::Double => Layout::scalar(8),
+        }
+    }
+}
+
+impl TypeLayout for BasicType {
+    fn layout(&self) -> Layout {
+        match self {
+            BasicType::Primitive(x) => x.layout(),
+            BasicType::Duration(x) => x.primitive().layout(),
+            BasicType::Timestamp(x) => x.primitive().layout(),
+            // enums are passed as a C int
+            BasicType::Enum(_) => Layout::scalar(4),
+            BasicType::Flags(x) => Primitive::from(x.flags_type).layout(),
+        }
+    }
+}
+
+impl TypeLayout for Optional<BasicType> {
+    fn layout(&self) -> Layout {
+        // flag followed by the value
+        Layout::of_fields([Primitive::Bool.layout(), self.inner.layout()].into_iter()).0
+    }
+}
+
+impl TypeLayout for BytesType {
+    fn layout(&self) -> Layout {
+        // pointer followed by the length
+        Layout::of_fields([Layout::POINTER, Layout::POINTER].into_iter()).0
+    }
+}
+
+impl TypeLayout for ListHandle {
+    fn layout(&self) -> Layout {
+        // pointer to the items followed by the count
+        Layout::of_fields([Layout::POINTER, Layout::POINTER].into_iter()).0
+    }
+}
+
+impl TypeLayout for AsynchronousInterface {
+    fn layout(&self) -> Layout {
+        // a function pointer per callback, the destroy function and the context
+        Layout::of_fields((0..self.inner.callbacks.len() + 2).map(|_| Layout::POINTER)).0
+    }
+}
+
+impl TypeLayout for FunctionArgStructField {
+    fn layout(&self) -> Layout {
+        match self {
+            FunctionArgStructField::Basic(x) => x.layout(),
+            FunctionArgStructField::String(_) => Layout::POINTER,
+            FunctionArgStructField::Bytes(x) => x.layout(),
+            FunctionArgStructField::Optional(x) => x.layout(),
+            FunctionArgStructField::List(x) => x.layout(),
+            FunctionArgStructField::Interface(x) => x.layout(),
+            FunctionArgStructField::Struct(x) => x.layout(),
+        }
+    }
+}
+
+impl TypeLayout for FunctionReturnStructField {
+    fn layout(&self) -> Layout {
+        match self {
+            FunctionReturnStructField::Basic(x) => x.layout(),
+            FunctionReturnStructField::ClassRef(_) => Layout::POINTER,
+            FunctionReturnStructField::Iterator(_) => Layout::POINTER,
+            FunctionReturnStructField::Struct(x) => x.layout(),
+        }
+    }
+}
+
+impl TypeLayout for CallbackArgStructField {
+    fn layout(&self) -> Layout {
+        match self {
+            CallbackArgStructField::Basic(x) => x.layout(),
+            CallbackArgStructField::String(_) => Layout::POINTER,
+            CallbackArgStructField::Bytes(x) => x.layout(),
+            CallbackArgStructField::Optional(x) => x.layout(),
+            CallbackArgStructField::Iterator(_) => Layout::POINTER,
+            CallbackArgStructField::Struct(x) => x.layout(),
+        }
+    }
+}
+
+impl TypeLayout for UniversalStructField {
+    fn layout(&self) -> Layout {
+        match self {
+            UniversalStructField::Basic(x) => x.layout(),
+            UniversalStructField::String(_) => Layout::POINTER,
+            UniversalStructField::Bytes(x) => x.layout(),
+            UniversalStructField::Optional(x) => x.layout(),
+            UniversalStructField::List(x) => x.layout(),
+            UniversalStructField::Struct(x) => x.layout(),
+        }
+    }
+}
@@ -1,11 +1,13 @@
 pub(crate) mod logged;
 
 mod formatting;
+mod layout;
 mod platforms;
 mod util;
 
 pub(crate) use self::platforms::*;
 pub(crate) use formatting::*;
+pub(crate) use layout::*;
 pub(crate) use util::*;
 
 pub(crate) use ::platforms::platform;
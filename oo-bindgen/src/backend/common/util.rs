@@ -1,3 +1,5 @@
+use crate::model::{PrimitiveValue, Representation};
+
 pub(crate) struct WithLast<I>
 where
     I: Iterator,
@@ -42,3 +44,49 @@ where
         }
     }
 }
+
+/// Integer value of a primitive, if it is an integer type
+pub(crate) fn integer_value(value: &PrimitiveValue) -> Option<i128> {
+    match value {
+        PrimitiveValue::Bool(_) | PrimitiveValue::Float(_) | PrimitiveValue::Double(_) => None,
+        PrimitiveValue::U8(x) => Some((*x).into()),
+        PrimitiveValue::S8(x) => Some((*x).into()),
+        PrimitiveValue::U16(x) => Some((*x).into()),
+        PrimitiveValue::S16(x) => Some((*x).into()),
+        PrimitiveValue::U32(x) => Some((*x).into()),
+        PrimitiveValue::S32(x) => Some((*x).into()),
+        PrimitiveValue::U64(x) => Some((*x).into()),
+        PrimitiveValue::S64(x) => Some((*x).into()),
+    }
+}
+
+/// Render an integer literal without any type suffix
+///
+/// Negative values are rendered as a minus sign followed by the magnitude in the requested representation
+pub(crate) fn format_integer(value: i128, representation: Representation) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let magnitude = value.unsigned_abs();
+    match representation {
+        Representation::Decimal => format!("{sign}{magnitude}"),
+        Representation::Hex => format!("{sign}0x{magnitude:02X}"),
+        Representation::Binary => format!("{sign}0b{magnitude:b}"),
+    }
+}
+
+/// Render a string literal with the escape sequences shared by C, C++, Java and C#
+pub(crate) fn quoted_string(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            _ => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
@@ -1,7 +1,11 @@
 use super::helpers::call_native_function;
+use super::interface::completion_source_type;
+use super::structure::{get_default_value, get_default_value_doc};
 use super::*;
 use heck::ToUpperCamelCase;
 
+const CANCELLATION_TOKEN: &str = "cancellationToken";
+
 pub(crate) fn generate(
     f: &mut dyn Printer,
     class: &Handle<Class<Validated>>,
@@ -20,8 +24,17 @@ pub(crate) fn generate(
         })?;
 
         f.writeln(&format!("public sealed class {classname}"))?;
-        if matches!(class.destruction_mode, DestructionMode::Dispose) {
-            f.write(": IDisposable")?;
+        let interfaces: Vec<String> = class
+            .interfaces
+            .iter()
+            .map(|x| format!("I{}", x.name.camel_case()))
+            .chain(
+                matches!(class.destruction_mode, DestructionMode::Dispose)
+                    .then(|| "IDisposable".to_string()),
+            )
+            .collect();
+        if !interfaces.is_empty() {
+            f.write(&format!(": {}", interfaces.join(", ")))?;
         }
 
         blocked(f, |f| {
@@ -63,11 +76,21 @@ pub(crate) fn generate(
                 f.newline()?;
             }
 
+            for property in &class.properties {
+                generate_property(f, property)?;
+                f.newline()?;
+            }
+
             for method in &class.future_methods {
                 generate_async_method(f, method)?;
                 f.newline()?;
             }
 
+            for method in &class.stream_methods {
+                generate_stream_method(f, method)?;
+                f.newline()?;
+            }
+
             for method in &class.static_methods {
                 generate_static_method(f, method)?;
                 f.newline()?;
@@ -113,49 +136,44 @@ fn generate_constructor(
     classname: &str,
     constructor: &ClassConstructor<Validated>,
 ) -> FormattingResult<()> {
-    documentation(f, |f| {
-        // Print top-level documentation
-        xmldoc_print(f, &constructor.function.doc)?;
-        f.newline()?;
+    let args = constructor.function.arguments.as_slice();
+    for_each_overload(f, args, |f, args, omitted| {
+        documentation(f, |f| {
+            // Print top-level documentation
+            xmldoc_print(f, &constructor.function.doc)?;
+            print_omitted_args(f, omitted)?;
+            f.newline()?;
 
-        // Print each parameter value
-        for param in &constructor.function.arguments {
-            f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
-            docstring_print(f, &param.doc)?;
-            f.write("</param>")?;
-        }
+            // Print each parameter value
+            for param in args {
+                f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
+                docstring_print(f, &param.doc)?;
+                f.write("</param>")?;
+            }
 
-        // Print exception
-        if let Some(error) = &constructor.function.error_type.get() {
-            f.writeln(&format!(
-                "<exception cref=\"{}\"></exception>",
-                error.exception_name.camel_case()
-            ))?;
-        }
+            // Print exception
+            if let Some(error) = &constructor.function.error_type.get() {
+                f.writeln(&format!(
+                    "<exception cref=\"{}\"></exception>",
+                    error.exception_name.camel_case()
+                ))?;
+            }
 
-        Ok(())
-    })?;
+            Ok(())
+        })?;
 
-    f.writeln(&format!("public {classname}("))?;
-    f.write(
-        &constructor
-            .function
-            .arguments
-            .iter()
-            .map(|param| {
-                format!(
-                    "{} {}",
-                    param.arg_type.get_dotnet_type(),
-                    param.name.mixed_case()
-                )
-            })
-            .collect::<Vec<String>>()
-            .join(", "),
-    )?;
-    f.write(")")?;
+        f.writeln(&format!("public {classname}("))?;
+        f.write(&parameters(args, omitted))?;
+        f.write(")")?;
 
-    blocked(f, |f| {
-        call_native_function(f, &constructor.function, "this.self = ", None, true)
+        if !omitted.is_empty() {
+            f.write(&format!(" : this({})", arguments(args, omitted)))?;
+            return blocked(f, |_| Ok(()));
+        }
+
+        blocked(f, |f| {
+            call_native_function(f, &constructor.function, "this.self = ", None, true)
+        })
     })
 }
 
@@ -212,27 +230,84 @@ fn generate_destructor(
 }
 
 fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> FormattingResult<()> {
+    let args = &method.native_function.arguments[1..];
+    for_each_overload(f, args, |f, args, omitted| {
+        documentation(f, |f| {
+            // Print top-level documentation
+            xmldoc_print(f, &method.native_function.doc)?;
+            print_omitted_args(f, omitted)?;
+            f.newline()?;
+
+            // Print each parameter value
+            for param in args {
+                f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
+                docstring_print(f, &param.doc)?;
+                f.write("</param>")?;
+            }
+
+            // Print return value
+            if let Some(doc) = &method.native_function.return_type.get_doc() {
+                f.writeln("<returns>")?;
+                docstring_print(f, doc)?;
+                f.write("</returns>")?;
+            }
+
+            // Print exception
+            if let Some(error) = &method.native_function.error_type.get() {
+                f.writeln(&format!(
+                    "<exception cref=\"{}\"></exception>",
+                    error.exception_name.camel_case()
+                ))?;
+            }
+
+            Ok(())
+        })?;
+
+        f.writeln(&format!(
+            "public {} {}(",
+            method.native_function.return_type.get_dotnet_type(),
+            method.name.camel_case()
+        ))?;
+        f.write(&parameters(args, omitted))?;
+        f.write(")")?;
+
+        blocked(f, |f| {
+            if omitted.is_empty() {
+                call_native_function(
+                    f,
+                    &method.native_function,
+                    "return ",
+                    Some("this".to_string()),
+                    false,
+                )
+            } else {
+                call_overload(f, &method.native_function, &method.name, args, omitted)
+            }
+        })
+    })
+}
+
+fn generate_property(f: &mut dyn Printer, property: &Property<Validated>) -> FormattingResult<()> {
+    let getter = &property.getter.native_function;
+
     documentation(f, |f| {
         // Print top-level documentation
-        xmldoc_print(f, &method.native_function.doc)?;
+        xmldoc_print(f, &getter.doc)?;
         f.newline()?;
 
-        // Print each parameter value
-        for param in method.native_function.arguments.iter().skip(1) {
-            f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
-            docstring_print(f, &param.doc)?;
-            f.write("</param>")?;
-        }
-
-        // Print return value
-        if let Some(doc) = &method.native_function.return_type.get_doc() {
-            f.writeln("<returns>")?;
+        // Print the value
+        if let Some(doc) = &getter.return_type.get_doc() {
+            f.writeln("<value>")?;
             docstring_print(f, doc)?;
-            f.write("</returns>")?;
+            f.write("</value>")?;
         }
 
-        // Print exception
-        if let Some(error) = &method.native_function.error_type.get() {
+        // Print exceptions
+        let setter_error = property
+            .setter
+            .as_ref()
+            .and_then(|x| x.native_function.error_type.get());
+        for error in getter.error_type.get().into_iter().chain(setter_error) {
             f.writeln(&format!(
                 "<exception cref=\"{}\"></exception>",
                 error.exception_name.camel_case()
@@ -243,36 +318,28 @@ fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> Formattin
     })?;
 
     f.writeln(&format!(
-        "public {} {}(",
-        method.native_function.return_type.get_dotnet_type(),
-        method.name.camel_case()
+        "public {} {}",
+        getter.return_type.get_dotnet_type(),
+        property.name.camel_case()
     ))?;
-    f.write(
-        &method
-            .native_function
-            .arguments
-            .iter()
-            .skip(1)
-            .map(|param| {
-                format!(
-                    "{} {}",
-                    param.arg_type.get_dotnet_type(),
-                    param.name.mixed_case()
-                )
-            })
-            .collect::<Vec<String>>()
-            .join(", "),
-    )?;
-    f.write(")")?;
-
     blocked(f, |f| {
-        call_native_function(
-            f,
-            &method.native_function,
-            "return ",
-            Some("this".to_string()),
-            false,
-        )
+        f.writeln("get")?;
+        blocked(f, |f| {
+            call_native_function(f, getter, "return ", Some("this".to_string()), false)
+        })?;
+        if let Some(setter) = &property.setter {
+            let setter = &setter.native_function;
+            let value = setter.arguments[1].name.mixed_case();
+            f.writeln("set")?;
+            blocked(f, |f| {
+                // the value of the setter is always named "value" in C#
+                if value != "value" {
+                    f.writeln(&format!("var {value} = value;"))?;
+                }
+                call_native_function(f, setter, "return ", Some("this".to_string()), false)
+            })?;
+        }
+        Ok(())
     })
 }
 
@@ -280,24 +347,244 @@ fn generate_static_method(
     f: &mut dyn Printer,
     method: &StaticMethod<Validated>,
 ) -> FormattingResult<()> {
+    let args = method.native_function.arguments.as_slice();
+    for_each_overload(f, args, |f, args, omitted| {
+        documentation(f, |f| {
+            // Print top-level documentation
+            xmldoc_print(f, &method.native_function.doc)?;
+            print_omitted_args(f, omitted)?;
+            f.newline()?;
+
+            // Print each parameter value
+            for param in args {
+                f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
+                docstring_print(f, &param.doc)?;
+                f.write("</param>")?;
+            }
+
+            // Print return value
+            if let Some(doc) = &method.native_function.return_type.get_doc() {
+                f.writeln("<returns>")?;
+                docstring_print(f, doc)?;
+                f.write("</returns>")?;
+            }
+
+            // Print exception
+            if let Some(error) = &method.native_function.error_type.get() {
+                f.writeln(&format!(
+                    "<exception cref=\"{}\"></exception>",
+                    error.exception_name.camel_case()
+                ))?;
+            }
+
+            Ok(())
+        })?;
+
+        f.writeln(&format!(
+            "public static {} {}(",
+            method.native_function.return_type.get_dotnet_type(),
+            method.name.camel_case()
+        ))?;
+        f.write(&parameters(args, omitted))?;
+        f.write(")")?;
+
+        blocked(f, |f| {
+            if omitted.is_empty() {
+                call_native_function(f, &method.native_function, "return ", None, false)
+            } else {
+                call_overload(f, &method.native_function, &method.name, args, omitted)
+            }
+        })
+    })
+}
+
+/// C# optional parameters require compile-time constants
+fn is_constant(value: &ValidatedDefaultValue) -> bool {
+    !matches!(
+        value,
+        ValidatedDefaultValue::Duration(_, _) | ValidatedDefaultValue::DefaultStruct(_, _, _)
+    )
+}
+
+/// Trailing parameters whose defaults are constants become optional parameters of the full
+/// signature. An overload forwarding to the full signature is generated for each of the
+/// remaining parameters with a default value.
+fn for_each_overload<F>(
+    f: &mut dyn Printer,
+    args: &[Arg<FunctionArgument, Validated>],
+    mut generate: F,
+) -> FormattingResult<()>
+where
+    F: FnMut(
+        &mut dyn Printer,
+        &[Arg<FunctionArgument, Validated>],
+        &[Arg<FunctionArgument, Validated>],
+    ) -> FormattingResult<()>,
+{
+    let required = args.iter().take_while(|x| x.default.is_none()).count();
+    let optional = args
+        .iter()
+        .rev()
+        .take_while(|x| x.default.as_ref().map(is_constant).unwrap_or(false))
+        .count();
+
+    generate(f, args, &[])?;
+    for count in (required..args.len() - optional).rev() {
+        f.newline()?;
+        let (args, omitted) = args.split_at(count);
+        generate(f, args, omitted)?;
+    }
+    Ok(())
+}
+
+fn print_omitted_args(
+    f: &mut dyn Printer,
+    omitted: &[Arg<FunctionArgument, Validated>],
+) -> FormattingResult<()> {
+    if omitted.is_empty() {
+        return Ok(());
+    }
+
+    f.newline()?;
+    f.writeln("<remarks>")?;
+    f.writeln("Omitted parameters are set to:")?;
+    f.writeln("<list type=\"bullet\">")?;
+    for arg in omitted {
+        if let Some(value) = &arg.default {
+            f.writeln(&format!(
+                "<item><description><c>{}</c>: {}</description></item>",
+                arg.name.mixed_case(),
+                get_default_value_doc(value)
+            ))?;
+        }
+    }
+    f.writeln("</list>")?;
+    f.writeln("</remarks>")
+}
+
+/// parameters of the full signature use optional parameters for trailing constant defaults
+fn parameters(
+    args: &[Arg<FunctionArgument, Validated>],
+    omitted: &[Arg<FunctionArgument, Validated>],
+) -> String {
+    let optional = if omitted.is_empty() {
+        args.iter()
+            .rev()
+            .take_while(|x| x.default.as_ref().map(is_constant).unwrap_or(false))
+            .count()
+    } else {
+        0
+    };
+
+    args.iter()
+        .enumerate()
+        .map(|(idx, param)| {
+            let param_str = format!(
+                "{} {}",
+                param.arg_type.get_dotnet_type(),
+                param.name.mixed_case()
+            );
+            match &param.default {
+                Some(value) if idx >= args.len() - optional => {
+                    format!("{} = {}", param_str, get_default_value(value))
+                }
+                _ => param_str,
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// arguments passed from an overload to the full signature
+fn arguments(
+    args: &[Arg<FunctionArgument, Validated>],
+    omitted: &[Arg<FunctionArgument, Validated>],
+) -> String {
+    args.iter()
+        .map(|x| x.name.mixed_case())
+        .chain(
+            omitted
+                .iter()
+                .filter_map(|x| x.default.as_ref().map(get_default_value)),
+        )
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn call_overload(
+    f: &mut dyn Printer,
+    function: &Function<Validated>,
+    name: &Name,
+    args: &[Arg<FunctionArgument, Validated>],
+    omitted: &[Arg<FunctionArgument, Validated>],
+) -> FormattingResult<()> {
+    let destination = if function.return_type.is_none() {
+        ""
+    } else {
+        "return "
+    };
+    f.writeln(&format!(
+        "{}{}({});",
+        destination,
+        name.camel_case(),
+        arguments(args, omitted)
+    ))
+}
+
+fn generate_async_method(
+    f: &mut dyn Printer,
+    method: &FutureMethod<Validated>,
+) -> FormattingResult<()> {
+    let completion_type = completion_source_type(&method.future);
+    let task_type = match &method.future.value {
+        Some(x) => format!("Task<{}>", x.value_type.get_dotnet_type()),
+        None => "Task".to_string(),
+    };
+
+    // Documentation
     documentation(f, |f| {
         // Print top-level documentation
         xmldoc_print(f, &method.native_function.doc)?;
         f.newline()?;
 
         // Print each parameter value
-        for param in &method.native_function.arguments {
+        for param in method.arguments_without_callback() {
             f.writeln(&format!("<param name=\"{}\">", param.name.mixed_case()))?;
             docstring_print(f, &param.doc)?;
             f.write("</param>")?;
         }
+        if method.cancellation.is_some() {
+            f.writeln(&format!(
+                "<param name=\"{CANCELLATION_TOKEN}\">token used to cancel the operation</param>"
+            ))?;
+        }
 
         // Print return value
-        if let Some(doc) = &method.native_function.return_type.get_doc() {
-            f.writeln("<returns>")?;
-            docstring_print(f, doc)?;
-            f.write("</returns>")?;
-        }
+        f.writeln("<returns>")?;
+        indented(f, |f| {
+            match &method.future.value {
+                Some(value) => {
+                    f.writeln("<see cref=\"System.Threading.Tasks.Task\"/> containing: ")?;
+                    docstring_print(f, &value.doc)?;
+                }
+                None => {
+                    f.writeln("<see cref=\"System.Threading.Tasks.Task\"/> that completes when the operation succeeds")?;
+                }
+            }
+            f.writeln("<para>")?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "The returned Task may fail exceptionally with <see cref=\"{}\" />",
+                    method
+                        .future
+                        .error_type
+                        .exception_name
+                        .to_upper_camel_case()
+                ))
+            })?;
+            f.writeln("</para>")
+        })?;
+        f.writeln("</returns>")?;
 
         // Print exception
         if let Some(error) = &method.native_function.error_type.get() {
@@ -311,15 +598,13 @@ fn generate_static_method(
     })?;
 
     f.writeln(&format!(
-        "public static {} {}(",
-        method.native_function.return_type.get_dotnet_type(),
+        "public {} {}(",
+        task_type,
         method.name.camel_case()
     ))?;
     f.write(
         &method
-            .native_function
-            .arguments
-            .iter()
+            .arguments_without_callback()
             .map(|param| {
                 format!(
                     "{} {}",
@@ -327,21 +612,64 @@ fn generate_static_method(
                     param.name.mixed_case()
                 )
             })
+            .chain(method.cancellation.iter().map(|_| {
+                format!("System.Threading.CancellationToken {CANCELLATION_TOKEN} = default")
+            }))
             .collect::<Vec<String>>()
             .join(", "),
     )?;
     f.write(")")?;
 
+    let tcs_var_name = "_oo_bindgen_tcs";
+
     blocked(f, |f| {
-        call_native_function(f, &method.native_function, "return ", None, false)
+        f.writeln(&format!(
+            "var {tcs_var_name} = new TaskCompletionSource<{completion_type}>();"
+        ))?;
+        f.writeln(&format!(
+            "var callback = new {}({});",
+            method.future.interface.name.camel_case(),
+            tcs_var_name
+        ))?;
+        match &method.cancellation {
+            None => call_native_function(
+                f,
+                &method.native_function,
+                "return ",
+                Some("this".to_string()),
+                false,
+            )?,
+            Some(cancellation) => {
+                f.writeln(&format!(
+                    "{} _operation;",
+                    method.native_function.return_type.get_dotnet_type()
+                ))?;
+                call_native_function(
+                    f,
+                    &method.native_function,
+                    "_operation = ",
+                    Some("this".to_string()),
+                    false,
+                )?;
+                f.writeln(&format!(
+                    "var _registration = {CANCELLATION_TOKEN}.Register(() => {}.{}(_operation));",
+                    NATIVE_FUNCTIONS_CLASSNAME,
+                    cancellation.function.name.camel_case()
+                ))?;
+                f.writeln(&format!(
+                    "{tcs_var_name}.Task.ContinueWith(_ => _registration.Dispose());"
+                ))?;
+            }
+        }
+        f.writeln(&format!("return {tcs_var_name}.Task;"))
     })
 }
 
-fn generate_async_method(
+fn generate_stream_method(
     f: &mut dyn Printer,
-    method: &FutureMethod<Validated>,
+    method: &StreamMethod<Validated>,
 ) -> FormattingResult<()> {
-    let callback_success_type = method.future.value_type.get_dotnet_type();
+    let item_type = method.stream.item_type.get_dotnet_type();
 
     // Documentation
     documentation(f, |f| {
@@ -359,14 +687,15 @@ fn generate_async_method(
         // Print return value
         f.writeln("<returns>")?;
         indented(f, |f| {
-            f.writeln("<see cref=\"System.Threading.Tasks.Task\"/> containing: ")?;
-            docstring_print(f, &method.future.value_type_doc)?;
+            f.writeln("<see cref=\"System.Collections.Generic.IAsyncEnumerable{T}\"/> of: ")?;
+            docstring_print(f, &method.stream.item_type_doc)?;
             f.writeln("<para>")?;
             indented(f, |f| {
+                f.writeln("The operation starts immediately and the sequence can only be enumerated once.")?;
                 f.writeln(&format!(
-                    "The returned Task may fail exceptionally with <see cref=\"{}\" />",
+                    "Enumerating the sequence may fail with <see cref=\"{}\" />",
                     method
-                        .future
+                        .stream
                         .error_type
                         .exception_name
                         .to_upper_camel_case()
@@ -376,20 +705,12 @@ fn generate_async_method(
         })?;
         f.writeln("</returns>")?;
 
-        // Print exception
-        if let Some(error) = &method.native_function.error_type.get() {
-            f.writeln(&format!(
-                "<exception cref=\"{}\"></exception>",
-                error.exception_name.camel_case()
-            ))?;
-        }
-
         Ok(())
     })?;
 
     f.writeln(&format!(
-        "public Task<{}> {}(",
-        callback_success_type,
+        "public System.Collections.Generic.IAsyncEnumerable<{}> {}(",
+        item_type,
         method.name.camel_case()
     ))?;
     f.write(
@@ -407,24 +728,24 @@ fn generate_async_method(
     )?;
     f.write(")")?;
 
-    let tcs_var_name = "_oo_bindgen_tcs";
+    let channel_var_name = "_oo_bindgen_channel";
 
     blocked(f, |f| {
         f.writeln(&format!(
-            "var {tcs_var_name} = new TaskCompletionSource<{callback_success_type}>();"
+            "var {channel_var_name} = new Helpers.StreamChannel<{item_type}>();"
         ))?;
         f.writeln(&format!(
             "var callback = new {}({});",
-            method.future.interface.name.camel_case(),
-            tcs_var_name
+            method.stream.interface.name.camel_case(),
+            channel_var_name
         ))?;
         call_native_function(
             f,
             &method.native_function,
-            "return ",
+            "",
             Some("this".to_string()),
             false,
         )?;
-        f.writeln(&format!("return {tcs_var_name}.Task;"))
+        f.writeln(&format!("return {channel_var_name};"))
     })
 }
@@ -11,6 +11,19 @@ impl ConvertToDotNet for DurationType {
         match self {
             Self::Milliseconds => Some(format!("TimeSpan.FromMilliseconds({from})")),
             Self::Seconds => Some(format!("TimeSpan.FromSeconds({from})")),
+            // a tick is 100 nanoseconds
+            Self::Microseconds => Some(format!("TimeSpan.FromTicks((long)({from} * 10))")),
+            Self::Nanoseconds => Some(format!("TimeSpan.FromTicks((long)({from} / 100))")),
+            Self::SignedMilliseconds => Some(format!("TimeSpan.FromMilliseconds({from})")),
+            Self::FloatSeconds => Some(format!("TimeSpan.FromSeconds({from})")),
+        }
+    }
+}
+
+impl ConvertToDotNet for TimestampType {
+    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+        match self {
+            Self::Milliseconds => Some(format!("DateTimeOffset.FromUnixTimeMilliseconds({from})")),
         }
     }
 }
@@ -47,7 +60,9 @@ impl ConvertToDotNet for BasicType {
         match self {
             Self::Primitive(x) => x.convert_to_dotnet(from),
             Self::Duration(x) => x.convert_to_dotnet(from),
+            Self::Timestamp(x) => x.convert_to_dotnet(from),
             Self::Enum(x) => x.convert_to_dotnet(from),
+            Self::Flags(_) => None,
         }
     }
 }
@@ -58,6 +73,27 @@ impl ConvertToDotNet for StringType {
     }
 }
 
+impl ConvertToDotNet for Optional<BasicType> {
+    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+        let value = format!("{from}.value");
+        let value = match &self.inner {
+            BasicType::Enum(x) => format!("({}){}", x.get_dotnet_type(), value),
+            BasicType::Flags(x) => format!("({}){}", x.get_dotnet_type(), value),
+            x => x.convert_to_dotnet(&value).unwrap_or(value),
+        };
+        Some(format!(
+            "({from}.hasValue != 0 ? ({}){value} : null)",
+            self.get_dotnet_type()
+        ))
+    }
+}
+
+impl ConvertToDotNet for BytesType {
+    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+        Some(format!("Helpers.RustBytes.FromNative({from})"))
+    }
+}
+
 impl<D> ConvertToDotNet for Handle<Interface<D>>
 where
     D: DocReference,
@@ -90,6 +126,15 @@ where
     }
 }
 
+impl ConvertToDotNet for ListHandle {
+    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+        Some(format!(
+            "{}Native.FromNative({from})",
+            self.name.camel_case()
+        ))
+    }
+}
+
 impl<D> ConvertToDotNet for Handle<AbstractIterator<D>>
 where
     D: DocReference,
@@ -139,6 +184,8 @@ impl ConvertToDotNet for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.convert_to_dotnet(from),
             CallbackArgStructField::Struct(x) => x.convert_to_dotnet(from),
             CallbackArgStructField::String(x) => x.convert_to_dotnet(from),
+            CallbackArgStructField::Bytes(x) => x.convert_to_dotnet(from),
+            CallbackArgStructField::Optional(x) => x.convert_to_dotnet(from),
         }
     }
 }
@@ -149,6 +196,9 @@ impl ConvertToDotNet for UniversalStructField {
             UniversalStructField::Basic(x) => x.convert_to_dotnet(from),
             UniversalStructField::Struct(x) => x.convert_to_dotnet(from),
             UniversalStructField::String(x) => x.convert_to_dotnet(from),
+            UniversalStructField::Bytes(x) => x.convert_to_dotnet(from),
+            UniversalStructField::Optional(x) => x.convert_to_dotnet(from),
+            UniversalStructField::List(x) => x.convert_to_dotnet(from),
         }
     }
 }
@@ -158,6 +208,9 @@ impl ConvertToDotNet for CallbackArgument {
         match self {
             Self::Basic(x) => x.convert_to_dotnet(from),
             Self::String(x) => x.convert_to_dotnet(from),
+            Self::Bytes(x) => x.convert_to_dotnet(from),
+            Self::Union(x) => x.convert_to_dotnet(from),
+            Self::Optional(x) => x.convert_to_dotnet(from),
             Self::Iterator(x) => x.convert_to_dotnet(from),
             Self::Struct(x) => x.convert_to_dotnet(from),
             Self::Class(x) => x.convert_to_dotnet(from),
@@ -189,19 +242,65 @@ impl ConvertToDotNet for PrimitiveRef {
     }
 }
 
+impl ValueRef {
+    /// read the value behind a pointer that is known to be non-null
+    pub(crate) fn read_dotnet_value(&self, expr: &str) -> String {
+        match self {
+            Self::String(x) => x.convert_to_dotnet(expr).unwrap(),
+            Self::Enum(x) => format!(
+                "({})Helpers.PrimitivePointer.Signed.ReadInt({expr})",
+                x.get_dotnet_type()
+            ),
+            Self::Duration(x) => {
+                let value = PrimitiveRef::new(x.primitive())
+                    .convert_to_dotnet(expr)
+                    .unwrap();
+                x.convert_to_dotnet(&value).unwrap()
+            }
+        }
+    }
+}
+
+impl ConvertToDotNet for ValueRef {
+    fn convert_to_dotnet(&self, expr: &str) -> Option<String> {
+        Some(format!(
+            "({expr} == IntPtr.Zero ? null : ({}){})",
+            self.get_dotnet_type(),
+            self.read_dotnet_value(expr)
+        ))
+    }
+}
+
 impl ConvertToDotNet for FunctionReturnValue {
     fn convert_to_dotnet(&self, from: &str) -> Option<String> {
         match self {
             Self::Basic(x) => x.convert_to_dotnet(from),
             Self::String(x) => x.convert_to_dotnet(from),
+            Self::Bytes(x) => x.convert_to_dotnet(from),
+            Self::Union(x) => x.convert_to_dotnet(from),
             Self::ClassRef(x) => x.convert_to_dotnet(from),
+            Self::Iterator(x) => Some(format!("new {}({})", x.name().camel_case(), from)),
             Self::Struct(x) => x.convert_to_dotnet(from),
             Self::StructRef(x) => x.untyped().convert_to_dotnet(from),
             Self::PrimitiveRef(x) => x.convert_to_dotnet(from),
+            Self::ValueRef(x) => x.convert_to_dotnet(from),
         }
     }
 }
 
+impl<D> ConvertToDotNet for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_dotnet(&self, from: &str) -> Option<String> {
+        Some(format!(
+            "{}Native.FromNative({})",
+            self.name.camel_case(),
+            from
+        ))
+    }
+}
+
 impl ConvertToDotNet for StructDeclarationHandle {
     fn convert_to_dotnet(&self, from: &str) -> Option<String> {
         Some(format!(
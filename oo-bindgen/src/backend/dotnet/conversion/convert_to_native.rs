@@ -1,3 +1,4 @@
+use crate::backend::dotnet::conversion::{optional_native_type, TypeInfo};
 use crate::model::*;
 
 /// Conversion from .NET types to native types
@@ -13,6 +14,23 @@ impl ConvertToNative for DurationType {
         match self {
             Self::Milliseconds => Some(format!("(ulong){from}.TotalMilliseconds")),
             Self::Seconds => Some(format!("(ulong){from}.TotalSeconds")),
+            // a tick is 100 nanoseconds
+            Self::Microseconds => Some(format!("(ulong)({from}.Ticks / 10)")),
+            Self::Nanoseconds => Some(format!("(ulong){from}.Ticks * 100")),
+            Self::SignedMilliseconds => Some(format!("(long){from}.TotalMilliseconds")),
+            Self::FloatSeconds => Some(format!("{from}.TotalSeconds")),
+        }
+    }
+
+    fn cleanup_native(&self, _from: &str) -> Option<String> {
+        None
+    }
+}
+
+impl ConvertToNative for TimestampType {
+    fn convert_to_native(&self, from: &str) -> Option<String> {
+        match self {
+            Self::Milliseconds => Some(format!("{from}.ToUnixTimeMilliseconds()")),
         }
     }
 
@@ -73,7 +91,9 @@ impl ConvertToNative for BasicType {
         match self {
             Self::Primitive(x) => x.convert_to_native(from),
             Self::Duration(x) => x.convert_to_native(from),
+            Self::Timestamp(x) => x.convert_to_native(from),
             Self::Enum(x) => x.convert_to_native(from),
+            Self::Flags(_) => None,
         }
     }
 
@@ -81,7 +101,9 @@ impl ConvertToNative for BasicType {
         match self {
             Self::Primitive(x) => x.cleanup_native(from),
             Self::Duration(x) => x.cleanup_native(from),
+            Self::Timestamp(x) => x.cleanup_native(from),
             Self::Enum(x) => x.cleanup_native(from),
+            Self::Flags(_) => None,
         }
     }
 }
@@ -96,6 +118,38 @@ impl ConvertToNative for StringType {
     }
 }
 
+impl ConvertToNative for Optional<BasicType> {
+    fn convert_to_native(&self, from: &str) -> Option<String> {
+        let value = format!("{from}.Value");
+        let value = match &self.inner {
+            BasicType::Enum(_) => format!("(int){value}"),
+            BasicType::Flags(x) => format!(
+                "({}){value}",
+                Primitive::from(x.flags_type).get_dotnet_type()
+            ),
+            x => x.convert_to_native(&value).unwrap_or(value),
+        };
+        let native_type = optional_native_type(&self.inner);
+        Some(format!(
+            "({from}.HasValue ? new {native_type}({value}) : new {native_type}())"
+        ))
+    }
+
+    fn cleanup_native(&self, _from: &str) -> Option<String> {
+        None
+    }
+}
+
+impl ConvertToNative for BytesType {
+    fn convert_to_native(&self, from: &str) -> Option<String> {
+        Some(format!("Helpers.RustBytes.ToNative({from})"))
+    }
+
+    fn cleanup_native(&self, from: &str) -> Option<String> {
+        Some(format!("Helpers.RustBytes.Destroy({from});"))
+    }
+}
+
 impl<D> ConvertToNative for Handle<Interface<D>>
 where
     D: DocReference,
@@ -111,8 +165,8 @@ where
                         from.to_string()
                     }
                 }
-                InterfaceCategory::Future => {
-                    // we don't perform functional transforms on future interfaces
+                InterfaceCategory::Future | InterfaceCategory::Stream => {
+                    // we don't perform functional transforms on future or stream interfaces
                     from.to_string()
                 }
             }
@@ -158,13 +212,63 @@ where
     }
 }
 
+impl<D> ConvertToNative for Handle<Map<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, from: &str) -> Option<String> {
+        Some(format!(
+            "{}Helpers.ToNative({})",
+            self.map_class.name.camel_case(),
+            from
+        ))
+    }
+
+    fn cleanup_native(&self, from: &str) -> Option<String> {
+        Some(format!(
+            "{}Helpers.Cleanup({});",
+            self.map_class.name.camel_case(),
+            from
+        ))
+    }
+}
+
+impl ConvertToNative for ListItemType {
+    fn convert_to_native(&self, from: &str) -> Option<String> {
+        match self {
+            Self::Basic(x) => x.convert_to_native(from),
+            Self::Struct(x) => x.convert_to_native(from),
+        }
+    }
+
+    fn cleanup_native(&self, from: &str) -> Option<String> {
+        match self {
+            Self::Basic(x) => x.cleanup_native(from),
+            Self::Struct(x) => x.cleanup_native(from),
+        }
+    }
+}
+
+impl ConvertToNative for ListHandle {
+    fn convert_to_native(&self, from: &str) -> Option<String> {
+        Some(format!("{}Native.ToNative({from})", self.name.camel_case()))
+    }
+
+    fn cleanup_native(&self, from: &str) -> Option<String> {
+        Some(format!("{from}.Dispose();"))
+    }
+}
+
 impl ConvertToNative for FunctionArgStructField {
     fn convert_to_native(&self, from: &str) -> Option<String> {
         match self {
             FunctionArgStructField::Basic(x) => x.convert_to_native(from),
             FunctionArgStructField::String(x) => x.convert_to_native(from),
+            FunctionArgStructField::Bytes(x) => x.convert_to_native(from),
+            FunctionArgStructField::Optional(x) => x.convert_to_native(from),
             FunctionArgStructField::Interface(x) => x.inner.convert_to_native(from),
             FunctionArgStructField::Struct(x) => x.convert_to_native(from),
+            FunctionArgStructField::List(x) => x.convert_to_native(from),
         }
     }
 
@@ -172,8 +276,11 @@ impl ConvertToNative for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.cleanup_native(from),
             FunctionArgStructField::String(x) => x.cleanup_native(from),
+            FunctionArgStructField::Bytes(x) => x.cleanup_native(from),
+            FunctionArgStructField::Optional(x) => x.cleanup_native(from),
             FunctionArgStructField::Interface(x) => x.inner.cleanup_native(from),
             FunctionArgStructField::Struct(x) => x.cleanup_native(from),
+            FunctionArgStructField::List(x) => x.cleanup_native(from),
         }
     }
 }
@@ -184,6 +291,9 @@ impl ConvertToNative for UniversalStructField {
             UniversalStructField::Basic(x) => x.convert_to_native(from),
             UniversalStructField::Struct(x) => x.convert_to_native(from),
             UniversalStructField::String(x) => x.convert_to_native(from),
+            UniversalStructField::Bytes(x) => x.convert_to_native(from),
+            UniversalStructField::Optional(x) => x.convert_to_native(from),
+            UniversalStructField::List(x) => x.convert_to_native(from),
         }
     }
 
@@ -192,6 +302,9 @@ impl ConvertToNative for UniversalStructField {
             UniversalStructField::Basic(x) => x.cleanup_native(from),
             UniversalStructField::Struct(x) => x.cleanup_native(from),
             UniversalStructField::String(x) => x.cleanup_native(from),
+            UniversalStructField::Bytes(x) => x.cleanup_native(from),
+            UniversalStructField::Optional(x) => x.cleanup_native(from),
+            UniversalStructField::List(x) => x.cleanup_native(from),
         }
     }
 }
@@ -201,7 +314,11 @@ impl ConvertToNative for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.convert_to_native(from),
             FunctionArgument::String(x) => x.convert_to_native(from),
+            FunctionArgument::Bytes(x) => x.convert_to_native(from),
+            FunctionArgument::Union(x) => x.convert_to_native(from),
+            FunctionArgument::Optional(x) => x.convert_to_native(from),
             FunctionArgument::Collection(x) => x.convert_to_native(from),
+            FunctionArgument::Map(x) => x.convert_to_native(from),
             FunctionArgument::Struct(x) => x.convert_to_native(from),
             FunctionArgument::StructRef(x) => x.inner.convert_to_native(from),
             FunctionArgument::ClassRef(x) => x.convert_to_native(from),
@@ -213,7 +330,11 @@ impl ConvertToNative for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.cleanup_native(from),
             FunctionArgument::String(x) => x.cleanup_native(from),
+            FunctionArgument::Bytes(x) => x.cleanup_native(from),
+            FunctionArgument::Union(x) => x.cleanup_native(from),
+            FunctionArgument::Optional(x) => x.cleanup_native(from),
             FunctionArgument::Collection(x) => x.cleanup_native(from),
+            FunctionArgument::Map(x) => x.cleanup_native(from),
             FunctionArgument::Struct(x) => x.cleanup_native(from),
             FunctionArgument::StructRef(x) => x.inner.cleanup_native(from),
             FunctionArgument::ClassRef(x) => x.cleanup_native(from),
@@ -272,6 +393,23 @@ impl ConvertToNative for StructDeclarationHandle {
     }
 }
 
+impl<D> ConvertToNative for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_native(&self, from: &str) -> Option<String> {
+        Some(format!(
+            "{}Native.ToNative({})",
+            self.name.camel_case(),
+            from
+        ))
+    }
+
+    fn cleanup_native(&self, from: &str) -> Option<String> {
+        Some(format!("{from}.Dispose();"))
+    }
+}
+
 impl<T, D> ConvertToNative for Handle<Struct<T, D>>
 where
     D: DocReference,
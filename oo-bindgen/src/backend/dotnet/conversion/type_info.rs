@@ -16,7 +16,17 @@ impl TypeInfo for DurationType {
     }
 
     fn get_native_type(&self) -> String {
-        "ulong".to_string()
+        self.primitive().get_native_type()
+    }
+}
+
+impl TypeInfo for TimestampType {
+    fn get_dotnet_type(&self) -> String {
+        "DateTimeOffset".to_string()
+    }
+
+    fn get_native_type(&self) -> String {
+        self.primitive().get_native_type()
     }
 }
 
@@ -67,12 +77,28 @@ where
     }
 }
 
+impl<D> TypeInfo for Handle<Flags<D>>
+where
+    D: DocReference,
+{
+    fn get_dotnet_type(&self) -> String {
+        self.name.camel_case()
+    }
+
+    fn get_native_type(&self) -> String {
+        // the enum has the same width as the native integer
+        self.name.camel_case()
+    }
+}
+
 impl TypeInfo for BasicType {
     fn get_dotnet_type(&self) -> String {
         match self {
             Self::Primitive(x) => x.get_dotnet_type(),
             Self::Duration(x) => x.get_dotnet_type(),
+            Self::Timestamp(x) => x.get_dotnet_type(),
             Self::Enum(x) => x.get_dotnet_type(),
+            Self::Flags(x) => x.get_dotnet_type(),
         }
     }
 
@@ -80,7 +106,9 @@ impl TypeInfo for BasicType {
         match self {
             Self::Primitive(x) => x.get_native_type(),
             Self::Duration(x) => x.get_native_type(),
+            Self::Timestamp(x) => x.get_native_type(),
             Self::Enum(x) => x.get_native_type(),
+            Self::Flags(x) => x.get_native_type(),
         }
     }
 }
@@ -95,6 +123,50 @@ impl TypeInfo for StringType {
     }
 }
 
+/// Name of the helper struct used to pass an optional value to and from native code
+pub(crate) fn optional_native_type(inner: &BasicType) -> &'static str {
+    match inner {
+        BasicType::Primitive(x) => match x {
+            Primitive::Bool => "Helpers.OptionalByte",
+            Primitive::U8 => "Helpers.OptionalByte",
+            Primitive::S8 => "Helpers.OptionalSByte",
+            Primitive::U16 => "Helpers.OptionalUShort",
+            Primitive::S16 => "Helpers.OptionalShort",
+            Primitive::U32 => "Helpers.OptionalUInt",
+            Primitive::S32 => "Helpers.OptionalInt",
+            Primitive::U64 => "Helpers.OptionalULong",
+            Primitive::S64 => "Helpers.OptionalLong",
+            Primitive::Float => "Helpers.OptionalFloat",
+            Primitive::Double => "Helpers.OptionalDouble",
+        },
+        BasicType::Duration(x) => optional_native_type(&BasicType::Primitive(x.primitive())),
+        BasicType::Timestamp(x) => optional_native_type(&BasicType::Primitive(x.primitive())),
+        // enums are passed as their underlying integer value
+        BasicType::Enum(_) => "Helpers.OptionalInt",
+        BasicType::Flags(x) => optional_native_type(&BasicType::Primitive(x.flags_type.into())),
+    }
+}
+
+impl TypeInfo for Optional<BasicType> {
+    fn get_dotnet_type(&self) -> String {
+        format!("{}?", self.inner.get_dotnet_type())
+    }
+
+    fn get_native_type(&self) -> String {
+        optional_native_type(&self.inner).to_string()
+    }
+}
+
+impl TypeInfo for BytesType {
+    fn get_dotnet_type(&self) -> String {
+        "byte[]".to_string()
+    }
+
+    fn get_native_type(&self) -> String {
+        "Helpers.ByteSlice".to_string()
+    }
+}
+
 pub(crate) fn base_functor_type<D>(cb: &CallbackFunction<D>) -> &'static str
 where
     D: DocReference,
@@ -179,27 +251,68 @@ where
     }
 }
 
-impl<D> TypeInfo for Handle<AbstractIterator<D>>
+impl<D> TypeInfo for Handle<Map<D>>
 where
     D: DocReference,
 {
     fn get_dotnet_type(&self) -> String {
-        match &self.item_type {
-            IteratorItemType::Primitive(x) => {
-                format!(
-                    "System.Collections.Generic.ICollection<{}>",
-                    x.get_dotnet_type()
-                )
-            }
-            IteratorItemType::Struct(x) => {
-                format!(
-                    "System.Collections.Generic.ICollection<{}>",
-                    x.name().camel_case()
-                )
-            }
+        format!(
+            "System.Collections.Generic.IDictionary<{}, {}>",
+            self.key_type.get_dotnet_type(),
+            self.value_type.get_dotnet_type()
+        )
+    }
+
+    fn get_native_type(&self) -> String {
+        INT_PTR_STRING.to_string()
+    }
+}
+
+impl TypeInfo for ListItemType {
+    fn get_dotnet_type(&self) -> String {
+        match self {
+            Self::Basic(x) => x.get_dotnet_type(),
+            Self::Struct(x) => x.get_dotnet_type(),
         }
     }
 
+    fn get_native_type(&self) -> String {
+        match self {
+            Self::Basic(x) => x.get_native_type(),
+            Self::Struct(x) => x.get_native_type(),
+        }
+    }
+}
+
+impl TypeInfo for ListHandle {
+    fn get_dotnet_type(&self) -> String {
+        format!(
+            "System.Collections.Generic.IList<{}>",
+            self.item_type.get_dotnet_type()
+        )
+    }
+
+    fn get_native_type(&self) -> String {
+        format!("{}Native", self.name.camel_case())
+    }
+}
+
+impl<D> TypeInfo for Handle<AbstractIterator<D>>
+where
+    D: DocReference,
+{
+    fn get_dotnet_type(&self) -> String {
+        let item_type = match &self.item_type {
+            IteratorItemType::Primitive(x) => x.get_dotnet_type(),
+            IteratorItemType::String(x) => x.get_dotnet_type(),
+            IteratorItemType::Enum(x) => x.get_dotnet_type(),
+            IteratorItemType::Duration(x) => x.get_dotnet_type(),
+            IteratorItemType::Class(x) => x.get_dotnet_type(),
+            IteratorItemType::Struct(x) => x.name().camel_case(),
+        };
+        format!("System.Collections.Generic.ICollection<{item_type}>")
+    }
+
     fn get_native_type(&self) -> String {
         INT_PTR_STRING.to_string()
     }
@@ -229,8 +342,11 @@ impl TypeInfo for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.get_dotnet_type(),
             FunctionArgStructField::String(x) => x.get_dotnet_type(),
+            FunctionArgStructField::Bytes(x) => x.get_dotnet_type(),
+            FunctionArgStructField::Optional(x) => x.get_dotnet_type(),
             FunctionArgStructField::Interface(x) => x.inner.get_dotnet_type(),
             FunctionArgStructField::Struct(x) => x.get_dotnet_type(),
+            FunctionArgStructField::List(x) => x.get_dotnet_type(),
         }
     }
 
@@ -238,8 +354,11 @@ impl TypeInfo for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.get_native_type(),
             FunctionArgStructField::String(x) => x.get_native_type(),
+            FunctionArgStructField::Bytes(x) => x.get_native_type(),
+            FunctionArgStructField::Optional(x) => x.get_native_type(),
             FunctionArgStructField::Interface(x) => x.inner.get_native_type(),
             FunctionArgStructField::Struct(x) => x.get_native_type(),
+            FunctionArgStructField::List(x) => x.get_native_type(),
         }
     }
 }
@@ -271,6 +390,8 @@ impl TypeInfo for CallbackArgStructField {
             Self::Iterator(x) => x.get_dotnet_type(),
             Self::Struct(x) => x.get_dotnet_type(),
             Self::String(x) => x.get_dotnet_type(),
+            Self::Bytes(x) => x.get_dotnet_type(),
+            Self::Optional(x) => x.get_dotnet_type(),
         }
     }
 
@@ -280,6 +401,8 @@ impl TypeInfo for CallbackArgStructField {
             Self::Iterator(x) => x.get_native_type(),
             Self::Struct(x) => x.get_native_type(),
             Self::String(x) => x.get_native_type(),
+            Self::Bytes(x) => x.get_native_type(),
+            Self::Optional(x) => x.get_native_type(),
         }
     }
 }
@@ -290,6 +413,9 @@ impl TypeInfo for UniversalStructField {
             UniversalStructField::Basic(x) => x.get_dotnet_type(),
             UniversalStructField::Struct(x) => x.get_dotnet_type(),
             UniversalStructField::String(x) => x.get_dotnet_type(),
+            UniversalStructField::Bytes(x) => x.get_dotnet_type(),
+            UniversalStructField::Optional(x) => x.get_dotnet_type(),
+            UniversalStructField::List(x) => x.get_dotnet_type(),
         }
     }
 
@@ -298,6 +424,9 @@ impl TypeInfo for UniversalStructField {
             UniversalStructField::Basic(x) => x.get_native_type(),
             UniversalStructField::Struct(x) => x.get_native_type(),
             UniversalStructField::String(x) => x.get_native_type(),
+            UniversalStructField::Bytes(x) => x.get_native_type(),
+            UniversalStructField::Optional(x) => x.get_native_type(),
+            UniversalStructField::List(x) => x.get_native_type(),
         }
     }
 }
@@ -307,7 +436,11 @@ impl TypeInfo for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.get_dotnet_type(),
             FunctionArgument::String(x) => x.get_dotnet_type(),
+            FunctionArgument::Bytes(x) => x.get_dotnet_type(),
+            FunctionArgument::Union(x) => x.get_dotnet_type(),
+            FunctionArgument::Optional(x) => x.get_dotnet_type(),
             FunctionArgument::Collection(x) => x.get_dotnet_type(),
+            FunctionArgument::Map(x) => x.get_dotnet_type(),
             FunctionArgument::Struct(x) => x.get_dotnet_type(),
             FunctionArgument::StructRef(x) => x.inner.get_dotnet_type(),
             FunctionArgument::ClassRef(x) => x.get_dotnet_type(),
@@ -319,7 +452,11 @@ impl TypeInfo for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.get_native_type(),
             FunctionArgument::String(x) => x.get_native_type(),
+            FunctionArgument::Bytes(x) => x.get_native_type(),
+            FunctionArgument::Union(x) => x.get_native_type(),
+            FunctionArgument::Optional(x) => x.get_native_type(),
             FunctionArgument::Collection(x) => x.get_native_type(),
+            FunctionArgument::Map(x) => x.get_native_type(),
             FunctionArgument::Struct(x) => x.get_native_type(),
             FunctionArgument::StructRef(x) => x.inner.get_native_type(),
             FunctionArgument::ClassRef(x) => x.get_native_type(),
@@ -333,6 +470,9 @@ impl TypeInfo for CallbackArgument {
         match self {
             Self::Basic(x) => x.get_dotnet_type(),
             Self::String(x) => x.get_dotnet_type(),
+            Self::Bytes(x) => x.get_dotnet_type(),
+            Self::Union(x) => x.get_dotnet_type(),
+            Self::Optional(x) => x.get_dotnet_type(),
             Self::Iterator(x) => x.get_dotnet_type(),
             Self::Struct(x) => x.get_dotnet_type(),
             Self::Class(x) => x.get_dotnet_type(),
@@ -343,6 +483,9 @@ impl TypeInfo for CallbackArgument {
         match self {
             Self::Basic(x) => x.get_native_type(),
             Self::String(x) => x.get_native_type(),
+            Self::Bytes(x) => x.get_native_type(),
+            Self::Union(x) => x.get_native_type(),
+            Self::Optional(x) => x.get_native_type(),
             Self::Iterator(x) => x.get_native_type(),
             Self::Struct(x) => x.get_native_type(),
             Self::Class(x) => x.get_native_type(),
@@ -376,15 +519,35 @@ impl TypeInfo for PrimitiveRef {
     }
 }
 
+impl TypeInfo for ValueRef {
+    fn get_dotnet_type(&self) -> String {
+        match self {
+            // strings are reference types and already nullable
+            Self::String(x) => x.get_dotnet_type(),
+            Self::Enum(x) => format!("{}?", x.get_dotnet_type()),
+            Self::Duration(x) => format!("{}?", x.get_dotnet_type()),
+        }
+    }
+
+    fn get_native_type(&self) -> String {
+        INT_PTR_STRING.to_string()
+    }
+}
+
 impl TypeInfo for FunctionReturnValue {
     fn get_dotnet_type(&self) -> String {
         match self {
             Self::Basic(x) => x.get_dotnet_type(),
             Self::String(x) => x.get_dotnet_type(),
+            Self::Bytes(x) => x.get_dotnet_type(),
+            Self::Union(x) => x.get_dotnet_type(),
             Self::ClassRef(x) => x.get_dotnet_type(),
+            // owned iterators are wrapped in a generated class
+            Self::Iterator(x) => x.name().camel_case(),
             Self::Struct(x) => x.get_dotnet_type(),
             Self::StructRef(x) => x.untyped().get_dotnet_type(),
             Self::PrimitiveRef(x) => x.get_dotnet_type(),
+            Self::ValueRef(x) => x.get_dotnet_type(),
         }
     }
 
@@ -392,10 +555,14 @@ impl TypeInfo for FunctionReturnValue {
         match self {
             Self::Basic(x) => x.get_native_type(),
             Self::String(x) => x.get_native_type(),
+            Self::Bytes(x) => x.get_native_type(),
+            Self::Union(x) => x.get_native_type(),
             Self::ClassRef(x) => x.get_native_type(),
+            Self::Iterator(x) => x.get_native_type(),
             Self::Struct(x) => x.get_native_type(),
             Self::StructRef(x) => x.untyped().get_native_type(),
             Self::PrimitiveRef(x) => x.get_native_type(),
+            Self::ValueRef(x) => x.get_native_type(),
         }
     }
 }
@@ -424,6 +591,19 @@ where
     }
 }
 
+impl<D> TypeInfo for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn get_dotnet_type(&self) -> String {
+        self.name.camel_case()
+    }
+
+    fn get_native_type(&self) -> String {
+        format!("{}Native", self.name.camel_case())
+    }
+}
+
 const VOID: &str = "void";
 
 impl<T, D> TypeInfo for OptionalReturnType<T, D>
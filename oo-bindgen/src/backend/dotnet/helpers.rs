@@ -75,6 +75,190 @@ pub(crate) fn generate_collection_helpers(
     })
 }
 
+pub(crate) fn generate_map_helpers(
+    f: &mut dyn Printer,
+    map: &Handle<Map<Validated>>,
+    lib: &Library,
+) -> FormattingResult<()> {
+    print_license(f, &lib.info.license_description)?;
+    print_imports(f)?;
+    f.newline()?;
+
+    namespaced(f, &lib.settings.name, |f| {
+        f.writeln(&format!(
+            "internal static class {}Helpers",
+            map.name().camel_case()
+        ))?;
+        blocked(f, |f| {
+            // ToNative function
+            f.writeln(&format!(
+                "internal static IntPtr ToNative({} value)",
+                map.get_dotnet_type()
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "var builder = {}.{}();",
+                    NATIVE_FUNCTIONS_CLASSNAME,
+                    map.create_func.name.camel_case()
+                ))?;
+
+                f.writeln("foreach (var el in value)")?;
+                blocked(f, |f| {
+                    let key_conversion = map
+                        .key_type
+                        .convert_to_native("el.Key")
+                        .unwrap_or_else(|| "el.Key".to_string());
+                    let value_conversion = map
+                        .value_type
+                        .convert_to_native("el.Value")
+                        .unwrap_or_else(|| "el.Value".to_string());
+                    f.writeln(&format!("var convertedKey = {key_conversion};"))?;
+                    f.writeln(&format!("var convertedValue = {value_conversion};"))?;
+
+                    f.writeln(&format!(
+                        "{}.{}(builder, convertedKey, convertedValue);",
+                        NATIVE_FUNCTIONS_CLASSNAME,
+                        map.insert_func.name.camel_case()
+                    ))?;
+
+                    if let Some(cleanup) = &map.key_type.cleanup_native("convertedKey") {
+                        f.writeln(cleanup)?;
+                    }
+                    if let Some(cleanup) = &map.value_type.cleanup_native("convertedValue") {
+                        f.writeln(cleanup)?;
+                    }
+
+                    Ok(())
+                })?;
+
+                f.writeln("return builder;")
+            })?;
+
+            // Cleanup function
+            f.writeln("internal static void Cleanup(IntPtr value)")?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "{}.{}(value);",
+                    NATIVE_FUNCTIONS_CLASSNAME,
+                    map.delete_func.name.camel_case()
+                ))
+            })?;
+
+            Ok(())
+        })
+    })
+}
+
+pub(crate) fn generate_list_helpers(
+    f: &mut dyn Printer,
+    list: &ListHandle,
+    lib: &Library,
+) -> FormattingResult<()> {
+    let class_name = list.get_native_type();
+    let item_type = list.item_type.get_dotnet_type();
+    // enums are marshalled as their underlying integer because Marshal.SizeOf rejects them
+    let (native_item_type, to_native_item, from_native_item) = match &list.item_type {
+        ListItemType::Basic(BasicType::Enum(_)) => (
+            "int".to_string(),
+            "(int)value[i]".to_string(),
+            format!("({item_type})nativeItem"),
+        ),
+        ListItemType::Basic(BasicType::Flags(x)) => {
+            let int_type = Primitive::from(x.flags_type).get_dotnet_type();
+            (
+                int_type.clone(),
+                format!("({int_type})value[i]"),
+                format!("({item_type})nativeItem"),
+            )
+        }
+        x => (
+            x.get_native_type(),
+            list_item_to_native(x, "value[i]"),
+            list_item_to_dotnet(x, "nativeItem"),
+        ),
+    };
+
+    print_license(f, &lib.info.license_description)?;
+    print_imports(f)?;
+    f.newline()?;
+
+    namespaced(f, &lib.settings.name, |f| {
+        f.writeln("[StructLayout(LayoutKind.Sequential)]")?;
+        f.writeln(&format!("internal struct {class_name}"))?;
+        blocked(f, |f| {
+            f.writeln("IntPtr items;")?;
+            f.writeln("UIntPtr count;")?;
+            f.newline()?;
+
+            f.writeln(&format!(
+                "internal static {class_name} ToNative(System.Collections.Generic.IList<{item_type}> value)"
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!("var size = Marshal.SizeOf<{native_item_type}>();"))?;
+                f.writeln(&format!("var result = new {class_name}();"))?;
+                f.writeln("result.count = (UIntPtr)value.Count;")?;
+                f.writeln("result.items = Marshal.AllocHGlobal(size * value.Count);")?;
+                f.writeln("for (int i = 0; i < value.Count; ++i)")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "Marshal.StructureToPtr<{native_item_type}>({to_native_item}, IntPtr.Add(result.items, i * size), false);"
+                    ))
+                })?;
+                f.writeln("return result;")
+            })?;
+            f.newline()?;
+
+            f.writeln(&format!(
+                "internal static System.Collections.Generic.IList<{item_type}> FromNative({class_name} native)"
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!("var size = Marshal.SizeOf<{native_item_type}>();"))?;
+                f.writeln("var count = (int)native.count;")?;
+                f.writeln(&format!(
+                    "var result = new System.Collections.Generic.List<{item_type}>(count);"
+                ))?;
+                f.writeln("for (int i = 0; i < count; ++i)")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "var nativeItem = Marshal.PtrToStructure<{native_item_type}>(IntPtr.Add(native.items, i * size));"
+                    ))?;
+                    f.writeln(&format!("result.Add({from_native_item});"))
+                })?;
+                f.writeln("return result;")
+            })?;
+            f.newline()?;
+
+            f.writeln("internal void Dispose()")?;
+            blocked(f, |f| {
+                if let Some(cleanup) = list.item_type.cleanup_native("nativeItem") {
+                    f.writeln(&format!("var size = Marshal.SizeOf<{native_item_type}>();"))?;
+                    f.writeln("for (int i = 0; i < (int)this.count; ++i)")?;
+                    blocked(f, |f| {
+                        f.writeln(&format!(
+                            "var nativeItem = Marshal.PtrToStructure<{native_item_type}>(IntPtr.Add(this.items, i * size));"
+                        ))?;
+                        f.writeln(&cleanup)
+                    })?;
+                }
+                f.writeln("Marshal.FreeHGlobal(this.items);")
+            })
+        })
+    })
+}
+
+fn list_item_to_native(item: &ListItemType, expr: &str) -> String {
+    item.convert_to_native(expr)
+        .unwrap_or_else(|| expr.to_string())
+}
+
+fn list_item_to_dotnet(item: &ListItemType, expr: &str) -> String {
+    let converted = match item {
+        ListItemType::Basic(x) => x.convert_to_dotnet(expr),
+        ListItemType::Struct(x) => x.convert_to_dotnet(expr),
+    };
+    converted.unwrap_or_else(|| expr.to_string())
+}
+
 pub(crate) fn generate_iterator_helpers(
     f: &mut dyn Printer,
     iter: &Handle<AbstractIterator<Validated>>,
@@ -90,10 +274,7 @@ pub(crate) fn generate_iterator_helpers(
             iter.name().camel_case()
         ))?;
         blocked(f, |f| {
-            let value_type = match &iter.item_type {
-                IteratorItemType::Primitive(x) => x.get_dotnet_type(),
-                IteratorItemType::Struct(x) => x.get_dotnet_type(),
-            };
+            let value_type = iterator_value_type(iter);
 
             // ToNative function
             f.writeln(&format!("internal static System.Collections.Generic.ICollection<{value_type}> FromNative(IntPtr value)"))?;
@@ -111,22 +292,10 @@ pub(crate) fn generate_iterator_helpers(
                     "for (var itRawValue = {next_call}; itRawValue != IntPtr.Zero; itRawValue = {next_call})"
                 ))?;
                 blocked(f, |f| {
-                    // convert the value
-                    match &iter.item_type {
-                        IteratorItemType::Primitive(x) => f.writeln(&format!(
-                            "builder.Add({});",
-                            PrimitiveRef::new(*x)
-                                .convert_to_dotnet("itRawValue")
-                                .unwrap()
-                        )),
-                        IteratorItemType::Struct(x) => {
-                            let conversion = x
-                                .declaration()
-                                .convert_to_dotnet("itRawValue")
-                                .unwrap_or_else(|| "itRawValue".to_string());
-                            f.writeln(&format!("builder.Add({conversion});"))
-                        }
-                    }
+                    f.writeln(&format!(
+                        "builder.Add({});",
+                        convert_iterator_value(iter, "itRawValue")
+                    ))
                 })?;
                 f.writeln("return builder.ToImmutable();")
             })
@@ -134,6 +303,162 @@ pub(crate) fn generate_iterator_helpers(
     })
 }
 
+pub(crate) fn generate_owned_iterator(
+    f: &mut dyn Printer,
+    iter: &Handle<AbstractIterator<Validated>>,
+    destroy: &Handle<Function<Validated>>,
+    lib: &Library,
+) -> FormattingResult<()> {
+    let class_name = iter.name().camel_case();
+    let value_type = iterator_value_type(iter);
+    let enumerator_type = format!("System.Collections.Generic.IEnumerator<{value_type}>");
+
+    print_license(f, &lib.info.license_description)?;
+    print_imports(f)?;
+    f.newline()?;
+
+    namespaced(f, &lib.settings.name, |f| {
+        documentation(f, |f| {
+            f.writeln("<summary>")?;
+            f.write(&format!(
+                "Sequence of {value_type} values returned from a native function"
+            ))?;
+            f.write("</summary>")?;
+            f.writeln("<remarks>")?;
+            f.write("The values may only be enumerated once. Disposing the instance releases the native iterator.")?;
+            f.write("</remarks>")
+        })?;
+        f.writeln(&format!(
+            "public sealed class {class_name} : System.Collections.Generic.IEnumerable<{value_type}>, IDisposable"
+        ))?;
+        blocked(f, |f| {
+            f.writeln("internal readonly IntPtr self;")?;
+            f.writeln("private bool disposed = false;")?;
+            f.writeln("private bool enumerated = false;")?;
+            f.newline()?;
+
+            f.writeln(&format!("internal {class_name}(IntPtr self)"))?;
+            blocked(f, |f| f.writeln("this.self = self;"))?;
+            f.newline()?;
+
+            documentation(f, |f| {
+                f.writeln("<summary>")?;
+                f.write("Retrieve an enumerator over the values")?;
+                f.write("</summary>")?;
+                f.writeln("<returns>")?;
+                f.write("Enumerator over the values")?;
+                f.write("</returns>")?;
+                f.writeln("<exception cref=\"InvalidOperationException\">")?;
+                f.write("Thrown if the values were already enumerated")?;
+                f.write("</exception>")
+            })?;
+            f.writeln(&format!("public {enumerator_type} GetEnumerator()"))?;
+            blocked(f, |f| {
+                f.writeln("if (this.enumerated)")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "throw new InvalidOperationException(\"{class_name} may only be enumerated once\");"
+                    ))
+                })?;
+                f.writeln("this.enumerated = true;")?;
+                f.writeln("return this.Enumerate();")
+            })?;
+            f.newline()?;
+
+            f.writeln(
+                "System.Collections.IEnumerator System.Collections.IEnumerable.GetEnumerator()",
+            )?;
+            blocked(f, |f| f.writeln("return this.GetEnumerator();"))?;
+            f.newline()?;
+
+            f.writeln(&format!("private {enumerator_type} Enumerate()"))?;
+            blocked(f, |f| {
+                f.writeln("while (true)")?;
+                blocked(f, |f| {
+                    f.writeln("if (this.disposed)")?;
+                    blocked(f, |f| {
+                        f.writeln(&format!(
+                            "throw new ObjectDisposedException(\"{class_name}\");"
+                        ))
+                    })?;
+                    f.writeln(&format!(
+                        "var itRawValue = {}.{}(this.self);",
+                        NATIVE_FUNCTIONS_CLASSNAME,
+                        iter.next_function.name.camel_case()
+                    ))?;
+                    f.writeln("if (itRawValue == IntPtr.Zero)")?;
+                    blocked(f, |f| f.writeln("yield break;"))?;
+                    f.writeln(&format!(
+                        "yield return {};",
+                        convert_iterator_value(iter, "itRawValue")
+                    ))
+                })
+            })?;
+            f.newline()?;
+
+            documentation(f, |f| {
+                f.writeln("<summary>")?;
+                f.write("Release the native iterator")?;
+                f.write("</summary>")
+            })?;
+            f.writeln("public void Dispose()")?;
+            blocked(f, |f| {
+                f.writeln("Dispose(true);")?;
+                f.writeln("GC.SuppressFinalize(this);")
+            })?;
+            f.newline()?;
+
+            documentation(f, |f| {
+                f.writeln("<summary>")?;
+                f.write("Finalizer")?;
+                f.write("</summary>")
+            })?;
+            f.writeln(&format!("~{class_name}()"))?;
+            blocked(f, |f| f.writeln("Dispose(false);"))?;
+            f.newline()?;
+
+            f.writeln("private void Dispose(bool disposing)")?;
+            blocked(f, |f| {
+                f.writeln("if (this.disposed)")?;
+                f.writeln("    return;")?;
+                f.newline()?;
+                f.writeln(&format!(
+                    "{}.{}(this.self);",
+                    NATIVE_FUNCTIONS_CLASSNAME,
+                    destroy.name.camel_case()
+                ))?;
+                f.newline()?;
+                f.writeln("this.disposed = true;")
+            })
+        })
+    })
+}
+
+fn iterator_value_type(iter: &Handle<AbstractIterator<Validated>>) -> String {
+    match &iter.item_type {
+        IteratorItemType::Primitive(x) => x.get_dotnet_type(),
+        IteratorItemType::String(x) => x.get_dotnet_type(),
+        IteratorItemType::Enum(x) => x.get_dotnet_type(),
+        IteratorItemType::Duration(x) => x.get_dotnet_type(),
+        IteratorItemType::Class(x) => x.get_dotnet_type(),
+        IteratorItemType::Struct(x) => x.get_dotnet_type(),
+    }
+}
+
+fn convert_iterator_value(iter: &Handle<AbstractIterator<Validated>>, expr: &str) -> String {
+    match &iter.item_type {
+        IteratorItemType::Primitive(x) => PrimitiveRef::new(*x).convert_to_dotnet(expr).unwrap(),
+        IteratorItemType::String(x) => ValueRef::String(*x).read_dotnet_value(expr),
+        IteratorItemType::Enum(x) => ValueRef::Enum(x.clone()).read_dotnet_value(expr),
+        IteratorItemType::Duration(x) => ValueRef::Duration(*x).read_dotnet_value(expr),
+        IteratorItemType::Class(x) => x.convert_to_dotnet(expr).unwrap(),
+        IteratorItemType::Struct(x) => x
+            .declaration()
+            .convert_to_dotnet(expr)
+            .unwrap_or_else(|| expr.to_string()),
+    }
+}
+
 pub(crate) fn call_native_function(
     f: &mut dyn Printer,
     method: &Function<Validated>,
@@ -51,6 +51,9 @@ impl ConstantReturnValue for DurationValue {
         match self {
             DurationValue::Milliseconds(x) => format!("TimeSpan.FromMilliseconds({x})"),
             DurationValue::Seconds(x) => format!("TimeSpan.FromSeconds({x})"),
+            DurationValue::Microseconds(x) => format!("TimeSpan.FromTicks({x}L * 10)"),
+            DurationValue::Nanoseconds(x) => format!("TimeSpan.FromTicks({x}L / 100)"),
+            DurationValue::SignedMilliseconds(x) => format!("TimeSpan.FromMilliseconds({x})"),
         }
     }
 }
@@ -203,7 +206,7 @@ pub(crate) fn generate(
         // write a Task-based implementation if it's a future interface
         if let InterfaceType::Future(fi) = interface {
             let class_name = fi.interface.name.camel_case();
-            let value_type = fi.value_type.get_dotnet_type();
+            let value_type = completion_source_type(fi);
             let success_method_name = fi
                 .interface
                 .settings
@@ -222,10 +225,15 @@ pub(crate) fn generate(
                 ))?;
                 blocked(f, |f| f.writeln("this.tcs = tcs;"))?;
                 f.newline()?;
-                f.writeln(&format!(
-                    "void {interface_name}.{success_method_name}({value_type} value)"
-                ))?;
-                blocked(f, |f| f.writeln("Task.Run(() => tcs.SetResult(value));"))?;
+                if fi.value.is_some() {
+                    f.writeln(&format!(
+                        "void {interface_name}.{success_method_name}({value_type} value)"
+                    ))?;
+                    blocked(f, |f| f.writeln("Task.Run(() => tcs.SetResult(value));"))?;
+                } else {
+                    f.writeln(&format!("void {interface_name}.{success_method_name}()"))?;
+                    blocked(f, |f| f.writeln("Task.Run(() => tcs.SetResult(true));"))?;
+                }
                 f.newline()?;
 
                 let error_method_name = fi
@@ -242,8 +250,54 @@ pub(crate) fn generate(
                 ))?;
                 blocked(f, |f| {
                     f.writeln(&format!(
-                        "Task.Run(() => tcs.SetException(new {}(err)));",
-                        fi.error_type.exception_name.camel_case()
+                        "Task.Run(() => tcs.SetException({}));",
+                        construct_exception(&fi.error_type, "err", "err")
+                    ))
+                })?;
+
+                Ok(())
+            })?;
+            f.newline()?;
+        }
+
+        // write an adapter feeding an asynchronous sequence if it's a stream interface
+        if let InterfaceType::Stream(si) = interface {
+            let class_name = si.interface.name.camel_case();
+            let settings = &si.interface.settings.stream;
+            let channel_type = format!("Helpers.StreamChannel<{}>", si.item_type.get_dotnet_type());
+
+            f.writeln(&format!("internal class {class_name}: {interface_name}"))?;
+            blocked(f, |f| {
+                f.writeln(&format!("private readonly {channel_type} channel;"))?;
+                f.newline()?;
+                f.writeln(&format!("internal {class_name}({channel_type} channel)"))?;
+                blocked(f, |f| f.writeln("this.channel = channel;"))?;
+                f.newline()?;
+                f.writeln(&format!(
+                    "void {}.{}({} item)",
+                    interface_name,
+                    settings.item_callback_method_name.camel_case(),
+                    si.item_type.get_dotnet_type()
+                ))?;
+                blocked(f, |f| f.writeln("channel.Add(item);"))?;
+                f.newline()?;
+                f.writeln(&format!(
+                    "void {}.{}()",
+                    interface_name,
+                    settings.complete_callback_method_name.camel_case()
+                ))?;
+                blocked(f, |f| f.writeln("channel.Complete();"))?;
+                f.newline()?;
+                f.writeln(&format!(
+                    "void {}.{}({} err)",
+                    interface_name,
+                    settings.failure_callback_method_name.camel_case(),
+                    si.error_type.inner.get_dotnet_type()
+                ))?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "channel.Fail({});",
+                        construct_exception(&si.error_type, "err", "err")
                     ))
                 })?;
 
@@ -403,6 +457,16 @@ pub(crate) fn generate(
     })
 }
 
+/// type of the TaskCompletionSource completed by a future interface
+///
+/// a void future completes a TaskCompletionSource<bool> whose task is exposed as a plain Task
+pub(crate) fn completion_source_type(future: &FutureInterface<Validated>) -> String {
+    match &future.value {
+        Some(x) => x.value_type.get_dotnet_type(),
+        None => "bool".to_string(),
+    }
+}
+
 pub(crate) fn generate_interface_implementation(
     f: &mut dyn Printer,
     interface: &Handle<Interface<Validated>>,
@@ -19,6 +19,7 @@ mod formatting;
 mod helpers;
 mod interface;
 mod structure;
+mod tagged_union;
 mod wrappers;
 
 pub(crate) const NATIVE_FUNCTIONS_CLASSNAME: &str = "NativeFunctions";
@@ -85,6 +86,14 @@ impl TargetFramework {
             TargetFramework::NetStandard2_1 => true,
         }
     }
+
+    /// IAsyncEnumerable<T> is part of .NET Standard 2.1 but requires a package on 2.0
+    pub(crate) fn supports_async_enumerable(&self) -> bool {
+        match self {
+            TargetFramework::NetStandard2_0 => false,
+            TargetFramework::NetStandard2_1 => true,
+        }
+    }
 }
 
 pub(crate) struct DotnetBindgenConfig {
@@ -108,10 +117,14 @@ pub(crate) fn generate_dotnet_bindings(
     generate_constants(lib, config)?;
     generate_structs(lib, config)?;
     generate_enums(lib, config)?;
+    generate_flags(lib, config)?;
+    generate_unions(lib, config)?;
     generate_exceptions(lib, config)?;
     generate_classes(lib, config)?;
     generate_interfaces(lib, config)?;
     generate_collection_helpers(lib, config)?;
+    generate_map_helpers(lib, config)?;
+    generate_list_helpers(lib, config)?;
     generate_iterator_helpers(lib, config)?;
 
     // generate the helper classes
@@ -131,7 +144,19 @@ fn generate_helpers(lib: &Library, config: &DotnetBindgenConfig) -> FormattingRe
     let mut f = FilePrinter::new(filename)?;
 
     print_license(&mut f, &lib.info.license_description)?;
-    f.writeln(include_str!("../../../static/dotnet/Helpers.cs"))
+    f.writeln(include_str!("../../../static/dotnet/Helpers.cs"))?;
+
+    if lib.has_stream_interfaces() {
+        let mut filename = config.output_dir.clone();
+        filename.push("Streams");
+        filename.set_extension("cs");
+        let mut f = FilePrinter::new(filename)?;
+
+        print_license(&mut f, &lib.info.license_description)?;
+        f.writeln(include_str!("../../../static/dotnet/Streams.cs"))?;
+    }
+
+    Ok(())
 }
 
 fn generate_csproj(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
@@ -210,6 +235,11 @@ fn generate_csproj(lib: &Library, config: &DotnetBindgenConfig) -> FormattingRes
     f.writeln(
         "    <PackageReference Include=\"System.Collections.Immutable\" Version=\"1.7.1\" />",
     )?;
+    if lib.has_stream_interfaces() && !config.target_framework.supports_async_enumerable() {
+        f.writeln(
+            "    <PackageReference Include=\"Microsoft.Bcl.AsyncInterfaces\" Version=\"1.1.1\" />",
+        )?;
+    }
     f.writeln(&format!(
         "    <None Include=\"{}\" Pack=\"true\" PackagePath=\"\" />",
         dunce::canonicalize(&lib.info.license_path)?.to_string_lossy()
@@ -339,7 +369,45 @@ fn generate_enums(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResu
     Ok(())
 }
 
+fn generate_flags(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+    for flags in lib.flags() {
+        // Open file
+        let mut filename = config.output_dir.clone();
+        filename.push(flags.name.camel_case());
+        filename.set_extension("cs");
+        let mut f = FilePrinter::new(filename)?;
+
+        generate_flags_enum(&mut f, flags, lib)?;
+    }
+
+    Ok(())
+}
+
+fn generate_unions(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+    for union in lib.unions() {
+        // Open file
+        let mut filename = config.output_dir.clone();
+        filename.push(union.name.camel_case());
+        filename.set_extension("cs");
+        let mut f = FilePrinter::new(filename)?;
+
+        tagged_union::generate(&mut f, lib, union)?;
+    }
+
+    Ok(())
+}
+
 fn generate_exceptions(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+    for base in lib.base_exceptions() {
+        // Open file
+        let mut filename = config.output_dir.clone();
+        filename.push(base.name.camel_case());
+        filename.set_extension("cs");
+        let mut f = FilePrinter::new(filename)?;
+
+        generate_base_exception(&mut f, base, lib)?;
+    }
+
     for err in lib.error_types() {
         // Open file
         let mut filename = config.output_dir.clone();
@@ -348,6 +416,15 @@ fn generate_exceptions(lib: &Library, config: &DotnetBindgenConfig) -> Formattin
         let mut f = FilePrinter::new(filename)?;
 
         generate_exception(&mut f, err, lib)?;
+
+        for (variant, _) in err.variant_exceptions() {
+            let mut filename = config.output_dir.clone();
+            filename.push(variant.exception_name.camel_case());
+            filename.set_extension("cs");
+            let mut f = FilePrinter::new(filename)?;
+
+            generate_variant_exception(&mut f, err, variant, lib)?;
+        }
     }
 
     Ok(())
@@ -358,15 +435,38 @@ fn generate_constant_set(
     set: &Handle<ConstantSet<Validated>>,
     lib: &Library,
 ) -> FormattingResult<()> {
-    fn get_type_as_string(value: &ConstantValue) -> &'static str {
+    fn get_type_as_string(value: &ConstantValue) -> String {
         match value {
-            ConstantValue::U8(_, _) => "byte",
+            ConstantValue::Primitive(x, _) => Primitive::from(*x).get_dotnet_type(),
+            ConstantValue::Duration(_) => "TimeSpan".to_string(),
+            ConstantValue::String(_) => "string".to_string(),
+            ConstantValue::Enum(x) => x.handle.name.camel_case(),
         }
     }
 
     fn get_value_as_string(value: &ConstantValue) -> String {
         match value {
-            ConstantValue::U8(x, Representation::Hex) => format!("0x{x:02X?}"),
+            ConstantValue::Primitive(x, rep) => match x {
+                PrimitiveValue::Bool(x) => x.to_string(),
+                PrimitiveValue::Float(x) => format!("{x:?}F"),
+                PrimitiveValue::Double(x) => format!("{x:?}"),
+                _ => format_integer(integer_value(x).unwrap(), *rep),
+            },
+            ConstantValue::Duration(x) => match x {
+                DurationValue::Milliseconds(x) => format!("TimeSpan.FromMilliseconds({x})"),
+                DurationValue::Seconds(x) => format!("TimeSpan.FromSeconds({x})"),
+                DurationValue::Microseconds(x) => format!("TimeSpan.FromTicks({x}L * 10)"),
+                DurationValue::Nanoseconds(x) => format!("TimeSpan.FromTicks({x}L / 100)"),
+                DurationValue::SignedMilliseconds(x) => {
+                    format!("TimeSpan.FromMilliseconds({x})")
+                }
+            },
+            ConstantValue::String(x) => quoted_string(x),
+            ConstantValue::Enum(x) => format!(
+                "{}.{}",
+                x.handle.name.camel_case(),
+                x.variant.name.camel_case()
+            ),
         }
     }
 
@@ -384,8 +484,14 @@ fn generate_constant_set(
         blocked(f, |f| {
             for value in &set.values {
                 documentation(f, |f| xmldoc_print(f, &value.doc))?;
+                // TimeSpan isn't a compile-time constant
+                let modifiers = match value.value {
+                    ConstantValue::Duration(_) => "static readonly",
+                    _ => "const",
+                };
                 f.writeln(&format!(
-                    "public const {} {} = {};",
+                    "public {} {} {} = {};",
+                    modifiers,
                     get_type_as_string(&value.value),
                     value.name.camel_case(),
                     get_value_as_string(&value.value),
@@ -426,6 +532,146 @@ fn generate_enum(
     })
 }
 
+fn generate_flags_enum(
+    f: &mut impl Printer,
+    flags: &Handle<Flags<Validated>>,
+    lib: &Library,
+) -> FormattingResult<()> {
+    print_license(f, &lib.info.license_description)?;
+    print_imports(f)?;
+    f.newline()?;
+
+    namespaced(f, &lib.settings.name, |f| {
+        documentation(f, |f| {
+            // Print top-level documentation
+            xmldoc_print(f, &flags.doc)
+        })?;
+
+        f.writeln("[Flags]")?;
+        f.writeln(&format!(
+            "public enum {} : {}",
+            flags.name.camel_case(),
+            Primitive::from(flags.flags_type).get_dotnet_type()
+        ))?;
+        blocked(f, |f| {
+            for bit in &flags.bits {
+                documentation(f, |f| xmldoc_print(f, &bit.doc))?;
+                f.writeln(&format!("{} = 0x{:X},", bit.name.camel_case(), bit.value))?;
+            }
+            Ok(())
+        })
+    })
+}
+
+fn generate_base_exception(
+    f: &mut impl Printer,
+    base: &Handle<BaseException<Validated>>,
+    lib: &Library,
+) -> FormattingResult<()> {
+    print_license(f, &lib.info.license_description)?;
+    print_imports(f)?;
+    f.newline()?;
+
+    namespaced(f, &lib.settings.name, |f| {
+        documentation(f, |f| xmldoc_print(f, &base.doc))?;
+
+        let name = base.name.camel_case();
+        let parent = match &base.parent {
+            None => "Exception".to_string(),
+            Some(x) => x.name.camel_case(),
+        };
+
+        f.writeln(&format!("public abstract class {name}: {parent}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("protected {name}(string message) : base(message)"))?;
+            blocked(f, |_| Ok(()))
+        })
+    })
+}
+
+fn generate_variant_exception(
+    f: &mut impl Printer,
+    err: &ErrorType<Validated>,
+    variant: &VariantException,
+    lib: &Library,
+) -> FormattingResult<()> {
+    print_license(f, &lib.info.license_description)?;
+    print_imports(f)?;
+    f.newline()?;
+
+    namespaced(f, &lib.settings.name, |f| {
+        let parent = err.exception_name.camel_case();
+        let error_name = err.inner.name.camel_case();
+        let exception_name = variant.exception_name.camel_case();
+
+        documentation(f, |f| {
+            f.writeln("<summary>")?;
+            f.write(&format!(
+                "Exception thrown in place of <see cref=\"{parent}\" /> when the error is <see cref=\"{error_name}.{}\" />",
+                variant.variant.camel_case()
+            ))?;
+            f.write("</summary>")
+        })?;
+
+        f.writeln(&format!("public sealed class {exception_name}: {parent}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "internal {exception_name}({error_name} error) : base(error)"
+            ))?;
+            blocked(f, |_| Ok(()))?;
+            if err.has_detail() {
+                let (params, args) = detailed_exception_parameters(err);
+                f.newline()?;
+                f.writeln(&format!(
+                    "internal {exception_name}({}) : base({})",
+                    params.join(", "),
+                    args.join(", ")
+                ))?;
+                blocked(f, |_| Ok(()))?;
+            }
+            Ok(())
+        })
+    })
+}
+
+/// parameter declarations and names of the constructor that accepts the detail of an error
+fn detailed_exception_parameters(err: &ErrorType<Validated>) -> (Vec<String>, Vec<&'static str>) {
+    let mut params = vec![format!("{} error", err.inner.name.camel_case())];
+    let mut args = vec!["error"];
+    if err.message {
+        params.push("string detail".to_string());
+        args.push("detail");
+    }
+    if let Some(payload) = &err.payload {
+        params.push(format!("{} payload", payload.name().camel_case()));
+        args.push("payload");
+    }
+    (params, args)
+}
+
+/// expression that creates the exception for an error, using the exception of the variant if it has one
+pub(crate) fn construct_exception(
+    err: &ErrorType<Validated>,
+    variable: &str,
+    args: &str,
+) -> String {
+    let error_name = err.inner.name.camel_case();
+    let mut expr = format!("new {}({args})", err.exception_name.camel_case());
+    for (x, _) in err
+        .variant_exceptions()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        expr = format!(
+            "{variable} == {error_name}.{} ? new {}({args}) : {expr}",
+            x.variant.camel_case(),
+            x.exception_name.camel_case()
+        );
+    }
+    expr
+}
+
 fn generate_exception(
     f: &mut impl Printer,
     err: &ErrorType<Validated>,
@@ -444,7 +690,12 @@ fn generate_exception(
         let error_name = err.inner.name.camel_case();
         let exception_name = err.exception_name.camel_case();
 
-        f.writeln(&format!("public class {exception_name}: Exception"))?;
+        let parent = match &err.base {
+            None => "Exception".to_string(),
+            Some(x) => x.name.camel_case(),
+        };
+
+        f.writeln(&format!("public class {exception_name}: {parent}"))?;
         blocked(f, |f| {
             documentation(f, |f| {
                 f.writeln("<summary>")?;
@@ -452,11 +703,54 @@ fn generate_exception(
                 f.write("</summary>")
             })?;
             f.writeln(&format!("public readonly {error_name} error;"))?;
+            if err.message {
+                documentation(f, |f| {
+                    f.writeln("<summary>")?;
+                    f.write("Detailed message of the error, or null if no message was provided")?;
+                    f.write("</summary>")
+                })?;
+                f.writeln("public readonly string detail;")?;
+            }
+            if let Some(payload) = &err.payload {
+                documentation(f, |f| {
+                    f.writeln("<summary>")?;
+                    f.write("Payload of the error, or null if no payload was provided")?;
+                    f.write("</summary>")
+                })?;
+                f.writeln(&format!(
+                    "public readonly {} payload;",
+                    payload.name().camel_case()
+                ))?;
+            }
             f.newline()?;
             f.writeln(&format!(
                 "internal {exception_name}({error_name} error) : base(error.ToString())"
             ))?;
-            blocked(f, |f| f.writeln("this.error = error;"))
+            blocked(f, |f| f.writeln("this.error = error;"))?;
+            if err.has_detail() {
+                let (params, _) = detailed_exception_parameters(err);
+                let message = if err.message {
+                    "detail == null ? error.ToString() : error.ToString() + \": \" + detail"
+                } else {
+                    "error.ToString()"
+                };
+                f.newline()?;
+                f.writeln(&format!(
+                    "internal {exception_name}({}) : base({message})",
+                    params.join(", ")
+                ))?;
+                blocked(f, |f| {
+                    f.writeln("this.error = error;")?;
+                    if err.message {
+                        f.writeln("this.detail = detail;")?;
+                    }
+                    if err.payload.is_some() {
+                        f.writeln("this.payload = payload;")?;
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
         })
     })
 }
@@ -508,6 +802,15 @@ fn generate_iterator_helpers(lib: &Library, config: &DotnetBindgenConfig) -> For
         let mut f = FilePrinter::new(filename)?;
 
         helpers::generate_iterator_helpers(&mut f, iter, lib)?;
+
+        if let Some(destroy) = &iter.destroy_function {
+            let mut filename = config.output_dir.clone();
+            filename.push(iter.name().camel_case());
+            filename.set_extension("cs");
+            let mut f = FilePrinter::new(filename)?;
+
+            helpers::generate_owned_iterator(&mut f, iter, destroy, lib)?;
+        }
     }
 
     Ok(())
@@ -530,6 +833,34 @@ fn generate_collection_helpers(
     Ok(())
 }
 
+fn generate_map_helpers(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+    for map in lib.maps() {
+        // Open file
+        let mut filename = config.output_dir.clone();
+        filename.push(format!("{}Helpers", map.name().camel_case()));
+        filename.set_extension("cs");
+        let mut f = FilePrinter::new(filename)?;
+
+        helpers::generate_map_helpers(&mut f, map, lib)?;
+    }
+
+    Ok(())
+}
+
+fn generate_list_helpers(lib: &Library, config: &DotnetBindgenConfig) -> FormattingResult<()> {
+    for list in lib.lists() {
+        // Open file
+        let mut filename = config.output_dir.clone();
+        filename.push(format!("{}Native", list.name.camel_case()));
+        filename.set_extension("cs");
+        let mut f = FilePrinter::new(filename)?;
+
+        helpers::generate_list_helpers(&mut f, list, lib)?;
+    }
+
+    Ok(())
+}
+
 fn print_license(f: &mut dyn Printer, license: &[String]) -> FormattingResult<()> {
     commented(f, |f| {
         for line in license.iter() {
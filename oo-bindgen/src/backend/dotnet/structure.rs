@@ -46,36 +46,7 @@ where
     T: StructFieldType,
 {
     match constructor.values.iter().find(|x| x.name == field.name) {
-        Some(x) => match &x.value {
-            ValidatedDefaultValue::Bool(x) => x.to_string(),
-            ValidatedDefaultValue::Number(x) => match x {
-                NumberValue::U8(x) => x.to_string(),
-                NumberValue::S8(x) => x.to_string(),
-                NumberValue::U16(x) => x.to_string(),
-                NumberValue::S16(x) => x.to_string(),
-                NumberValue::U32(x) => x.to_string(),
-                NumberValue::S32(x) => x.to_string(),
-                NumberValue::U64(x) => x.to_string(),
-                NumberValue::S64(x) => x.to_string(),
-                NumberValue::Float(x) => format!("{x}F"),
-                NumberValue::Double(x) => x.to_string(),
-            },
-            ValidatedDefaultValue::Duration(t, x) => match t {
-                DurationType::Milliseconds => {
-                    format!("TimeSpan.FromMilliseconds({})", t.get_value_string(*x))
-                }
-                DurationType::Seconds => {
-                    format!("TimeSpan.FromSeconds({})", t.get_value_string(*x))
-                }
-            },
-            ValidatedDefaultValue::Enum(x, variant) => {
-                format!("{}.{}", x.name.camel_case(), variant.camel_case())
-            }
-            ValidatedDefaultValue::String(x) => format!("\"{x}\""),
-            ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
-                format!("new {}()", handle.name().camel_case())
-            }
-        },
+        Some(x) => get_default_value(&x.value),
         None => field.name.mixed_case(),
     }
 }
@@ -112,14 +83,48 @@ where
     })
 }
 
-fn get_default_value_doc(x: &ValidatedDefaultValue) -> String {
+pub(crate) fn get_default_value(x: &ValidatedDefaultValue) -> String {
     match x {
         ValidatedDefaultValue::Bool(x) => x.to_string(),
-        ValidatedDefaultValue::Number(x) => x.to_string(),
-        ValidatedDefaultValue::Duration(DurationType::Milliseconds, x) => {
-            format!("{}ms", x.as_millis())
+        ValidatedDefaultValue::Number(x) => match x {
+            NumberValue::U8(x) => x.to_string(),
+            NumberValue::S8(x) => x.to_string(),
+            NumberValue::U16(x) => x.to_string(),
+            NumberValue::S16(x) => x.to_string(),
+            NumberValue::U32(x) => x.to_string(),
+            NumberValue::S32(x) => x.to_string(),
+            NumberValue::U64(x) => x.to_string(),
+            NumberValue::S64(x) => x.to_string(),
+            NumberValue::Float(x) => format!("{x}F"),
+            NumberValue::Double(x) => x.to_string(),
+        },
+        ValidatedDefaultValue::Duration(t, x) => match t {
+            DurationType::Milliseconds | DurationType::SignedMilliseconds => {
+                format!("TimeSpan.FromMilliseconds({})", t.get_value_string(*x))
+            }
+            DurationType::Seconds | DurationType::FloatSeconds => {
+                format!("TimeSpan.FromSeconds({})", t.get_value_string(*x))
+            }
+            DurationType::Microseconds | DurationType::Nanoseconds => {
+                // a tick is 100 nanoseconds
+                format!("TimeSpan.FromTicks({})", x.as_nanos() / 100)
+            }
+        },
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!("{}.{}", x.name.camel_case(), variant.camel_case())
+        }
+        ValidatedDefaultValue::String(x) => format!("\"{x}\""),
+        ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
+            format!("new {}()", handle.name().camel_case())
         }
-        ValidatedDefaultValue::Duration(DurationType::Seconds, x) => format!("{}s", x.as_secs()),
+    }
+}
+
+pub(crate) fn get_default_value_doc(x: &ValidatedDefaultValue) -> String {
+    match x {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => x.to_string(),
+        ValidatedDefaultValue::Duration(t, x) => t.get_value_abbreviation(*x),
         ValidatedDefaultValue::Enum(handle, variant) => format!(
             "<see cref=\"{}.{}\" />",
             handle.name.camel_case(),
@@ -0,0 +1,176 @@
+use crate::backend::dotnet::doc::*;
+use crate::backend::dotnet::formatting::*;
+use crate::backend::dotnet::*;
+
+pub(crate) fn generate(
+    f: &mut dyn Printer,
+    lib: &Library,
+    handle: &Handle<Union<Validated>>,
+) -> FormattingResult<()> {
+    let union_name = handle.name.camel_case();
+    let union_native_name = format!("{union_name}Native");
+    let value_native_name = format!("{union_name}NativeValue");
+
+    print_license(f, &lib.info.license_description)?;
+    print_imports(f)?;
+    f.newline()?;
+
+    namespaced(f, &lib.settings.name, |f| {
+        documentation(f, |f| xmldoc_print(f, &handle.doc))?;
+
+        // Abstract base class with a nested sealed subclass per variant
+        f.writeln(&format!("public abstract class {union_name}"))?;
+        blocked(f, |f| {
+            f.writeln("// only the nested variant classes may derive from this class")?;
+            f.writeln(&format!("private {union_name}() {{ }}"))?;
+
+            for variant in &handle.variants {
+                let variant_name = variant.name.camel_case();
+                // the payload type is fully qualified as it may be shadowed by a variant class
+                let value_type = format!(
+                    "global::{}.{}",
+                    lib.settings.name,
+                    variant.value.name().camel_case()
+                );
+
+                f.newline()?;
+                documentation(f, |f| xmldoc_print(f, &variant.doc))?;
+                f.writeln(&format!(
+                    "public sealed class {variant_name} : {union_name}"
+                ))?;
+                blocked(f, |f| {
+                    documentation(f, |f| {
+                        f.writeln("<summary>Payload of the variant</summary>")
+                    })?;
+                    f.writeln(&format!("public readonly {value_type} Value;"))?;
+                    f.newline()?;
+                    documentation(f, |f| {
+                        f.writeln(&format!(
+                            "<summary>Construct the {variant_name} variant</summary>"
+                        ))?;
+                        f.writeln("<param name=\"value\">Payload of the variant</param>")
+                    })?;
+                    f.writeln(&format!("public {variant_name}({value_type} value)"))?;
+                    blocked(f, |f| {
+                        f.writeln(
+                            "this.Value = value ?? throw new ArgumentNullException(nameof(value));",
+                        )
+                    })
+                })?;
+            }
+            Ok(())
+        })?;
+
+        f.newline()?;
+
+        // Every payload starts at the same offset, mirroring the C union
+        f.writeln("[StructLayout(LayoutKind.Explicit)]")?;
+        f.writeln(&format!("internal struct {value_native_name}"))?;
+        blocked(f, |f| {
+            for variant in &handle.variants {
+                f.writeln(&format!(
+                    "[FieldOffset(0)] internal {}Native {};",
+                    variant.value.name().camel_case(),
+                    variant.name.camel_case()
+                ))?;
+            }
+            Ok(())
+        })?;
+
+        f.newline()?;
+
+        f.writeln("[StructLayout(LayoutKind.Sequential)]")?;
+        f.writeln(&format!("internal struct {union_native_name}"))?;
+        blocked(f, |f| {
+            f.writeln("int tag;")?;
+            f.writeln(&format!("{value_native_name} value;"))?;
+
+            f.newline()?;
+
+            // Convert from .NET to native
+            f.writeln(&format!(
+                "internal static {union_native_name} ToNative({union_name} self)"
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "{union_native_name} result = new {union_native_name}();"
+                ))?;
+                f.writeln("switch (self)")?;
+                blocked(f, |f| {
+                    for (index, variant) in handle.variants.iter().enumerate() {
+                        f.writeln(&format!(
+                            "case {}.{} x:",
+                            union_name,
+                            variant.name.camel_case()
+                        ))?;
+                        indented(f, |f| {
+                            f.writeln(&format!("result.tag = {index};"))?;
+                            f.writeln(&format!(
+                                "result.value.{} = {}Native.ToNative(x.Value);",
+                                variant.name.camel_case(),
+                                variant.value.name().camel_case()
+                            ))?;
+                            f.writeln("break;")
+                        })?;
+                    }
+                    f.writeln("default:")?;
+                    indented(f, |f| {
+                        f.writeln(&format!(
+                            "throw new ArgumentException(\"Unknown variant of {union_name}\");"
+                        ))
+                    })
+                })?;
+                f.writeln("return result;")
+            })?;
+
+            f.newline()?;
+
+            // Convert from native to .NET
+            f.writeln(&format!(
+                "internal static {union_name} FromNative({union_native_name} native)"
+            ))?;
+            blocked(f, |f| {
+                f.writeln("switch (native.tag)")?;
+                blocked(f, |f| {
+                    for (index, variant) in handle.variants.iter().enumerate() {
+                        f.writeln(&format!(
+                            "case {}: return new {}.{}({}Native.FromNative(native.value.{}));",
+                            index,
+                            union_name,
+                            variant.name.camel_case(),
+                            variant.value.name().camel_case(),
+                            variant.name.camel_case()
+                        ))?;
+                    }
+                    f.writeln("default:")?;
+                    indented(f, |f| {
+                        f.writeln(&format!(
+                            "throw new InvalidOperationException(\"Unknown variant of {union_name}\");"
+                        ))
+                    })
+                })
+            })?;
+
+            f.newline()?;
+
+            // Finalizer
+            f.writeln("internal void Dispose()")?;
+            blocked(f, |f| {
+                f.writeln("switch (this.tag)")?;
+                blocked(f, |f| {
+                    for (index, variant) in handle.variants.iter().enumerate() {
+                        f.writeln(&format!("case {index}:"))?;
+                        indented(f, |f| {
+                            f.writeln(&format!(
+                                "this.value.{}.Dispose();",
+                                variant.name.camel_case()
+                            ))?;
+                            f.writeln("break;")
+                        })?;
+                    }
+                    Ok(())
+                })
+            })
+        })
+    })
+}
@@ -78,11 +78,43 @@ pub(crate) fn generate_native_functions_class(
             for func in lib.functions() {
                 write_pinvoke_signature(f, func, &lib.settings.c_ffi_prefix, config)?;
             }
+            for err in lib.error_types() {
+                write_error_detail_pinvoke_signatures(f, err, config)?;
+            }
             Ok(())
         })
     })
 }
 
+fn write_throw(
+    f: &mut dyn Printer,
+    err: &ErrorType<Validated>,
+    error: &str,
+) -> FormattingResult<()> {
+    let mut args = vec![error.to_string()];
+    if err.message {
+        f.writeln(&format!(
+            "var _detail = PInvoke.{}();",
+            err.inner.name.camel_case() + "LastMessage"
+        ))?;
+        args.push(format!(
+            "_detail == IntPtr.Zero ? null : {}",
+            StringType.convert_to_dotnet("_detail").unwrap()
+        ));
+    }
+    if let Some(payload) = &err.payload {
+        f.writeln(&format!(
+            "var _payload = PInvoke.{}();",
+            err.inner.name.camel_case() + "LastPayload"
+        ))?;
+        args.push(payload.declaration().convert_to_dotnet("_payload").unwrap());
+    }
+    f.writeln(&format!(
+        "throw {};",
+        construct_exception(err, error, &args.join(", "))
+    ))
+}
+
 fn write_exception_and_return_blocks(
     f: &mut dyn Printer,
     err: &ErrorType<Validated>,
@@ -91,22 +123,19 @@ fn write_exception_and_return_blocks(
 ) -> FormattingResult<()> {
     match func.return_type.get() {
         Some(ret) => {
+            let separator = if params.is_empty() { "" } else { ", " };
             f.writeln(&format!(
-                "var _error_result = PInvoke.{}({}, out {} _return_value);",
+                "var _error_result = PInvoke.{}({}{}out {} _return_value);",
                 func.name.camel_case(),
                 params,
+                separator,
                 ret.value.get_native_type()
             ))?;
             f.writeln(&format!(
                 "if(_error_result != {}.Ok)",
                 err.inner.name.camel_case()
             ))?;
-            blocked(f, |f| {
-                f.writeln(&format!(
-                    "throw new {}(_error_result);",
-                    err.exception_name.camel_case()
-                ))
-            })?;
+            blocked(f, |f| write_throw(f, err, "_error_result"))?;
             f.writeln("return _return_value;")
         }
         None => {
@@ -116,12 +145,7 @@ fn write_exception_and_return_blocks(
                 params
             ))?;
             f.writeln(&format!("if(error != {}.Ok)", err.inner.name.camel_case()))?;
-            blocked(f, |f| {
-                f.writeln(&format!(
-                    "throw new {}(error);",
-                    err.exception_name.camel_case()
-                ))
-            })
+            blocked(f, |f| write_throw(f, err, "error"))
         }
     }
 }
@@ -252,3 +276,32 @@ fn write_pinvoke_signature(
 
     f.write(");")
 }
+
+fn write_error_detail_pinvoke_signatures(
+    f: &mut dyn Printer,
+    err: &ErrorType<Validated>,
+    config: &DotnetBindgenConfig,
+) -> FormattingResult<()> {
+    let functions = [
+        (err.message, err.last_message_function_name(), "LastMessage"),
+        (
+            err.payload.is_some(),
+            err.last_payload_function_name(),
+            "LastPayload",
+        ),
+    ];
+
+    for (_, entry_point, suffix) in functions.iter().filter(|(enabled, _, _)| *enabled) {
+        f.writeln(&format!(
+            "[DllImport(\"{}\", CallingConvention = CallingConvention.Cdecl, EntryPoint = \"{}\")]",
+            config.ffi_name, entry_point
+        ))?;
+        f.writeln(&format!(
+            "internal static extern IntPtr {}{}();",
+            err.inner.name.camel_case(),
+            suffix
+        ))?;
+    }
+
+    Ok(())
+}
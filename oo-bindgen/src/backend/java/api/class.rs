@@ -1,4 +1,5 @@
 use super::doc::*;
+use super::structure::{get_default_value, get_default_value_doc};
 use super::*;
 use heck::ToUpperCamelCase;
 
@@ -13,8 +14,17 @@ pub(crate) fn generate(
 
     // Class definition
     f.writeln(&format!("public final class {classname}"))?;
-    if matches!(class.destruction_mode, DestructionMode::Dispose) {
-        f.write(" implements AutoCloseable")?;
+    let interfaces: Vec<String> = class
+        .interfaces
+        .iter()
+        .map(|x| x.name.camel_case())
+        .chain(
+            matches!(class.destruction_mode, DestructionMode::Dispose)
+                .then(|| "AutoCloseable".to_string()),
+        )
+        .collect();
+    if !interfaces.is_empty() {
+        f.write(&format!(" implements {}", interfaces.join(", ")))?;
     }
 
     blocked(f, |f| {
@@ -45,11 +55,21 @@ pub(crate) fn generate(
             f.newline()?;
         }
 
+        for method in &class.property_accessors(Some("get")) {
+            generate_method(f, method)?;
+            f.newline()?;
+        }
+
         for method in &class.future_methods {
             generate_async_method(f, method)?;
             f.newline()?;
         }
 
+        for method in &class.stream_methods {
+            generate_stream_method(f, method)?;
+            f.newline()?;
+        }
+
         for method in &class.static_methods {
             generate_static_method(f, method)?;
             f.newline()?;
@@ -90,56 +110,49 @@ fn generate_constructor(
     classname: &str,
     constructor: &ClassConstructor<Validated>,
 ) -> FormattingResult<()> {
-    documentation(f, |f| {
-        // Print top-level documentation
-        javadoc_print(f, &constructor.function.doc)?;
-        f.newline()?;
+    let args = constructor.function.arguments.as_slice();
+    for_each_overload(f, args, |f, args, omitted| {
+        documentation(f, |f| {
+            // Print top-level documentation
+            javadoc_print(f, &constructor.function.doc)?;
+            print_omitted_args(f, omitted)?;
+            f.newline()?;
 
-        // Print each parameter value
-        for param in constructor.function.arguments.iter() {
-            f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
-            docstring_print(f, &param.doc)?;
-        }
+            // Print each parameter value
+            for param in args {
+                f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
+                docstring_print(f, &param.doc)?;
+            }
 
-        // Print exception
-        if let Some(error) = &constructor.function.error_type.get() {
-            f.writeln(&format!(
-                "@throws {} {}",
-                error.exception_name.camel_case(),
-                error.inner.name.camel_case()
-            ))?;
-        }
+            // Print exception
+            if let Some(error) = &constructor.function.error_type.get() {
+                f.writeln(&format!(
+                    "@throws {} {}",
+                    error.exception_name.camel_case(),
+                    error.inner.name.camel_case()
+                ))?;
+            }
 
-        Ok(())
-    })?;
+            Ok(())
+        })?;
 
-    f.writeln(&format!("public {classname}("))?;
-    f.write(
-        &constructor
-            .function
-            .arguments
-            .iter()
-            .map(|param| {
-                format!(
-                    "{} {}",
-                    param.arg_type.as_java_primitive(),
-                    param.name.mixed_case()
-                )
-            })
-            .collect::<Vec<String>>()
-            .join(", "),
-    )?;
-    f.write(")")?;
+        f.writeln(&format!("public {classname}("))?;
+        f.write(&parameters(args))?;
+        f.write(")")?;
 
-    blocked(f, |f| {
-        call_native_function(
-            f,
-            &constructor.function,
-            &format!("{classname} object = "),
-            false,
-        )?;
-        f.writeln("this.self = object.self;")?;
-        f.writeln("object.disposed.set(true);")
+        blocked(f, |f| {
+            if !omitted.is_empty() {
+                return f.writeln(&format!("this({});", arguments(args, omitted)));
+            }
+            call_native_function(
+                f,
+                &constructor.function,
+                &format!("{classname} object = "),
+                false,
+            )?;
+            f.writeln("this.self = object.self;")?;
+            f.writeln("object.disposed.set(true);")
+        })
     })
 }
 
@@ -205,66 +218,59 @@ fn generate_destructor(
 }
 
 fn generate_method(f: &mut dyn Printer, method: &Method<Validated>) -> FormattingResult<()> {
-    documentation(f, |f| {
-        // Print top-level documentation
-        javadoc_print(f, &method.native_function.doc)?;
-        f.newline()?;
+    let args = &method.native_function.arguments[1..];
+    for_each_overload(f, args, |f, args, omitted| {
+        documentation(f, |f| {
+            // Print top-level documentation
+            javadoc_print(f, &method.native_function.doc)?;
+            print_omitted_args(f, omitted)?;
+            f.newline()?;
 
-        // Print each parameter value
-        for param in method.native_function.arguments.iter().skip(1) {
-            f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
-            docstring_print(f, &param.doc)?;
-        }
+            // Print each parameter value
+            for param in args {
+                f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
+                docstring_print(f, &param.doc)?;
+            }
 
-        // Print return value
-        if let Some(doc) = &method.native_function.return_type.get_doc() {
-            f.writeln("@return ")?;
-            docstring_print(f, doc)?;
-        }
+            // Print return value
+            if let Some(doc) = &method.native_function.return_type.get_doc() {
+                f.writeln("@return ")?;
+                docstring_print(f, doc)?;
+            }
 
-        // Print exception
-        if let Some(error) = &method.native_function.error_type.get() {
-            f.writeln(&format!(
-                "@throws {} {}",
-                error.exception_name.camel_case(),
-                error.inner.name.camel_case()
-            ))?;
-        }
+            // Print exception
+            if let Some(error) = &method.native_function.error_type.get() {
+                f.writeln(&format!(
+                    "@throws {} {}",
+                    error.exception_name.camel_case(),
+                    error.inner.name.camel_case()
+                ))?;
+            }
 
-        Ok(())
-    })?;
+            Ok(())
+        })?;
 
-    f.writeln(&format!(
-        "public {} {}(",
-        method.native_function.return_type.as_java_primitive(),
-        method.name.mixed_case()
-    ))?;
-    f.write(
-        &method
-            .native_function
-            .arguments
-            .iter()
-            .skip(1)
-            .map(|param| {
-                format!(
-                    "{} {}",
-                    param.arg_type.as_java_primitive(),
-                    param.name.mixed_case()
-                )
-            })
-            .collect::<Vec<String>>()
-            .join(", "),
-    )?;
-    f.write(")")?;
+        f.writeln(&format!(
+            "public {} {}(",
+            method.native_function.return_type.as_java_primitive(),
+            method.name.mixed_case()
+        ))?;
+        f.write(&parameters(args))?;
+        f.write(")")?;
 
-    if let Some(error) = method.native_function.error_type.get() {
-        if error.exception_type == ExceptionType::CheckedException {
-            f.write(&format!(" throws {}", error.exception_name.camel_case()))?;
+        if let Some(error) = method.native_function.error_type.get() {
+            if error.exception_type == ExceptionType::CheckedException {
+                f.write(&format!(" throws {}", error.exception_name.camel_case()))?;
+            }
         }
-    }
 
-    blocked(f, |f| {
-        call_native_function(f, &method.native_function, "return ", true)
+        blocked(f, |f| {
+            if omitted.is_empty() {
+                call_native_function(f, &method.native_function, "return ", true)
+            } else {
+                call_overload(f, &method.native_function, &method.name, args, omitted)
+            }
+        })
     })
 }
 
@@ -272,21 +278,217 @@ fn generate_static_method(
     f: &mut dyn Printer,
     method: &StaticMethod<Validated>,
 ) -> FormattingResult<()> {
+    let args = method.native_function.arguments.as_slice();
+    for_each_overload(f, args, |f, args, omitted| {
+        documentation(f, |f| {
+            // Print top-level documentation
+            javadoc_print(f, &method.native_function.doc)?;
+            print_omitted_args(f, omitted)?;
+            f.newline()?;
+
+            // Print each parameter value
+            for param in args {
+                f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
+                docstring_print(f, &param.doc)?;
+            }
+
+            // Print return value
+            if let Some(doc) = &method.native_function.return_type.get_doc() {
+                f.writeln("@return ")?;
+                docstring_print(f, doc)?;
+            }
+
+            // Print exception
+            if let Some(error) = &method.native_function.error_type.get() {
+                f.writeln(&format!(
+                    "@throws {} {}",
+                    error.exception_name.camel_case(),
+                    error.inner.name.camel_case()
+                ))?;
+            }
+
+            Ok(())
+        })?;
+
+        f.writeln(&format!(
+            "public static {} {}(",
+            method.native_function.return_type.as_java_primitive(),
+            method.name.mixed_case()
+        ))?;
+        f.write(&parameters(args))?;
+        f.write(")")?;
+
+        if let Some(error) = method.native_function.error_type.get() {
+            if error.exception_type == ExceptionType::CheckedException {
+                f.write(&format!(" throws {}", error.exception_name.camel_case()))?;
+            }
+        }
+
+        blocked(f, |f| {
+            if omitted.is_empty() {
+                call_native_function(f, &method.native_function, "return ", false)
+            } else {
+                call_overload(f, &method.native_function, &method.name, args, omitted)
+            }
+        })
+    })
+}
+
+/// Java has no default arguments, so an overload is generated for each trailing parameter
+/// with a default value. Each overload forwards to the full signature.
+fn for_each_overload<F>(
+    f: &mut dyn Printer,
+    args: &[Arg<FunctionArgument, Validated>],
+    mut generate: F,
+) -> FormattingResult<()>
+where
+    F: FnMut(
+        &mut dyn Printer,
+        &[Arg<FunctionArgument, Validated>],
+        &[Arg<FunctionArgument, Validated>],
+    ) -> FormattingResult<()>,
+{
+    let required = args.iter().take_while(|x| x.default.is_none()).count();
+    for count in (required..=args.len()).rev() {
+        if count != args.len() {
+            f.newline()?;
+        }
+        let (args, omitted) = args.split_at(count);
+        generate(f, args, omitted)?;
+    }
+    Ok(())
+}
+
+fn print_omitted_args(
+    f: &mut dyn Printer,
+    omitted: &[Arg<FunctionArgument, Validated>],
+) -> FormattingResult<()> {
+    if omitted.is_empty() {
+        return Ok(());
+    }
+
+    f.newline()?;
+    f.writeln("<p>Omitted parameters are set to:")?;
+    f.writeln("<ul>")?;
+    for arg in omitted {
+        if let Some(value) = &arg.default {
+            f.writeln(&format!(
+                "<li> {{@code {}}} : {}</li>",
+                arg.name.mixed_case(),
+                get_default_value_doc(value)
+            ))?;
+        }
+    }
+    f.writeln("</ul>")
+}
+
+fn parameters(args: &[Arg<FunctionArgument, Validated>]) -> String {
+    args.iter()
+        .map(|param| {
+            format!(
+                "{} {}",
+                param.arg_type.as_java_primitive(),
+                param.name.mixed_case()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// arguments passed from an overload to the full signature
+fn arguments(
+    args: &[Arg<FunctionArgument, Validated>],
+    omitted: &[Arg<FunctionArgument, Validated>],
+) -> String {
+    args.iter()
+        .map(|x| x.name.mixed_case())
+        .chain(
+            omitted
+                .iter()
+                .filter_map(|x| x.default.as_ref().map(get_default_value)),
+        )
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn call_overload(
+    f: &mut dyn Printer,
+    function: &Function<Validated>,
+    name: &Name,
+    args: &[Arg<FunctionArgument, Validated>],
+    omitted: &[Arg<FunctionArgument, Validated>],
+) -> FormattingResult<()> {
+    let destination = if function.return_type.is_none() {
+        ""
+    } else {
+        "return "
+    };
+    f.writeln(&format!(
+        "{}{}({});",
+        destination,
+        name.mixed_case(),
+        arguments(args, omitted)
+    ))
+}
+
+fn generate_async_method(
+    f: &mut dyn Printer,
+    method: &FutureMethod<Validated>,
+) -> FormattingResult<()> {
+    let value_type = match &method.future.value {
+        Some(x) => x.value_type.as_java_object(),
+        None => "Void".to_string(),
+    };
+    let settings = method.future.interface.settings.clone();
+    let interface_name = method.future.interface.name.camel_case();
+    let callback_param_name = settings
+        .future
+        .async_method_callback_parameter_name
+        .mixed_case();
+
+    let future_type = format!("java.util.concurrent.CompletableFuture<{value_type}>");
+
+    // Documentation
     documentation(f, |f| {
         // Print top-level documentation
         javadoc_print(f, &method.native_function.doc)?;
         f.newline()?;
+        f.writeln("<p>")?;
+        indented(f, |f| {
+            f.writeln(&format!(
+                "The returned stage may fail exceptionally with {{@link {}}}",
+                method
+                    .future
+                    .error_type
+                    .exception_name
+                    .to_upper_camel_case()
+            ))
+        })?;
+        f.writeln("</p>")?;
+        if method.cancellation.is_some() {
+            f.writeln("<p>")?;
+            indented(f, |f| {
+                f.writeln("Cancelling the stage with {@link java.util.concurrent.CompletableFuture#cancel(boolean)} cancels the operation")
+            })?;
+            f.writeln("</p>")?;
+        }
+        f.newline()?;
 
         // Print each parameter value
-        for param in method.native_function.arguments.iter() {
+        for param in method.arguments_without_callback() {
             f.writeln(&format!("@param {} ", param.name.mixed_case()))?;
             docstring_print(f, &param.doc)?;
         }
 
         // Print return value
-        if let Some(doc) = &method.native_function.return_type.get_doc() {
-            f.writeln("@return ")?;
-            docstring_print(f, doc)?;
+        match &method.future.value {
+            Some(value) => {
+                f.writeln("@return {@link java.util.concurrent.CompletionStage} containing: ")?;
+                docstring_print(f, &value.doc)?;
+            }
+            None => {
+                f.writeln("@return {@link java.util.concurrent.CompletionStage} that completes when the operation succeeds")?;
+            }
         }
 
         // Print exception
@@ -302,15 +504,13 @@ fn generate_static_method(
     })?;
 
     f.writeln(&format!(
-        "public static {} {}(",
-        method.native_function.return_type.as_java_primitive(),
+        "public java.util.concurrent.CompletionStage<{}> {}(",
+        value_type,
         method.name.mixed_case()
     ))?;
     f.write(
         &method
-            .native_function
-            .arguments
-            .iter()
+            .arguments_without_callback()
             .map(|param| {
                 format!(
                     "{} {}",
@@ -330,23 +530,85 @@ fn generate_static_method(
     }
 
     blocked(f, |f| {
-        call_native_function(f, &method.native_function, "return ", false)
+        f.writeln(&format!("{future_type} _future = new {future_type}();"))?;
+
+        f.writeln(&format!(
+            "{interface_name} {callback_param_name} = new {interface_name}() {{"
+        ))?;
+        indented(f, |f| {
+            let success_method_name = settings.future.success_callback_method_name.mixed_case();
+            if method.future.value.is_some() {
+                f.writeln(&format!(
+                    "public void {success_method_name}({value_type} value)"
+                ))?;
+                blocked(f, |f| f.writeln("_future.complete(value);"))?;
+            } else {
+                f.writeln(&format!("public void {success_method_name}()"))?;
+                blocked(f, |f| f.writeln("_future.complete(null);"))?;
+            }
+
+            f.newline()?;
+            f.writeln(&format!(
+                "public void {}({} error)",
+                settings.future.failure_callback_method_name.mixed_case(),
+                method.future.error_type.inner.name.camel_case()
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "_future.completeExceptionally({});",
+                    exception::construct(&method.future.error_type, "error", "error")
+                ))
+            })?;
+
+            Ok(())
+        })?;
+        f.writeln("};")?;
+
+        match &method.cancellation {
+            None => call_native_function(f, &method.native_function, "return ", true)?,
+            Some(cancellation) => {
+                call_native_function(
+                    f,
+                    &method.native_function,
+                    &format!(
+                        "final {} _operation = ",
+                        method.native_function.return_type.as_java_primitive()
+                    ),
+                    true,
+                )?;
+                f.writeln("_future.whenComplete((_value, _error) -> {")?;
+                indented(f, |f| {
+                    f.writeln(
+                        "if (_error instanceof java.util.concurrent.CancellationException) {",
+                    )?;
+                    indented(f, |f| {
+                        f.writeln(&format!(
+                            "{}.Wrapped.{}(_operation);",
+                            NATIVE_FUNCTIONS_CLASSNAME, cancellation.function.name
+                        ))
+                    })?;
+                    f.writeln("}")
+                })?;
+                f.writeln("});")?;
+            }
+        }
+        f.writeln("return _future;")
     })
 }
 
-fn generate_async_method(
+fn generate_stream_method(
     f: &mut dyn Printer,
-    method: &FutureMethod<Validated>,
+    method: &StreamMethod<Validated>,
 ) -> FormattingResult<()> {
-    let value_type = method.future.value_type.as_java_object();
-    let settings = method.future.interface.settings.clone();
-    let interface_name = method.future.interface.name.camel_case();
+    let item_type = method.stream.item_type.as_java_object();
+    let settings = method.stream.interface.settings.clone();
+    let interface_name = method.stream.interface.name.camel_case();
     let callback_param_name = settings
-        .future
-        .async_method_callback_parameter_name
+        .stream
+        .stream_method_callback_parameter_name
         .mixed_case();
 
-    let future_type = format!("java.util.concurrent.CompletableFuture<{value_type}>");
+    let publisher_type = format!("StreamPublisher<{item_type}>");
 
     // Documentation
     documentation(f, |f| {
@@ -355,10 +617,11 @@ fn generate_async_method(
         f.newline()?;
         f.writeln("<p>")?;
         indented(f, |f| {
+            f.writeln("The operation starts immediately and the items are buffered until they are requested.")?;
             f.writeln(&format!(
-                "The returned stage may fail exceptionally with {{@link {}}}",
+                "The publisher supports a single subscriber and may signal {{@link {}}}",
                 method
-                    .future
+                    .stream
                     .error_type
                     .exception_name
                     .to_upper_camel_case()
@@ -373,25 +636,15 @@ fn generate_async_method(
             docstring_print(f, &param.doc)?;
         }
 
-        // Print return value
-        f.writeln("@return {@link java.util.concurrent.CompletionStage} containing: ")?;
-        docstring_print(f, &method.future.value_type_doc)?;
-
-        // Print exception
-        if let Some(error) = &method.native_function.error_type.get() {
-            f.writeln(&format!(
-                "@throws {} {}",
-                error.exception_name.camel_case(),
-                error.inner.name.camel_case()
-            ))?;
-        }
+        f.writeln("@return {@link java.util.concurrent.Flow.Publisher} of: ")?;
+        docstring_print(f, &method.stream.item_type_doc)?;
 
         Ok(())
     })?;
 
     f.writeln(&format!(
-        "public java.util.concurrent.CompletionStage<{}> {}(",
-        value_type,
+        "public java.util.concurrent.Flow.Publisher<{}> {}(",
+        item_type,
         method.name.mixed_case()
     ))?;
     f.write(
@@ -409,36 +662,39 @@ fn generate_async_method(
     )?;
     f.write(")")?;
 
-    if let Some(error) = method.native_function.error_type.get() {
-        if error.exception_type == ExceptionType::CheckedException {
-            f.write(&format!(" throws {}", error.exception_name.camel_case()))?;
-        }
-    }
-
     blocked(f, |f| {
-        f.writeln(&format!("{future_type} _future = new {future_type}();"))?;
+        f.writeln(&format!(
+            "final {publisher_type} _publisher = new {publisher_type}();"
+        ))?;
 
         f.writeln(&format!(
             "{interface_name} {callback_param_name} = new {interface_name}() {{"
         ))?;
         indented(f, |f| {
             f.writeln(&format!(
-                "public void {}({} value)",
-                settings.future.success_callback_method_name.mixed_case(),
-                value_type
+                "public void {}({} item)",
+                settings.stream.item_callback_method_name.mixed_case(),
+                method.stream.item_type.as_java_primitive()
+            ))?;
+            blocked(f, |f| f.writeln("_publisher.onItem(item);"))?;
+
+            f.newline()?;
+            f.writeln(&format!(
+                "public void {}()",
+                settings.stream.complete_callback_method_name.mixed_case()
             ))?;
-            blocked(f, |f| f.writeln("_future.complete(value);"))?;
+            blocked(f, |f| f.writeln("_publisher.onComplete();"))?;
 
             f.newline()?;
             f.writeln(&format!(
                 "public void {}({} error)",
-                settings.future.failure_callback_method_name.mixed_case(),
-                method.future.error_type.inner.name.camel_case()
+                settings.stream.failure_callback_method_name.mixed_case(),
+                method.stream.error_type.inner.name.camel_case()
             ))?;
             blocked(f, |f| {
                 f.writeln(&format!(
-                    "_future.completeExceptionally(new {}(error));",
-                    method.future.error_type.exception_name.camel_case()
+                    "_publisher.onError({});",
+                    exception::construct(&method.stream.error_type, "error", "error")
                 ))
             })?;
 
@@ -446,7 +702,7 @@ fn generate_async_method(
         })?;
         f.writeln("};")?;
 
-        call_native_function(f, &method.native_function, "return ", true)?;
-        f.writeln("return _future;")
+        call_native_function(f, &method.native_function, "", true)?;
+        f.writeln("return _publisher;")
     })
 }
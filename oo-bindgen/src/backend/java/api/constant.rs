@@ -5,15 +5,69 @@ pub(crate) fn generate(
     f: &mut impl Printer,
     set: &Handle<ConstantSet<Validated>>,
 ) -> FormattingResult<()> {
-    fn get_type_as_string(value: &ConstantValue) -> &'static str {
+    fn get_type_as_string(value: &ConstantValue) -> String {
         match value {
-            ConstantValue::U8(_, _) => "UByte",
+            ConstantValue::Primitive(x, _) => match x {
+                PrimitiveValue::Bool(_) => "boolean".to_string(),
+                PrimitiveValue::U8(_) => "UByte".to_string(),
+                PrimitiveValue::S8(_) => "byte".to_string(),
+                PrimitiveValue::U16(_) => "UShort".to_string(),
+                PrimitiveValue::S16(_) => "short".to_string(),
+                PrimitiveValue::U32(_) => "UInteger".to_string(),
+                PrimitiveValue::S32(_) => "int".to_string(),
+                PrimitiveValue::U64(_) => "ULong".to_string(),
+                PrimitiveValue::S64(_) => "long".to_string(),
+                PrimitiveValue::Float(_) => "float".to_string(),
+                PrimitiveValue::Double(_) => "double".to_string(),
+            },
+            ConstantValue::Duration(_) => "java.time.Duration".to_string(),
+            ConstantValue::String(_) => "String".to_string(),
+            ConstantValue::Enum(x) => x.handle.name.camel_case(),
         }
     }
 
     fn get_value_as_string(value: &ConstantValue) -> String {
         match value {
-            ConstantValue::U8(x, Representation::Hex) => format!("UByte.valueOf(0x{x:02X?})"),
+            ConstantValue::Primitive(x, rep) => {
+                let int = || format_integer(integer_value(x).unwrap(), *rep);
+                match x {
+                    PrimitiveValue::Bool(x) => x.to_string(),
+                    PrimitiveValue::U8(_) => format!("UByte.valueOf({})", int()),
+                    PrimitiveValue::S8(_) => int(),
+                    PrimitiveValue::U16(_) => format!("UShort.valueOf({})", int()),
+                    PrimitiveValue::S16(_) => int(),
+                    // the literal must be a long as values above 2^31 don't fit in an int
+                    PrimitiveValue::U32(_) => format!("UInteger.valueOf({}L)", int()),
+                    PrimitiveValue::S32(_) => int(),
+                    // decimal long literals can't represent values above 2^63, but hex and binary ones can
+                    PrimitiveValue::U64(v)
+                        if *rep == Representation::Decimal && *v > i64::MAX as u64 =>
+                    {
+                        format!("ULong.valueOf(\"{v}\")")
+                    }
+                    PrimitiveValue::U64(_) => format!("ULong.valueOf({}L)", int()),
+                    PrimitiveValue::S64(_) => format!("{}L", int()),
+                    PrimitiveValue::Float(x) => format!("{x:?}f"),
+                    PrimitiveValue::Double(x) => format!("{x:?}"),
+                }
+            }
+            ConstantValue::Duration(x) => match x {
+                DurationValue::Milliseconds(x) => format!("java.time.Duration.ofMillis({x}L)"),
+                DurationValue::Seconds(x) => format!("java.time.Duration.ofSeconds({x}L)"),
+                DurationValue::Microseconds(x) => {
+                    format!("java.time.Duration.of({x}L, java.time.temporal.ChronoUnit.MICROS)")
+                }
+                DurationValue::Nanoseconds(x) => format!("java.time.Duration.ofNanos({x}L)"),
+                DurationValue::SignedMilliseconds(x) => {
+                    format!("java.time.Duration.ofMillis({x}L)")
+                }
+            },
+            ConstantValue::String(x) => quoted_string(x),
+            ConstantValue::Enum(x) => format!(
+                "{}.{}",
+                x.handle.name.camel_case(),
+                x.variant.name.capital_snake_case()
+            ),
         }
     }
 
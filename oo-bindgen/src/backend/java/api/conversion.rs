@@ -47,7 +47,9 @@ impl JavaType for BasicType {
         match self {
             Self::Primitive(x) => x.as_java_primitive(),
             Self::Duration(_) => "java.time.Duration".to_string(),
+            Self::Timestamp(_) => "java.time.Instant".to_string(),
             Self::Enum(handle) => handle.name.camel_case(),
+            Self::Flags(handle) => handle.name.camel_case(),
         }
     }
 
@@ -55,7 +57,9 @@ impl JavaType for BasicType {
         match self {
             Self::Primitive(x) => x.as_java_object(),
             Self::Duration(_) => "java.time.Duration".to_string(),
+            Self::Timestamp(_) => "java.time.Instant".to_string(),
             Self::Enum(handle) => handle.name.camel_case(),
+            Self::Flags(handle) => handle.name.camel_case(),
         }
     }
 }
@@ -70,6 +74,50 @@ impl JavaType for StringType {
     }
 }
 
+impl JavaType for ListItemType {
+    fn as_java_primitive(&self) -> String {
+        self.as_java_object()
+    }
+
+    fn as_java_object(&self) -> String {
+        match self {
+            Self::Basic(x) => x.as_java_object(),
+            Self::Struct(x) => x.as_java_object(),
+        }
+    }
+}
+
+impl JavaType for ListHandle {
+    fn as_java_primitive(&self) -> String {
+        self.as_java_object()
+    }
+
+    fn as_java_object(&self) -> String {
+        format!("java.util.List<{}>", self.item_type.as_java_object())
+    }
+}
+
+impl JavaType for Optional<BasicType> {
+    // absent values are represented by null, so always use the boxed type
+    fn as_java_primitive(&self) -> String {
+        self.inner.as_java_object()
+    }
+
+    fn as_java_object(&self) -> String {
+        self.inner.as_java_object()
+    }
+}
+
+impl JavaType for BytesType {
+    fn as_java_primitive(&self) -> String {
+        "byte[]".to_string()
+    }
+
+    fn as_java_object(&self) -> String {
+        "byte[]".to_string()
+    }
+}
+
 impl<D> JavaType for Handle<Interface<D>>
 where
     D: DocReference,
@@ -83,6 +131,19 @@ where
     }
 }
 
+impl<D> JavaType for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn as_java_primitive(&self) -> String {
+        self.as_java_object()
+    }
+
+    fn as_java_object(&self) -> String {
+        self.name.camel_case()
+    }
+}
+
 impl<D> JavaType for Handle<Collection<D>>
 where
     D: DocReference,
@@ -96,6 +157,23 @@ where
     }
 }
 
+impl<D> JavaType for Handle<Map<D>>
+where
+    D: DocReference,
+{
+    fn as_java_primitive(&self) -> String {
+        self.as_java_object()
+    }
+
+    fn as_java_object(&self) -> String {
+        format!(
+            "java.util.Map<{}, {}>",
+            self.key_type.as_java_object(),
+            self.value_type.as_java_object()
+        )
+    }
+}
+
 impl JavaType for ClassDeclarationHandle {
     fn as_java_primitive(&self) -> String {
         self.as_java_object()
@@ -121,6 +199,10 @@ impl JavaType for IteratorItemType {
         match self {
             IteratorItemType::Struct(x) => x.as_java_primitive(),
             IteratorItemType::Primitive(x) => x.as_java_primitive(),
+            IteratorItemType::String(x) => x.as_java_primitive(),
+            IteratorItemType::Enum(x) => BasicType::Enum(x.clone()).as_java_primitive(),
+            IteratorItemType::Duration(x) => BasicType::Duration(*x).as_java_primitive(),
+            IteratorItemType::Class(x) => x.as_java_primitive(),
         }
     }
 
@@ -128,6 +210,10 @@ impl JavaType for IteratorItemType {
         match self {
             IteratorItemType::Struct(x) => x.as_java_object(),
             IteratorItemType::Primitive(x) => x.as_java_object(),
+            IteratorItemType::String(x) => x.as_java_object(),
+            IteratorItemType::Enum(x) => BasicType::Enum(x.clone()).as_java_object(),
+            IteratorItemType::Duration(x) => BasicType::Duration(*x).as_java_object(),
+            IteratorItemType::Class(x) => x.as_java_object(),
         }
     }
 }
@@ -183,8 +269,11 @@ impl JavaType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.as_java_primitive(),
             FunctionArgStructField::String(x) => x.as_java_primitive(),
+            FunctionArgStructField::Bytes(x) => x.as_java_primitive(),
+            FunctionArgStructField::Optional(x) => x.as_java_primitive(),
             FunctionArgStructField::Interface(x) => x.inner.as_java_primitive(),
             FunctionArgStructField::Struct(x) => x.as_java_primitive(),
+            FunctionArgStructField::List(x) => x.as_java_primitive(),
         }
     }
 
@@ -192,8 +281,11 @@ impl JavaType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.as_java_object(),
             FunctionArgStructField::String(x) => x.as_java_object(),
+            FunctionArgStructField::Bytes(x) => x.as_java_object(),
+            FunctionArgStructField::Optional(x) => x.as_java_object(),
             FunctionArgStructField::Interface(x) => x.inner.as_java_object(),
             FunctionArgStructField::Struct(x) => x.as_java_object(),
+            FunctionArgStructField::List(x) => x.as_java_object(),
         }
     }
 }
@@ -225,6 +317,8 @@ impl JavaType for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.as_java_primitive(),
             CallbackArgStructField::Struct(x) => x.as_java_primitive(),
             CallbackArgStructField::String(x) => x.as_java_primitive(),
+            CallbackArgStructField::Bytes(x) => x.as_java_primitive(),
+            CallbackArgStructField::Optional(x) => x.as_java_primitive(),
         }
     }
 
@@ -234,6 +328,8 @@ impl JavaType for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.as_java_object(),
             CallbackArgStructField::Struct(x) => x.as_java_object(),
             CallbackArgStructField::String(x) => x.as_java_object(),
+            CallbackArgStructField::Bytes(x) => x.as_java_object(),
+            CallbackArgStructField::Optional(x) => x.as_java_object(),
         }
     }
 }
@@ -244,6 +340,9 @@ impl JavaType for UniversalStructField {
             UniversalStructField::Basic(x) => x.as_java_primitive(),
             UniversalStructField::Struct(x) => x.as_java_primitive(),
             UniversalStructField::String(x) => x.as_java_primitive(),
+            UniversalStructField::Bytes(x) => x.as_java_primitive(),
+            UniversalStructField::Optional(x) => x.as_java_primitive(),
+            UniversalStructField::List(x) => x.as_java_primitive(),
         }
     }
 
@@ -252,6 +351,9 @@ impl JavaType for UniversalStructField {
             UniversalStructField::Basic(x) => x.as_java_object(),
             UniversalStructField::Struct(x) => x.as_java_object(),
             UniversalStructField::String(x) => x.as_java_object(),
+            UniversalStructField::Bytes(x) => x.as_java_object(),
+            UniversalStructField::Optional(x) => x.as_java_object(),
+            UniversalStructField::List(x) => x.as_java_object(),
         }
     }
 }
@@ -261,7 +363,11 @@ impl JavaType for FunctionArgument {
         match self {
             Self::Basic(x) => x.as_java_primitive(),
             Self::String(x) => x.as_java_primitive(),
+            Self::Bytes(x) => x.as_java_primitive(),
+            Self::Union(x) => x.as_java_primitive(),
+            Self::Optional(x) => x.as_java_primitive(),
             Self::Collection(x) => x.as_java_primitive(),
+            Self::Map(x) => x.as_java_primitive(),
             Self::Struct(x) => x.as_java_primitive(),
             Self::StructRef(x) => x.inner.as_java_primitive(),
             Self::ClassRef(x) => x.as_java_primitive(),
@@ -273,7 +379,11 @@ impl JavaType for FunctionArgument {
         match self {
             Self::Basic(x) => x.as_java_object(),
             Self::String(x) => x.as_java_object(),
+            Self::Bytes(x) => x.as_java_object(),
+            Self::Union(x) => x.as_java_object(),
+            Self::Optional(x) => x.as_java_object(),
             Self::Collection(x) => x.as_java_object(),
+            Self::Map(x) => x.as_java_object(),
             Self::Struct(x) => x.as_java_object(),
             Self::StructRef(x) => x.inner.as_java_object(),
             Self::ClassRef(x) => x.as_java_object(),
@@ -287,6 +397,9 @@ impl JavaType for CallbackArgument {
         match self {
             Self::Basic(x) => x.as_java_primitive(),
             Self::String(x) => x.as_java_primitive(),
+            Self::Bytes(x) => x.as_java_primitive(),
+            Self::Union(x) => x.as_java_primitive(),
+            Self::Optional(x) => x.as_java_primitive(),
             Self::Iterator(x) => x.as_java_primitive(),
             Self::Struct(x) => x.as_java_primitive(),
             Self::Class(x) => x.as_java_primitive(),
@@ -297,6 +410,9 @@ impl JavaType for CallbackArgument {
         match self {
             Self::Basic(x) => x.as_java_object(),
             Self::String(x) => x.as_java_object(),
+            Self::Bytes(x) => x.as_java_object(),
+            Self::Union(x) => x.as_java_object(),
+            Self::Optional(x) => x.as_java_object(),
             Self::Iterator(x) => x.as_java_object(),
             Self::Struct(x) => x.as_java_object(),
             Self::Class(x) => x.as_java_object(),
@@ -331,15 +447,35 @@ impl JavaType for PrimitiveRef {
     }
 }
 
+/// all of the referenced types are already objects in Java
+impl JavaType for ValueRef {
+    fn as_java_primitive(&self) -> String {
+        self.as_java_object()
+    }
+
+    fn as_java_object(&self) -> String {
+        match self {
+            Self::String(x) => x.as_java_object(),
+            Self::Enum(x) => BasicType::Enum(x.clone()).as_java_object(),
+            Self::Duration(x) => BasicType::Duration(*x).as_java_object(),
+        }
+    }
+}
+
 impl JavaType for FunctionReturnValue {
     fn as_java_primitive(&self) -> String {
         match self {
             Self::Basic(x) => x.as_java_primitive(),
             Self::String(x) => x.as_java_primitive(),
+            Self::Bytes(x) => x.as_java_primitive(),
+            Self::Union(x) => x.as_java_primitive(),
             Self::ClassRef(x) => x.as_java_primitive(),
+            // owned iterators are wrapped in a generated class
+            Self::Iterator(x) => x.name().camel_case(),
             Self::Struct(x) => x.as_java_primitive(),
             Self::StructRef(x) => x.untyped().as_java_primitive(),
             Self::PrimitiveRef(x) => x.as_java_primitive(),
+            Self::ValueRef(x) => x.as_java_primitive(),
         }
     }
 
@@ -347,10 +483,14 @@ impl JavaType for FunctionReturnValue {
         match self {
             Self::Basic(x) => x.as_java_object(),
             Self::String(x) => x.as_java_object(),
+            Self::Bytes(x) => x.as_java_object(),
+            Self::Union(x) => x.as_java_object(),
             Self::ClassRef(x) => x.as_java_object(),
+            Self::Iterator(x) => x.name().camel_case(),
             Self::Struct(x) => x.as_java_object(),
             Self::StructRef(x) => x.untyped().as_java_object(),
             Self::PrimitiveRef(x) => x.as_java_object(),
+            Self::ValueRef(x) => x.as_java_object(),
         }
     }
 }
@@ -0,0 +1,147 @@
+/**
+ * Publisher of the items delivered to a stream interface
+ *
+ * <p>The operation producing the items starts when the publisher is returned. Items are buffered
+ * until the subscriber requests them and a failure of the stream is signalled after the items
+ * received before it. Only a single subscriber is supported, additional subscribers are rejected
+ * with {@link IllegalStateException}.</p>
+ *
+ * @param <T> type of the items
+ */
+final class StreamPublisher<T> implements java.util.concurrent.Flow.Publisher<T> {
+
+    private final java.util.ArrayDeque<T> items = new java.util.ArrayDeque<>();
+    private java.util.concurrent.Flow.Subscriber<? super T> subscriber = null;
+    private boolean subscribed = false;
+    private boolean complete = false;
+    private Throwable error = null;
+    private boolean draining = false;
+    private long demand = 0;
+
+    void onItem(T item) {
+        synchronized (this) {
+            if (complete || error != null) {
+                return;
+            }
+            items.add(item);
+        }
+        drain();
+    }
+
+    void onComplete() {
+        synchronized (this) {
+            if (error == null) {
+                complete = true;
+            }
+        }
+        drain();
+    }
+
+    void onError(Throwable error) {
+        synchronized (this) {
+            if (!complete && this.error == null) {
+                this.error = error;
+            }
+        }
+        drain();
+    }
+
+    @Override
+    public void subscribe(java.util.concurrent.Flow.Subscriber<? super T> subscriber) {
+        java.util.Objects.requireNonNull(subscriber, "subscriber");
+
+        final boolean accepted;
+        synchronized (this) {
+            accepted = !subscribed;
+            if (accepted) {
+                this.subscribed = true;
+                this.subscriber = subscriber;
+            }
+        }
+
+        if (!accepted) {
+            subscriber.onSubscribe(new java.util.concurrent.Flow.Subscription() {
+                @Override
+                public void request(long n) {}
+
+                @Override
+                public void cancel() {}
+            });
+            subscriber.onError(new IllegalStateException("The stream only supports a single subscriber"));
+            return;
+        }
+
+        subscriber.onSubscribe(new java.util.concurrent.Flow.Subscription() {
+            @Override
+            public void request(long n) {
+                StreamPublisher.this.request(n);
+            }
+
+            @Override
+            public void cancel() {
+                StreamPublisher.this.cancel();
+            }
+        });
+        drain();
+    }
+
+    private void request(long n) {
+        synchronized (this) {
+            if (n <= 0) {
+                items.clear();
+                error = new IllegalArgumentException("The number of requested items must be positive");
+            } else {
+                demand += n;
+                if (demand < 0) {
+                    demand = Long.MAX_VALUE;
+                }
+            }
+        }
+        drain();
+    }
+
+    private synchronized void cancel() {
+        subscriber = null;
+        items.clear();
+    }
+
+    private void drain() {
+        synchronized (this) {
+            if (draining || subscriber == null) {
+                return;
+            }
+            draining = true;
+        }
+
+        while (true) {
+            final java.util.concurrent.Flow.Subscriber<? super T> current;
+            T item = null;
+            Throwable failure = null;
+            synchronized (this) {
+                current = subscriber;
+                if (current == null) {
+                    draining = false;
+                    return;
+                }
+                if (demand > 0 && !items.isEmpty()) {
+                    item = items.poll();
+                    demand--;
+                } else if (items.isEmpty() && (complete || error != null)) {
+                    failure = error;
+                    subscriber = null;
+                } else {
+                    draining = false;
+                    return;
+                }
+            }
+
+            if (item != null) {
+                current.onNext(item);
+            } else if (failure != null) {
+                current.onError(failure);
+            } else {
+                current.onComplete();
+            }
+        }
+    }
+}
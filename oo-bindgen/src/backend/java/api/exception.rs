@@ -1,6 +1,33 @@
 use super::doc::*;
 use super::*;
 
+fn root_exception(exception_type: ExceptionType) -> &'static str {
+    match exception_type {
+        ExceptionType::CheckedException => "Exception",
+        ExceptionType::UncheckedException => "RuntimeException",
+    }
+}
+
+pub(crate) fn generate_base(
+    f: &mut impl Printer,
+    base: &Handle<BaseException<Validated>>,
+) -> FormattingResult<()> {
+    // Documentation
+    documentation(f, |f| javadoc_print(f, &base.doc))?;
+
+    let name = base.name.camel_case();
+    let parent = match &base.parent {
+        None => root_exception(base.exception_type).to_string(),
+        Some(x) => x.name.camel_case(),
+    };
+
+    f.writeln(&format!("public abstract class {name} extends {parent}"))?;
+    blocked(f, |f| {
+        f.writeln(&format!("protected {name}(String message)"))?;
+        blocked(f, |f| f.writeln("super(message);"))
+    })
+}
+
 pub(crate) fn generate(f: &mut impl Printer, error: &ErrorType<Validated>) -> FormattingResult<()> {
     // Documentation
     documentation(f, |f| javadoc_print(f, &error.inner.doc))?;
@@ -8,23 +35,151 @@ pub(crate) fn generate(f: &mut impl Printer, error: &ErrorType<Validated>) -> Fo
     let exception_name = error.exception_name.camel_case();
     let error_name = error.inner.name.camel_case();
 
-    let exception_type = match error.exception_type {
-        ExceptionType::CheckedException => "Exception",
-        ExceptionType::UncheckedException => "RuntimeException",
+    let parent = match &error.base {
+        None => root_exception(error.exception_type).to_string(),
+        Some(x) => x.name.camel_case(),
+    };
+
+    // variant exceptions derive from this class
+    let modifier = if error.variant_exceptions.is_empty() {
+        "public final"
+    } else {
+        "public"
     };
 
     // Enum definition
     f.writeln(&format!(
-        "public final class {exception_name} extends {exception_type}"
+        "{modifier} class {exception_name} extends {parent}"
     ))?;
     blocked(f, |f| {
         documentation(f, |f| f.writeln("Error detail"))?;
         f.writeln(&format!("public final {error_name} error;"))?;
+        if error.message {
+            documentation(f, |f| {
+                f.writeln("Detailed message of the error, or null if no message was provided")
+            })?;
+            f.writeln("public final String detail;")?;
+        }
+        if let Some(payload) = &error.payload {
+            documentation(f, |f| {
+                f.writeln("Payload of the error, or null if no payload was provided")
+            })?;
+            f.writeln(&format!(
+                "public final {} payload;",
+                payload.name().camel_case()
+            ))?;
+        }
         f.newline()?;
         f.writeln(&format!("{exception_name}({error_name} error)"))?;
         blocked(f, |f| {
-            f.writeln("super(error.toString());")?;
-            f.writeln("this.error = error;")
-        })
+            if error.has_detail() {
+                let mut args = vec!["error"];
+                if error.message {
+                    args.push("null");
+                }
+                if error.payload.is_some() {
+                    args.push("null");
+                }
+                f.writeln(&format!("this({});", args.join(", ")))
+            } else {
+                f.writeln("super(error.toString());")?;
+                f.writeln("this.error = error;")
+            }
+        })?;
+        if error.has_detail() {
+            f.newline()?;
+            f.writeln(&format!(
+                "{exception_name}({})",
+                detailed_parameters(error).join(", ")
+            ))?;
+            blocked(f, |f| {
+                if error.message {
+                    f.writeln("super(detail == null ? error.toString() : error.toString() + \": \" + detail);")?;
+                    f.writeln("this.detail = detail;")?;
+                } else {
+                    f.writeln("super(error.toString());")?;
+                }
+                f.writeln("this.error = error;")?;
+                if error.payload.is_some() {
+                    f.writeln("this.payload = payload;")?;
+                }
+                Ok(())
+            })?;
+        }
+        Ok(())
     })
 }
+
+pub(crate) fn generate_variant(
+    f: &mut impl Printer,
+    error: &ErrorType<Validated>,
+    variant: &VariantException,
+) -> FormattingResult<()> {
+    let parent = error.exception_name.camel_case();
+    let error_name = error.inner.name.camel_case();
+    let exception_name = variant.exception_name.camel_case();
+
+    // Documentation
+    documentation(f, |f| {
+        f.writeln(&format!(
+            "Exception thrown in place of {{@link {parent}}} when the error is {{@link {error_name}#{}}}",
+            variant.variant.capital_snake_case()
+        ))
+    })?;
+
+    f.writeln(&format!(
+        "public final class {exception_name} extends {parent}"
+    ))?;
+    blocked(f, |f| {
+        f.writeln(&format!("{exception_name}({error_name} error)"))?;
+        blocked(f, |f| f.writeln("super(error);"))?;
+        if error.has_detail() {
+            f.newline()?;
+            f.writeln(&format!(
+                "{exception_name}({})",
+                detailed_parameters(error).join(", ")
+            ))?;
+            blocked(f, |f| {
+                let mut args = vec!["error"];
+                if error.message {
+                    args.push("detail");
+                }
+                if error.payload.is_some() {
+                    args.push("payload");
+                }
+                f.writeln(&format!("super({});", args.join(", ")))
+            })?;
+        }
+        Ok(())
+    })
+}
+
+fn detailed_parameters(error: &ErrorType<Validated>) -> Vec<String> {
+    let mut params = vec![format!("{} error", error.inner.name.camel_case())];
+    if error.message {
+        params.push("String detail".to_string());
+    }
+    if let Some(payload) = &error.payload {
+        params.push(format!("{} payload", payload.name().camel_case()));
+    }
+    params
+}
+
+/// expression that creates the exception for an error, using the exception of the variant if it has one
+pub(crate) fn construct(error: &ErrorType<Validated>, variable: &str, args: &str) -> String {
+    let error_name = error.inner.name.camel_case();
+    let mut expr = format!("new {}({args})", error.exception_name.camel_case());
+    for (x, _) in error
+        .variant_exceptions()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        expr = format!(
+            "{variable} == {error_name}.{} ? new {}({args}) : {expr}",
+            x.variant.capital_snake_case(),
+            x.exception_name.camel_case()
+        );
+    }
+    expr
+}
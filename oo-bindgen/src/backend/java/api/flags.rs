@@ -0,0 +1,210 @@
+use super::doc::*;
+use super::*;
+
+pub(crate) fn generate(
+    f: &mut impl Printer,
+    flags: &Handle<Flags<Validated>>,
+) -> FormattingResult<()> {
+    let flags_name = flags.name.camel_case();
+
+    // Documentation
+    documentation(f, |f| javadoc_print(f, &flags.doc))?;
+
+    // Immutable wrapper around a set of the bits
+    f.writeln(&format!("public final class {flags_name}"))?;
+    blocked(f, |f| {
+        documentation(f, |f| {
+            f.writeln(&format!("Bits that may be set in {{@link {flags_name}}}"))
+        })?;
+        f.writeln("public enum Flag")?;
+        blocked(f, |f| {
+            for bit in &flags.bits {
+                documentation(f, |f| javadoc_print(f, &bit.doc))?;
+                f.writeln(&format!(
+                    "{}(0x{:X}L),",
+                    bit.name.capital_snake_case(),
+                    bit.value
+                ))?;
+            }
+            f.write(";")?;
+
+            f.newline()?;
+
+            f.writeln("final private long mask;")?;
+
+            f.newline()?;
+
+            f.writeln("private Flag(long mask)")?;
+            blocked(f, |f| f.writeln("this.mask = mask;"))
+        })?;
+
+        f.newline()?;
+
+        f.writeln("private final java.util.EnumSet<Flag> flags;")?;
+
+        f.newline()?;
+
+        f.writeln(&format!(
+            "private {flags_name}(java.util.EnumSet<Flag> flags)"
+        ))?;
+        blocked(f, |f| f.writeln("this.flags = flags;"))?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Create a set without any flags")?;
+            f.writeln("@return empty set")
+        })?;
+        f.writeln(&format!("public static {flags_name} none()"))?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "return new {flags_name}(java.util.EnumSet.noneOf(Flag.class));"
+            ))
+        })?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Create a set with every flag")?;
+            f.writeln("@return full set")
+        })?;
+        f.writeln(&format!("public static {flags_name} all()"))?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "return new {flags_name}(java.util.EnumSet.allOf(Flag.class));"
+            ))
+        })?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Create a set from some flags")?;
+            f.writeln("@param flags flags in the set")?;
+            f.writeln("@return set containing the flags")
+        })?;
+        f.writeln(&format!("public static {flags_name} of(Flag... flags)"))?;
+        blocked(f, |f| {
+            f.writeln("final java.util.EnumSet<Flag> set = java.util.EnumSet.noneOf(Flag.class);")?;
+            f.writeln("java.util.Collections.addAll(set, flags);")?;
+            f.writeln(&format!("return new {flags_name}(set);"))
+        })?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Create a set from a collection of flags")?;
+            f.writeln("@param flags flags in the set")?;
+            f.writeln("@return set containing the flags")
+        })?;
+        f.writeln(&format!(
+            "public static {flags_name} of(java.util.Collection<Flag> flags)"
+        ))?;
+        blocked(f, |f| {
+            f.writeln("final java.util.EnumSet<Flag> set = java.util.EnumSet.noneOf(Flag.class);")?;
+            f.writeln("set.addAll(flags);")?;
+            f.writeln(&format!("return new {flags_name}(set);"))
+        })?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Check if a flag is set")?;
+            f.writeln("@param flag flag to check")?;
+            f.writeln("@return true if the flag is set")
+        })?;
+        f.writeln("public boolean contains(Flag flag)")?;
+        blocked(f, |f| f.writeln("return flags.contains(flag);"))?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Check if no flags are set")?;
+            f.writeln("@return true if no flags are set")
+        })?;
+        f.writeln("public boolean isEmpty()")?;
+        blocked(f, |f| f.writeln("return flags.isEmpty();"))?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Create a copy of this set with an additional flag")?;
+            f.writeln("@param flag flag to set")?;
+            f.writeln("@return new set")
+        })?;
+        f.writeln(&format!("public {flags_name} with(Flag flag)"))?;
+        blocked(f, |f| {
+            f.writeln("final java.util.EnumSet<Flag> set = java.util.EnumSet.copyOf(flags);")?;
+            f.writeln("set.add(flag);")?;
+            f.writeln(&format!("return new {flags_name}(set);"))
+        })?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Create a copy of this set without a flag")?;
+            f.writeln("@param flag flag to clear")?;
+            f.writeln("@return new set")
+        })?;
+        f.writeln(&format!("public {flags_name} without(Flag flag)"))?;
+        blocked(f, |f| {
+            f.writeln("final java.util.EnumSet<Flag> set = java.util.EnumSet.copyOf(flags);")?;
+            f.writeln("set.remove(flag);")?;
+            f.writeln(&format!("return new {flags_name}(set);"))
+        })?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Copy the flags into a mutable set")?;
+            f.writeln("@return copy of the flags")
+        })?;
+        f.writeln("public java.util.EnumSet<Flag> toEnumSet()")?;
+        blocked(f, |f| f.writeln("return java.util.EnumSet.copyOf(flags);"))?;
+
+        f.newline()?;
+
+        f.writeln("long toBits()")?;
+        blocked(f, |f| {
+            f.writeln("long bits = 0;")?;
+            f.writeln("for (Flag flag : flags)")?;
+            blocked(f, |f| f.writeln("bits |= flag.mask;"))?;
+            f.writeln("return bits;")
+        })?;
+
+        f.newline()?;
+
+        f.writeln("// bits that don't correspond to a flag are discarded")?;
+        f.writeln(&format!("static {flags_name} fromBits(long bits)"))?;
+        blocked(f, |f| {
+            f.writeln("final java.util.EnumSet<Flag> set = java.util.EnumSet.noneOf(Flag.class);")?;
+            f.writeln("for (Flag flag : Flag.values())")?;
+            blocked(f, |f| {
+                f.writeln("if ((bits & flag.mask) == flag.mask)")?;
+                blocked(f, |f| f.writeln("set.add(flag);"))
+            })?;
+            f.writeln(&format!("return new {flags_name}(set);"))
+        })?;
+
+        f.newline()?;
+
+        f.writeln("@Override")?;
+        f.writeln("public boolean equals(Object other)")?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "return other instanceof {flags_name} && flags.equals((({flags_name}) other).flags);"
+            ))
+        })?;
+
+        f.newline()?;
+
+        f.writeln("@Override")?;
+        f.writeln("public int hashCode()")?;
+        blocked(f, |f| f.writeln("return flags.hashCode();"))?;
+
+        f.newline()?;
+
+        f.writeln("@Override")?;
+        f.writeln("public String toString()")?;
+        blocked(f, |f| f.writeln("return flags.toString();"))
+    })
+}
@@ -41,6 +41,11 @@ impl ToConstantValue for DurationValue {
         match self {
             DurationValue::Milliseconds(x) => format!("java.time.Duration.ofMillis({x})"),
             DurationValue::Seconds(x) => format!("java.time.Duration.ofSeconds({x})"),
+            DurationValue::Microseconds(x) => {
+                format!("java.time.Duration.of({x}L, java.time.temporal.ChronoUnit.MICROS)")
+            }
+            DurationValue::Nanoseconds(x) => format!("java.time.Duration.ofNanos({x}L)"),
+            DurationValue::SignedMilliseconds(x) => format!("java.time.Duration.ofMillis({x})"),
         }
     }
 }
@@ -0,0 +1,118 @@
+use super::doc::*;
+use super::*;
+
+pub(crate) fn generate_owned(
+    f: &mut dyn Printer,
+    iter: &Handle<AbstractIterator<Validated>>,
+    destroy: &Handle<Function<Validated>>,
+) -> FormattingResult<()> {
+    let classname = iter.name().camel_case();
+    let value_type = iter.item_type.as_java_object();
+    let next_function = &iter.next_function.name;
+
+    documentation(f, |f| {
+        f.writeln(&format!(
+            "Sequence of {{@link {value_type}}} values returned from a native function"
+        ))?;
+        f.newline()?;
+        f.writeln("<p>The values may only be iterated once. Closing the instance releases the native iterator.</p>")
+    })?;
+    f.writeln(&format!(
+        "public final class {classname} implements Iterable<{value_type}>, AutoCloseable"
+    ))?;
+    blocked(f, |f| {
+        f.writeln("final private long self;")?;
+        f.writeln("private java.util.concurrent.atomic.AtomicBoolean disposed = new java.util.concurrent.atomic.AtomicBoolean(false);")?;
+        f.writeln("private boolean iterated = false;")?;
+
+        f.newline()?;
+
+        f.writeln(&format!("private {classname}(long self)"))?;
+        blocked(f, |f| f.writeln("this.self = self;"))?;
+
+        f.newline()?;
+
+        documentation(f, |f| {
+            f.writeln("Retrieve an iterator over the values")?;
+            f.newline()?;
+            f.writeln("@return iterator over the values")?;
+            f.writeln("@throws IllegalStateException if the values were already iterated")
+        })?;
+        f.writeln("@Override")?;
+        f.writeln(&format!(
+            "public java.util.Iterator<{value_type}> iterator()"
+        ))?;
+        blocked(f, |f| {
+            f.writeln("if (this.iterated)")?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "throw new IllegalStateException(\"{classname} may only be iterated once\");"
+                ))
+            })?;
+            f.writeln("this.iterated = true;")?;
+            f.newline()?;
+            f.writeln(&format!("return new java.util.Iterator<{value_type}>()"))?;
+            f.writeln("{")?;
+            indented(f, |f| {
+                f.writeln(&format!("private {value_type} current = null;"))?;
+                f.writeln("private boolean finished = false;")?;
+                f.newline()?;
+                f.writeln("@Override")?;
+                f.writeln("public boolean hasNext()")?;
+                blocked(f, |f| {
+                    f.writeln("if (this.current == null && !this.finished)")?;
+                    blocked(f, |f| {
+                        f.writeln(&format!("if ({classname}.this.disposed.get())"))?;
+                        blocked(f, |f| {
+                            f.writeln(&format!(
+                                "throw new IllegalStateException(\"{classname} was closed\");"
+                            ))
+                        })?;
+                        f.writeln(&format!(
+                            "this.current = {NATIVE_FUNCTIONS_CLASSNAME}.Wrapped.{next_function}({classname}.this);"
+                        ))?;
+                        f.writeln("this.finished = this.current == null;")
+                    })?;
+                    f.writeln("return this.current != null;")
+                })?;
+                f.newline()?;
+                f.writeln("@Override")?;
+                f.writeln(&format!("public {value_type} next()"))?;
+                blocked(f, |f| {
+                    f.writeln("if (!this.hasNext())")?;
+                    blocked(f, |f| {
+                        f.writeln("throw new java.util.NoSuchElementException();")
+                    })?;
+                    f.writeln(&format!("final {value_type} value = this.current;"))?;
+                    f.writeln("this.current = null;")?;
+                    f.writeln("return value;")
+                })
+            })?;
+            f.writeln("};")
+        })?;
+
+        f.newline()?;
+
+        documentation(f, |f| javadoc_print(f, &destroy.doc))?;
+        f.writeln("@Override")?;
+        f.writeln("public void close()")?;
+        blocked(f, |f| {
+            f.writeln("if (this.disposed.getAndSet(true))")?;
+            f.writeln("    return;")?;
+
+            f.newline()?;
+
+            f.writeln(&format!(
+                "{}.Wrapped.{}(this);",
+                NATIVE_FUNCTIONS_CLASSNAME, destroy.name
+            ))
+        })?;
+
+        f.newline()?;
+
+        // Finalizer method
+        f.writeln("@Override")?;
+        f.writeln("public void finalize()")?;
+        blocked(f, |f| f.writeln("this.close();"))
+    })
+}
@@ -13,10 +13,13 @@ mod conversion;
 mod doc;
 mod enumeration;
 mod exception;
+mod flags;
 mod formatting;
 mod interface;
+mod iterator;
 mod nullable;
 mod structure;
+mod tagged_union;
 
 const NATIVE_FUNCTIONS_CLASSNAME: &str = "NativeFunctions";
 
@@ -90,6 +93,9 @@ pub(crate) fn generate_java_bindings(
     logged::create_dir_all(config.java_source_dir(lib))?;
 
     emit_binding_library_loader(lib, config)?;
+    if lib.has_stream_interfaces() {
+        emit_stream_publisher(lib, config)?;
+    }
 
     // Create all the direct mappings
     generate_native_func_class(lib, config)?;
@@ -99,6 +105,8 @@ pub(crate) fn generate_java_bindings(
     generate_exceptions(lib, config)?;
     generate_structs(lib, config)?;
     generate_enums(lib, config)?;
+    generate_flags(lib, config)?;
+    generate_unions(lib, config)?;
     generate_classes(lib, config)?;
     generate_interfaces(lib, config)?;
 
@@ -180,12 +188,23 @@ fn generate_pom(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<(
 
         f.newline()?;
 
-        // General properties
+        // General properties, streams are based on java.util.concurrent.Flow available since Java 9
+        let java_version = if lib.has_stream_interfaces() {
+            "9"
+        } else {
+            "1.8"
+        };
         f.writeln("<properties>")?;
-        f.writeln("    <project.java.version>1.8</project.java.version>")?;
+        f.writeln(&format!(
+            "    <project.java.version>{java_version}</project.java.version>"
+        ))?;
         f.writeln("    <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>")?;
-        f.writeln("    <maven.compiler.target>1.8</maven.compiler.target>")?;
-        f.writeln("    <maven.compiler.source>1.8</maven.compiler.source>")?;
+        f.writeln(&format!(
+            "    <maven.compiler.target>{java_version}</maven.compiler.target>"
+        ))?;
+        f.writeln(&format!(
+            "    <maven.compiler.source>{java_version}</maven.compiler.source>"
+        ))?;
         f.writeln("</properties>")?;
 
         f.newline()?;
@@ -260,6 +279,18 @@ fn write_null_checks(
                 f.writeln(&format!("java.util.Objects.requireNonNull(_item, \"List {arg_name} may not contain a null member\");"))
             })?;
         }
+        if let FunctionArgument::Map(x) = &arg.arg_type {
+            f.writeln(&format!(
+                "for(java.util.Map.Entry<{}, {}> _entry: {}.entrySet())",
+                x.key_type.as_java_object(),
+                x.value_type.as_java_object(),
+                arg_name
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!("java.util.Objects.requireNonNull(_entry.getKey(), \"Map {arg_name} may not contain a null key\");"))?;
+                f.writeln(&format!("java.util.Objects.requireNonNull(_entry.getValue(), \"Map {arg_name} may not contain a null value\");"))
+            })?;
+        }
     }
     Ok(())
 }
@@ -273,6 +304,15 @@ fn emit_binding_library_loader(lib: &Library, config: &JavaBindgenConfig) -> For
     Ok(())
 }
 
+fn emit_stream_publisher(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+    let file = include_str!("./copy/StreamPublisher.java");
+    let mut f = create_file("StreamPublisher", config, lib)?;
+    for line in file.lines() {
+        f.writeln(line)?;
+    }
+    Ok(())
+}
+
 fn generate_native_func_class(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
     let mut f = create_file(NATIVE_FUNCTIONS_CLASSNAME, config, lib)?;
 
@@ -341,18 +381,26 @@ fn generate_native_func_class(lib: &Library, config: &JavaBindgenConfig) -> Form
 
         f.newline()?;
 
-        fn skip(c: FunctionCategory) -> bool {
-            match c {
+        let skip = |func: &Handle<Function<Validated>>| -> bool {
+            match func.category {
                 FunctionCategory::Native => false,
+                FunctionCategory::ClassDestructor => false,
                 // we don't generate any of these
                 FunctionCategory::CollectionCreate => true,
                 FunctionCategory::CollectionDestroy => true,
                 FunctionCategory::CollectionAdd => true,
-                FunctionCategory::IteratorNext => true,
+                FunctionCategory::MapCreate => true,
+                FunctionCategory::MapDestroy => true,
+                FunctionCategory::MapInsert => true,
+                // owned iterators are traversed from the generated Java class
+                FunctionCategory::IteratorNext => !lib
+                    .iterators()
+                    .any(|it| it.is_owned() && it.next_function.name == func.name),
+                FunctionCategory::IteratorDestroy => false,
             }
-        }
+        };
 
-        for handle in lib.functions().filter(|func| !skip(func.category)) {
+        for handle in lib.functions().filter(|func| !skip(func)) {
             f.writeln(&format!(
                 "private static native {} {}(",
                 handle.return_type.as_java_primitive(),
@@ -380,7 +428,7 @@ fn generate_native_func_class(lib: &Library, config: &JavaBindgenConfig) -> Form
         f.writeln("// wrappers around the native functions that do null checking")?;
         f.writeln("static class Wrapped")?;
         blocked(f, |f| {
-            for handle in lib.functions().filter(|func| !skip(func.category)) {
+            for handle in lib.functions().filter(|func| !skip(func)) {
                 f.writeln(&format!(
                     "static {} {}(",
                     handle.return_type.as_java_primitive(),
@@ -435,9 +483,19 @@ fn generate_constants(lib: &Library, config: &JavaBindgenConfig) -> FormattingRe
 }
 
 fn generate_exceptions(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+    for base in lib.base_exceptions() {
+        let mut f = create_file(&base.name.camel_case(), config, lib)?;
+        exception::generate_base(&mut f, base)?;
+    }
+
     for error in lib.error_types() {
         let mut f = create_file(&error.exception_name.camel_case(), config, lib)?;
         exception::generate(&mut f, error)?;
+
+        for (variant, _) in error.variant_exceptions() {
+            let mut f = create_file(&variant.exception_name.camel_case(), config, lib)?;
+            exception::generate_variant(&mut f, error, variant)?;
+        }
     }
 
     Ok(())
@@ -466,6 +524,25 @@ fn generate_enums(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult
     Ok(())
 }
 
+fn generate_flags(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+    for handle in lib.flags() {
+        let mut f = create_file(&handle.name.camel_case(), config, lib)?;
+        flags::generate(&mut f, handle)?;
+    }
+
+    Ok(())
+}
+
+fn generate_unions(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
+    let package = format!("{}.{}", config.group_id, lib.settings.name.kebab_case());
+    for union in lib.unions() {
+        let mut f = create_file(&union.name.camel_case(), config, lib)?;
+        tagged_union::generate(&mut f, union, &package)?;
+    }
+
+    Ok(())
+}
+
 fn generate_classes(lib: &Library, config: &JavaBindgenConfig) -> FormattingResult<()> {
     for class in lib.classes() {
         let mut f = create_file(&class.name().camel_case(), config, lib)?;
@@ -477,6 +554,13 @@ fn generate_classes(lib: &Library, config: &JavaBindgenConfig) -> FormattingResu
         class::generate_static(&mut f, class)?;
     }
 
+    for iter in lib.iterators() {
+        if let Some(destroy) = &iter.destroy_function {
+            let mut f = create_file(&iter.name().camel_case(), config, lib)?;
+            iterator::generate_owned(&mut f, iter, destroy)?;
+        }
+    }
+
     Ok(())
 }
 
@@ -32,7 +32,9 @@ impl Nullable for BasicType {
         match self {
             BasicType::Primitive(x) => x.is_nullable(),
             BasicType::Duration(_) => true,
+            BasicType::Timestamp(_) => true,
             BasicType::Enum(_) => true,
+            BasicType::Flags(_) => true,
         }
     }
 }
@@ -42,7 +44,11 @@ impl Nullable for FunctionArgument {
         match self {
             Self::Basic(x) => x.is_nullable(),
             Self::String(_) => true,
+            Self::Bytes(_) => true,
+            Self::Union(_) => true,
+            Self::Optional(_) => false,
             Self::Collection(_) => true,
+            Self::Map(_) => true,
             Self::Struct(_) => true,
             Self::StructRef(_) => true,
             Self::ClassRef(_) => true,
@@ -56,8 +62,11 @@ impl Nullable for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.is_nullable(),
             FunctionArgStructField::String(_) => true,
+            FunctionArgStructField::Bytes(_) => true,
+            FunctionArgStructField::Optional(_) => false,
             FunctionArgStructField::Interface(_) => true,
             FunctionArgStructField::Struct(_) => true,
+            FunctionArgStructField::List(_) => true,
         }
     }
 }
@@ -80,6 +89,8 @@ impl Nullable for CallbackArgStructField {
             CallbackArgStructField::Iterator(_) => true,
             CallbackArgStructField::Struct(_) => true,
             CallbackArgStructField::String(_) => true,
+            CallbackArgStructField::Bytes(_) => true,
+            CallbackArgStructField::Optional(_) => false,
         }
     }
 }
@@ -90,6 +101,9 @@ impl Nullable for UniversalStructField {
             UniversalStructField::Basic(x) => x.is_nullable(),
             UniversalStructField::Struct(_) => true,
             UniversalStructField::String(_) => false,
+            UniversalStructField::Bytes(_) => false,
+            UniversalStructField::Optional(_) => false,
+            UniversalStructField::List(_) => true,
         }
     }
 }
@@ -99,8 +113,11 @@ impl IsStruct for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(_) => false,
             FunctionArgStructField::String(_) => false,
+            FunctionArgStructField::Bytes(_) => false,
+            FunctionArgStructField::Optional(_) => false,
             FunctionArgStructField::Interface(_) => false,
             FunctionArgStructField::Struct(_) => true,
+            FunctionArgStructField::List(_) => false,
         }
     }
 }
@@ -123,6 +140,8 @@ impl IsStruct for CallbackArgStructField {
             CallbackArgStructField::Iterator(_) => false,
             CallbackArgStructField::Struct(_) => true,
             CallbackArgStructField::String(_) => false,
+            CallbackArgStructField::Bytes(_) => false,
+            CallbackArgStructField::Optional(_) => false,
         }
     }
 }
@@ -133,6 +152,9 @@ impl IsStruct for UniversalStructField {
             UniversalStructField::Basic(_) => false,
             UniversalStructField::Struct(_) => true,
             UniversalStructField::String(_) => false,
+            UniversalStructField::Bytes(_) => false,
+            UniversalStructField::Optional(_) => false,
+            UniversalStructField::List(_) => false,
         }
     }
 }
@@ -142,7 +164,12 @@ impl IsStruct for FunctionArgument {
         match self {
             FunctionArgument::Basic(_) => false,
             FunctionArgument::String(_) => false,
+            FunctionArgument::Bytes(_) => false,
+            // unions forward the field checks to the payload of the active variant
+            FunctionArgument::Union(_) => true,
+            FunctionArgument::Optional(_) => false,
             FunctionArgument::Collection(_) => false,
+            FunctionArgument::Map(_) => false,
             FunctionArgument::Struct(_) => true,
             FunctionArgument::StructRef(_) => true,
             FunctionArgument::ClassRef(_) => false,
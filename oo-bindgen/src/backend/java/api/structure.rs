@@ -25,48 +25,52 @@ where
     T: StructFieldType,
 {
     match constructor.values.iter().find(|x| x.name == field.name) {
-        Some(x) => match &x.value {
-            ValidatedDefaultValue::Bool(x) => x.to_string(),
-            ValidatedDefaultValue::Number(x) => match x {
-                NumberValue::U8(x) => format!("UByte.valueOf({x})"),
-                NumberValue::S8(x) => format!("(byte) {x}"),
-                NumberValue::U16(x) => format!("UShort.valueOf({x})"),
-                NumberValue::S16(x) => format!("(short) {x}"),
-                NumberValue::U32(x) => format!("UInteger.valueOf({x}L)"),
-                NumberValue::S32(x) => x.to_string(),
-                NumberValue::U64(x) => format!("ULong.valueOf({x}L)"),
-                NumberValue::S64(x) => x.to_string(),
-                NumberValue::Float(x) => format!("{x}F"),
-                NumberValue::Double(x) => x.to_string(),
-            },
-            ValidatedDefaultValue::Duration(t, x) => match t {
-                DurationType::Milliseconds => {
-                    format!("java.time.Duration.ofMillis({})", t.get_value_string(*x))
-                }
-                DurationType::Seconds => {
-                    format!("java.time.Duration.ofSeconds({})", t.get_value_string(*x))
-                }
-            },
-            ValidatedDefaultValue::Enum(x, variant) => {
-                format!("{}.{}", x.name.camel_case(), variant.capital_snake_case())
+        Some(x) => get_default_value(&x.value),
+        None => field.name.mixed_case(),
+    }
+}
+
+pub(crate) fn get_default_value(x: &ValidatedDefaultValue) -> String {
+    match x {
+        ValidatedDefaultValue::Bool(x) => x.to_string(),
+        ValidatedDefaultValue::Number(x) => match x {
+            NumberValue::U8(x) => format!("UByte.valueOf({x})"),
+            NumberValue::S8(x) => format!("(byte) {x}"),
+            NumberValue::U16(x) => format!("UShort.valueOf({x})"),
+            NumberValue::S16(x) => format!("(short) {x}"),
+            NumberValue::U32(x) => format!("UInteger.valueOf({x}L)"),
+            NumberValue::S32(x) => x.to_string(),
+            NumberValue::U64(x) => format!("ULong.valueOf({x}L)"),
+            NumberValue::S64(x) => x.to_string(),
+            NumberValue::Float(x) => format!("{x}F"),
+            NumberValue::Double(x) => x.to_string(),
+        },
+        ValidatedDefaultValue::Duration(t, x) => match t {
+            DurationType::Milliseconds | DurationType::SignedMilliseconds => {
+                format!("java.time.Duration.ofMillis({})", t.get_value_string(*x))
+            }
+            DurationType::Seconds => {
+                format!("java.time.Duration.ofSeconds({})", t.get_value_string(*x))
             }
-            ValidatedDefaultValue::String(x) => format!("\"{x}\""),
-            ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
-                format!("new {}()", handle.name().camel_case(),)
+            DurationType::Microseconds | DurationType::Nanoseconds | DurationType::FloatSeconds => {
+                format!("java.time.Duration.ofNanos({}L)", x.as_nanos())
             }
         },
-        None => field.name.mixed_case(),
+        ValidatedDefaultValue::Enum(x, variant) => {
+            format!("{}.{}", x.name.camel_case(), variant.capital_snake_case())
+        }
+        ValidatedDefaultValue::String(x) => format!("\"{x}\""),
+        ValidatedDefaultValue::DefaultStruct(handle, _, _) => {
+            format!("new {}()", handle.name().camel_case(),)
+        }
     }
 }
 
-fn get_default_value_doc(x: &ValidatedDefaultValue) -> String {
+pub(crate) fn get_default_value_doc(x: &ValidatedDefaultValue) -> String {
     match x {
         ValidatedDefaultValue::Bool(x) => x.to_string(),
         ValidatedDefaultValue::Number(x) => x.to_string(),
-        ValidatedDefaultValue::Duration(DurationType::Milliseconds, x) => {
-            format!("{}ms", x.as_millis())
-        }
-        ValidatedDefaultValue::Duration(DurationType::Seconds, x) => format!("{}s", x.as_secs()),
+        ValidatedDefaultValue::Duration(t, x) => t.get_value_abbreviation(*x),
         ValidatedDefaultValue::Enum(x, variant) => format!(
             "{{@link {}#{}}}",
             x.name.camel_case(),
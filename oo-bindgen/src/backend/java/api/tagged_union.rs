@@ -0,0 +1,55 @@
+use super::doc::*;
+use super::*;
+
+pub(crate) fn generate(
+    f: &mut impl Printer,
+    handle: &Handle<Union<Validated>>,
+    package: &str,
+) -> FormattingResult<()> {
+    let union_name = handle.name.camel_case();
+
+    // Documentation
+    documentation(f, |f| javadoc_print(f, &handle.doc))?;
+
+    // Abstract base class with a nested final subclass per variant
+    f.writeln(&format!("public abstract class {union_name}"))?;
+    blocked(f, |f| {
+        f.writeln("// only the nested variant classes may extend this class")?;
+        f.writeln(&format!("private {union_name}() {{}}"))?;
+
+        for variant in &handle.variants {
+            let variant_name = variant.name.camel_case();
+            // the payload type is fully qualified as it may be shadowed by a variant class
+            let value_type = format!("{}.{}", package, variant.value.name().camel_case());
+
+            f.newline()?;
+            documentation(f, |f| javadoc_print(f, &variant.doc))?;
+            f.writeln(&format!(
+                "public static final class {variant_name} extends {union_name}"
+            ))?;
+            blocked(f, |f| {
+                documentation(f, |f| f.writeln("Payload of the variant"))?;
+                f.writeln(&format!("public final {value_type} value;"))?;
+                f.newline()?;
+                documentation(f, |f| {
+                    f.writeln(&format!("Construct the {variant_name} variant"))?;
+                    f.writeln("@param value Payload of the variant")
+                })?;
+                f.writeln(&format!("public {variant_name}({value_type} value)"))?;
+                blocked(f, |f| f.writeln("this.value = value;"))?;
+                f.newline()?;
+                f.writeln("@Override")?;
+                f.writeln("void _assertFieldsNotNull()")?;
+                blocked(f, |f| {
+                    f.writeln(
+                        "java.util.Objects.requireNonNull(value, \"value cannot be null\");",
+                    )?;
+                    f.writeln("value._assertFieldsNotNull();")
+                })
+            })?;
+        }
+
+        f.newline()?;
+        f.writeln("abstract void _assertFieldsNotNull();")
+    })
+}
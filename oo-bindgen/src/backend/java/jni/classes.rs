@@ -1,4 +1,4 @@
-use crate::model::Library;
+use crate::model::{Library, Name};
 
 use crate::backend::java::jni::JniBindgenConfig;
 use crate::backend::*;
@@ -18,8 +18,15 @@ pub(crate) fn generate_classes_cache(
     })?;
     f.writeln("}")?;
 
-    for class in lib.classes() {
-        let class_name = class.name().camel_case();
+    // owned iterators are wrapped in Java classes just like regular classes
+    let names: Vec<&Name> = lib
+        .classes()
+        .map(|x| x.name())
+        .chain(lib.iterators().filter(|x| x.is_owned()).map(|x| x.name()))
+        .collect();
+
+    for name in names.iter() {
+        let class_name = name.camel_case();
         f.newline()?;
         f.writeln(&format!("pub(crate) struct {class_name} {{"))?;
         indented(f, |f| f.writeln("info: ClassInfo"))?;
@@ -29,7 +36,6 @@ pub(crate) fn generate_classes_cache(
         indented(f, |f| {
             f.writeln("fn init(env: &jni::JNIEnv) -> Self {")?;
             indented(f, |f| {
-                let class_name = class.name().camel_case();
                 f.writeln(&format!(
                     "let class = env.find_class(\"L{lib_path}/{class_name};\").expect(\"Unable to find class {class_name}\");"
                 ))?;
@@ -55,6 +61,12 @@ pub(crate) fn generate_classes_cache(
 
             f.writeln(&format!("pub(crate) fn to_jni(&self, env: &jni::JNIEnv, value: *mut {}::{}) -> jni::sys::jobject {{", config.ffi_name, class_name))?;
             indented(f, |f| {
+                // null pointers are used to signal the end of iterators over classes
+                f.writeln("if value.is_null() {")?;
+                indented(f, |f| {
+                    f.writeln("return jni::objects::JObject::null().into_inner();")
+                })?;
+                f.writeln("}")?;
                 f.writeln("env.new_object_unchecked(&self.info.class, self.info.constructor, &[jni::objects::JValue::Long(value as i64)]).unwrap().into_inner()")
             })?;
             f.writeln("}")
@@ -66,12 +78,8 @@ pub(crate) fn generate_classes_cache(
 
     f.writeln("pub(crate) struct Classes {")?;
     indented(f, |f| {
-        for class in lib.classes() {
-            f.writeln(&format!(
-                "pub(crate) {}: {},",
-                class.name(),
-                class.name().camel_case()
-            ))?;
+        for name in names.iter() {
+            f.writeln(&format!("pub(crate) {}: {},", name, name.camel_case()))?;
         }
 
         Ok(())
@@ -86,12 +94,8 @@ pub(crate) fn generate_classes_cache(
         indented(f, |f| {
             f.writeln("Self {")?;
             indented(f, |f| {
-                for class in lib.classes() {
-                    f.writeln(&format!(
-                        "{}: {}::init(env),",
-                        class.name(),
-                        class.name().camel_case()
-                    ))?;
+                for name in names.iter() {
+                    f.writeln(&format!("{}: {}::init(env),", name, name.camel_case()))?;
                 }
                 Ok(())
             })?;
@@ -26,12 +26,26 @@ impl ConvertibleToJni for DurationType {
         let method = match self {
             DurationType::Milliseconds => "to_jni_millis",
             DurationType::Seconds => "to_jni_seconds",
+            DurationType::Microseconds => "to_jni_micros",
+            DurationType::Nanoseconds => "to_jni_nanos",
+            DurationType::SignedMilliseconds => "to_jni_signed_millis",
+            DurationType::FloatSeconds => "to_jni_float_seconds",
         };
 
         format!("_cache.duration.{method}(&_env, {expr})")
     }
 }
 
+impl ConvertibleToJni for TimestampType {
+    fn convert(&self, expr: &str) -> String {
+        let method = match self {
+            TimestampType::Milliseconds => "to_jni_millis",
+        };
+
+        format!("_cache.instant.{method}(&_env, {expr})")
+    }
+}
+
 impl<D> ConvertibleToJni for Handle<Enum<D>>
 where
     D: DocReference,
@@ -41,12 +55,76 @@ where
     }
 }
 
+impl<D> ConvertibleToJni for Handle<Flags<D>>
+where
+    D: DocReference,
+{
+    fn convert(&self, expr: &str) -> String {
+        format!("_cache.flags.{}.to_jni(&_env, {})", self.name, expr)
+    }
+}
+
 impl ConvertibleToJni for StringType {
     fn convert(&self, expr: &str) -> String {
         format!("_env.new_string(unsafe {{ std::ffi::CStr::from_ptr({expr}) }}.to_string_lossy()).unwrap().into_inner()")
     }
 }
 
+impl ConvertibleToJni for BytesType {
+    fn convert(&self, expr: &str) -> String {
+        format!("_env.byte_array_from_slice(unsafe {{ {expr}.to_slice() }}).unwrap()")
+    }
+}
+
+impl ConvertibleToJni for Optional<BasicType> {
+    fn convert(&self, expr: &str) -> String {
+        // absent values are null and present values are always boxed
+        let value = boxed_value(&self.inner, "x");
+        format!("match {expr}.into_option() {{ Some(x) => {value}, None => jni::objects::JObject::null().into_inner() }}")
+    }
+}
+
+impl ConvertibleToJni for ListHandle {
+    fn convert(&self, expr: &str) -> String {
+        format!(
+            "crate::lists::{}::to_jni(_cache, &_env, {})",
+            self.name.camel_case(),
+            expr
+        )
+    }
+}
+
+/// Convert a basic value to the object used in contexts where primitives are always boxed
+pub(crate) fn boxed_value(value: &BasicType, expr: &str) -> String {
+    match value {
+        BasicType::Primitive(x) => match x {
+            Primitive::Bool => {
+                format!("_cache.primitives.boolean.create(&_env, {expr}).into_inner()")
+            }
+            Primitive::U8 => UnsignedConverter::U8.apply(expr),
+            Primitive::S8 => format!("_cache.primitives.byte.create(&_env, {expr}).into_inner()"),
+            Primitive::U16 => UnsignedConverter::U16.apply(expr),
+            Primitive::S16 => format!("_cache.primitives.short.create(&_env, {expr}).into_inner()"),
+            Primitive::U32 => UnsignedConverter::U32.apply(expr),
+            Primitive::S32 => {
+                format!("_cache.primitives.integer.create(&_env, {expr}).into_inner()")
+            }
+            Primitive::U64 => UnsignedConverter::U64.apply(expr),
+            Primitive::S64 => format!("_cache.primitives.long.create(&_env, {expr}).into_inner()"),
+            Primitive::Float => {
+                format!("_cache.primitives.float.create(&_env, {expr}).into_inner()")
+            }
+            Primitive::Double => {
+                format!("_cache.primitives.double.create(&_env, {expr}).into_inner()")
+            }
+        },
+        BasicType::Duration(x) => x.convert(expr),
+        BasicType::Timestamp(x) => x.convert(expr),
+        BasicType::Enum(x) => x.convert(expr),
+        BasicType::Flags(x) => x.convert(expr),
+    }
+}
+
 impl MaybeConvertibleToJni for Primitive {
     fn maybe_convert(&self, expr: &str) -> Option<String> {
         match self {
@@ -70,7 +148,9 @@ impl MaybeConvertibleToJni for BasicType {
         match self {
             Self::Primitive(x) => x.maybe_convert(expr),
             Self::Duration(x) => x.maybe_convert(expr),
+            Self::Timestamp(x) => x.maybe_convert(expr),
             Self::Enum(x) => x.maybe_convert(expr),
+            Self::Flags(x) => x.maybe_convert(expr),
         }
     }
 }
@@ -78,7 +158,7 @@ impl MaybeConvertibleToJni for BasicType {
 impl ConvertibleToJni for StructDeclarationHandle {
     fn convert(&self, expr: &str) -> String {
         format!(
-            "{}.as_ref().map(|x| _cache.structs.struct_{}.to_jni(_cache, &_env, &value)).or_else(|| jni::objects::JObject::null().into_inner())",
+            "unsafe {{ {}.as_ref() }}.map(|x| _cache.structs.{}.to_jni(_cache, &_env, x)).unwrap_or_else(|| jni::objects::JObject::null().into_inner())",
             expr,
             self.name
         )
@@ -117,6 +197,18 @@ where
     }
 }
 
+impl<D> ConvertibleToJni for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn convert(&self, expr: &str) -> String {
+        format!(
+            "_cache.unions.{}.to_jni(_cache, &_env, &{})",
+            self.name, expr
+        )
+    }
+}
+
 impl<T> ConvertibleToJni for UniversalOr<T>
 where
     T: StructFieldType,
@@ -147,6 +239,8 @@ impl MaybeConvertibleToJni for CallbackArgStructField {
             Self::Iterator(x) => x.maybe_convert(expr),
             Self::Struct(x) => x.maybe_convert(expr),
             Self::String(x) => x.maybe_convert(expr),
+            Self::Bytes(x) => x.maybe_convert(expr),
+            Self::Optional(x) => x.maybe_convert(expr),
         }
     }
 }
@@ -157,6 +251,9 @@ impl MaybeConvertibleToJni for UniversalStructField {
             Self::Basic(x) => x.maybe_convert(expr),
             Self::Struct(x) => x.maybe_convert(expr),
             Self::String(x) => x.maybe_convert(expr),
+            Self::Bytes(x) => x.maybe_convert(expr),
+            Self::Optional(x) => x.maybe_convert(expr),
+            Self::List(x) => x.maybe_convert(expr),
         }
     }
 }
@@ -166,6 +263,9 @@ impl MaybeConvertibleToJni for CallbackArgument {
         match self {
             Self::Basic(x) => x.maybe_convert(expr),
             Self::String(x) => x.maybe_convert(expr),
+            Self::Bytes(x) => x.maybe_convert(expr),
+            Self::Union(x) => x.maybe_convert(expr),
+            Self::Optional(x) => x.maybe_convert(expr),
             Self::Iterator(x) => x.maybe_convert(expr),
             Self::Struct(x) => x.maybe_convert(expr),
             Self::Class(x) => x.maybe_convert(expr),
@@ -181,15 +281,33 @@ impl MaybeConvertibleToJni for PrimitiveRef {
     }
 }
 
+impl ConvertibleToJni for ValueRef {
+    fn convert(&self, expr: &str) -> String {
+        let value = match self {
+            Self::String(x) => x.convert("x"),
+            Self::Enum(x) => x.convert("(*x).into()"),
+            Self::Duration(x) => x.convert("*x"),
+        };
+        format!(
+            "unsafe {{ {expr}.as_ref() }}.map(|x| {value}).unwrap_or_else(|| jni::objects::JObject::null().into_inner())"
+        )
+    }
+}
+
 impl MaybeConvertibleToJni for FunctionReturnValue {
     fn maybe_convert(&self, expr: &str) -> Option<String> {
         match self {
             Self::Basic(x) => x.maybe_convert(expr),
             Self::String(x) => x.maybe_convert(expr),
+            Self::Bytes(x) => x.maybe_convert(expr),
+            Self::Union(x) => x.maybe_convert(expr),
             Self::ClassRef(x) => x.maybe_convert(expr),
+            // owned iterators are wrapped in a generated Java class
+            Self::Iterator(x) => x.iter_class.maybe_convert(expr),
             Self::Struct(x) => x.maybe_convert(expr),
             Self::StructRef(x) => x.untyped().maybe_convert(expr),
             Self::PrimitiveRef(x) => x.maybe_convert(expr),
+            Self::ValueRef(x) => x.maybe_convert(expr),
         }
     }
 }
@@ -199,6 +317,10 @@ impl MaybeConvertibleToJni for IteratorItemType {
         match self {
             IteratorItemType::Struct(x) => x.maybe_convert(expr),
             IteratorItemType::Primitive(x) => x.maybe_convert(expr),
+            IteratorItemType::String(x) => x.maybe_convert(expr),
+            IteratorItemType::Enum(x) => x.maybe_convert(expr),
+            IteratorItemType::Duration(x) => x.maybe_convert(expr),
+            IteratorItemType::Class(x) => x.maybe_convert(expr),
         }
     }
 }
@@ -43,6 +43,35 @@ impl ConvertibleToRust for StringType {
     }
 }
 
+impl ConvertibleToRust for BytesType {
+    fn to_rust(&self, expr: &str) -> Option<String> {
+        Some(format!(
+            "_env.get_byte_array_elements({expr}, jni::objects::ReleaseMode::NoCopyBack).unwrap()"
+        ))
+    }
+
+    fn call_site(&self, expr: &str) -> Option<String> {
+        Some(format!("crate::util::byte_slice(&{expr}).into()"))
+    }
+}
+
+impl ConvertibleToRust for Optional<BasicType> {
+    fn to_rust(&self, expr: &str) -> Option<String> {
+        // optional values are always boxed and null when absent
+        let value = self
+            .inner
+            .to_rust_from_object(expr)
+            .unwrap_or_else(|| expr.to_string());
+        Some(format!(
+            "if {expr}.is_null() {{ None }} else {{ Some({value}) }}"
+        ))
+    }
+
+    fn call_site(&self, expr: &str) -> Option<String> {
+        Some(format!("{expr}.into()"))
+    }
+}
+
 impl ConvertibleToRust for Primitive {
     fn to_rust(&self, expr: &str) -> Option<String> {
         match self {
@@ -102,6 +131,32 @@ impl ConvertibleToRust for DurationType {
             DurationType::Seconds => {
                 format!("_cache.duration.to_rust_seconds(&_env, {expr})")
             }
+            DurationType::Microseconds => {
+                format!("_cache.duration.to_rust_micros(&_env, {expr})")
+            }
+            DurationType::Nanoseconds => {
+                format!("_cache.duration.to_rust_nanos(&_env, {expr})")
+            }
+            DurationType::SignedMilliseconds => {
+                format!("_cache.duration.to_rust_signed_millis(&_env, {expr})")
+            }
+            DurationType::FloatSeconds => {
+                format!("_cache.duration.to_rust_float_seconds(&_env, {expr})")
+            }
+        })
+    }
+
+    fn call_site(&self, _expr: &str) -> Option<String> {
+        None
+    }
+}
+
+impl ConvertibleToRust for TimestampType {
+    fn to_rust(&self, expr: &str) -> Option<String> {
+        Some(match self {
+            TimestampType::Milliseconds => {
+                format!("_cache.instant.to_rust_millis(&_env, {expr})")
+            }
         })
     }
 
@@ -123,12 +178,27 @@ impl ConvertibleToRust for Handle<Enum<Unvalidated>> {
     }
 }
 
+impl ConvertibleToRust for FlagsHandle {
+    fn to_rust(&self, expr: &str) -> Option<String> {
+        Some(format!(
+            "_cache.flags.{}.to_rust(&_env, {})",
+            self.name, expr
+        ))
+    }
+
+    fn call_site(&self, _expr: &str) -> Option<String> {
+        None
+    }
+}
+
 impl ConvertibleToRust for BasicType {
     fn to_rust(&self, expr: &str) -> Option<String> {
         match self {
             BasicType::Primitive(x) => x.to_rust(expr),
             BasicType::Duration(x) => x.to_rust(expr),
+            BasicType::Timestamp(x) => x.to_rust(expr),
             BasicType::Enum(x) => x.to_rust(expr),
+            BasicType::Flags(x) => x.to_rust(expr),
         }
     }
 
@@ -136,7 +206,9 @@ impl ConvertibleToRust for BasicType {
         match self {
             BasicType::Primitive(x) => x.to_rust_from_object(expr),
             BasicType::Duration(x) => x.to_rust_from_object(expr),
+            BasicType::Timestamp(x) => x.to_rust_from_object(expr),
             BasicType::Enum(x) => x.to_rust_from_object(expr),
+            BasicType::Flags(x) => x.to_rust_from_object(expr),
         }
     }
 
@@ -144,7 +216,9 @@ impl ConvertibleToRust for BasicType {
         match self {
             BasicType::Primitive(x) => x.call_site(expr),
             BasicType::Duration(x) => x.call_site(expr),
+            BasicType::Timestamp(x) => x.call_site(expr),
             BasicType::Enum(x) => x.call_site(expr),
+            BasicType::Flags(x) => x.call_site(expr),
         }
     }
 }
@@ -165,6 +239,38 @@ impl ConvertibleToRust for CollectionHandle {
     }
 }
 
+impl ConvertibleToRust for MapHandle {
+    fn to_rust(&self, expr: &str) -> Option<String> {
+        // create the helper guard object that allocates and fills the native map from the Java map
+        Some(format!(
+            "collections::{}::new(_env, {}).unwrap()",
+            self.map_class.name.camel_case(),
+            expr
+        ))
+    }
+
+    fn call_site(&self, expr: &str) -> Option<String> {
+        // use the inner native map type for the function call
+        Some(format!("*{expr}"))
+    }
+}
+
+impl ConvertibleToRust for ListHandle {
+    fn to_rust(&self, expr: &str) -> Option<String> {
+        // create the helper guard object that owns the native items converted from the list
+        Some(format!(
+            "crate::lists::{}::new(_cache, _env, {})",
+            self.name.camel_case(),
+            expr
+        ))
+    }
+
+    fn call_site(&self, expr: &str) -> Option<String> {
+        // borrow the native items owned by the guard
+        Some(format!("{expr}.as_ffi()"))
+    }
+}
+
 impl ConvertibleToRust for AsynchronousInterface {
     fn to_rust(&self, expr: &str) -> Option<String> {
         self.inner.to_rust(expr)
@@ -219,6 +325,19 @@ where
     }
 }
 
+impl ConvertibleToRust for UnionHandle {
+    fn to_rust(&self, expr: &str) -> Option<String> {
+        Some(format!(
+            "_cache.unions.{}.to_rust(_cache, &_env, {})",
+            self.name, expr
+        ))
+    }
+
+    fn call_site(&self, expr: &str) -> Option<String> {
+        Some(format!("{expr}.1"))
+    }
+}
+
 impl ConvertibleToRust for UniversalOr<FunctionArgStructField> {
     fn to_rust(&self, expr: &str) -> Option<String> {
         match self {
@@ -260,7 +379,11 @@ impl ConvertibleToRust for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.to_rust(expr),
             FunctionArgument::String(x) => x.to_rust(expr),
+            FunctionArgument::Bytes(x) => x.to_rust(expr),
+            FunctionArgument::Union(x) => x.to_rust(expr),
+            FunctionArgument::Optional(x) => x.to_rust(expr),
             FunctionArgument::Collection(x) => x.to_rust(expr),
+            FunctionArgument::Map(x) => x.to_rust(expr),
             FunctionArgument::Struct(x) => x.to_rust(expr),
             FunctionArgument::StructRef(x) => x.to_rust(expr),
             FunctionArgument::ClassRef(x) => x.to_rust(expr),
@@ -272,7 +395,11 @@ impl ConvertibleToRust for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.to_rust_from_object(expr),
             FunctionArgument::String(x) => x.to_rust_from_object(expr),
+            FunctionArgument::Bytes(x) => x.to_rust_from_object(expr),
+            FunctionArgument::Union(x) => x.to_rust_from_object(expr),
+            FunctionArgument::Optional(x) => x.to_rust_from_object(expr),
             FunctionArgument::Collection(x) => x.to_rust_from_object(expr),
+            FunctionArgument::Map(x) => x.to_rust_from_object(expr),
             FunctionArgument::Struct(x) => x.to_rust_from_object(expr),
             FunctionArgument::StructRef(x) => x.to_rust_from_object(expr),
             FunctionArgument::ClassRef(x) => x.to_rust_from_object(expr),
@@ -284,7 +411,11 @@ impl ConvertibleToRust for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.call_site(expr),
             FunctionArgument::String(x) => x.call_site(expr),
+            FunctionArgument::Bytes(x) => x.call_site(expr),
+            FunctionArgument::Union(x) => x.call_site(expr),
+            FunctionArgument::Optional(x) => x.call_site(expr),
             FunctionArgument::Collection(x) => x.call_site(expr),
+            FunctionArgument::Map(x) => x.call_site(expr),
             FunctionArgument::Struct(x) => x.call_site(expr),
             FunctionArgument::StructRef(x) => x.call_site(expr),
             FunctionArgument::ClassRef(x) => x.call_site(expr),
@@ -321,6 +452,9 @@ impl ConvertibleToRust for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.to_rust(expr),
             FunctionArgStructField::String(x) => x.to_rust(expr),
+            FunctionArgStructField::Bytes(x) => x.to_rust(expr),
+            FunctionArgStructField::Optional(x) => x.to_rust(expr),
+            FunctionArgStructField::List(x) => x.to_rust(expr),
             FunctionArgStructField::Interface(x) => x.to_rust(expr),
             FunctionArgStructField::Struct(x) => x.to_rust(expr),
         }
@@ -330,6 +464,9 @@ impl ConvertibleToRust for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.to_rust_from_object(expr),
             FunctionArgStructField::String(x) => x.to_rust_from_object(expr),
+            FunctionArgStructField::Bytes(x) => x.to_rust_from_object(expr),
+            FunctionArgStructField::Optional(x) => x.to_rust_from_object(expr),
+            FunctionArgStructField::List(x) => x.to_rust_from_object(expr),
             FunctionArgStructField::Interface(x) => x.to_rust_from_object(expr),
             FunctionArgStructField::Struct(x) => x.to_rust_from_object(expr),
         }
@@ -339,6 +476,9 @@ impl ConvertibleToRust for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.call_site(expr),
             FunctionArgStructField::String(x) => x.call_site(expr),
+            FunctionArgStructField::Bytes(x) => x.call_site(expr),
+            FunctionArgStructField::Optional(x) => x.call_site(expr),
+            FunctionArgStructField::List(x) => x.call_site(expr),
             FunctionArgStructField::Interface(x) => x.call_site(expr),
             FunctionArgStructField::Struct(x) => x.call_site(expr),
         }
@@ -351,6 +491,9 @@ impl ConvertibleToRust for UniversalStructField {
             UniversalStructField::Basic(x) => x.to_rust(expr),
             UniversalStructField::Struct(x) => x.to_rust(expr),
             UniversalStructField::String(x) => x.to_rust(expr),
+            UniversalStructField::Bytes(x) => x.to_rust(expr),
+            UniversalStructField::Optional(x) => x.to_rust(expr),
+            UniversalStructField::List(x) => x.to_rust(expr),
         }
     }
 
@@ -359,6 +502,9 @@ impl ConvertibleToRust for UniversalStructField {
             UniversalStructField::Basic(x) => x.to_rust_from_object(expr),
             UniversalStructField::Struct(x) => x.to_rust_from_object(expr),
             UniversalStructField::String(x) => x.to_rust_from_object(expr),
+            UniversalStructField::Bytes(x) => x.to_rust_from_object(expr),
+            UniversalStructField::Optional(x) => x.to_rust_from_object(expr),
+            UniversalStructField::List(x) => x.to_rust_from_object(expr),
         }
     }
 
@@ -367,6 +513,9 @@ impl ConvertibleToRust for UniversalStructField {
             UniversalStructField::Basic(x) => x.call_site(expr),
             UniversalStructField::Struct(x) => x.call_site(expr),
             UniversalStructField::String(x) => x.call_site(expr),
+            UniversalStructField::Bytes(x) => x.call_site(expr),
+            UniversalStructField::Optional(x) => x.call_site(expr),
+            UniversalStructField::List(x) => x.call_site(expr),
         }
     }
 }
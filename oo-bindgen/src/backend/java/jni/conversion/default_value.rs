@@ -31,18 +31,32 @@ impl DefaultValue for DurationType {
     }
 }
 
+impl DefaultValue for TimestampType {
+    fn get_default_value(&self) -> &str {
+        NULL_DEFAULT_VALUE
+    }
+}
+
 impl DefaultValue for EnumHandle {
     fn get_default_value(&self) -> &str {
         NULL_DEFAULT_VALUE
     }
 }
 
+impl DefaultValue for FlagsHandle {
+    fn get_default_value(&self) -> &str {
+        NULL_DEFAULT_VALUE
+    }
+}
+
 impl DefaultValue for BasicType {
     fn get_default_value(&self) -> &str {
         match self {
             BasicType::Primitive(x) => x.get_default_value(),
             BasicType::Duration(x) => x.get_default_value(),
+            BasicType::Timestamp(x) => x.get_default_value(),
             BasicType::Enum(x) => x.get_default_value(),
+            BasicType::Flags(x) => x.get_default_value(),
         }
     }
 }
@@ -53,6 +67,18 @@ impl DefaultValue for StringType {
     }
 }
 
+impl DefaultValue for Optional<BasicType> {
+    fn get_default_value(&self) -> &str {
+        NULL_DEFAULT_VALUE
+    }
+}
+
+impl DefaultValue for BytesType {
+    fn get_default_value(&self) -> &str {
+        NULL_DEFAULT_VALUE
+    }
+}
+
 impl DefaultValue for ClassDeclarationHandle {
     fn get_default_value(&self) -> &str {
         NULL_DEFAULT_VALUE
@@ -71,21 +97,37 @@ impl DefaultValue for UniversalDeclarationOr<FunctionReturnStructField> {
     }
 }
 
+impl DefaultValue for UnionHandle {
+    fn get_default_value(&self) -> &str {
+        NULL_DEFAULT_VALUE
+    }
+}
+
 impl DefaultValue for PrimitiveRef {
     fn get_default_value(&self) -> &str {
         NULL_DEFAULT_VALUE
     }
 }
 
+impl DefaultValue for ValueRef {
+    fn get_default_value(&self) -> &str {
+        NULL_DEFAULT_VALUE
+    }
+}
+
 impl DefaultValue for FunctionReturnValue {
     fn get_default_value(&self) -> &str {
         match self {
             FunctionReturnValue::Basic(x) => x.get_default_value(),
             FunctionReturnValue::String(x) => x.get_default_value(),
+            FunctionReturnValue::Bytes(x) => x.get_default_value(),
+            FunctionReturnValue::Union(x) => x.get_default_value(),
             FunctionReturnValue::ClassRef(x) => x.get_default_value(),
+            FunctionReturnValue::Iterator(x) => x.iter_class.get_default_value(),
             FunctionReturnValue::Struct(x) => x.get_default_value(),
             FunctionReturnValue::StructRef(x) => x.get_default_value(),
             FunctionReturnValue::PrimitiveRef(x) => x.get_default_value(),
+            FunctionReturnValue::ValueRef(x) => x.get_default_value(),
         }
     }
 }
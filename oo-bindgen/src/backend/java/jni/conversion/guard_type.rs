@@ -25,6 +25,36 @@ impl GuardType for StringType {
     }
 }
 
+impl GuardType for Optional<BasicType> {
+    fn guard_type(&self) -> Option<String> {
+        None
+    }
+
+    fn guard_transform(&self, _expr: &str) -> Option<String> {
+        None
+    }
+}
+
+impl GuardType for BytesType {
+    fn guard_type(&self) -> Option<String> {
+        Some("jni::objects::AutoArray<'a, 'a, jni::sys::jbyte>".to_string())
+    }
+
+    fn guard_transform(&self, _expr: &str) -> Option<String> {
+        None
+    }
+}
+
+impl GuardType for ListHandle {
+    fn guard_type(&self) -> Option<String> {
+        Some(format!("crate::lists::{}<'a>", self.name.camel_case()))
+    }
+
+    fn guard_transform(&self, _expr: &str) -> Option<String> {
+        None
+    }
+}
+
 impl GuardType for AsynchronousInterface {
     fn guard_type(&self) -> Option<String> {
         None
@@ -69,6 +99,9 @@ impl GuardType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.guard_type(),
             FunctionArgStructField::String(x) => x.guard_type(),
+            FunctionArgStructField::Bytes(x) => x.guard_type(),
+            FunctionArgStructField::Optional(x) => x.guard_type(),
+            FunctionArgStructField::List(x) => x.guard_type(),
             FunctionArgStructField::Interface(x) => x.guard_type(),
             FunctionArgStructField::Struct(x) => x.guard_type(),
         }
@@ -78,6 +111,9 @@ impl GuardType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.guard_transform(expr),
             FunctionArgStructField::String(x) => x.guard_transform(expr),
+            FunctionArgStructField::Bytes(x) => x.guard_transform(expr),
+            FunctionArgStructField::Optional(x) => x.guard_transform(expr),
+            FunctionArgStructField::List(x) => x.guard_transform(expr),
             FunctionArgStructField::Interface(x) => x.guard_transform(expr),
             FunctionArgStructField::Struct(x) => x.guard_transform(expr),
         }
@@ -90,6 +126,9 @@ impl GuardType for UniversalStructField {
             UniversalStructField::Basic(x) => x.guard_type(),
             UniversalStructField::Struct(x) => x.guard_type(),
             UniversalStructField::String(x) => x.guard_type(),
+            UniversalStructField::Bytes(x) => x.guard_type(),
+            UniversalStructField::Optional(x) => x.guard_type(),
+            UniversalStructField::List(x) => x.guard_type(),
         }
     }
 
@@ -98,6 +137,9 @@ impl GuardType for UniversalStructField {
             UniversalStructField::Basic(x) => x.guard_transform(expr),
             UniversalStructField::Struct(x) => x.guard_transform(expr),
             UniversalStructField::String(x) => x.guard_transform(expr),
+            UniversalStructField::Bytes(x) => x.guard_transform(expr),
+            UniversalStructField::Optional(x) => x.guard_transform(expr),
+            UniversalStructField::List(x) => x.guard_transform(expr),
         }
     }
 }
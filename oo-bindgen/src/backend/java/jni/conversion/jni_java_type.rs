@@ -41,18 +41,32 @@ impl JniJavaType for DurationType {
     }
 }
 
+impl JniJavaType for TimestampType {
+    fn jni_java_type(&self) -> &'static str {
+        OBJECT_TYPE
+    }
+}
+
 impl JniJavaType for EnumHandle {
     fn jni_java_type(&self) -> &'static str {
         OBJECT_TYPE
     }
 }
 
+impl JniJavaType for FlagsHandle {
+    fn jni_java_type(&self) -> &'static str {
+        OBJECT_TYPE
+    }
+}
+
 impl JniJavaType for BasicType {
     fn jni_java_type(&self) -> &'static str {
         match self {
             BasicType::Primitive(x) => x.jni_java_type(),
             BasicType::Duration(x) => x.jni_java_type(),
+            BasicType::Timestamp(x) => x.jni_java_type(),
             BasicType::Enum(x) => x.jni_java_type(),
+            BasicType::Flags(x) => x.jni_java_type(),
         }
     }
 }
@@ -63,6 +77,24 @@ impl JniJavaType for StringType {
     }
 }
 
+impl JniJavaType for Optional<BasicType> {
+    fn jni_java_type(&self) -> &'static str {
+        OBJECT_TYPE
+    }
+}
+
+impl JniJavaType for BytesType {
+    fn jni_java_type(&self) -> &'static str {
+        OBJECT_TYPE
+    }
+}
+
+impl JniJavaType for ListHandle {
+    fn jni_java_type(&self) -> &'static str {
+        OBJECT_TYPE
+    }
+}
+
 impl JniJavaType for AsynchronousInterface {
     fn jni_java_type(&self) -> &'static str {
         OBJECT_TYPE
@@ -86,6 +118,9 @@ impl JniJavaType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.jni_java_type(),
             FunctionArgStructField::String(x) => x.jni_java_type(),
+            FunctionArgStructField::Bytes(x) => x.jni_java_type(),
+            FunctionArgStructField::Optional(x) => x.jni_java_type(),
+            FunctionArgStructField::List(x) => x.jni_java_type(),
             FunctionArgStructField::Interface(x) => x.jni_java_type(),
             FunctionArgStructField::Struct(x) => x.jni_java_type(),
         }
@@ -98,6 +133,9 @@ impl JniJavaType for UniversalStructField {
             UniversalStructField::Basic(x) => x.jni_java_type(),
             UniversalStructField::Struct(x) => x.jni_java_type(),
             UniversalStructField::String(x) => x.jni_java_type(),
+            UniversalStructField::Bytes(x) => x.jni_java_type(),
+            UniversalStructField::Optional(x) => x.jni_java_type(),
+            UniversalStructField::List(x) => x.jni_java_type(),
         }
     }
 }
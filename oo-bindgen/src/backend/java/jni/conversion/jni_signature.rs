@@ -34,18 +34,32 @@ impl JniSignatureType for DurationType {
     }
 }
 
+impl JniSignatureType for TimestampType {
+    fn jni_signature_type(&self) -> &str {
+        JNI_SYS_JOBJECT
+    }
+}
+
 impl JniSignatureType for EnumHandle {
     fn jni_signature_type(&self) -> &str {
         JNI_SYS_JOBJECT
     }
 }
 
+impl JniSignatureType for FlagsHandle {
+    fn jni_signature_type(&self) -> &str {
+        JNI_SYS_JOBJECT
+    }
+}
+
 impl JniSignatureType for BasicType {
     fn jni_signature_type(&self) -> &str {
         match self {
             BasicType::Primitive(x) => x.jni_signature_type(),
             BasicType::Duration(x) => x.jni_signature_type(),
+            BasicType::Timestamp(x) => x.jni_signature_type(),
             BasicType::Enum(x) => x.jni_signature_type(),
+            BasicType::Flags(x) => x.jni_signature_type(),
         }
     }
 }
@@ -56,12 +70,30 @@ impl JniSignatureType for StringType {
     }
 }
 
+impl JniSignatureType for Optional<BasicType> {
+    fn jni_signature_type(&self) -> &str {
+        "jni::sys::jobject"
+    }
+}
+
+impl JniSignatureType for BytesType {
+    fn jni_signature_type(&self) -> &str {
+        "jni::sys::jbyteArray"
+    }
+}
+
 impl JniSignatureType for CollectionHandle {
     fn jni_signature_type(&self) -> &str {
         JNI_SYS_JOBJECT
     }
 }
 
+impl JniSignatureType for MapHandle {
+    fn jni_signature_type(&self) -> &str {
+        JNI_SYS_JOBJECT
+    }
+}
+
 impl<T> JniSignatureType for UniversalOr<T>
 where
     T: StructFieldType,
@@ -92,6 +124,12 @@ impl JniSignatureType for ClassDeclarationHandle {
     }
 }
 
+impl JniSignatureType for UnionHandle {
+    fn jni_signature_type(&self) -> &str {
+        JNI_SYS_JOBJECT
+    }
+}
+
 impl JniSignatureType for InterfaceHandle {
     fn jni_signature_type(&self) -> &str {
         JNI_SYS_JOBJECT
@@ -103,7 +141,11 @@ impl JniSignatureType for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.jni_signature_type(),
             FunctionArgument::String(x) => x.jni_signature_type(),
+            FunctionArgument::Bytes(x) => x.jni_signature_type(),
+            FunctionArgument::Union(x) => x.jni_signature_type(),
+            FunctionArgument::Optional(x) => x.jni_signature_type(),
             FunctionArgument::Collection(x) => x.jni_signature_type(),
+            FunctionArgument::Map(x) => x.jni_signature_type(),
             FunctionArgument::Struct(x) => x.jni_signature_type(),
             FunctionArgument::StructRef(x) => x.jni_signature_type(),
             FunctionArgument::ClassRef(x) => x.jni_signature_type(),
@@ -118,15 +160,25 @@ impl JniSignatureType for PrimitiveRef {
     }
 }
 
+impl JniSignatureType for ValueRef {
+    fn jni_signature_type(&self) -> &str {
+        JNI_SYS_JOBJECT
+    }
+}
+
 impl JniSignatureType for FunctionReturnValue {
     fn jni_signature_type(&self) -> &str {
         match self {
             FunctionReturnValue::Basic(x) => x.jni_signature_type(),
             FunctionReturnValue::String(x) => x.jni_signature_type(),
+            FunctionReturnValue::Bytes(x) => x.jni_signature_type(),
+            FunctionReturnValue::Union(x) => x.jni_signature_type(),
             FunctionReturnValue::ClassRef(x) => x.jni_signature_type(),
+            FunctionReturnValue::Iterator(x) => x.iter_class.jni_signature_type(),
             FunctionReturnValue::Struct(x) => x.jni_signature_type(),
             FunctionReturnValue::StructRef(x) => x.jni_signature_type(),
             FunctionReturnValue::PrimitiveRef(x) => x.jni_signature_type(),
+            FunctionReturnValue::ValueRef(x) => x.jni_signature_type(),
         }
     }
 }
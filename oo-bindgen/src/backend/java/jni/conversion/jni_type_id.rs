@@ -46,24 +46,72 @@ impl JniTypeId for StringType {
     }
 }
 
+impl JniTypeId for BytesType {
+    fn jni_type_id(&self) -> TypeId {
+        TypeId::Fixed("[B")
+    }
+}
+
+impl JniTypeId for Optional<BasicType> {
+    fn jni_type_id(&self) -> TypeId {
+        // optional values are always boxed
+        match &self.inner {
+            BasicType::Primitive(x) => match x {
+                Primitive::Bool => TypeId::Fixed("Ljava/lang/Boolean;"),
+                Primitive::S8 => TypeId::Fixed("Ljava/lang/Byte;"),
+                Primitive::S16 => TypeId::Fixed("Ljava/lang/Short;"),
+                Primitive::S32 => TypeId::Fixed("Ljava/lang/Integer;"),
+                Primitive::S64 => TypeId::Fixed("Ljava/lang/Long;"),
+                Primitive::Float => TypeId::Fixed("Ljava/lang/Float;"),
+                Primitive::Double => TypeId::Fixed("Ljava/lang/Double;"),
+                Primitive::U8 | Primitive::U16 | Primitive::U32 | Primitive::U64 => x.jni_type_id(),
+            },
+            BasicType::Duration(x) => x.jni_type_id(),
+            BasicType::Timestamp(x) => x.jni_type_id(),
+            BasicType::Enum(x) => x.jni_type_id(),
+            BasicType::Flags(x) => x.jni_type_id(),
+        }
+    }
+}
+
+impl JniTypeId for ListHandle {
+    fn jni_type_id(&self) -> TypeId {
+        TypeId::Fixed("Ljava/util/List;")
+    }
+}
+
 impl JniTypeId for DurationType {
     fn jni_type_id(&self) -> TypeId {
         TypeId::Fixed("Ljava/time/Duration;")
     }
 }
 
+impl JniTypeId for TimestampType {
+    fn jni_type_id(&self) -> TypeId {
+        TypeId::Fixed("Ljava/time/Instant;")
+    }
+}
+
 impl JniTypeId for EnumHandle {
     fn jni_type_id(&self) -> TypeId {
         TypeId::LibraryType(self.name.clone())
     }
 }
 
+impl JniTypeId for FlagsHandle {
+    fn jni_type_id(&self) -> TypeId {
+        TypeId::LibraryType(self.name.clone())
+    }
+}
+
 impl JniTypeId for BasicType {
     fn jni_type_id(&self) -> TypeId {
         match self {
             BasicType::Primitive(x) => x.jni_type_id(),
             BasicType::Duration(x) => x.jni_type_id(),
+            BasicType::Timestamp(x) => x.jni_type_id(),
             BasicType::Enum(x) => x.jni_type_id(),
+            BasicType::Flags(x) => x.jni_type_id(),
         }
     }
 }
@@ -95,11 +143,20 @@ impl JniTypeId for UniversalStructHandle {
     }
 }
 
+impl JniTypeId for UnionHandle {
+    fn jni_type_id(&self) -> TypeId {
+        TypeId::LibraryType(self.name.clone())
+    }
+}
+
 impl JniTypeId for CallbackArgument {
     fn jni_type_id(&self) -> TypeId {
         match self {
             CallbackArgument::Basic(x) => x.jni_type_id(),
             CallbackArgument::String(x) => x.jni_type_id(),
+            CallbackArgument::Bytes(x) => x.jni_type_id(),
+            CallbackArgument::Union(x) => x.jni_type_id(),
+            CallbackArgument::Optional(x) => x.jni_type_id(),
             CallbackArgument::Iterator(x) => x.jni_type_id(),
             CallbackArgument::Class(x) => x.jni_type_id(),
             CallbackArgument::Struct(x) => x.jni_type_id(),
@@ -136,6 +193,9 @@ impl JniTypeId for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.jni_type_id(),
             FunctionArgStructField::String(x) => x.jni_type_id(),
+            FunctionArgStructField::Bytes(x) => x.jni_type_id(),
+            FunctionArgStructField::Optional(x) => x.jni_type_id(),
+            FunctionArgStructField::List(x) => x.jni_type_id(),
             FunctionArgStructField::Interface(x) => x.inner.jni_type_id(),
             FunctionArgStructField::Struct(x) => x.jni_type_id(),
         }
@@ -160,6 +220,8 @@ impl JniTypeId for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.jni_type_id(),
             CallbackArgStructField::Struct(x) => x.jni_type_id(),
             CallbackArgStructField::String(x) => x.jni_type_id(),
+            CallbackArgStructField::Bytes(x) => x.jni_type_id(),
+            CallbackArgStructField::Optional(x) => x.jni_type_id(),
         }
     }
 }
@@ -170,6 +232,9 @@ impl JniTypeId for UniversalStructField {
             UniversalStructField::Basic(x) => x.jni_type_id(),
             UniversalStructField::Struct(x) => x.jni_type_id(),
             UniversalStructField::String(x) => x.jni_type_id(),
+            UniversalStructField::Bytes(x) => x.jni_type_id(),
+            UniversalStructField::Optional(x) => x.jni_type_id(),
+            UniversalStructField::List(x) => x.jni_type_id(),
         }
     }
 }
@@ -14,9 +14,13 @@ impl RustType for Primitive {
 
 impl RustType for DurationType {
     fn get_rust_type(&self, _ffi_name: &str) -> String {
-        match self {
-            DurationType::Milliseconds | DurationType::Seconds => "u64".to_string(),
-        }
+        self.primitive().get_c_rust_type().to_string()
+    }
+}
+
+impl RustType for TimestampType {
+    fn get_rust_type(&self, _ffi_name: &str) -> String {
+        self.primitive().get_c_rust_type().to_string()
     }
 }
 
@@ -26,12 +30,22 @@ impl RustType for EnumHandle {
     }
 }
 
+impl RustType for FlagsHandle {
+    fn get_rust_type(&self, _ffi_name: &str) -> String {
+        Primitive::from(self.flags_type)
+            .get_c_rust_type()
+            .to_string()
+    }
+}
+
 impl RustType for BasicType {
     fn get_rust_type(&self, ffi_name: &str) -> String {
         match self {
             BasicType::Primitive(x) => x.get_rust_type(ffi_name),
             BasicType::Duration(x) => x.get_rust_type(ffi_name),
+            BasicType::Timestamp(x) => x.get_rust_type(ffi_name),
             BasicType::Enum(x) => x.get_rust_type(ffi_name),
+            BasicType::Flags(x) => x.get_rust_type(ffi_name),
         }
     }
 }
@@ -42,6 +56,22 @@ impl RustType for StringType {
     }
 }
 
+impl RustType for Optional<BasicType> {
+    fn get_rust_type(&self, ffi_name: &str) -> String {
+        format!(
+            "{}::ffi::Optional<{}>",
+            ffi_name,
+            self.inner.get_c_rust_type()
+        )
+    }
+}
+
+impl RustType for BytesType {
+    fn get_rust_type(&self, ffi_name: &str) -> String {
+        format!("{ffi_name}::ffi::ByteSlice")
+    }
+}
+
 impl RustType for AbstractIteratorHandle {
     fn get_rust_type(&self, ffi_name: &str) -> String {
         format!("*mut {}::{}", ffi_name, self.name().camel_case())
@@ -66,11 +96,20 @@ impl RustType for UniversalStructHandle {
     }
 }
 
+impl RustType for UnionHandle {
+    fn get_rust_type(&self, ffi_name: &str) -> String {
+        format!("{}::ffi::{}", ffi_name, self.name.camel_case())
+    }
+}
+
 impl RustType for CallbackArgument {
     fn get_rust_type(&self, ffi_name: &str) -> String {
         match self {
             CallbackArgument::Basic(x) => x.get_rust_type(ffi_name),
             CallbackArgument::String(x) => x.get_rust_type(ffi_name),
+            CallbackArgument::Bytes(x) => x.get_rust_type(ffi_name),
+            CallbackArgument::Union(x) => x.get_rust_type(ffi_name),
+            CallbackArgument::Optional(x) => x.get_rust_type(ffi_name),
             CallbackArgument::Iterator(x) => x.get_rust_type(ffi_name),
             CallbackArgument::Class(x) => x.get_rust_type(ffi_name),
             CallbackArgument::Struct(x) => x.get_rust_type(ffi_name),
@@ -31,10 +31,13 @@ impl UnwrapValue for Primitive {
 
 impl UnwrapValue for DurationType {
     fn unwrap_value(&self) -> &str {
-        match self {
-            DurationType::Milliseconds => UNWRAP_OBJECT,
-            DurationType::Seconds => UNWRAP_OBJECT,
-        }
+        UNWRAP_OBJECT
+    }
+}
+
+impl UnwrapValue for TimestampType {
+    fn unwrap_value(&self) -> &str {
+        UNWRAP_OBJECT
     }
 }
 
@@ -44,18 +47,44 @@ impl UnwrapValue for EnumHandle {
     }
 }
 
+impl UnwrapValue for FlagsHandle {
+    fn unwrap_value(&self) -> &str {
+        UNWRAP_OBJECT
+    }
+}
+
 impl UnwrapValue for StringType {
     fn unwrap_value(&self) -> &str {
         UNWRAP_OBJECT
     }
 }
 
+impl UnwrapValue for BytesType {
+    fn unwrap_value(&self) -> &str {
+        UNWRAP_OBJECT
+    }
+}
+
+impl UnwrapValue for ListHandle {
+    fn unwrap_value(&self) -> &str {
+        UNWRAP_OBJECT
+    }
+}
+
+impl UnwrapValue for Optional<BasicType> {
+    fn unwrap_value(&self) -> &str {
+        UNWRAP_OBJECT
+    }
+}
+
 impl UnwrapValue for BasicType {
     fn unwrap_value(&self) -> &str {
         match self {
             BasicType::Primitive(x) => x.unwrap_value(),
             BasicType::Duration(x) => x.unwrap_value(),
+            BasicType::Timestamp(x) => x.unwrap_value(),
             BasicType::Enum(x) => x.unwrap_value(),
+            BasicType::Flags(x) => x.unwrap_value(),
         }
     }
 }
@@ -113,6 +142,9 @@ impl UnwrapValue for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.unwrap_value(),
             FunctionArgStructField::String(x) => x.unwrap_value(),
+            FunctionArgStructField::Bytes(x) => x.unwrap_value(),
+            FunctionArgStructField::Optional(x) => x.unwrap_value(),
+            FunctionArgStructField::List(x) => x.unwrap_value(),
             FunctionArgStructField::Interface(x) => x.unwrap_value(),
             FunctionArgStructField::Struct(x) => x.unwrap_value(),
         }
@@ -137,6 +169,8 @@ impl UnwrapValue for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.unwrap_value(),
             CallbackArgStructField::Struct(x) => x.unwrap_value(),
             CallbackArgStructField::String(x) => x.unwrap_value(),
+            CallbackArgStructField::Bytes(x) => x.unwrap_value(),
+            CallbackArgStructField::Optional(x) => x.unwrap_value(),
         }
     }
 }
@@ -147,6 +181,9 @@ impl UnwrapValue for UniversalStructField {
             UniversalStructField::Basic(x) => x.unwrap_value(),
             UniversalStructField::Struct(x) => x.unwrap_value(),
             UniversalStructField::String(x) => x.unwrap_value(),
+            UniversalStructField::Bytes(x) => x.unwrap_value(),
+            UniversalStructField::Optional(x) => x.unwrap_value(),
+            UniversalStructField::List(x) => x.unwrap_value(),
         }
     }
 }
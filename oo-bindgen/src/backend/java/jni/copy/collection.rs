@@ -14,6 +14,15 @@ pub struct Collection {
     iterator_next_method: jni::objects::JMethodID<'static>,
     list_iterator_method: jni::objects::JMethodID<'static>,
     list_size_method: jni::objects::JMethodID<'static>,
+
+    // Map stuff
+    _map_class: jni::objects::GlobalRef,
+    _map_entry_class: jni::objects::GlobalRef,
+    _set_class: jni::objects::GlobalRef,
+    map_entry_set_method: jni::objects::JMethodID<'static>,
+    map_entry_get_key_method: jni::objects::JMethodID<'static>,
+    map_entry_get_value_method: jni::objects::JMethodID<'static>,
+    set_iterator_method: jni::objects::JMethodID<'static>,
 }
 
 impl Collection {
@@ -31,6 +40,14 @@ impl Collection {
         let list_iterator_method = env.get_method_id(list_class, "iterator", "()Ljava/util/Iterator;").map(|mid| mid.into_inner().into()).expect("Unable to find List::iterator()");
         let list_size_method = env.get_method_id(list_class, "size", "()I").map(|mid| mid.into_inner().into()).expect("Unable to find List::size()");
 
+        let map_class = env.find_class("Ljava/util/Map;").expect("Unable to find java/util/Map class");
+        let map_entry_class = env.find_class("Ljava/util/Map$Entry;").expect("Unable to find java/util/Map$Entry class");
+        let set_class = env.find_class("Ljava/util/Set;").expect("Unable to find java/util/Set class");
+        let map_entry_set_method = env.get_method_id(map_class, "entrySet", "()Ljava/util/Set;").map(|mid| mid.into_inner().into()).expect("Unable to find Map::entrySet()");
+        let map_entry_get_key_method = env.get_method_id(map_entry_class, "getKey", "()Ljava/lang/Object;").map(|mid| mid.into_inner().into()).expect("Unable to find Map.Entry::getKey()");
+        let map_entry_get_value_method = env.get_method_id(map_entry_class, "getValue", "()Ljava/lang/Object;").map(|mid| mid.into_inner().into()).expect("Unable to find Map.Entry::getValue()");
+        let set_iterator_method = env.get_method_id(set_class, "iterator", "()Ljava/util/Iterator;").map(|mid| mid.into_inner().into()).expect("Unable to find Set::iterator()");
+
         Self {
             array_list_class: env.new_global_ref(array_list_class).unwrap(),
             array_list_constructor,
@@ -42,6 +59,13 @@ impl Collection {
             iterator_next_method,
             list_iterator_method,
             list_size_method,
+            _map_class: env.new_global_ref(map_class).unwrap(),
+            _map_entry_class: env.new_global_ref(map_entry_class).unwrap(),
+            _set_class: env.new_global_ref(set_class).unwrap(),
+            map_entry_set_method,
+            map_entry_get_key_method,
+            map_entry_get_value_method,
+            set_iterator_method,
         }
     }
 
@@ -68,4 +92,17 @@ impl Collection {
     pub fn get_size(&self, env: &jni::JNIEnv, obj: jni::objects::JObject) -> u32 {
         env.call_method_unchecked(obj, self.list_size_method, JavaType::Primitive(Primitive::Int), &[]).unwrap().i().unwrap() as u32
     }
+
+    pub fn get_entry_iterator<'a>(&self, env: &jni::JNIEnv<'a>, obj: jni::objects::JObject<'a>) -> jni::objects::JObject<'a> {
+        let entries = env.auto_local(env.call_method_unchecked(obj, self.map_entry_set_method, JavaType::Object("java/util/Set".to_string()), &[]).unwrap().l().unwrap());
+        env.call_method_unchecked(entries.as_obj(), self.set_iterator_method, JavaType::Object("java/util/Iterator".to_string()), &[]).unwrap().l().unwrap()
+    }
+
+    pub fn get_entry_key<'a>(&self, env: &jni::JNIEnv<'a>, obj: jni::objects::JObject<'a>) -> jni::objects::JObject<'a> {
+        env.call_method_unchecked(obj, self.map_entry_get_key_method, JavaType::Object("java/lang/Object".to_string()), &[]).unwrap().l().unwrap()
+    }
+
+    pub fn get_entry_value<'a>(&self, env: &jni::JNIEnv<'a>, obj: jni::objects::JObject<'a>) -> jni::objects::JObject<'a> {
+        env.call_method_unchecked(obj, self.map_entry_get_value_method, JavaType::Object("java/lang/Object".to_string()), &[]).unwrap().l().unwrap()
+    }
 }
@@ -5,8 +5,10 @@ pub struct Duration {
     class: jni::objects::GlobalRef,
     of_millis_method: jni::objects::JStaticMethodID<'static>,
     of_seconds_method: jni::objects::JStaticMethodID<'static>,
+    of_seconds_nanos_method: jni::objects::JStaticMethodID<'static>,
     to_millis_method: jni::objects::JMethodID<'static>,
     get_seconds_method: jni::objects::JMethodID<'static>,
+    get_nano_method: jni::objects::JMethodID<'static>,
 }
 
 impl Duration {
@@ -15,30 +17,48 @@ impl Duration {
 
         let of_millis_method = env.get_static_method_id(class, "ofMillis", "(J)Ljava/time/Duration;").map(|mid| mid.into_inner().into()).expect("Unable to find Duration::ofMillis()");
         let of_seconds_method = env.get_static_method_id(class, "ofSeconds", "(J)Ljava/time/Duration;").map(|mid| mid.into_inner().into()).expect("Unable to find Duration::ofSeconds()");
+        let of_seconds_nanos_method = env.get_static_method_id(class, "ofSeconds", "(JJ)Ljava/time/Duration;").map(|mid| mid.into_inner().into()).expect("Unable to find Duration::ofSeconds(long, long)");
         let to_millis_method = env.get_method_id(class, "toMillis", "()J").map(|mid| mid.into_inner().into()).expect("Unable to find Duration::toMillis()");
         let get_seconds_method = env.get_method_id(class, "getSeconds", "()J").map(|mid| mid.into_inner().into()).expect("Unable to find Duration::getSeconds()");
+        let get_nano_method = env.get_method_id(class, "getNano", "()I").map(|mid| mid.into_inner().into()).expect("Unable to find Duration::getNano()");
 
         Self {
             class: env.new_global_ref(class).unwrap(),
             of_millis_method,
             of_seconds_method,
+            of_seconds_nanos_method,
             to_millis_method,
             get_seconds_method,
+            get_nano_method,
         }
     }
 
     pub fn to_rust_millis(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> u64 {
-        env.call_method_unchecked(obj, self.to_millis_method, JavaType::Primitive(Primitive::Long), &[]).unwrap().j().unwrap() as u64
+        self.to_rust_signed_millis(env, obj) as u64
     }
 
     pub fn to_rust_seconds(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> u64 {
-        env.call_method_unchecked(obj, self.get_seconds_method, JavaType::Primitive(Primitive::Long), &[]).unwrap().j().unwrap() as u64
+        self.get_seconds(env, obj) as u64
+    }
+
+    pub fn to_rust_micros(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> u64 {
+        (self.get_seconds(env, obj) as u64).saturating_mul(1_000_000).saturating_add(self.get_nano(env, obj) as u64 / 1_000)
+    }
+
+    pub fn to_rust_nanos(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> u64 {
+        (self.get_seconds(env, obj) as u64).saturating_mul(1_000_000_000).saturating_add(self.get_nano(env, obj) as u64)
+    }
+
+    pub fn to_rust_signed_millis(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> i64 {
+        env.call_method_unchecked(obj, self.to_millis_method, JavaType::Primitive(Primitive::Long), &[]).unwrap().j().unwrap()
+    }
+
+    pub fn to_rust_float_seconds(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> f64 {
+        self.get_seconds(env, obj) as f64 + self.get_nano(env, obj) as f64 / 1e9
     }
 
     pub fn to_jni_millis(&self, env: &jni::JNIEnv, millis: u64) -> jni::sys::jobject {
-        env.call_static_method_unchecked(&self.class, self.of_millis_method, JavaType::Object("java/time/Duration".to_string()), &[JValue::Long(millis as i64)])
-            .unwrap()
-            .l().unwrap().into_inner()
+        self.to_jni_signed_millis(env, millis as i64)
     }
 
     pub fn to_jni_seconds(&self, env: &jni::JNIEnv, seconds: u64) -> jni::sys::jobject {
@@ -46,4 +66,37 @@ impl Duration {
             .unwrap()
             .l().unwrap().into_inner()
     }
+
+    pub fn to_jni_micros(&self, env: &jni::JNIEnv, micros: u64) -> jni::sys::jobject {
+        self.of_seconds_nanos(env, (micros / 1_000_000) as i64, ((micros % 1_000_000) * 1_000) as i64)
+    }
+
+    pub fn to_jni_nanos(&self, env: &jni::JNIEnv, nanos: u64) -> jni::sys::jobject {
+        self.of_seconds_nanos(env, (nanos / 1_000_000_000) as i64, (nanos % 1_000_000_000) as i64)
+    }
+
+    pub fn to_jni_signed_millis(&self, env: &jni::JNIEnv, millis: i64) -> jni::sys::jobject {
+        env.call_static_method_unchecked(&self.class, self.of_millis_method, JavaType::Object("java/time/Duration".to_string()), &[JValue::Long(millis)])
+            .unwrap()
+            .l().unwrap().into_inner()
+    }
+
+    pub fn to_jni_float_seconds(&self, env: &jni::JNIEnv, seconds: f64) -> jni::sys::jobject {
+        let whole = seconds.floor();
+        self.of_seconds_nanos(env, whole as i64, ((seconds - whole) * 1e9) as i64)
+    }
+
+    fn get_seconds(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> i64 {
+        env.call_method_unchecked(obj, self.get_seconds_method, JavaType::Primitive(Primitive::Long), &[]).unwrap().j().unwrap()
+    }
+
+    fn get_nano(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> i32 {
+        env.call_method_unchecked(obj, self.get_nano_method, JavaType::Primitive(Primitive::Int), &[]).unwrap().i().unwrap()
+    }
+
+    fn of_seconds_nanos(&self, env: &jni::JNIEnv, seconds: i64, nanos: i64) -> jni::sys::jobject {
+        env.call_static_method_unchecked(&self.class, self.of_seconds_nanos_method, JavaType::Object("java/time/Duration".to_string()), &[JValue::Long(seconds), JValue::Long(nanos)])
+            .unwrap()
+            .l().unwrap().into_inner()
+    }
 }
@@ -0,0 +1,33 @@
+use jni::objects::JValue;
+use jni::signature::*;
+
+pub struct Instant {
+    class: jni::objects::GlobalRef,
+    of_epoch_milli_method: jni::objects::JStaticMethodID<'static>,
+    to_epoch_milli_method: jni::objects::JMethodID<'static>,
+}
+
+impl Instant {
+    pub fn init(env: &jni::JNIEnv) -> Self {
+        let class = env.find_class("Ljava/time/Instant;").expect("Unable to find java/time/Instant class");
+
+        let of_epoch_milli_method = env.get_static_method_id(class, "ofEpochMilli", "(J)Ljava/time/Instant;").map(|mid| mid.into_inner().into()).expect("Unable to find Instant::ofEpochMilli()");
+        let to_epoch_milli_method = env.get_method_id(class, "toEpochMilli", "()J").map(|mid| mid.into_inner().into()).expect("Unable to find Instant::toEpochMilli()");
+
+        Self {
+            class: env.new_global_ref(class).unwrap(),
+            of_epoch_milli_method,
+            to_epoch_milli_method,
+        }
+    }
+
+    pub fn to_rust_millis(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> i64 {
+        env.call_method_unchecked(obj, self.to_epoch_milli_method, JavaType::Primitive(Primitive::Long), &[]).unwrap().j().unwrap()
+    }
+
+    pub fn to_jni_millis(&self, env: &jni::JNIEnv, millis: i64) -> jni::sys::jobject {
+        env.call_static_method_unchecked(&self.class, self.of_epoch_milli_method, JavaType::Object("java/time/Instant".to_string()), &[JValue::Long(millis)])
+            .unwrap()
+            .l().unwrap().into_inner()
+    }
+}
@@ -17,4 +17,13 @@ impl Drop for LocalFrameGuard<'_> {
 
 pub(crate) fn local_frame(env: jni::JNIEnv, count: i32) -> jni::errors::Result<LocalFrameGuard> {
     LocalFrameGuard::new(env, count)
-}
\ No newline at end of file
+}
+
+pub(crate) fn byte_slice<'s>(array: &'s jni::objects::AutoArray<'_, '_, jni::sys::jbyte>) -> &'s [u8] {
+    let len = array.size().unwrap() as usize;
+    if len == 0 {
+        return &[];
+    }
+    // safety: the elements are pinned for as long as the AutoArray is borrowed
+    unsafe { std::slice::from_raw_parts(array.as_ptr() as *const u8, len) }
+}
@@ -1,5 +1,5 @@
 use crate::backend::*;
-use crate::model::Library;
+use crate::model::{ErrorType, Library, Name, Validated};
 
 use crate::backend::java::jni::JniBindgenConfig;
 
@@ -22,23 +22,33 @@ pub(crate) fn generate_exceptions_cache(
 
     f.writeln("impl ExceptionInfo {")?;
     indented(f, |f| {
-        f.writeln("pub(crate) fn throw(&self, env: &jni::JNIEnv, error: jni::sys::jobject) {")?;
+        f.writeln(
+            "pub(crate) fn throw(&self, env: &jni::JNIEnv, args: &[jni::objects::JValue]) {",
+        )?;
         indented(f, |f| {
-            f.writeln("let obj = env.new_object_unchecked(&self.class, self.constructor, &[jni::objects::JValue::Object(error.into())]).unwrap();")?;
+            f.writeln(
+                "let obj = env.new_object_unchecked(&self.class, self.constructor, args).unwrap();",
+            )?;
             f.writeln("env.throw(jni::objects::JThrowable::from(obj)).unwrap()")
         })?;
         f.writeln("}")?;
-        for error in lib.error_types() {
-            let camel_name = error.exception_name.camel_case();
+        for (exception_name, error) in exceptions(lib) {
+            let camel_name = exception_name.camel_case();
             let enum_name = error.inner.name.camel_case();
+            let mut params = format!("L{lib_path}/{enum_name};");
+            if error.message {
+                params.push_str("Ljava/lang/String;");
+            }
+            if let Some(payload) = &error.payload {
+                params.push_str(&format!("L{lib_path}/{};", payload.name().camel_case()));
+            }
             f.newline()?;
             f.writeln(&format!(
-                "fn init_{}(env: &jni::JNIEnv) -> Self {{",
-                error.exception_name
+                "fn init_{exception_name}(env: &jni::JNIEnv) -> Self {{"
             ))?;
             indented(f, |f| {
                 f.writeln(&format!("let class = env.find_class(\"L{lib_path}/{camel_name};\").expect(\"Unable to find exception {camel_name}\");"))?;
-                f.writeln(&format!("let constructor = env.get_method_id(class, \"<init>\", \"(L{lib_path}/{enum_name};)V\").map(|mid| mid.into_inner().into()).expect(\"Unable to find constructor of {camel_name}\");"))?;
+                f.writeln(&format!("let constructor = env.get_method_id(class, \"<init>\", \"({params})V\").map(|mid| mid.into_inner().into()).expect(\"Unable to find constructor of {camel_name}\");"))?;
                 f.writeln("Self { class : env.new_global_ref(class).unwrap(), constructor }")
             })?;
             f.writeln("}")?;
@@ -52,11 +62,8 @@ pub(crate) fn generate_exceptions_cache(
     // Top-level exceptions struct
     f.writeln("pub struct Exceptions")?;
     blocked(f, |f| {
-        for error in lib.error_types() {
-            f.writeln(&format!(
-                "pub(crate) {}: ExceptionInfo,",
-                error.exception_name
-            ))?;
+        for (exception_name, _) in exceptions(lib) {
+            f.writeln(&format!("pub(crate) {exception_name}: ExceptionInfo,"))?;
         }
         Ok(())
     })?;
@@ -69,10 +76,9 @@ pub(crate) fn generate_exceptions_cache(
         indented(f, |f| {
             f.writeln("Self {")?;
             indented(f, |f| {
-                for error in lib.error_types() {
+                for (exception_name, _) in exceptions(lib) {
                     f.writeln(&format!(
-                        "{}: ExceptionInfo::init_{}(env),",
-                        error.exception_name, error.exception_name
+                        "{exception_name}: ExceptionInfo::init_{exception_name}(env),"
                     ))?;
                 }
                 Ok(())
@@ -83,3 +89,12 @@ pub(crate) fn generate_exceptions_cache(
     })?;
     f.writeln("}")
 }
+
+/// every exception that may be thrown, along with the error type it reports
+fn exceptions(lib: &Library) -> impl Iterator<Item = (&Name, &ErrorType<Validated>)> {
+    lib.error_types().flat_map(|error| {
+        std::iter::once(&error.exception_name)
+            .chain(error.variant_exceptions.iter().map(|x| &x.exception_name))
+            .map(move |name| (name, error))
+    })
+}
@@ -0,0 +1,95 @@
+use crate::backend::java::jni::JniBindgenConfig;
+use crate::backend::*;
+use crate::model::*;
+
+pub(crate) fn generate_flags_cache(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &JniBindgenConfig,
+) -> FormattingResult<()> {
+    let lib_path = config.java_signature_path(&lib.settings.name);
+
+    // Top-level flags struct
+    f.writeln("pub struct Flags")?;
+    blocked(f, |f| {
+        for flags in lib.flags() {
+            f.writeln(&format!("pub {}: {},", flags.name, flags.name.camel_case()))?;
+        }
+
+        Ok(())
+    })?;
+
+    f.newline()?;
+
+    f.writeln("impl Flags")?;
+    blocked(f, |f| {
+        f.writeln("pub fn init(env: &jni::JNIEnv) -> Self")?;
+        blocked(f, |f| {
+            f.writeln("Self")?;
+            blocked(f, |f| {
+                for flags in lib.flags() {
+                    f.writeln(&format!(
+                        "{}: {}::init(env),",
+                        flags.name,
+                        flags.name.camel_case()
+                    ))?;
+                }
+                Ok(())
+            })
+        })
+    })?;
+
+    // Each flags implementation
+    for flags in lib.flags() {
+        let flags_name = flags.name.camel_case();
+        let class_path = format!("{lib_path}/{flags_name}");
+        let int_type = Primitive::from(flags.flags_type).get_c_rust_type();
+
+        f.writeln(&format!("pub struct {flags_name}"))?;
+        blocked(f, |f| {
+            f.writeln("class: jni::objects::GlobalRef,")?;
+            f.writeln("to_bits_method: jni::objects::JMethodID<'static>,")?;
+            f.writeln("from_bits_method: jni::objects::JStaticMethodID<'static>,")
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl {flags_name}"))?;
+        blocked(f, |f| {
+            f.writeln("pub fn init(env: &jni::JNIEnv) -> Self")?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "let class = env.find_class(\"L{class_path};\").expect(\"Unable to find {flags_name}\");"
+                ))?;
+                f.writeln("Self")?;
+                blocked(f, |f| {
+                    f.writeln(&format!("to_bits_method: env.get_method_id(class, \"toBits\", \"()J\").map(|mid| mid.into_inner().into()).expect(\"Unable to find {flags_name}::toBits()\"),"))?;
+                    f.writeln(&format!("from_bits_method: env.get_static_method_id(class, \"fromBits\", \"(J)L{class_path};\").map(|mid| mid.into_inner().into()).expect(\"Unable to find {flags_name}::fromBits()\"),"))?;
+                    f.writeln("class: env.new_global_ref(class).unwrap(),")
+                })
+            })?;
+
+            f.newline()?;
+
+            f.writeln(&format!(
+                "pub fn to_rust(&self, env: &jni::JNIEnv, obj: jni::sys::jobject) -> {int_type}"
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!("env.call_method_unchecked(obj, self.to_bits_method, jni::signature::JavaType::Primitive(jni::signature::Primitive::Long), &[]).unwrap().j().unwrap() as {int_type}"))
+            })?;
+
+            f.newline()?;
+
+            f.writeln(&format!(
+                "pub fn to_jni(&self, env: &jni::JNIEnv, value: {int_type}) -> jni::sys::jobject"
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!("env.call_static_method_unchecked(&self.class, self.from_bits_method, jni::signature::JavaType::Object(\"{class_path}\".to_string()), &[jni::objects::JValue::Long(value as i64)]).unwrap().l().unwrap().into_inner()"))
+            })
+        })?;
+
+        f.newline()?;
+    }
+
+    Ok(())
+}
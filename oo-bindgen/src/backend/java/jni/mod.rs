@@ -9,8 +9,10 @@ mod classes;
 mod conversion;
 mod enums;
 mod exceptions;
+mod flags;
 mod interface;
 mod structs;
+mod unions;
 
 /// Configuration for JNI (Rust) generation
 pub struct JniBindgenConfig<'a> {
@@ -60,6 +62,7 @@ pub fn generate_jni(path: &Path, lib: &Library, config: &JniBindgenConfig) -> Fo
     write_functions(&mut f, lib, config)?;
     write_collection_conversions(&mut f, lib, config)?;
     write_iterator_conversions(&mut f, lib, config)?;
+    write_list_conversions(&mut f, lib, config)?;
 
     module("classes", &mut f, |f| {
         classes::generate_classes_cache(f, lib, config)
@@ -69,8 +72,14 @@ pub fn generate_jni(path: &Path, lib: &Library, config: &JniBindgenConfig) -> Fo
         enums::generate_enums_cache(f, lib, config)
     })?;
 
+    module("flags", &mut f, |f| {
+        flags::generate_flags_cache(f, lib, config)
+    })?;
+
     module("structs", &mut f, |f| structs::generate(f, lib, config))?;
 
+    module("unions", &mut f, |f| unions::generate(f, lib, config))?;
+
     module("interfaces", &mut f, |f| {
         interface::generate_interfaces_cache(f, lib, config)
     })?;
@@ -83,6 +92,7 @@ pub fn generate_jni(path: &Path, lib: &Library, config: &JniBindgenConfig) -> Fo
     module_string("primitives", &mut f, include_str!("copy/primitives.rs"))?;
     module_string("unsigned", &mut f, include_str!("copy/unsigned.rs"))?;
     module_string("duration", &mut f, include_str!("copy/duration.rs"))?;
+    module_string("instant", &mut f, include_str!("copy/instant.rs"))?;
     module_string("collection", &mut f, include_str!("copy/collection.rs"))?;
     module_string("pointers", &mut f, include_str!("copy/pointers.rs"))?;
     module_string("util", &mut f, include_str!("copy/util.rs"))?;
@@ -98,10 +108,13 @@ fn generate_cache(f: &mut dyn Printer) -> FormattingResult<()> {
         f.writeln("primitives: primitives::Primitives,")?;
         f.writeln("unsigned: unsigned::Unsigned,")?;
         f.writeln("duration: duration::Duration,")?;
+        f.writeln("instant: instant::Instant,")?;
         f.writeln("collection: collection::Collection,")?;
         f.writeln("classes: classes::Classes,")?;
         f.writeln("enums: enums::Enums,")?;
+        f.writeln("flags: flags::Flags,")?;
         f.writeln("structs: structs::Structs,")?;
+        f.writeln("unions: unions::Unions,")?;
         f.writeln("interfaces: interfaces::Interfaces,")?;
         f.writeln("exceptions: exceptions::Exceptions,")?;
         Ok(())
@@ -117,10 +130,13 @@ fn generate_cache(f: &mut dyn Printer) -> FormattingResult<()> {
             f.writeln("let primitives = primitives::Primitives::init(&env);")?;
             f.writeln("let unsigned = unsigned::Unsigned::init(&env);")?;
             f.writeln("let duration = duration::Duration::init(&env);")?;
+            f.writeln("let instant = instant::Instant::init(&env);")?;
             f.writeln("let collection = collection::Collection::init(&env);")?;
             f.writeln("let classes = classes::Classes::init(&env);")?;
             f.writeln("let enums = enums::Enums::init(&env);")?;
+            f.writeln("let flags = flags::Flags::init(&env);")?;
             f.writeln("let structs = structs::Structs::init(&env);")?;
+            f.writeln("let unions = unions::Unions::init(&env);")?;
             f.writeln("let interfaces = interfaces::Interfaces::init(&env);")?;
             f.writeln("let exceptions = exceptions::Exceptions::init(&env);")?;
             f.writeln("Self")?;
@@ -129,10 +145,13 @@ fn generate_cache(f: &mut dyn Printer) -> FormattingResult<()> {
                 f.writeln("primitives,")?;
                 f.writeln("unsigned,")?;
                 f.writeln("duration,")?;
+                f.writeln("instant,")?;
                 f.writeln("collection,")?;
                 f.writeln("classes,")?;
                 f.writeln("enums,")?;
+                f.writeln("flags,")?;
                 f.writeln("structs,")?;
+                f.writeln("unions,")?;
                 f.writeln("interfaces,")?;
                 f.writeln("exceptions,")?;
                 Ok(())
@@ -185,13 +204,17 @@ fn write_collection_conversions(
     config: &JniBindgenConfig,
 ) -> FormattingResult<()> {
     f.newline()?;
-    f.writeln("/// convert Java lists into native API collections")?;
+    f.writeln("/// convert Java lists and maps into native API collections")?;
     f.writeln("pub(crate) mod collections {")?;
     indented(f, |f| {
         for col in lib.collections() {
             f.newline()?;
             write_collection_guard(f, config, col)?;
         }
+        for map in lib.maps() {
+            f.newline()?;
+            write_map_guard(f, config, map)?;
+        }
         Ok(())
     })?;
     f.writeln("}")
@@ -235,6 +258,31 @@ fn write_iterator_conversion(
                         .unwrap_or_else(|| "*next".to_string());
                     f.writeln(&format!("let next = _env.auto_local({converted});"))?;
                 }
+                IteratorItemType::String(x) => {
+                    f.writeln(&format!(
+                        "let next = _env.auto_local({});",
+                        x.convert("next")
+                    ))?;
+                }
+                IteratorItemType::Enum(x) => {
+                    f.writeln(&format!(
+                        "let next = _env.auto_local({});",
+                        x.convert("(*next).into()")
+                    ))?;
+                }
+                IteratorItemType::Duration(x) => {
+                    f.writeln(&format!(
+                        "let next = _env.auto_local({});",
+                        x.convert("*next")
+                    ))?;
+                }
+                IteratorItemType::Class(x) => {
+                    // instances are borrowed from the iterator
+                    f.writeln(&format!(
+                        "let next = _env.auto_local({});",
+                        x.convert("next as *const _ as *mut _")
+                    ))?;
+                }
                 IteratorItemType::Struct(x) => {
                     f.writeln(&format!(
                         "let next = _env.auto_local({});",
@@ -250,6 +298,139 @@ fn write_iterator_conversion(
     f.writeln("}")
 }
 
+fn write_list_conversions(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &JniBindgenConfig,
+) -> FormattingResult<()> {
+    f.newline()?;
+    f.writeln("/// convert Java lists into native API lists and back")?;
+    f.writeln("pub(crate) mod lists {")?;
+    indented(f, |f| {
+        for list in lib.lists() {
+            f.newline()?;
+            write_list_guard(f, config, list)?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+fn write_list_guard(
+    f: &mut dyn Printer,
+    config: &JniBindgenConfig,
+    list: &ListHandle,
+) -> FormattingResult<()> {
+    let list_name = list.name.camel_case();
+    let ffi_list_name = format!("{}::ffi::{}", config.ffi_name, list_name);
+    let item_type = match &list.item_type {
+        ListItemType::Basic(x) => x.get_rust_type(config.ffi_name),
+        ListItemType::Struct(x) => x.get_rust_type(config.ffi_name),
+    };
+
+    f.writeln(&format!(
+        "/// Guard that owns the native items of a {ffi_list_name} built from a Java list"
+    ))?;
+    f.writeln(&format!("pub(crate) struct {list_name}<'a> {{"))?;
+    indented(f, |f| {
+        f.writeln(&format!("items: Vec<{item_type}>,"))?;
+        match &list.item_type {
+            ListItemType::Basic(_) => {
+                f.writeln(
+                    "/// empty guard objects require this field to make use of the lifetime",
+                )?;
+                f.writeln("_phantom: std::marker::PhantomData<&'a usize>,")
+            }
+            ListItemType::Struct(x) => {
+                f.writeln("/// guards of the converted struct items")?;
+                f.writeln(&format!(
+                    "guards: Vec<crate::structs::instances::{}Guard<'a>>,",
+                    x.name().camel_case()
+                ))
+            }
+        }
+    })?;
+    f.writeln("}")?;
+
+    f.newline()?;
+
+    f.writeln(&format!("impl<'a> {list_name}<'a> {{"))?;
+    indented(f, |f| {
+        f.writeln("pub(crate) fn new(_cache: &'a crate::JCache, _env: &'a jni::JNIEnv, list: jni::sys::jobject) -> Self {")?;
+        indented(f, |f| {
+            f.writeln("let mut items = Vec::new();")?;
+            if let ListItemType::Struct(_) = &list.item_type {
+                f.writeln("let mut guards = Vec::new();")?;
+            }
+            f.writeln(
+                "let it = _env.auto_local(_cache.collection.get_iterator(_env, list.into()));",
+            )?;
+            f.writeln("while _cache.collection.has_next(_env, it.as_obj()) {")?;
+            indented(f, |f| {
+                match &list.item_type {
+                    ListItemType::Basic(x) => {
+                        f.writeln(
+                            "let next = _env.auto_local(_cache.collection.next(_env, it.as_obj()));",
+                        )?;
+                        let converted = x
+                            .to_rust_from_object("next.as_obj().into_inner()")
+                            .unwrap_or_else(|| "next.as_obj().into_inner()".to_string());
+                        f.writeln(&format!("items.push({converted});"))
+                    }
+                    ListItemType::Struct(x) => {
+                        // the local reference is kept alive because the guard may borrow from it
+                        f.writeln("let next = _cache.collection.next(_env, it.as_obj());")?;
+                        f.writeln(&format!(
+                            "let (guard, next) = {};",
+                            x.to_rust("next.into_inner()").unwrap()
+                        ))?;
+                        f.writeln("guards.push(guard);")?;
+                        f.writeln("items.push(next);")
+                    }
+                }
+            })?;
+            f.writeln("}")?;
+            match &list.item_type {
+                ListItemType::Basic(_) => {
+                    f.writeln("Self { items, _phantom: std::marker::PhantomData }")
+                }
+                ListItemType::Struct(_) => f.writeln("Self { items, guards }"),
+            }
+        })?;
+        f.writeln("}")?;
+
+        f.newline()?;
+
+        f.writeln(&format!(
+            "pub(crate) fn as_ffi(&self) -> {ffi_list_name} {{"
+        ))?;
+        indented(f, |f| f.writeln("self.items.as_slice().into()"))?;
+        f.writeln("}")?;
+
+        f.newline()?;
+
+        f.writeln(&format!("pub(crate) fn to_jni(_cache: &crate::JCache, _env: &jni::JNIEnv, value: {ffi_list_name}) -> jni::sys::jobject {{"))?;
+        indented(f, |f| {
+            f.writeln("let list = _cache.collection.new_array_list(_env);")?;
+            f.writeln("for item in unsafe { value.to_slice() } {")?;
+            indented(f, |f| {
+                let converted = match &list.item_type {
+                    ListItemType::Basic(x) => boxed_value(x, "*item"),
+                    ListItemType::Struct(x) => {
+                        format!("_cache.structs.{}.to_jni(_cache, _env, item)", x.name())
+                    }
+                };
+                f.writeln(&format!("let item = _env.auto_local({converted});"))?;
+                f.writeln("_cache.collection.add_to_array_list(_env, list, item.as_obj());")
+            })?;
+            f.writeln("}")?;
+            f.writeln("list.into_inner()")
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")
+}
+
 fn write_collection_guard(
     f: &mut dyn Printer,
     config: &JniBindgenConfig,
@@ -350,24 +531,138 @@ fn write_collection_guard(
     f.writeln("}")
 }
 
+fn write_map_guard(
+    f: &mut dyn Printer,
+    config: &JniBindgenConfig,
+    map: &Handle<Map<Validated>>,
+) -> FormattingResult<()> {
+    let map_name = map.map_class.name.camel_case();
+    let c_ffi_prefix = map.map_class.settings.c_ffi_prefix.clone();
+
+    f.writeln("/// Guard that builds the C map type from a Java map")?;
+    f.writeln(&format!("pub(crate) struct {map_name} {{"))?;
+    indented(f, |f| {
+        f.writeln(&format!("inner: *mut {}::{}", config.ffi_name, map_name))
+    })?;
+    f.writeln("}")?;
+
+    f.newline()?;
+
+    f.writeln(&format!("impl std::ops::Deref for {map_name} {{"))?;
+    indented(f, |f| {
+        f.writeln(&format!(
+            "type Target = *mut {}::{};",
+            config.ffi_name, map_name
+        ))?;
+        f.newline()?;
+        f.writeln("fn deref(&self) -> &Self::Target {")?;
+        indented(f, |f| f.writeln("&self.inner"))?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")?;
+
+    f.newline()?;
+
+    f.writeln(&format!("impl {map_name} {{"))?;
+    indented(f, |f| {
+        f.writeln("pub(crate) fn new(_env: jni::JNIEnv, map: jni::sys::jobject) -> Result<Self, jni::errors::Error> {")?;
+        indented(f, |f| {
+            f.writeln("let _cache = crate::get_cache();")?;
+            f.writeln(&format!(
+                "let col = Self {{ inner: unsafe {{ {}::ffi::{}_{}() }} }};",
+                config.ffi_name, c_ffi_prefix, map.create_func.name
+            ))?;
+            f.writeln(
+                "let it = _env.auto_local(_cache.collection.get_entry_iterator(&_env, map.into()));",
+            )?;
+            f.writeln("while _cache.collection.has_next(&_env, it.as_obj()) {")?;
+            indented(f, |f| {
+                f.writeln(
+                    "let entry = _env.auto_local(_cache.collection.next(&_env, it.as_obj()));",
+                )?;
+                f.writeln(
+                    "let key = _env.auto_local(_cache.collection.get_entry_key(&_env, entry.as_obj()));",
+                )?;
+                f.writeln(
+                    "let value = _env.auto_local(_cache.collection.get_entry_value(&_env, entry.as_obj()));",
+                )?;
+                if let Some(converted) = map
+                    .key_type
+                    .to_rust_from_object("key.as_obj().into_inner()")
+                {
+                    f.writeln(&format!("let key = {converted};"))?;
+                }
+                if let Some(converted) = map
+                    .value_type
+                    .to_rust_from_object("value.as_obj().into_inner()")
+                {
+                    f.writeln(&format!("let value = {converted};"))?;
+                }
+                let key = map
+                    .key_type
+                    .call_site("key")
+                    .unwrap_or_else(|| "key".to_string());
+                let value = map
+                    .value_type
+                    .call_site("value")
+                    .unwrap_or_else(|| "value".to_string());
+                f.writeln(&format!(
+                    "unsafe {{ {}::ffi::{}_{}(col.inner, {}, {}) }};",
+                    config.ffi_name, c_ffi_prefix, map.insert_func.name, key, value
+                ))?;
+                Ok(())
+            })?;
+            f.writeln("}")?;
+            f.writeln("Ok(col)")?;
+            Ok(())
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")?;
+
+    f.newline()?;
+
+    f.writeln("/// Destroy the C map on drop")?;
+    f.writeln(&format!("impl Drop for {map_name} {{"))?;
+    indented(f, |f| {
+        f.writeln("fn drop(&mut self) {")?;
+        indented(f, |f| {
+            f.writeln(&format!(
+                "unsafe {{ {}::ffi::{}_{}(self.inner) }}",
+                config.ffi_name, c_ffi_prefix, map.delete_func.name
+            ))
+        })?;
+        f.writeln("}")
+    })?;
+    f.writeln("}")
+}
+
 fn write_functions(
     f: &mut dyn Printer,
     lib: &Library,
     config: &JniBindgenConfig,
 ) -> FormattingResult<()> {
-    fn skip(c: FunctionCategory) -> bool {
-        match c {
+    let skip = |func: &Handle<Function<Validated>>| -> bool {
+        match func.category {
             FunctionCategory::Native => false,
+            FunctionCategory::ClassDestructor => false,
             // these all get used internally to the JNI and
             // don't need external wrappers accessed from Java
             FunctionCategory::CollectionCreate => true,
             FunctionCategory::CollectionDestroy => true,
             FunctionCategory::CollectionAdd => true,
-            FunctionCategory::IteratorNext => true,
+            FunctionCategory::MapCreate => true,
+            FunctionCategory::MapDestroy => true,
+            FunctionCategory::MapInsert => true,
+            // owned iterators are traversed from the generated Java class
+            FunctionCategory::IteratorNext => !lib
+                .iterators()
+                .any(|it| it.is_owned() && it.next_function.name == func.name),
+            FunctionCategory::IteratorDestroy => false,
         }
-    }
+    };
 
-    for handle in lib.functions().filter(|f| !skip(f.category)) {
+    for handle in lib.functions().filter(|f| !skip(f)) {
         f.newline()?;
         write_function(f, lib, config, handle)?;
     }
@@ -407,6 +702,63 @@ fn write_function_signature(
     )
 }
 
+fn write_throw(
+    f: &mut dyn Printer,
+    config: &JniBindgenConfig,
+    error_type: &ErrorType<Validated>,
+) -> FormattingResult<()> {
+    let mut args = vec!["jni::objects::JValue::Object(_error.into())"];
+    if error_type.message {
+        f.writeln(&format!(
+            "let _detail = {}::ffi::{}();",
+            config.ffi_name,
+            error_type.last_message_function_name()
+        ))?;
+        f.writeln(&format!(
+            "let _detail = if _detail.is_null() {{ jni::objects::JObject::null().into_inner() }} else {{ {} }};",
+            StringType.convert("_detail")
+        ))?;
+        args.push("jni::objects::JValue::Object(_detail.into())");
+    }
+    if let Some(payload) = &error_type.payload {
+        f.writeln(&format!(
+            "let _payload = {}::ffi::{}();",
+            config.ffi_name,
+            error_type.last_payload_function_name()
+        ))?;
+        f.writeln(&format!(
+            "let _payload = {};",
+            payload.declaration().convert("_payload")
+        ))?;
+        args.push("jni::objects::JValue::Object(_payload.into())");
+    }
+    if error_type.variant_exceptions.is_empty() {
+        return f.writeln(&format!(
+            "let error = _cache.exceptions.{}.throw(&_env, &[{}]);",
+            error_type.exception_name,
+            args.join(", ")
+        ));
+    }
+    f.writeln("let _exception = match _result")?;
+    blocked(f, |f| {
+        for (x, value) in error_type.variant_exceptions() {
+            f.writeln(&format!(
+                "{value} => &_cache.exceptions.{},",
+                x.exception_name
+            ))?;
+        }
+        f.writeln(&format!(
+            "_ => &_cache.exceptions.{},",
+            error_type.exception_name
+        ))
+    })?;
+    f.write(";")?;
+    f.writeln(&format!(
+        "let error = _exception.throw(&_env, &[{}]);",
+        args.join(", ")
+    ))
+}
+
 fn write_function(
     f: &mut dyn Printer,
     lib: &Library,
@@ -487,20 +839,17 @@ fn write_function(
                         "let _error = {};",
                         error_type.inner.convert("_result")
                     ))?;
-                    f.writeln(&format!(
-                        "let error = _cache.exceptions.{}.throw(&_env, _error);",
-                        error_type.exception_name
-                    ))
+                    write_throw(f, config, &error_type)
                 })?;
             }
             SignatureType::ErrorWithReturn(error_type, return_type, _) => {
                 f.writeln("let _result = if _result == 0")?;
-                blocked(f, |f| {
-                    f.writeln("let _result = unsafe { _out.assume_init() };")?;
-                    if let Some(conversion) = return_type.maybe_convert("_result") {
-                        f.writeln(&conversion)?;
+                blocked(f, |f| match return_type.maybe_convert("_result") {
+                    Some(conversion) => {
+                        f.writeln("let _result = unsafe { _out.assume_init() };")?;
+                        f.writeln(&conversion)
                     }
-                    Ok(())
+                    None => f.writeln("unsafe { _out.assume_init() }"),
                 })?;
                 f.writeln("else")?;
                 blocked(f, |f| {
@@ -508,10 +857,7 @@ fn write_function(
                         "let _error = {};",
                         error_type.inner.convert("_result")
                     ))?;
-                    f.writeln(&format!(
-                        "let error = _cache.exceptions.{}.throw(&_env, _error);",
-                        error_type.exception_name
-                    ))?;
+                    write_throw(f, config, &error_type)?;
                     f.writeln(return_type.get_default_value())
                 })?;
                 f.write(";")?;
@@ -14,7 +14,8 @@ pub(crate) fn generate(
 
     f.newline()?;
 
-    f.writeln("mod instances {")?;
+    // the guards are referenced by the union conversions
+    f.writeln("pub(crate) mod instances {")?;
     indented(f, |f| generate_structs(f, lib, config))?;
     f.writeln("}")
 }
@@ -0,0 +1,241 @@
+use crate::backend::java::jni::JniBindgenConfig;
+use crate::backend::*;
+use crate::model::*;
+
+pub(crate) fn generate(
+    f: &mut dyn Printer,
+    lib: &Library,
+    config: &JniBindgenConfig,
+) -> FormattingResult<()> {
+    f.newline()?;
+
+    generate_top_level_cache(f, lib)?;
+
+    f.newline()?;
+
+    f.writeln("mod instances {")?;
+    indented(f, |f| {
+        for union in lib.unions() {
+            generate_union_fields(f, union)?;
+            generate_union_init(f, union, config)?;
+            generate_conversion_to_rust(f, union, config)?;
+            generate_conversion_to_jni(f, union, config)?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")
+}
+
+fn generate_top_level_cache(f: &mut dyn Printer, lib: &Library) -> FormattingResult<()> {
+    f.writeln("pub struct Unions")?;
+    blocked(f, |f| {
+        for union in lib.unions() {
+            f.writeln(&format!(
+                "pub {}: instances::{},",
+                union.name,
+                union.name.camel_case()
+            ))?;
+        }
+
+        Ok(())
+    })?;
+
+    f.newline()?;
+
+    f.writeln("impl Unions")?;
+    blocked(f, |f| {
+        f.writeln("pub fn init(env: &jni::JNIEnv) -> Self")?;
+        blocked(f, |f| {
+            f.writeln("Self")?;
+            blocked(f, |f| {
+                for union in lib.unions() {
+                    f.writeln(&format!(
+                        "{}: instances::{}::init(env),",
+                        union.name,
+                        union.name.camel_case()
+                    ))?;
+                }
+                Ok(())
+            })
+        })
+    })
+}
+
+fn generate_union_fields(
+    f: &mut dyn Printer,
+    handle: &Handle<Union<Validated>>,
+) -> FormattingResult<()> {
+    f.newline()?;
+    f.writeln(&format!("pub struct {}", handle.name.camel_case()))?;
+    blocked(f, |f| {
+        for variant in &handle.variants {
+            f.writeln(&format!("{}: jni::objects::GlobalRef,", variant.name))?;
+            f.writeln(&format!(
+                "{}_constructor: jni::objects::JMethodID<'static>,",
+                variant.name
+            ))?;
+            f.writeln(&format!(
+                "{}_value: jni::objects::JFieldID<'static>,",
+                variant.name
+            ))?;
+        }
+        Ok(())
+    })
+}
+
+fn generate_union_init(
+    f: &mut dyn Printer,
+    handle: &Handle<Union<Validated>>,
+    config: &JniBindgenConfig,
+) -> FormattingResult<()> {
+    let lib_path = config.java_signature_path(&handle.settings.name);
+    let union_name = handle.name.camel_case();
+
+    f.newline()?;
+    f.writeln(&format!("impl {union_name}"))?;
+    blocked(f, |f| {
+        f.writeln("pub fn init(env: &jni::JNIEnv) -> Self")?;
+        blocked(f, |f| {
+            for variant in &handle.variants {
+                // each variant is a nested class of the abstract union class
+                let class_name = format!("{}${}", union_name, variant.name.camel_case());
+                let value_sig = format!("L{}/{};", lib_path, variant.value.name().camel_case());
+                f.writeln(&format!(
+                    "let {name} = env.find_class(\"{lib_path}/{class_name}\").expect(\"Unable to find {class_name}\");",
+                    name = variant.name
+                ))?;
+                f.writeln(&format!(
+                    "let {name}_constructor = env.get_method_id({name}, \"<init>\", \"({value_sig})V\").map(|mid| mid.into_inner().into()).expect(\"Unable to find constructor of {class_name}\");",
+                    name = variant.name
+                ))?;
+                f.writeln(&format!(
+                    "let {name}_value = env.get_field_id({name}, \"value\", \"{value_sig}\").map(|mid| mid.into_inner().into()).expect(\"Unable to find value field of {class_name}\");",
+                    name = variant.name
+                ))?;
+            }
+            f.writeln("Self")?;
+            blocked(f, |f| {
+                for variant in &handle.variants {
+                    f.writeln(&format!(
+                        "{name}: env.new_global_ref({name}).unwrap(),",
+                        name = variant.name
+                    ))?;
+                    f.writeln(&format!("{}_constructor,", variant.name))?;
+                    f.writeln(&format!("{}_value,", variant.name))?;
+                }
+                Ok(())
+            })
+        })
+    })
+}
+
+fn generate_conversion_to_rust(
+    f: &mut dyn Printer,
+    handle: &Handle<Union<Validated>>,
+    config: &JniBindgenConfig,
+) -> FormattingResult<()> {
+    let union_name = handle.name.camel_case();
+    let ffi_union_name = format!("{}::ffi::{}", config.ffi_name, union_name);
+    let ffi_variant_name = format!("{}::ffi::{}Variant", config.ffi_name, union_name);
+    let guard_name = format!("{union_name}Guard");
+
+    f.newline()?;
+    f.writeln(&format!(
+        "/// Guard object ensures the payload of a {ffi_union_name} is valid"
+    ))?;
+    f.writeln(&format!("pub(crate) enum {guard_name}<'a> {{"))?;
+    indented(f, |f| {
+        for variant in &handle.variants {
+            f.writeln(&format!(
+                "{}(crate::structs::instances::{}Guard<'a>),",
+                variant.name.camel_case(),
+                variant.value.name().camel_case()
+            ))?;
+        }
+        Ok(())
+    })?;
+    f.writeln("}")?;
+
+    f.newline()?;
+    f.writeln(&format!("impl {union_name}"))?;
+    blocked(f, |f| {
+        f.writeln(&format!("pub(crate) fn to_rust<'a>(&self, _cache: &'a crate::JCache, _env: &'a jni::JNIEnv, obj: jni::sys::jobject) -> ({guard_name}<'a>, {ffi_union_name})"))?;
+        blocked(f, |f| {
+            for variant in &handle.variants {
+                f.writeln(&format!(
+                    "if _env.is_instance_of(obj, &self.{}).unwrap()",
+                    variant.name
+                ))?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "let value = _env.get_field_unchecked(obj, self.{}_value, jni::signature::JavaType::Object(String::new())).unwrap().l().unwrap().into_inner();",
+                        variant.name
+                    ))?;
+                    f.writeln(&format!(
+                        "let (guard, value) = _cache.structs.{}.to_rust(_cache, _env, value);",
+                        variant.value.name()
+                    ))?;
+                    f.writeln(&format!(
+                        "return ({}::{}(guard), {}::{}(value).into());",
+                        guard_name,
+                        variant.name.camel_case(),
+                        ffi_variant_name,
+                        variant.name.camel_case()
+                    ))
+                })?;
+            }
+            f.newline()?;
+            f.writeln(&format!("panic!(\"Unknown variant of {union_name}\")"))
+        })
+    })
+}
+
+fn generate_conversion_to_jni(
+    f: &mut dyn Printer,
+    handle: &Handle<Union<Validated>>,
+    config: &JniBindgenConfig,
+) -> FormattingResult<()> {
+    let union_name = handle.name.camel_case();
+    let ffi_union_name = format!("{}::ffi::{}", config.ffi_name, union_name);
+    let ffi_variant_name = format!("{}::ffi::{}Variant", config.ffi_name, union_name);
+
+    f.newline()?;
+    f.writeln(&format!("impl {union_name}"))?;
+    blocked(f, |f| {
+        f.writeln(&format!("pub(crate) fn to_jni(&self, _cache: &crate::JCache, _env: &jni::JNIEnv, value: &{ffi_union_name}) -> jni::sys::jobject"))?;
+        blocked(f, |f| {
+            f.writeln("// automatically free the local reference to the payload, only the union object is returned")?;
+            f.writeln("_env.with_local_frame(2, || {")?;
+            indented(f, |f| {
+                f.writeln(&format!(
+                    "let obj = match <{ffi_variant_name} as std::convert::TryFrom<&{ffi_union_name}>>::try_from(value) {{"
+                ))?;
+                indented(f, |f| {
+                    for variant in &handle.variants {
+                        f.writeln(&format!(
+                            "Ok({}::{}(x)) => {{",
+                            ffi_variant_name,
+                            variant.name.camel_case()
+                        ))?;
+                        indented(f, |f| {
+                            f.writeln(&format!(
+                                "let payload = _cache.structs.{}.to_jni(_cache, _env, &x);",
+                                variant.value.name()
+                            ))?;
+                            f.writeln(&format!(
+                                "_env.new_object_unchecked(&self.{name}, self.{name}_constructor, &[jni::objects::JValue::Object(payload.into())]).unwrap()",
+                                name = variant.name
+                            ))
+                        })?;
+                        f.writeln("}")?;
+                    }
+                    f.writeln("// the tag doesn't match any variant")?;
+                    f.writeln("Err(_) => jni::objects::JObject::null(),")
+                })?;
+                f.writeln("};")?;
+                f.writeln("Ok(obj)")
+            })?;
+            f.writeln("}).unwrap().into_inner()")
+        })
+    })
+}
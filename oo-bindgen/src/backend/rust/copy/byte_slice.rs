@@ -0,0 +1,31 @@
+/// Borrowed sequence of bytes passed across the C ABI as a pointer and a length
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct ByteSlice {
+    pub value: *const u8,
+    pub len: usize,
+}
+
+impl ByteSlice {
+    /// Borrow the bytes as a slice
+    ///
+    /// # Safety
+    ///
+    /// The pointer must either be NULL or reference at least `len` bytes that outlive `'a`
+    pub unsafe fn to_slice<'a>(&self) -> &'a [u8] {
+        if self.value.is_null() || self.len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(self.value, self.len)
+        }
+    }
+}
+
+impl From<&[u8]> for ByteSlice {
+    fn from(value: &[u8]) -> Self {
+        Self {
+            value: value.as_ptr(),
+            len: value.len(),
+        }
+    }
+}
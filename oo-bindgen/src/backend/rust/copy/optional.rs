@@ -0,0 +1,35 @@
+/// Value that may be absent passed across the C ABI as a flag and a value
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub struct Optional<T> {
+    pub has_value: bool,
+    pub value: T,
+}
+
+impl<T> From<Option<T>> for Optional<T>
+where
+    T: Default,
+{
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Self {
+                has_value: true,
+                value,
+            },
+            None => Self {
+                has_value: false,
+                value: T::default(),
+            },
+        }
+    }
+}
+
+impl<T> Optional<T> {
+    pub fn into_option(self) -> Option<T> {
+        if self.has_value {
+            Some(self.value)
+        } else {
+            None
+        }
+    }
+}
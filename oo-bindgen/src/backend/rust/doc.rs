@@ -0,0 +1,76 @@
+use heck::ToUpperCamelCase;
+
+use crate::backend::*;
+use crate::model::*;
+
+pub(crate) fn rustdoc_print(f: &mut dyn Printer, doc: &Doc<Validated>) -> FormattingResult<()> {
+    f.newline()?;
+    docstring_print(f, &doc.brief)?;
+
+    for detail in &doc.details {
+        f.newline()?;
+
+        match detail {
+            DocParagraph::Details(docstring) => {
+                f.newline()?;
+                docstring_print(f, docstring)?;
+            }
+            DocParagraph::Warning(docstring) => {
+                f.writeln("**Warning:** ")?;
+                docstring_print(f, docstring)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn docstring_print(
+    f: &mut dyn Printer,
+    docstring: &DocString<Validated>,
+) -> FormattingResult<()> {
+    for el in docstring.elements() {
+        match el {
+            DocStringElement::Text(text) => f.write(text)?,
+            DocStringElement::Null => f.write("`NULL`")?,
+            DocStringElement::Iterator => f.write("iterator")?,
+            DocStringElement::Reference(reference) => print_rust_reference(f, reference)?,
+        }
+    }
+
+    Ok(())
+}
+
+fn print_rust_reference(f: &mut dyn Printer, reference: &Validated) -> FormattingResult<()> {
+    match reference {
+        Validated::Argument(param_name) => f.write(&format!("`{param_name}`")),
+        Validated::Class(class) => f.write(&format!("`{}`", class.name.to_upper_camel_case())),
+        Validated::ClassMethod(_, _, function) => f.write(&format!("[Self::{}]", function.name)),
+        Validated::ClassConstructor(_, constructor) => {
+            f.write(&format!("[Self::{}]", constructor.function.name))
+        }
+        Validated::ClassDestructor(_, destructor) => {
+            f.write(&format!("[Self::{}]", destructor.function.name))
+        }
+        Validated::Struct(st) => f.write(&format!("`{}`", st.name().to_upper_camel_case())),
+        Validated::StructField(st, field_name) => f.write(&format!(
+            "`{}::{}`",
+            st.name().to_upper_camel_case(),
+            field_name
+        )),
+        Validated::Enum(handle) => f.write(&format!("`{}`", handle.name.to_upper_camel_case())),
+        Validated::EnumVariant(handle, variant_name) => f.write(&format!(
+            "`{}::{}`",
+            handle.name.to_upper_camel_case(),
+            variant_name.to_upper_camel_case()
+        )),
+        Validated::Interface(interface) => {
+            f.write(&format!("`{}`", interface.name.to_upper_camel_case()))
+        }
+        Validated::InterfaceMethod(interface, callback_name) => f.write(&format!(
+            "`{}::{}`",
+            interface.name.to_upper_camel_case(),
+            callback_name
+        )),
+    }
+}
@@ -0,0 +1,271 @@
+use heck::ToUpperCamelCase;
+
+use crate::backend::rust::doc::*;
+use crate::backend::rust::rust_struct::RustStruct;
+use crate::backend::rust::rust_type::RustType;
+use crate::backend::rust::RustCodegen;
+use crate::backend::*;
+use crate::model::*;
+
+const TRAIT_NAME: &str = "Implementation";
+const MACRO_NAME: &str = "implement_ffi";
+
+/// path of the generated types from the functions defined by the macro
+const MACRO_FFI_PATH: &str = "crate::$($module)::+::";
+
+/// function called by the FFI layer that the user implements
+pub(crate) struct ImplementedFunction<'a> {
+    pub(crate) function: &'a Handle<Function<Validated>>,
+    /// the implementation only starts the operation, the FFI layer returns its identifier
+    pub(crate) cancellable: bool,
+}
+
+/// Write the trait with one method per implemented function and the macro that wires an
+/// implementation of the trait to the functions called by the FFI layer
+pub(crate) fn write_implementation(
+    f: &mut dyn Printer,
+    library: &Library,
+    functions: &[ImplementedFunction],
+) -> FormattingResult<()> {
+    let trait_signatures = Signatures {
+        library,
+        ffi_path: "",
+    };
+    let macro_signatures = Signatures {
+        library,
+        ffi_path: MACRO_FFI_PATH,
+    };
+
+    doxygen(f, |f| {
+        f.writeln("Functions called by the FFI layer to implement the library")?;
+        f.newline()?;
+        f.writeln(&format!(
+            "Implement this trait and wire it to the exported symbols with `{MACRO_NAME}!`"
+        ))
+    })?;
+    f.writeln("#[allow(clippy::missing_safety_doc)]")?;
+    f.writeln("#[allow(clippy::needless_lifetimes)]")?;
+    f.writeln("#[allow(clippy::too_many_arguments)]")?;
+    f.writeln(&format!("pub trait {TRAIT_NAME}"))?;
+    blocked(f, |f| {
+        for (i, x) in functions.iter().enumerate() {
+            if i > 0 {
+                f.newline()?;
+            }
+            write_function_doc(f, x.function)?;
+            f.writeln(&format!("{};", trait_signatures.declaration(x)))?;
+        }
+        Ok(())
+    })?;
+
+    f.newline()?;
+    doxygen(f, |f| {
+        f.writeln(&format!(
+            "Define the functions called by the FFI layer so that they forward to an implementation of `{TRAIT_NAME}`"
+        ))?;
+        f.newline()?;
+        f.writeln("Invoke it in the crate root with the path of the module that includes the generated code")?;
+        f.writeln(&format!(
+            "and the implementing type, e.g. `ffi::{MACRO_NAME}!(ffi, MyImplementation);`"
+        ))
+    })?;
+    f.writeln("#[allow(unused_macros)]")?;
+    f.writeln(&format!("macro_rules! {MACRO_NAME}"))?;
+    blocked(f, |f| {
+        f.writeln("($($module:ident)::+, $implementation:ty) =>")?;
+        blocked(f, |f| {
+            for x in functions {
+                f.writeln("#[allow(clippy::needless_lifetimes)]")?;
+                f.writeln("#[allow(clippy::too_many_arguments)]")?;
+                f.writeln(&format!("pub(crate) {}", macro_signatures.declaration(x)))?;
+                blocked(f, |f| {
+                    let args = x
+                        .function
+                        .arguments
+                        .iter()
+                        .map(|param| param.name.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    f.writeln(&format!(
+                        "<$implementation as {MACRO_FFI_PATH}{TRAIT_NAME}>::{}({args})",
+                        x.function.name
+                    ))
+                })?;
+            }
+            Ok(())
+        })?;
+        f.write(";")
+    })?;
+    f.writeln("#[allow(unused_imports)]")?;
+    f.writeln(&format!("pub(crate) use {MACRO_NAME};"))
+}
+
+fn write_function_doc(f: &mut dyn Printer, function: &Function<Validated>) -> FormattingResult<()> {
+    doxygen(f, |f| {
+        rustdoc_print(f, &function.doc)?;
+
+        if !function.arguments.is_empty() {
+            f.newline()?;
+            f.writeln("# Arguments")?;
+            f.newline()?;
+            for param in &function.arguments {
+                f.writeln(&format!("* `{}` - ", param.name))?;
+                docstring_print(f, &param.doc)?;
+            }
+        }
+
+        if let Some(doc) = function.return_type.get_doc() {
+            f.newline()?;
+            f.writeln("# Returns")?;
+            f.newline()?;
+            docstring_print(f, doc)?;
+        }
+
+        Ok(())
+    })
+}
+
+/// Rust types of the values exchanged with the implementation
+struct Signatures<'a> {
+    library: &'a Library,
+    /// path prefix of the types defined in the generated code
+    ffi_path: &'a str,
+}
+
+impl Signatures<'_> {
+    fn declaration(&self, x: &ImplementedFunction) -> String {
+        let function = x.function;
+        let args = function
+            .arguments
+            .iter()
+            .map(|param| format!("{}: {}", param.name, self.arg_type(function, param)))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        let value = if x.cancellable {
+            None
+        } else {
+            function
+                .return_type
+                .get_value()
+                .map(|value| self.return_type(value))
+        };
+
+        let ret = match (function.error_type.get(), value) {
+            (None, None) => "".to_string(),
+            (None, Some(value)) => format!(" -> {value}"),
+            (Some(err), value) => format!(
+                " -> Result<{}, {}>",
+                value.unwrap_or_else(|| "()".to_string()),
+                self.error_type(err)
+            ),
+        };
+
+        // all references share the lifetime of the call
+        let lifetime = if args.contains("'a") || ret.contains("'a") {
+            "<'a>"
+        } else {
+            ""
+        };
+
+        format!("unsafe fn {}{lifetime}({args}){ret}", function.name)
+    }
+
+    fn basic_type(&self, x: &BasicType) -> String {
+        match x {
+            BasicType::Enum(handle) => {
+                format!("{}{}", self.ffi_path, handle.name.to_upper_camel_case())
+            }
+            BasicType::Flags(handle) => {
+                format!("{}{}", self.ffi_path, handle.name.to_upper_camel_case())
+            }
+            _ => x.as_rust_type(),
+        }
+    }
+
+    fn struct_type(&self, name: &Name) -> String {
+        let lifetime = self.library.structs().any(|x| {
+            x.name() == name
+                && match x {
+                    StructType::FunctionArg(x) => x.annotate_c_with_lifetime(),
+                    StructType::FunctionReturn(x) => x.annotate_c_with_lifetime(),
+                    StructType::CallbackArg(x) => x.annotate_c_with_lifetime(),
+                    StructType::Universal(x) => x.annotate_c_with_lifetime(),
+                }
+        });
+        let lifetime = if lifetime { "<'a>" } else { "" };
+        format!("{}{}{lifetime}", self.ffi_path, name.to_upper_camel_case())
+    }
+
+    fn class_type(&self, class: &ClassDeclarationHandle) -> String {
+        // the iterator may borrow the values it iterates over
+        let lifetime = self
+            .library
+            .iterators()
+            .any(|x| x.iter_class.name == class.name && x.has_lifetime_annotation);
+        let lifetime = if lifetime { "<'a>" } else { "" };
+        format!("crate::{}{lifetime}", class.name.to_upper_camel_case())
+    }
+
+    fn arg_type(
+        &self,
+        function: &Function<Validated>,
+        param: &Arg<FunctionArgument, Validated>,
+    ) -> String {
+        match &param.arg_type {
+            FunctionArgument::Basic(x) => self.basic_type(x),
+            FunctionArgument::String(_) => "&'a std::ffi::CStr".to_string(),
+            FunctionArgument::Bytes(_) => "&'a [u8]".to_string(),
+            FunctionArgument::Optional(x) => format!("Option<{}>", self.basic_type(&x.inner)),
+            FunctionArgument::Union(x) => {
+                format!("{}{}Variant", self.ffi_path, x.name.to_upper_camel_case())
+            }
+            FunctionArgument::Collection(x) => x.as_rust_type(),
+            FunctionArgument::Map(x) => x.as_rust_type(),
+            FunctionArgument::Struct(x) => self.struct_type(x.name()),
+            FunctionArgument::StructRef(x) => format!("&'a {}", self.struct_type(&x.inner.name)),
+            FunctionArgument::ClassRef(x) => {
+                match RustCodegen::reference_conversion(function, param) {
+                    Some("as_mut") => format!("&'a mut {}", self.class_type(x)),
+                    Some(_) => format!("&'a {}", self.class_type(x)),
+                    None => format!("*mut {}", self.class_type(x)),
+                }
+            }
+            FunctionArgument::Interface(x) => {
+                format!("{}{}", self.ffi_path, x.name.to_upper_camel_case())
+            }
+        }
+    }
+
+    fn return_type(&self, value: &FunctionReturnValue) -> String {
+        match value {
+            FunctionReturnValue::Basic(x) => self.basic_type(x),
+            FunctionReturnValue::PrimitiveRef(x) => x.as_rust_type(),
+            FunctionReturnValue::ValueRef(ValueRef::Enum(x)) => {
+                format!("*const {}{}", self.ffi_path, x.name.to_upper_camel_case())
+            }
+            FunctionReturnValue::ValueRef(x) => x.as_rust_type(),
+            FunctionReturnValue::String(_) => "&'a std::ffi::CStr".to_string(),
+            FunctionReturnValue::Bytes(_) => "&'a [u8]".to_string(),
+            FunctionReturnValue::Union(x) => {
+                format!("{}{}Variant", self.ffi_path, x.name.to_upper_camel_case())
+            }
+            FunctionReturnValue::ClassRef(x) => format!("*mut {}", self.class_type(x)),
+            FunctionReturnValue::Iterator(x) => x.as_rust_type(),
+            FunctionReturnValue::Struct(x) => self.struct_type(x.name()),
+            FunctionReturnValue::StructRef(x) => {
+                format!("Option<&'a {}>", self.struct_type(&x.untyped().name))
+            }
+        }
+    }
+
+    fn error_type(&self, err: &ErrorType<Validated>) -> String {
+        // the detail records a message or payload along with the error
+        let suffix = if err.has_detail() { "Detail" } else { "" };
+        format!(
+            "{}{}{suffix}",
+            self.ffi_path,
+            err.inner.name.to_upper_camel_case()
+        )
+    }
+}
@@ -7,12 +7,15 @@ use heck::ToUpperCamelCase;
 use crate::backend::*;
 use crate::model::*;
 
+use crate::backend::rust::implementation::ImplementedFunction;
 use crate::backend::rust::rust_struct::RustStruct;
 use crate::backend::rust::rust_type::RustType;
 
 use crate::backend::rust::rust_type::LifetimeInfo;
 use crate::backend::rust::type_converter::TypeConverter;
 
+mod doc;
+mod implementation;
 mod rust_struct;
 mod rust_type;
 mod type_converter;
@@ -23,6 +26,9 @@ mod type_converter;
 /// The user must then go and write a number of functions referenced by the FFI functions to glue
 /// the C API layer to the underlying rust crate.
 ///
+/// These functions are also described by the generated `Implementation` trait. Instead of writing
+/// them by hand, the user may implement the trait and define them with the `implement_ffi!` macro.
+///
 /// This function is typically called from a build.rs script
 pub fn generate_ffi(library: &Library) -> FormattingResult<()> {
     RustCodegen::new(library).generate()
@@ -44,6 +50,14 @@ impl<'a> RustCodegen<'a> {
     fn generate(self) -> FormattingResult<()> {
         let mut f = FilePrinter::new(&self.dest_path)?;
 
+        f.writeln(include_str!("copy/byte_slice.rs"))?;
+        f.writeln(include_str!("copy/optional.rs"))?;
+
+        if self.library.settings.panic.catch_unwind {
+            Self::write_log_panic(&mut f, &self.library.settings.panic)?;
+            f.newline()?;
+        }
+
         for statement in self.library.statements() {
             match statement {
                 Statement::StructDefinition(s) => match s {
@@ -53,20 +67,540 @@ impl<'a> RustCodegen<'a> {
                     StructType::Universal(s) => self.write_struct_definition(&mut f, s)?,
                 },
                 Statement::EnumDefinition(handle) => self.write_enum_definition(&mut f, handle)?,
-                Statement::FunctionDefinition(handle) => {
-                    Self::write_function(&mut f, handle, &self.library.settings.c_ffi_prefix)?
+                Statement::FlagsDefinition(handle) => Self::write_flags_definition(&mut f, handle)?,
+                Statement::ErrorType(handle) if handle.has_detail() => {
+                    Self::write_error_detail(&mut f, handle)?
+                }
+                Statement::ListDeclaration(handle) => Self::write_list_definition(&mut f, handle)?,
+                Statement::UnionDefinition(handle) => {
+                    self.write_union_definition(&mut f, handle)?
                 }
+                Statement::FunctionDefinition(handle) => match self.cancelled_by(handle) {
+                    Some((method, variant)) => {
+                        self.write_cancel_function(&mut f, handle, method, variant)?
+                    }
+                    None => Self::write_function(
+                        &mut f,
+                        handle,
+                        &self.library.settings.c_ffi_prefix,
+                        self.cancellable_future(handle),
+                    )?,
+                },
                 Statement::InterfaceDefinition(t) => {
                     self.write_interface(&mut f, t.untyped(), t.mode())?;
+                    if let InterfaceType::Future(fi) = t {
+                        if self.is_cancellable_future(t.untyped()) {
+                            f.newline()?;
+                            Self::write_future_cancellation(&mut f, fi)?;
+                        }
+                        if let Some(variant) = &fi.error_type.drop_variant {
+                            f.newline()?;
+                            Self::write_future_promise(&mut f, fi, variant)?;
+                        }
+                    }
                 }
                 _ => (),
             }
             f.newline()?;
         }
 
+        // the cancel functions are written entirely by the generator
+        let functions: Vec<ImplementedFunction> = self
+            .library
+            .functions()
+            .filter(|x| self.cancelled_by(x).is_none())
+            .map(|x| ImplementedFunction {
+                function: x,
+                cancellable: self.cancellable_future(x).is_some(),
+            })
+            .collect();
+        implementation::write_implementation(&mut f, self.library, &functions)?;
+        f.newline()?;
+
+        self.write_layout_tests(&mut f)?;
+
+        Ok(())
+    }
+
+    /// Assert the layout of the structs against the values asserted in the C header
+    fn write_layout_tests(&self, f: &mut dyn Printer) -> FormattingResult<()> {
+        f.writeln("#[cfg(all(test, target_pointer_width = \"64\"))]")?;
+        f.writeln("mod layout_tests")?;
+        blocked(f, |f| {
+            // libraries without structs don't assert anything
+            f.writeln("#[allow(unused_imports)]")?;
+            f.writeln("use super::*;")?;
+            for st in self.library.structs() {
+                match st {
+                    StructType::FunctionArg(x) => Self::write_layout_assertions(f, x)?,
+                    StructType::FunctionReturn(x) => Self::write_layout_assertions(f, x)?,
+                    StructType::CallbackArg(x) => Self::write_layout_assertions(f, x)?,
+                    StructType::Universal(x) => Self::write_layout_assertions(f, x)?,
+                }
+            }
+            Ok(())
+        })
+    }
+
+    fn write_layout_assertions<T>(
+        f: &mut dyn Printer,
+        handle: &Handle<Struct<T, Validated>>,
+    ) -> FormattingResult<()>
+    where
+        T: StructFieldType + RustType + TypeLayout,
+    {
+        // C doesn't allow empty structs, so the header doesn't assert their layout
+        if handle.fields.is_empty() {
+            return Ok(());
+        }
+
+        let layout = struct_layout(handle);
+        let lifetime = if handle.annotate_c_with_lifetime() {
+            "<'static>"
+        } else {
+            ""
+        };
+        let struct_name = format!("{}{lifetime}", handle.name().to_upper_camel_case());
+
+        f.newline()?;
+        f.writeln(&format!(
+            "const _: () = assert!(std::mem::size_of::<{struct_name}>() == {});",
+            layout.layout.size
+        ))?;
+        f.writeln(&format!(
+            "const _: () = assert!(std::mem::align_of::<{struct_name}>() == {});",
+            layout.layout.align
+        ))?;
+        for (field, offset) in &layout.offsets {
+            f.writeln(&format!(
+                "const _: () = assert!(std::mem::offset_of!({struct_name}, {field}) == {offset});"
+            ))?;
+        }
         Ok(())
     }
 
+    fn write_log_panic(f: &mut dyn Printer, settings: &PanicSettings) -> FormattingResult<()> {
+        f.writeln("/// report a panic caught at the FFI boundary")?;
+        f.writeln("fn log_panic(location: &str, panic: Box<dyn std::any::Any + Send>)")?;
+        blocked(f, |f| {
+            f.writeln("let message = if let Some(x) = panic.downcast_ref::<&str>()")?;
+            blocked(f, |f| f.writeln("x"))?;
+            f.writeln("else if let Some(x) = panic.downcast_ref::<String>()")?;
+            blocked(f, |f| f.writeln("x.as_str()"))?;
+            f.writeln("else")?;
+            blocked(f, |f| f.writeln("\"unknown panic payload\""))?;
+            f.write(";")?;
+            match &settings.log_function {
+                Some(log) => f.writeln(&format!(
+                    "{log}(&format!(\"panic in {{location}}: {{message}}\"));"
+                )),
+                None => f.writeln("eprintln!(\"panic in {location}: {message}\");"),
+            }
+        })
+    }
+
+    /// Wrap the body of a generated function in `catch_unwind` if enabled in the settings.
+    ///
+    /// If the body panics, the panic is logged and the function evaluates to the fallback.
+    fn catch_unwind<B, F>(
+        f: &mut dyn Printer,
+        settings: &PanicSettings,
+        location: &str,
+        body: B,
+        fallback: F,
+    ) -> FormattingResult<()>
+    where
+        B: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+        F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+    {
+        if !settings.catch_unwind {
+            return body(f);
+        }
+
+        f.writeln("match std::panic::catch_unwind(std::panic::AssertUnwindSafe(||")?;
+        blocked(f, body)?;
+        f.write("))")?;
+        blocked(f, |f| {
+            f.writeln("Ok(x) => x,")?;
+            f.writeln("Err(panic) =>")?;
+            blocked(f, |f| {
+                f.writeln(&format!("log_panic(\"{location}\", panic);"))?;
+                fallback(f)
+            })
+        })
+    }
+
+    fn cancellable_futures(&self) -> impl Iterator<Item = &FutureMethod<Validated>> {
+        self.library
+            .classes()
+            .flat_map(|x| x.future_methods.iter())
+            .filter(|x| x.cancellation.is_some())
+    }
+
+    /// cancellable future method implemented by the native function
+    fn cancellable_future(
+        &self,
+        function: &Function<Validated>,
+    ) -> Option<&FutureMethod<Validated>> {
+        self.cancellable_futures()
+            .find(|x| x.native_function.name == function.name)
+    }
+
+    /// future method cancelled by the native function and the error variant it completes with
+    fn cancelled_by(
+        &self,
+        function: &Function<Validated>,
+    ) -> Option<(&FutureMethod<Validated>, &Name)> {
+        self.cancellable_futures()
+            .find_map(|x| match &x.cancellation {
+                Some(c) if c.function.name == function.name => Some((x, &c.variant)),
+                _ => None,
+            })
+    }
+
+    fn is_cancellable_future(&self, interface: &Interface<Validated>) -> bool {
+        self.cancellable_futures()
+            .any(|x| x.future.interface.name == interface.name)
+    }
+
+    fn write_cancel_function(
+        &self,
+        f: &mut dyn Printer,
+        handle: &Handle<Function<Validated>>,
+        method: &FutureMethod<Validated>,
+        variant: &Name,
+    ) -> FormattingResult<()> {
+        f.writeln("#[allow(clippy::missing_safety_doc)]")?;
+        f.writeln("#[no_mangle]")?;
+        f.writeln(&format!(
+            "pub unsafe extern \"C\" fn {}_{}(operation: u64)",
+            self.library.settings.c_ffi_prefix, handle.name
+        ))?;
+        blocked(f, |f| {
+            Self::catch_unwind(
+                f,
+                &self.library.settings.panic,
+                &format!("{}_{}", self.library.settings.c_ffi_prefix, handle.name),
+                |f| {
+                    f.writeln(&format!(
+                        "{}::cancel(operation, {}::{});",
+                        method.future.interface.name.to_upper_camel_case(),
+                        method.future.error_type.inner.name.to_upper_camel_case(),
+                        variant.to_upper_camel_case()
+                    ))
+                },
+                |_| Ok(()),
+            )
+        })
+    }
+
+    /// Promise that completes a future interface exactly once.
+    ///
+    /// Dropping the promise without completing it invokes the failure callback with the drop
+    /// variant of the error type, so the callback can never be silently lost.
+    fn write_future_promise(
+        f: &mut dyn Printer,
+        future: &FutureInterface<Validated>,
+        variant: &Name,
+    ) -> FormattingResult<()> {
+        let interface_name = future.interface.name.to_upper_camel_case();
+        let promise_name = format!("{interface_name}Promise");
+        let error_name = future.error_type.inner.name.to_upper_camel_case();
+        let success = &future
+            .interface
+            .settings
+            .future
+            .success_callback_method_name;
+        let failure = &future
+            .interface
+            .settings
+            .future
+            .failure_callback_method_name;
+        let value_type = match &future.value {
+            Some(x) => x.value_type.as_rust_type(),
+            None => "()".to_string(),
+        };
+        let requires_lifetime = future
+            .interface
+            .callbacks
+            .iter()
+            .any(|cb| cb.rust_requires_lifetime());
+
+        f.writeln(&format!(
+            "/// Completes the {interface_name} callback exactly once, with {error_name}::{} if dropped before completion",
+            variant.to_upper_camel_case()
+        ))?;
+        f.writeln(&format!("pub struct {promise_name}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("inner: Option<{interface_name}>,"))
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl {promise_name}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "pub(crate) fn new(inner: {interface_name}) -> Self"
+            ))?;
+            blocked(f, |f| f.writeln("Self { inner: Some(inner) }"))?;
+
+            f.newline()?;
+
+            f.writeln("/// Complete the future with the result of the operation")?;
+            if requires_lifetime {
+                f.writeln("#[allow(clippy::needless_lifetimes)]")?;
+                f.writeln(&format!(
+                    "pub(crate) fn complete<'a>(mut self, result: Result<{value_type}, {error_name}>)"
+                ))?;
+            } else {
+                f.writeln(&format!(
+                    "pub(crate) fn complete(mut self, result: Result<{value_type}, {error_name}>)"
+                ))?;
+            }
+            blocked(f, |f| {
+                f.writeln("if let Some(inner) = self.inner.take()")?;
+                blocked(f, |f| {
+                    f.writeln("match result")?;
+                    blocked(f, |f| {
+                        if future.value.is_some() {
+                            f.writeln(&format!("Ok(value) => inner.{success}(value),"))?;
+                        } else {
+                            f.writeln(&format!("Ok(()) => inner.{success}(),"))?;
+                        }
+                        f.writeln(&format!("Err(err) => inner.{failure}(err),"))
+                    })
+                })
+            })
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl Drop for {promise_name}"))?;
+        blocked(f, |f| {
+            f.writeln("fn drop(&mut self)")?;
+            blocked(f, |f| {
+                f.writeln("if let Some(inner) = self.inner.take()")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "inner.{failure}({error_name}::{});",
+                        variant.to_upper_camel_case()
+                    ))
+                })
+            })
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl {interface_name}"))?;
+        blocked(f, |f| {
+            f.writeln("/// Wrap the callback in a promise that completes it if dropped")?;
+            f.writeln(&format!(
+                "pub(crate) fn into_promise(self) -> {promise_name}"
+            ))?;
+            blocked(f, |f| f.writeln(&format!("{promise_name}::new(self)")))
+        })
+    }
+
+    /// Registry of pending operations that lets a generated cancel function complete a future.
+    ///
+    /// The handler passed to the implementation is replaced by a wrapper that forwards the
+    /// first completion to the original handler. Cancelling takes the original handler out of
+    /// the registry so that any later completion by the implementation is ignored.
+    fn write_future_cancellation(
+        f: &mut dyn Printer,
+        future: &FutureInterface<Validated>,
+    ) -> FormattingResult<()> {
+        let handle = &future.interface;
+        let interface_name = handle.name.to_upper_camel_case();
+        let operation_name = format!("{interface_name}Operation");
+        let slot_name = format!("{interface_name}Slot");
+        let registry_name = format!("{}_OPERATIONS", handle.name.to_uppercase());
+        let counter_name = format!("{}_NEXT_OPERATION", handle.name.to_uppercase());
+        let ctx = &handle.settings.interface.context_variable_name;
+        let destroy = &handle.settings.interface.destroy_func_name;
+        let failure = &handle.settings.future.failure_callback_method_name;
+        let error_type = future.error_type.inner.name.to_upper_camel_case();
+
+        f.writeln(&format!(
+            "type {slot_name} = std::sync::Arc<std::sync::Mutex<Option<{interface_name}>>>;"
+        ))?;
+        f.newline()?;
+        f.writeln(&format!("static {registry_name}: std::sync::Mutex<std::collections::BTreeMap<u64, {slot_name}>> = std::sync::Mutex::new(std::collections::BTreeMap::new());"))?;
+        f.writeln(&format!("static {counter_name}: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);"))?;
+        f.newline()?;
+
+        f.writeln(&format!(
+            "/// Context of a cancellable {interface_name} that forwards the first completion to the original handler"
+        ))?;
+        f.writeln(&format!("struct {operation_name}"))?;
+        blocked(f, |f| {
+            f.writeln("id: u64,")?;
+            f.writeln(&format!("handler: {slot_name},"))
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl {operation_name}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "fn take(handler: &{slot_name}) -> Option<{interface_name}>"
+            ))?;
+            blocked(f, |f| f.writeln("handler.lock().unwrap().take()"))?;
+
+            for cb in &handle.callbacks {
+                let lifetime = if cb.c_requires_lifetime() { "<'a>" } else { "" };
+                f.newline()?;
+                f.writeln(&format!("extern \"C\" fn {}{lifetime}(", cb.name))?;
+                f.write(
+                    &cb.arguments
+                        .iter()
+                        .map(|arg| format!("{}: {}", arg.name, arg.arg_type.as_c_type()))
+                        .chain(std::iter::once(format!("{ctx}: *mut std::os::raw::c_void")))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                )?;
+                f.write(")")?;
+                blocked(f, |f| {
+                    f.writeln(&format!(
+                        "let operation = unsafe {{ &*({ctx} as *const {operation_name}) }};"
+                    ))?;
+                    f.writeln("if let Some(handler) = Self::take(&operation.handler)")?;
+                    blocked(f, |f| {
+                        f.writeln(&format!("if let Some(cb) = handler.{}", cb.name))?;
+                        blocked(f, |f| {
+                            f.writeln(&format!(
+                                "cb({});",
+                                cb.arguments
+                                    .iter()
+                                    .map(|arg| arg.name.to_string())
+                                    .chain(std::iter::once(format!("handler.{ctx}")))
+                                    .collect::<Vec<String>>()
+                                    .join(", ")
+                            ))
+                        })
+                    })
+                })?;
+            }
+
+            f.newline()?;
+            f.writeln(&format!(
+                "extern \"C\" fn {destroy}({ctx}: *mut std::os::raw::c_void)"
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "let operation = unsafe {{ Box::from_raw({ctx} as *mut {operation_name}) }};"
+                ))?;
+                f.writeln(&format!(
+                    "{registry_name}.lock().unwrap().remove(&operation.id);"
+                ))
+            })
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl {interface_name}"))?;
+        blocked(f, |f| {
+            f.writeln("/// Wrap the handler so that the operation may be cancelled with the returned identifier")?;
+            f.writeln("pub(crate) fn into_cancellable(self) -> (Self, u64)")?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "let id = {counter_name}.fetch_add(1, std::sync::atomic::Ordering::Relaxed);"
+                ))?;
+                f.writeln("let handler = std::sync::Arc::new(std::sync::Mutex::new(Some(self)));")?;
+                f.writeln(&format!(
+                    "{registry_name}.lock().unwrap().insert(id, handler.clone());"
+                ))?;
+                f.writeln(&format!(
+                    "let operation = Box::new({operation_name} {{ id, handler }});"
+                ))?;
+                f.writeln("let wrapper = Self")?;
+                blocked(f, |f| {
+                    for cb in &handle.callbacks {
+                        f.writeln(&format!(
+                            "{name}: Some({operation_name}::{name}),",
+                            name = cb.name
+                        ))?;
+                    }
+                    f.writeln(&format!("{destroy}: Some({operation_name}::{destroy}),"))?;
+                    f.writeln(&format!(
+                        "{ctx}: Box::into_raw(operation) as *mut std::os::raw::c_void,"
+                    ))
+                })?;
+                f.write(";")?;
+                f.writeln("(wrapper, id)")
+            })?;
+
+            f.newline()?;
+            f.writeln(
+                "/// Complete a pending operation with an error, ignoring any later completion",
+            )?;
+            f.writeln(&format!(
+                "pub(crate) fn cancel(operation: u64, error: {error_type})"
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!(
+                    "let handler = {registry_name}.lock().unwrap().remove(&operation);"
+                ))?;
+                f.writeln(&format!(
+                    "if let Some(handler) = handler.as_ref().and_then({operation_name}::take)"
+                ))?;
+                blocked(f, |f| f.writeln(&format!("handler.{failure}(error);")))
+            })
+        })
+    }
+
+    fn write_list_definition(f: &mut dyn Printer, handle: &ListHandle) -> FormattingResult<()> {
+        let list_name = handle.name.to_upper_camel_case();
+        let item_type = handle.item_type.as_c_type();
+
+        f.writeln(&format!(
+            "/// Borrowed list of {item_type} passed across the C ABI as a pointer and a count"
+        ))?;
+        f.writeln("#[repr(C)]")?;
+        f.writeln("#[derive(Copy, Clone)]")?;
+        f.writeln(&format!("pub struct {list_name}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("pub items: *const {item_type},"))?;
+            f.writeln("pub count: usize,")
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl {list_name}"))?;
+        blocked(f, |f| {
+            f.writeln("/// Borrow the items as a slice")?;
+            f.writeln("///")?;
+            f.writeln("/// # Safety")?;
+            f.writeln("///")?;
+            f.writeln("/// The pointer must either be NULL or reference at least `count` items that outlive `'a`")?;
+            f.writeln(&format!(
+                "pub unsafe fn to_slice<'a>(&self) -> &'a [{item_type}]"
+            ))?;
+            blocked(f, |f| {
+                f.writeln("if self.items.is_null() || self.count == 0")?;
+                blocked(f, |f| f.writeln("&[]"))?;
+                f.writeln("else")?;
+                blocked(f, |f| {
+                    f.writeln("std::slice::from_raw_parts(self.items, self.count)")
+                })
+            })
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl From<&[{item_type}]> for {list_name}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("fn from(value: &[{item_type}]) -> Self"))?;
+            blocked(f, |f| {
+                f.writeln("Self")?;
+                blocked(f, |f| {
+                    f.writeln("items: value.as_ptr(),")?;
+                    f.writeln("count: value.len(),")
+                })
+            })
+        })
+    }
+
     fn write_struct_definition<T>(
         &self,
         f: &mut dyn Printer,
@@ -163,113 +697,588 @@ impl<'a> RustCodegen<'a> {
                             ampersand = ampersand
                         ))
                     }
-                })?;
+                })?;
+
+                f.newline()?;
+
+                // Mutator
+                f.writeln("#[allow(clippy::needless_lifetimes)]")?;
+                f.writeln(&format!(
+                    "pub fn set_{name}{fn_lifetime}(&{lifetime}mut self, value: {element_type})",
+                    name = field.name,
+                    element_type = field.field_type.as_rust_type(),
+                    fn_lifetime = fn_lifetime,
+                    lifetime = field_lifetime
+                ))?;
+                blocked(f, |f| {
+                    if let Some(conversion) = field.field_type.conversion() {
+                        conversion.convert_to_c(f, "value", &format!("self.{} = ", field.name))?;
+                        f.write(";")
+                    } else {
+                        f.writeln(&format!("self.{} = value;", field.name))
+                    }
+                })?;
+
+                f.newline()?;
+            }
+            Ok(())
+        })?;
+
+        // Write the Rust version with all public fields
+        let rust_struct_name = format!("{struct_name}Fields");
+        if handle.has_conversion() {
+            f.writeln(&format!("pub struct {rust_struct_name}{rust_lifetime}"))?;
+            blocked(f, |f| {
+                for element in &handle.fields {
+                    f.writeln(&format!(
+                        "pub {}: {},",
+                        element.name,
+                        element.field_type.as_rust_type()
+                    ))?;
+                }
+                Ok(())
+            })?;
+
+            // Write the conversion to the C representation
+            f.writeln(&format!(
+                "impl{rust_lifetime} From<{rust_struct_name}{rust_lifetime}> for {struct_name}{c_lifetime}",
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!("fn from(from: {rust_struct_name}) -> Self"))?;
+                blocked(f, |f| {
+                    f.writeln("Self")?;
+                    blocked(f, |f| {
+                        for element in &handle.fields {
+                            if let Some(conversion) = element.field_type.conversion() {
+                                conversion.convert_to_c(
+                                    f,
+                                    &format!("from.{}", element.name),
+                                    &format!("{}: ", element.name),
+                                )?;
+                                f.write(",")?;
+                            } else {
+                                f.writeln(&format!("{name}: from.{name},", name = element.name))?;
+                            }
+                        }
+                        Ok(())
+                    })
+                })
+            })
+        } else {
+            f.writeln(&format!(
+                "pub type {rust_struct_name}{c_lifetime} = {struct_name}{c_lifetime};"
+            ))
+        }
+    }
+
+    fn write_enum_definition(
+        &self,
+        f: &mut dyn Printer,
+        handle: &Handle<Enum<Validated>>,
+    ) -> FormattingResult<()> {
+        let enum_name = handle.name.to_upper_camel_case();
+        f.writeln("#[repr(C)]")?;
+        f.writeln("#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]")?;
+        f.writeln(&format!("pub enum {enum_name}"))?;
+        blocked(f, |f| {
+            for variant in &handle.variants {
+                f.writeln(&format!(
+                    "{} = {},",
+                    variant.name.to_upper_camel_case(),
+                    variant.value
+                ))?;
+            }
+            Ok(())
+        })?;
+
+        // Conversion routines
+        f.writeln(&format!("impl From<{enum_name}> for std::os::raw::c_int"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("fn from(value: {enum_name}) -> Self"))?;
+            blocked(f, |f| {
+                f.writeln("match value")?;
+                blocked(f, |f| {
+                    for variant in &handle.variants {
+                        f.writeln(&format!(
+                            "{}::{} => {},",
+                            enum_name,
+                            variant.name.to_upper_camel_case(),
+                            variant.value
+                        ))?;
+                    }
+                    Ok(())
+                })
+            })
+        })?;
+
+        f.writeln(&format!(
+            "impl std::convert::TryFrom<std::os::raw::c_int> for {enum_name}"
+        ))?;
+        blocked(f, |f| {
+            f.writeln("type Error = std::os::raw::c_int;")?;
+            f.newline()?;
+            f.writeln("fn try_from(value: std::os::raw::c_int) -> Result<Self, Self::Error>")?;
+            blocked(f, |f| {
+                f.writeln("match value")?;
+                blocked(f, |f| {
+                    for variant in &handle.variants {
+                        f.writeln(&format!(
+                            "{} => Ok({}::{}),",
+                            variant.value,
+                            enum_name,
+                            variant.name.to_upper_camel_case(),
+                        ))?;
+                    }
+                    f.writeln("_ => Err(value),")
+                })
+            })
+        })?;
+
+        match &handle.unknown_value {
+            // values received from C are only converted with TryFrom
+            UnknownValuePolicy::Error => Ok(()),
+            UnknownValuePolicy::Panic => Self::write_from_c_int(f, &enum_name, |f| {
+                f.writeln(&format!(
+                    "Err(value) => panic!(\"{{value}} is not a variant of {enum_name}\"),"
+                ))
+            }),
+            UnknownValuePolicy::MapTo(variant) => Self::write_from_c_int(f, &enum_name, |f| {
+                f.writeln(&format!(
+                    "Err(_) => {}::{},",
+                    enum_name,
+                    variant.to_upper_camel_case()
+                ))
+            }),
+            UnknownValuePolicy::Clamp => Self::write_from_c_int(f, &enum_name, |f| {
+                // the greatest variant below the value, otherwise the lowest variant
+                let mut variants: Vec<&EnumVariant<Validated>> = handle.variants.iter().collect();
+                variants.sort_by_key(|x| std::cmp::Reverse(x.value));
+                f.writeln("Err(value) =>")?;
+                blocked(f, |f| {
+                    if let Some((lowest, others)) = variants.split_last() {
+                        for variant in others {
+                            f.writeln(&format!(
+                                "if value > {} {{ return {}::{}; }}",
+                                variant.value,
+                                enum_name,
+                                variant.name.to_upper_camel_case()
+                            ))?;
+                        }
+                        f.writeln(&format!(
+                            "{}::{}",
+                            enum_name,
+                            lowest.name.to_upper_camel_case()
+                        ))?;
+                    }
+                    Ok(())
+                })
+            }),
+        }
+    }
+
+    /// write the conversion of values received from C for enums that don't report unknown values
+    fn write_from_c_int<F>(
+        f: &mut dyn Printer,
+        enum_name: &str,
+        unknown_arm: F,
+    ) -> FormattingResult<()>
+    where
+        F: FnOnce(&mut dyn Printer) -> FormattingResult<()>,
+    {
+        f.writeln(&format!("impl {enum_name}"))?;
+        blocked(f, |f| {
+            f.writeln("/// convert a value received from C, applying the policy for values that aren't variants")?;
+            f.writeln("pub fn from_c_int(value: std::os::raw::c_int) -> Self")?;
+            blocked(f, |f| {
+                f.writeln("match Self::try_from(value)")?;
+                blocked(f, |f| {
+                    f.writeln("Ok(x) => x,")?;
+                    unknown_arm(f)
+                })
+            })
+        })
+    }
+
+    fn write_flags_definition(
+        f: &mut dyn Printer,
+        handle: &Handle<Flags<Validated>>,
+    ) -> FormattingResult<()> {
+        let flags_name = handle.name.to_upper_camel_case();
+        let int_type = Primitive::from(handle.flags_type).get_c_rust_type();
+        let all = handle.bits.iter().fold(0, |acc, bit| acc | bit.value);
+
+        f.writeln(&format!("/// Set of bits stored in a {int_type}"))?;
+        f.writeln("#[repr(transparent)]")?;
+        f.writeln("#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]")?;
+        f.writeln(&format!("pub struct {flags_name}({int_type});"))?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl {flags_name}"))?;
+        blocked(f, |f| {
+            for bit in &handle.bits {
+                f.writeln(&format!(
+                    "pub const {}: Self = Self(0x{:X});",
+                    bit.name.capital_snake_case(),
+                    bit.value
+                ))?;
+            }
+            f.newline()?;
+            f.writeln("/// Set without any bits")?;
+            f.writeln("pub const fn empty() -> Self")?;
+            blocked(f, |f| f.writeln("Self(0)"))?;
+            f.newline()?;
+            f.writeln("/// Set with every defined bit")?;
+            f.writeln("pub const fn all() -> Self")?;
+            blocked(f, |f| f.writeln(&format!("Self(0x{all:X})")))?;
+            f.newline()?;
+            f.writeln("/// Underlying integer value")?;
+            f.writeln(&format!("pub const fn bits(&self) -> {int_type}"))?;
+            blocked(f, |f| f.writeln("self.0"))?;
+            f.newline()?;
+            f.writeln("/// Convert from an integer, returning None if any undefined bit is set")?;
+            f.writeln(&format!(
+                "pub const fn from_bits(bits: {int_type}) -> Option<Self>"
+            ))?;
+            blocked(f, |f| {
+                f.writeln("if bits & !Self::all().0 == 0")?;
+                blocked(f, |f| f.writeln("Some(Self(bits))"))?;
+                f.writeln("else")?;
+                blocked(f, |f| f.writeln("None"))
+            })?;
+            f.newline()?;
+            f.writeln("/// Convert from an integer, clearing any undefined bits")?;
+            f.writeln(&format!(
+                "pub const fn from_bits_truncate(bits: {int_type}) -> Self"
+            ))?;
+            blocked(f, |f| f.writeln("Self(bits & Self::all().0)"))?;
+            f.newline()?;
+            f.writeln("/// Convert from an integer, keeping any undefined bits")?;
+            f.writeln(&format!(
+                "pub const fn from_bits_retain(bits: {int_type}) -> Self"
+            ))?;
+            blocked(f, |f| f.writeln("Self(bits)"))?;
+            f.newline()?;
+            f.writeln("/// True if no bits are set")?;
+            f.writeln("pub const fn is_empty(&self) -> bool")?;
+            blocked(f, |f| f.writeln("self.0 == 0"))?;
+            f.newline()?;
+            f.writeln("/// True if every bit of other is also set in self")?;
+            f.writeln("pub const fn contains(&self, other: Self) -> bool")?;
+            blocked(f, |f| f.writeln("self.0 & other.0 == other.0"))?;
+            f.newline()?;
+            f.writeln("/// True if any bit of other is also set in self")?;
+            f.writeln("pub const fn intersects(&self, other: Self) -> bool")?;
+            blocked(f, |f| f.writeln("self.0 & other.0 != 0"))?;
+            f.newline()?;
+            f.writeln("/// Set the bits of other")?;
+            f.writeln("pub fn insert(&mut self, other: Self)")?;
+            blocked(f, |f| f.writeln("self.0 |= other.0;"))?;
+            f.newline()?;
+            f.writeln("/// Clear the bits of other")?;
+            f.writeln("pub fn remove(&mut self, other: Self)")?;
+            blocked(f, |f| f.writeln("self.0 &= !other.0;"))?;
+            f.newline()?;
+            f.writeln("/// Set or clear the bits of other")?;
+            f.writeln("pub fn set(&mut self, other: Self, value: bool)")?;
+            blocked(f, |f| {
+                f.writeln("if value")?;
+                blocked(f, |f| f.writeln("self.insert(other);"))?;
+                f.writeln("else")?;
+                blocked(f, |f| f.writeln("self.remove(other);"))
+            })
+        })?;
+
+        f.newline()?;
+
+        for (op, method, expr) in [
+            ("BitOr", "bitor", "self.0 | rhs.0"),
+            ("BitAnd", "bitand", "self.0 & rhs.0"),
+            ("BitXor", "bitxor", "self.0 ^ rhs.0"),
+            ("Sub", "sub", "self.0 & !rhs.0"),
+        ] {
+            f.writeln(&format!("impl std::ops::{op} for {flags_name}"))?;
+            blocked(f, |f| {
+                f.writeln("type Output = Self;")?;
+                f.writeln(&format!("fn {method}(self, rhs: Self) -> Self"))?;
+                blocked(f, |f| f.writeln(&format!("Self({expr})")))
+            })?;
+            f.newline()?;
+            f.writeln(&format!("impl std::ops::{op}Assign for {flags_name}"))?;
+            blocked(f, |f| {
+                f.writeln(&format!("fn {method}_assign(&mut self, rhs: Self)"))?;
+                blocked(f, |f| f.writeln(&format!("*self = Self({expr});")))
+            })?;
+            f.newline()?;
+        }
+
+        f.writeln(&format!("impl std::ops::Not for {flags_name}"))?;
+        blocked(f, |f| {
+            f.writeln("type Output = Self;")?;
+            f.writeln("fn not(self) -> Self")?;
+            blocked(f, |f| f.writeln("Self(!self.0 & Self::all().0)"))
+        })?;
+
+        f.newline()?;
+
+        // Conversion routines
+        f.writeln(&format!("impl From<{flags_name}> for {int_type}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("fn from(value: {flags_name}) -> Self"))?;
+            blocked(f, |f| f.writeln("value.0"))
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl From<{int_type}> for {flags_name}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("fn from(value: {int_type}) -> Self"))?;
+            blocked(f, |f| f.writeln(&format!("{flags_name}(value)")))
+        })
+    }
+
+    fn write_error_detail(
+        f: &mut dyn Printer,
+        handle: &ErrorType<Validated>,
+    ) -> FormattingResult<()> {
+        let error_name = handle.inner.name.to_upper_camel_case();
+        let detail_name = format!("{error_name}Detail");
+        let last_detail_name = format!("{error_name}LastDetail");
+        let thread_local_name = format!("{}_LAST_DETAIL", handle.inner.name.capital_snake_case());
+        let payload_name = handle
+            .payload
+            .as_ref()
+            .map(|x| x.name().to_upper_camel_case());
+
+        f.writeln(&format!(
+            "/// {error_name} along with additional detail that the bindings retrieve from the last error on the calling thread"
+        ))?;
+        f.writeln(&format!("pub struct {detail_name}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("pub error: {error_name},"))?;
+            if handle.message {
+                f.writeln("pub message: Option<String>,")?;
+            }
+            if let Some(payload_name) = &payload_name {
+                f.writeln(&format!("pub payload: Option<{payload_name}>,"))?;
+            }
+            Ok(())
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl {detail_name}"))?;
+        blocked(f, |f| {
+            f.writeln("/// Error without any detail")?;
+            f.writeln(&format!("pub fn new(error: {error_name}) -> Self"))?;
+            blocked(f, |f| {
+                f.writeln("Self")?;
+                blocked(f, |f| {
+                    f.writeln("error,")?;
+                    if handle.message {
+                        f.writeln("message: None,")?;
+                    }
+                    if payload_name.is_some() {
+                        f.writeln("payload: None,")?;
+                    }
+                    Ok(())
+                })
+            })?;
 
+            if handle.message {
                 f.newline()?;
+                f.writeln("/// Attach a message to the error")?;
+                f.writeln("pub fn with_message<S: Into<String>>(self, message: S) -> Self")?;
+                blocked(f, |f| {
+                    f.writeln("Self { message: Some(message.into()), ..self }")
+                })?;
+            }
 
-                // Mutator
-                f.writeln("#[allow(clippy::needless_lifetimes)]")?;
+            if let Some(payload_name) = &payload_name {
+                f.newline()?;
+                f.writeln("/// Attach a payload to the error")?;
                 f.writeln(&format!(
-                    "pub fn set_{name}{fn_lifetime}(&{lifetime}mut self, value: {element_type})",
-                    name = field.name,
-                    element_type = field.field_type.as_rust_type(),
-                    fn_lifetime = fn_lifetime,
-                    lifetime = field_lifetime
+                    "pub fn with_payload<P: Into<{payload_name}>>(self, payload: P) -> Self"
                 ))?;
                 blocked(f, |f| {
-                    if let Some(conversion) = field.field_type.conversion() {
-                        conversion.convert_to_c(f, "value", &format!("self.{} = ", field.name))?;
-                        f.write(";")
-                    } else {
-                        f.writeln(&format!("self.{} = value;", field.name))
-                    }
+                    f.writeln("Self { payload: Some(payload.into()), ..self }")
                 })?;
+            }
 
-                f.newline()?;
+            f.newline()?;
+
+            f.writeln("// record the detail as the last error on this thread")?;
+            f.writeln(&format!("fn record(self) -> {error_name}"))?;
+            blocked(f, |f| {
+                f.writeln(&format!("{thread_local_name}.with(|last|"))?;
+                blocked(f, |f| {
+                    f.writeln(&format!("*last.borrow_mut() = {last_detail_name}"))?;
+                    blocked(f, |f| {
+                        if handle.message {
+                            f.writeln("// interior NUL bytes would truncate the message")?;
+                            f.writeln("message: self.message.and_then(|x| std::ffi::CString::new(x.replace('\\0', \"\")).ok()),")?;
+                        }
+                        if payload_name.is_some() {
+                            f.writeln("payload: self.payload,")?;
+                        }
+                        Ok(())
+                    })?;
+                    f.write(";")
+                })?;
+                f.write(");")?;
+                f.writeln("self.error")
+            })
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl From<{error_name}> for {detail_name}"))?;
+        blocked(f, |f| {
+            f.writeln(&format!("fn from(error: {error_name}) -> Self"))?;
+            blocked(f, |f| f.writeln("Self::new(error)"))
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("struct {last_detail_name}"))?;
+        blocked(f, |f| {
+            if handle.message {
+                f.writeln("message: Option<std::ffi::CString>,")?;
+            }
+            if let Some(payload_name) = &payload_name {
+                f.writeln(&format!("payload: Option<{payload_name}>,"))?;
             }
             Ok(())
         })?;
 
-        // Write the Rust version with all public fields
-        let rust_struct_name = format!("{struct_name}Fields");
-        if handle.has_conversion() {
-            f.writeln(&format!("pub struct {rust_struct_name}{rust_lifetime}"))?;
+        f.newline()?;
+
+        f.writeln("thread_local!")?;
+        blocked(f, |f| {
+            f.writeln(&format!(
+                "static {thread_local_name}: std::cell::RefCell<{last_detail_name}> = const {{ std::cell::RefCell::new({last_detail_name}"
+            ))?;
             blocked(f, |f| {
-                for element in &handle.fields {
-                    f.writeln(&format!(
-                        "pub {}: {},",
-                        element.name,
-                        element.field_type.as_rust_type()
-                    ))?;
+                if handle.message {
+                    f.writeln("message: None,")?;
+                }
+                if payload_name.is_some() {
+                    f.writeln("payload: None,")?;
                 }
                 Ok(())
             })?;
+            f.write(") };")
+        })?;
 
-            // Write the conversion to the C representation
+        if handle.message {
+            f.newline()?;
+            f.writeln("#[no_mangle]")?;
             f.writeln(&format!(
-                "impl{rust_lifetime} From<{rust_struct_name}{rust_lifetime}> for {struct_name}{c_lifetime}",
+                "pub extern \"C\" fn {}() -> *const std::os::raw::c_char",
+                handle.last_message_function_name()
             ))?;
             blocked(f, |f| {
-                f.writeln(&format!("fn from(from: {rust_struct_name}) -> Self"))?;
-                blocked(f, |f| {
-                    f.writeln("Self")?;
-                    blocked(f, |f| {
-                        for element in &handle.fields {
-                            if let Some(conversion) = element.field_type.conversion() {
-                                conversion.convert_to_c(
-                                    f,
-                                    &format!("from.{}", element.name),
-                                    &format!("{}: ", element.name),
-                                )?;
-                                f.write(",")?;
-                            } else {
-                                f.writeln(&format!("{name}: from.{name},", name = element.name))?;
-                            }
-                        }
-                        Ok(())
-                    })
-                })
-            })
-        } else {
+                f.writeln(&format!("{thread_local_name}.with(|last| last.borrow().message.as_ref().map_or(std::ptr::null(), |x| x.as_ptr()))"))
+            })?;
+        }
+
+        if let Some(payload_name) = &payload_name {
+            f.newline()?;
+            f.writeln("#[no_mangle]")?;
             f.writeln(&format!(
-                "pub type {rust_struct_name}{c_lifetime} = {struct_name}{c_lifetime};"
-            ))
+                "pub extern \"C\" fn {}() -> *const {payload_name}",
+                handle.last_payload_function_name()
+            ))?;
+            blocked(f, |f| {
+                f.writeln(&format!("{thread_local_name}.with(|last| last.borrow().payload.as_ref().map_or(std::ptr::null(), |x| x as *const _))"))
+            })?;
         }
+
+        Ok(())
     }
 
-    fn write_enum_definition(
+    fn write_union_definition(
         &self,
         f: &mut dyn Printer,
-        handle: &Handle<Enum<Validated>>,
+        handle: &Handle<Union<Validated>>,
     ) -> FormattingResult<()> {
-        let enum_name = handle.name.to_upper_camel_case();
+        let union_name = handle.name.to_upper_camel_case();
+        let value_name = format!("{union_name}Value");
+        let variant_name = format!("{union_name}Variant");
+
+        // Write the C representation: a tag and an untagged union of the payloads
         f.writeln("#[repr(C)]")?;
-        f.writeln("#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]")?;
-        f.writeln(&format!("pub enum {enum_name}"))?;
+        f.writeln(&format!("pub union {value_name}"))?;
         blocked(f, |f| {
             for variant in &handle.variants {
                 f.writeln(&format!(
-                    "{} = {},",
+                    "pub {}: std::mem::ManuallyDrop<{}>,",
+                    variant.name,
+                    variant.value.name().to_upper_camel_case()
+                ))?;
+            }
+            Ok(())
+        })?;
+
+        f.newline()?;
+
+        f.writeln("#[repr(C)]")?;
+        f.writeln(&format!("pub struct {union_name}"))?;
+        blocked(f, |f| {
+            f.writeln("pub tag: std::os::raw::c_int,")?;
+            f.writeln(&format!("pub value: {value_name},"))
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!("impl Clone for {union_name}"))?;
+        blocked(f, |f| {
+            f.writeln("fn clone(&self) -> Self")?;
+            blocked(f, |f| {
+                f.writeln(&format!("match {variant_name}::try_from(self)"))?;
+                blocked(f, |f| {
+                    f.writeln("Ok(x) => x.into(),")?;
+                    // no variant is active, so there is no payload to clone
+                    f.writeln(
+                        "Err(tag) => Self { tag, value: unsafe { std::ptr::read(&self.value) } },",
+                    )
+                })
+            })
+        })?;
+
+        f.newline()?;
+
+        // Write the rust representation
+        f.writeln("#[derive(Clone)]")?;
+        f.writeln(&format!("pub enum {variant_name}"))?;
+        blocked(f, |f| {
+            for variant in &handle.variants {
+                f.writeln(&format!(
+                    "{}({}),",
                     variant.name.to_upper_camel_case(),
-                    variant.value
+                    variant.value.name().to_upper_camel_case()
                 ))?;
             }
             Ok(())
         })?;
 
+        f.newline()?;
+
         // Conversion routines
-        f.writeln(&format!("impl From<{enum_name}> for std::os::raw::c_int"))?;
+        f.writeln(&format!("impl From<{variant_name}> for {union_name}"))?;
         blocked(f, |f| {
-            f.writeln(&format!("fn from(value: {enum_name}) -> Self"))?;
+            f.writeln(&format!("fn from(from: {variant_name}) -> Self"))?;
             blocked(f, |f| {
-                f.writeln("match value")?;
+                f.writeln("match from")?;
                 blocked(f, |f| {
-                    for variant in &handle.variants {
+                    for (index, variant) in handle.variants.iter().enumerate() {
                         f.writeln(&format!(
-                            "{}::{} => {},",
-                            enum_name,
+                            "{}::{}(x) => Self {{ tag: {}, value: {} {{ {}: std::mem::ManuallyDrop::new(x) }} }},",
+                            variant_name,
                             variant.name.to_upper_camel_case(),
-                            variant.value
+                            index,
+                            value_name,
+                            variant.name
                         ))?;
                     }
                     Ok(())
@@ -277,23 +1286,58 @@ impl<'a> RustCodegen<'a> {
             })
         })?;
 
-        f.writeln(&format!("impl From<std::os::raw::c_int> for {enum_name}"))?;
+        f.newline()?;
+
+        f.writeln(&format!(
+            "impl std::convert::TryFrom<&{union_name}> for {variant_name}"
+        ))?;
         blocked(f, |f| {
-            f.writeln("fn from(value: std::os::raw::c_int) -> Self")?;
+            f.writeln("type Error = std::os::raw::c_int;")?;
+            f.newline()?;
+            f.writeln(&format!(
+                "fn try_from(from: &{union_name}) -> Result<Self, Self::Error>"
+            ))?;
             blocked(f, |f| {
-                f.writeln("match value")?;
+                f.writeln("match from.tag")?;
                 blocked(f, |f| {
-                    for variant in &handle.variants {
+                    for (index, variant) in handle.variants.iter().enumerate() {
                         f.writeln(&format!(
-                            "{} => {}::{},",
-                            variant.value,
-                            enum_name,
+                            "{} => Ok({}::{}(unsafe {{ (*from.value.{}).clone() }})),",
+                            index,
+                            variant_name,
                             variant.name.to_upper_camel_case(),
+                            variant.name
                         ))?;
                     }
-                    f.writeln(&format!(
-                        "_ => panic!(\"{{value}} is not a variant of {enum_name}\"),"
-                    ))
+                    f.writeln("tag => Err(tag),")
+                })
+            })
+        })?;
+
+        f.newline()?;
+
+        f.writeln(&format!(
+            "impl std::convert::TryFrom<{union_name}> for {variant_name}"
+        ))?;
+        blocked(f, |f| {
+            f.writeln("type Error = std::os::raw::c_int;")?;
+            f.newline()?;
+            f.writeln(&format!(
+                "fn try_from(from: {union_name}) -> Result<Self, Self::Error>"
+            ))?;
+            blocked(f, |f| {
+                f.writeln("match from.tag")?;
+                blocked(f, |f| {
+                    for (index, variant) in handle.variants.iter().enumerate() {
+                        f.writeln(&format!(
+                            "{} => Ok({}::{}(std::mem::ManuallyDrop::into_inner(unsafe {{ from.value.{} }}))),",
+                            index,
+                            variant_name,
+                            variant.name.to_upper_camel_case(),
+                            variant.name
+                        ))?;
+                    }
+                    f.writeln("tag => Err(tag),")
                 })
             })
         })
@@ -303,6 +1347,7 @@ impl<'a> RustCodegen<'a> {
         f: &mut dyn Printer,
         handle: &Handle<Function<Validated>>,
         prefix: &str,
+        cancellable: Option<&FutureMethod<Validated>>,
     ) -> FormattingResult<()> {
         f.writeln("#[allow(clippy::missing_safety_doc)]")?;
         f.writeln("#[no_mangle]")?;
@@ -320,6 +1365,22 @@ impl<'a> RustCodegen<'a> {
                 .join(", "),
         )?;
 
+        fn write_error_arm(
+            f: &mut dyn Printer,
+            err: &ErrorType<Validated>,
+            converter: &TypeConverter,
+        ) -> FormattingResult<()> {
+            if err.has_detail() {
+                // the implementation may return either the error or its detail
+                f.writeln("#[allow(clippy::useless_conversion)]")?;
+                f.writeln(&format!(
+                    "let err = {}Detail::from(err).record();",
+                    err.inner.name.to_upper_camel_case()
+                ))?;
+            }
+            converter.convert_to_c(f, "err", "")
+        }
+
         fn write_error_return(
             f: &mut dyn Printer,
             _error: &ErrorType<Validated>,
@@ -347,14 +1408,60 @@ impl<'a> RustCodegen<'a> {
             }
         }
 
-        blocked(f, |f| {
+        let write_body = |f: &mut dyn Printer| {
             for param in &handle.arguments {
-                if let Some(converter) = param.arg_type.conversion() {
-                    converter.convert_from_c(f, &param.name, &format!("let {} = ", param.name))?;
+                if let Some(method) = Self::reference_conversion(handle, param) {
+                    // the implementation receives a reference that is never null
+                    f.writeln(&format!(
+                        "let {} = match {}.{}()",
+                        param.name, param.name, method
+                    ))?;
+                    blocked(f, |f| {
+                        f.writeln("Some(x) => x,")?;
+                        f.writeln("None =>")?;
+                        blocked(f, |f| Self::write_null_argument(f, handle))
+                    })?;
+                    f.write(";")?;
+                } else if let Some(converter) = param.arg_type.conversion() {
+                    match Self::bad_argument_error(handle, param) {
+                        Some((err, variant)) => {
+                            // the conversion fails for values that can't be converted
+                            converter.convert_from_c(
+                                f,
+                                &param.name,
+                                &format!("let {} = match ", param.name),
+                            )?;
+                            blocked(f, |f| {
+                                f.writeln("Ok(x) => x,")?;
+                                f.writeln("Err(_) =>")?;
+                                blocked(f, |f| Self::write_error_value(f, err, variant, "return "))
+                            })?;
+                        }
+                        None => {
+                            converter.convert_from_c(
+                                f,
+                                &param.name,
+                                &format!("let {} = ", param.name),
+                            )?;
+                        }
+                    }
                     f.write(";")?;
                 }
             }
 
+            // the implementation completes a wrapper that may also be completed by the cancel function
+            if let Some(method) = cancellable {
+                let callback = &method
+                    .future
+                    .interface
+                    .settings
+                    .future
+                    .async_method_callback_parameter_name;
+                f.writeln(&format!(
+                    "let ({callback}, _operation) = {callback}.into_cancellable();"
+                ))?;
+            }
+
             fn basic_invocation(f: &mut dyn Printer, name: &str) -> FormattingResult<()> {
                 f.writeln(&format!("crate::{name}("))
             }
@@ -388,6 +1495,10 @@ impl<'a> RustCodegen<'a> {
 
             match handle.get_signature_type() {
                 SignatureType::NoErrorNoReturn => {}
+                SignatureType::NoErrorWithReturn(_, _) if cancellable.is_some() => {
+                    f.write(";")?;
+                    f.writeln("_operation")?;
+                }
                 SignatureType::NoErrorWithReturn(ret, _) => {
                     if let Some(conversion) = ret.conversion() {
                         f.write(";")?;
@@ -406,15 +1517,21 @@ impl<'a> RustCodegen<'a> {
                             )
                         })?;
                         f.writeln("Err(err) =>")?;
-                        blocked(f, |f| converter.convert_to_c(f, "err", ""))
+                        blocked(f, |f| write_error_arm(f, &err, &converter))
                     })?;
                 }
                 SignatureType::ErrorWithReturn(err, result_type, _) => {
                     blocked(f, |f| {
                         let converter = TypeConverter::ValidatedEnum(err.inner.clone());
-                        f.writeln("Ok(x) =>")?;
+                        if cancellable.is_some() {
+                            f.writeln("Ok(()) =>")?;
+                        } else {
+                            f.writeln("Ok(x) =>")?;
+                        }
                         blocked(f, |f| {
-                            if let Some(converter) = result_type.conversion() {
+                            if cancellable.is_some() {
+                                f.writeln("let x = _operation;")?;
+                            } else if let Some(converter) = result_type.conversion() {
                                 converter.convert_to_c(f, "x", "let x = ")?;
                                 f.write(";")?;
                             }
@@ -426,15 +1543,152 @@ impl<'a> RustCodegen<'a> {
                             )
                         })?;
                         f.writeln("Err(err) =>")?;
-                        blocked(f, |f| converter.convert_to_c(f, "err", ""))
+                        blocked(f, |f| write_error_arm(f, &err, &converter))
                     })?;
                 }
             }
 
             Ok(())
+        };
+
+        // the value returned to the caller when the implementation panics
+        let write_fallback = |f: &mut dyn Printer| {
+            let fallback = handle.settings.panic.fallback;
+            match handle.get_signature_type() {
+                SignatureType::NoErrorNoReturn => match fallback {
+                    PanicFallback::DefaultOrAbort => Ok(()),
+                    PanicFallback::Abort => f.writeln("std::process::abort()"),
+                },
+                SignatureType::NoErrorWithReturn(ret, _) => match (fallback, ret.default_value()) {
+                    (PanicFallback::DefaultOrAbort, Some(value)) => {
+                        Self::write_default_value(f, value, "")
+                    }
+                    _ => f.writeln("std::process::abort()"),
+                },
+                SignatureType::ErrorNoReturn(err) | SignatureType::ErrorWithReturn(err, _, _) => {
+                    Self::write_error_value(f, &err, &handle.settings.panic.error_variant_name, "")
+                }
+            }
+        };
+
+        blocked(f, |f| {
+            Self::catch_unwind(
+                f,
+                &handle.settings.panic,
+                &format!("{}_{}", prefix, handle.name),
+                write_body,
+                write_fallback,
+            )
         })
     }
 
+    /// error variant reported when an argument can't be converted
+    ///
+    /// Functions with such arguments are required to have a bad argument error when they are built
+    fn bad_argument_error<'b>(
+        handle: &'b Function<Validated>,
+        param: &Arg<FunctionArgument, Validated>,
+    ) -> Option<(&'b ErrorType<Validated>, &'b Name)> {
+        if !param.arg_type.can_be_invalid() {
+            return None;
+        }
+        let err = handle.error_type.get()?;
+        err.bad_argument_variant
+            .as_ref()
+            .map(|variant| (err, variant))
+    }
+
+    /// method converting a pointer argument into the reference passed to the implementation
+    fn reference_conversion(
+        handle: &Function<Validated>,
+        param: &Arg<FunctionArgument, Validated>,
+    ) -> Option<&'static str> {
+        if !param.arg_type.is_null_checked(handle.category) {
+            return None;
+        }
+        match &param.arg_type {
+            // class instances may be shared, so exclusive access is opt-in
+            FunctionArgument::ClassRef(_) if param.mutable => Some("as_mut"),
+            _ => Some("as_ref"),
+        }
+    }
+
+    /// return early from a function that received a null pointer
+    ///
+    /// Functions report it with their bad argument error if they have one, otherwise they return
+    /// a value of their return type which is required to exist when they are built
+    fn write_null_argument(
+        f: &mut dyn Printer,
+        handle: &Function<Validated>,
+    ) -> FormattingResult<()> {
+        if handle.settings.null_argument == NullArgumentFallback::Abort {
+            return f.writeln("std::process::abort()");
+        }
+
+        let null_argument_value = |f: &mut dyn Printer, to: &str| match handle
+            .return_type
+            .get_value()
+            .and_then(|x| x.null_argument_value())
+        {
+            Some(value) => Self::write_default_value(f, value, to),
+            None => unreachable!("functions without a value to return for null arguments abort"),
+        };
+
+        match handle.get_signature_type() {
+            SignatureType::NoErrorNoReturn => f.writeln("return;"),
+            SignatureType::NoErrorWithReturn(_, _) => null_argument_value(f, "return "),
+            SignatureType::ErrorNoReturn(err) | SignatureType::ErrorWithReturn(err, _, _) => {
+                if let Some(variant) = &err.bad_argument_variant {
+                    return Self::write_error_value(f, &err, variant, "return ");
+                }
+                if handle.return_type.get_value().is_some() {
+                    null_argument_value(f, "out.write(")?;
+                    f.write(");")?;
+                }
+                let converter = TypeConverter::ValidatedEnum(err.inner.clone());
+                converter.convert_to_c(
+                    f,
+                    &format!("{}::Ok", err.inner.name.to_upper_camel_case()),
+                    "return ",
+                )
+            }
+        }
+    }
+
+    /// write the default of a return value
+    fn write_default_value(
+        f: &mut dyn Printer,
+        value: ReturnDefault,
+        to: &str,
+    ) -> FormattingResult<()> {
+        match value {
+            ReturnDefault::Zero => f.writeln(&format!("{to}Default::default()")),
+            ReturnDefault::Null => f.writeln(&format!("{to}std::ptr::null()")),
+            ReturnDefault::NullMut => f.writeln(&format!("{to}std::ptr::null_mut()")),
+        }
+    }
+
+    /// write the C value of an error variant, recording it as the last error if it has detail
+    fn write_error_value(
+        f: &mut dyn Printer,
+        err: &ErrorType<Validated>,
+        variant: &Name,
+        to: &str,
+    ) -> FormattingResult<()> {
+        let error_name = err.inner.name.to_upper_camel_case();
+        let variant = format!("{error_name}::{}", variant.to_upper_camel_case());
+        let converter = TypeConverter::ValidatedEnum(err.inner.clone());
+        if err.has_detail() {
+            // replace any detail left over from a previous error
+            f.writeln(&format!(
+                "let err = {error_name}Detail::new({variant}).record();"
+            ))?;
+            converter.convert_to_c(f, "err", to)
+        } else {
+            converter.convert_to_c(f, &variant, to)
+        }
+    }
+
     fn write_interface(
         &self,
         f: &mut dyn Printer,
@@ -533,6 +1787,7 @@ impl<'a> RustCodegen<'a> {
             InterfaceCategory::Synchronous => false,
             InterfaceCategory::Asynchronous => true,
             InterfaceCategory::Future => true,
+            InterfaceCategory::Stream => true,
         };
 
         // Send/Sync trait
@@ -575,7 +1830,7 @@ impl<'a> RustCodegen<'a> {
                 }
 
                 // Function body
-                blocked(f, |f| {
+                let write_body = |f: &mut dyn Printer| {
                     for arg in &callback.arguments {
                         if let Some(converter) = arg.arg_type.conversion() {
                             converter.convert_to_c(
@@ -600,11 +1855,20 @@ impl<'a> RustCodegen<'a> {
                     let call = format!("cb({params})");
 
                     if let Some(v) = &callback.return_type.get_value() {
-                        f.writeln(&format!("self.{}.map(|cb| ", callback.name))?;
+                        if v.can_be_invalid() {
+                            // values that can't be converted are returned as if the callback wasn't set
+                            f.writeln(&format!("self.{}.and_then(|cb| ", callback.name))?;
+                        } else {
+                            f.writeln(&format!("self.{}.map(|cb| ", callback.name))?;
+                        }
                         blocked(f, |f| {
                             if let Some(conversion) = v.conversion() {
                                 f.writeln(&format!("let _result = {call};"))?;
-                                conversion.convert_from_c(f, "_result", "")
+                                conversion.convert_from_c(f, "_result", "")?;
+                                if v.can_be_invalid() {
+                                    f.write(".ok()")?;
+                                }
+                                Ok(())
                             } else {
                                 f.writeln(&call)
                             }
@@ -620,6 +1884,22 @@ impl<'a> RustCodegen<'a> {
                     }
 
                     Ok(())
+                };
+
+                blocked(f, |f| {
+                    Self::catch_unwind(
+                        f,
+                        &settings.panic,
+                        &format!("{}::{}", name, callback.name),
+                        write_body,
+                        |f| {
+                            if callback.return_type.is_none() {
+                                Ok(())
+                            } else {
+                                f.writeln("None")
+                            }
+                        },
+                    )
                 })?;
             }
             Ok(())
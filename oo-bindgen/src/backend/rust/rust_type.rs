@@ -71,8 +71,11 @@ impl RustType for BasicType {
     fn as_rust_type(&self) -> String {
         match self {
             Self::Primitive(x) => x.as_rust_type(),
+            Self::Duration(DurationType::SignedMilliseconds) => Primitive::S64.as_rust_type(),
             Self::Duration(_) => "std::time::Duration".to_string(),
+            Self::Timestamp(_) => "std::time::SystemTime".to_string(),
             Self::Enum(handle) => handle.name.to_upper_camel_case(),
+            Self::Flags(handle) => handle.name.to_upper_camel_case(),
         }
     }
 
@@ -87,8 +90,11 @@ impl RustType for BasicType {
     fn conversion(&self) -> Option<TypeConverter> {
         match self {
             Self::Primitive(x) => x.conversion(),
+            Self::Duration(DurationType::SignedMilliseconds) => None,
             Self::Duration(x) => Some(TypeConverter::Duration(*x)),
+            Self::Timestamp(x) => Some(TypeConverter::Timestamp(*x)),
             Self::Enum(x) => Some(TypeConverter::UnvalidatedEnum(x.clone())),
+            Self::Flags(x) => Some(TypeConverter::Flags(x.clone())),
         }
     }
 }
@@ -103,6 +109,36 @@ impl LifetimeInfo for StringType {
     }
 }
 
+impl LifetimeInfo for BytesType {
+    fn rust_requires_lifetime(&self) -> bool {
+        true
+    }
+
+    fn c_requires_lifetime(&self) -> bool {
+        false
+    }
+}
+
+impl LifetimeInfo for ListHandle {
+    fn rust_requires_lifetime(&self) -> bool {
+        true
+    }
+
+    fn c_requires_lifetime(&self) -> bool {
+        false
+    }
+}
+
+impl LifetimeInfo for Optional<BasicType> {
+    fn rust_requires_lifetime(&self) -> bool {
+        false
+    }
+
+    fn c_requires_lifetime(&self) -> bool {
+        false
+    }
+}
+
 impl<D> LifetimeInfo for Handle<Collection<D>>
 where
     D: DocReference,
@@ -116,6 +152,32 @@ where
     }
 }
 
+impl<D> LifetimeInfo for Handle<Map<D>>
+where
+    D: DocReference,
+{
+    fn rust_requires_lifetime(&self) -> bool {
+        false
+    }
+
+    fn c_requires_lifetime(&self) -> bool {
+        false
+    }
+}
+
+impl<D> LifetimeInfo for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn rust_requires_lifetime(&self) -> bool {
+        false
+    }
+
+    fn c_requires_lifetime(&self) -> bool {
+        false
+    }
+}
+
 impl<T> LifetimeInfo for UniversalOr<T>
 where
     T: StructFieldType + LifetimeInfo,
@@ -204,7 +266,11 @@ impl LifetimeInfo for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.rust_requires_lifetime(),
             FunctionArgument::String(x) => x.rust_requires_lifetime(),
+            FunctionArgument::Bytes(x) => x.rust_requires_lifetime(),
+            FunctionArgument::Union(x) => x.rust_requires_lifetime(),
+            FunctionArgument::Optional(x) => x.rust_requires_lifetime(),
             FunctionArgument::Collection(x) => x.rust_requires_lifetime(),
+            FunctionArgument::Map(x) => x.rust_requires_lifetime(),
             FunctionArgument::Struct(x) => x.rust_requires_lifetime(),
             FunctionArgument::StructRef(x) => x.inner.rust_requires_lifetime(),
             FunctionArgument::ClassRef(x) => x.rust_requires_lifetime(),
@@ -216,7 +282,11 @@ impl LifetimeInfo for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.c_requires_lifetime(),
             FunctionArgument::String(x) => x.c_requires_lifetime(),
+            FunctionArgument::Bytes(x) => x.c_requires_lifetime(),
+            FunctionArgument::Union(x) => x.c_requires_lifetime(),
+            FunctionArgument::Optional(x) => x.c_requires_lifetime(),
             FunctionArgument::Collection(x) => x.c_requires_lifetime(),
+            FunctionArgument::Map(x) => x.c_requires_lifetime(),
             FunctionArgument::Struct(x) => x.c_requires_lifetime(),
             FunctionArgument::StructRef(x) => x.inner.c_requires_lifetime(),
             FunctionArgument::ClassRef(x) => x.c_requires_lifetime(),
@@ -243,6 +313,88 @@ impl RustType for StringType {
     }
 }
 
+impl RustType for BytesType {
+    fn as_rust_type(&self) -> String {
+        "&'a [u8]".to_string()
+    }
+
+    fn as_c_type(&self) -> String {
+        "ByteSlice".to_string()
+    }
+
+    fn is_copyable(&self) -> bool {
+        true // just copying the pointer and length
+    }
+
+    fn conversion(&self) -> Option<TypeConverter> {
+        Some(TypeConverter::Bytes(*self))
+    }
+}
+
+impl ListItemType {
+    /// type of the items as they are laid out in memory for the C FFI
+    pub(crate) fn as_c_type(&self) -> String {
+        match self {
+            Self::Basic(x) => x.get_c_rust_type().to_string(),
+            Self::Struct(x) => x.name().to_upper_camel_case(),
+        }
+    }
+}
+
+impl RustType for ListHandle {
+    fn as_rust_type(&self) -> String {
+        format!("&'a [{}]", self.item_type.as_c_type())
+    }
+
+    fn as_c_type(&self) -> String {
+        self.name.to_upper_camel_case()
+    }
+
+    fn is_copyable(&self) -> bool {
+        true // just copying the pointer and count
+    }
+
+    fn conversion(&self) -> Option<TypeConverter> {
+        Some(TypeConverter::List(self.clone()))
+    }
+}
+
+impl RustType for Optional<BasicType> {
+    fn as_rust_type(&self) -> String {
+        format!("Option<{}>", self.inner.as_rust_type())
+    }
+
+    fn as_c_type(&self) -> String {
+        format!("Optional<{}>", self.inner.as_c_type())
+    }
+
+    fn is_copyable(&self) -> bool {
+        true
+    }
+
+    fn conversion(&self) -> Option<TypeConverter> {
+        Some(TypeConverter::Optional(self.clone()))
+    }
+}
+
+impl RustType for UnionHandle {
+    fn as_rust_type(&self) -> String {
+        format!("{}Variant", self.name.to_upper_camel_case())
+    }
+
+    fn as_c_type(&self) -> String {
+        self.name.to_upper_camel_case()
+    }
+
+    fn is_copyable(&self) -> bool {
+        false
+    }
+
+    fn conversion(&self) -> Option<TypeConverter> {
+        Some(TypeConverter::Union(self.clone()))
+    }
+}
+
 impl<D> RustType for Handle<Collection<D>>
 where
     D: DocReference,
@@ -265,6 +417,28 @@ where
     }
 }
 
+impl<D> RustType for Handle<Map<D>>
+where
+    D: DocReference,
+{
+    fn as_rust_type(&self) -> String {
+        format!("*mut crate::{}", self.name().to_upper_camel_case())
+    }
+
+    fn as_c_type(&self) -> String {
+        format!("*mut crate::{}", self.name().to_upper_camel_case())
+    }
+
+    fn is_copyable(&self) -> bool {
+        // just copying the pointer
+        true
+    }
+
+    fn conversion(&self) -> Option<TypeConverter> {
+        None
+    }
+}
+
 impl<T> RustType for UniversalOr<T>
 where
     T: StructFieldType + LifetimeInfo,
@@ -378,6 +552,7 @@ where
             InterfaceCategory::Synchronous => None,
             InterfaceCategory::Asynchronous => None,
             InterfaceCategory::Future => None,
+            InterfaceCategory::Stream => None,
         }
     }
 }
@@ -418,7 +593,11 @@ impl RustType for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.as_rust_type(),
             FunctionArgument::String(x) => x.as_rust_type(),
+            FunctionArgument::Bytes(x) => x.as_rust_type(),
+            FunctionArgument::Union(x) => x.as_rust_type(),
+            FunctionArgument::Optional(x) => x.as_rust_type(),
             FunctionArgument::Collection(x) => x.as_rust_type(),
+            FunctionArgument::Map(x) => x.as_rust_type(),
             FunctionArgument::Struct(x) => x.as_rust_type(),
             FunctionArgument::StructRef(x) => x.inner.as_rust_type(),
             FunctionArgument::ClassRef(x) => x.as_rust_type(),
@@ -430,7 +609,11 @@ impl RustType for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.as_c_type(),
             FunctionArgument::String(x) => x.as_c_type(),
+            FunctionArgument::Bytes(x) => x.as_c_type(),
+            FunctionArgument::Union(x) => x.as_c_type(),
+            FunctionArgument::Optional(x) => x.as_c_type(),
             FunctionArgument::Collection(x) => x.as_c_type(),
+            FunctionArgument::Map(x) => x.as_c_type(),
             FunctionArgument::Struct(x) => x.as_c_type(),
             FunctionArgument::StructRef(x) => x.inner.as_c_type(),
             FunctionArgument::ClassRef(x) => x.as_c_type(),
@@ -442,7 +625,11 @@ impl RustType for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.is_copyable(),
             FunctionArgument::String(x) => x.is_copyable(),
+            FunctionArgument::Bytes(x) => x.is_copyable(),
+            FunctionArgument::Union(x) => x.is_copyable(),
+            FunctionArgument::Optional(x) => x.is_copyable(),
             FunctionArgument::Collection(x) => x.is_copyable(),
+            FunctionArgument::Map(x) => x.is_copyable(),
             FunctionArgument::Struct(x) => x.is_copyable(),
             FunctionArgument::StructRef(x) => x.inner.is_copyable(),
             FunctionArgument::ClassRef(x) => x.is_copyable(),
@@ -454,7 +641,11 @@ impl RustType for FunctionArgument {
         match self {
             FunctionArgument::Basic(x) => x.conversion(),
             FunctionArgument::String(x) => x.conversion(),
+            FunctionArgument::Bytes(x) => x.conversion(),
+            FunctionArgument::Union(x) => x.conversion(),
+            FunctionArgument::Optional(x) => x.conversion(),
             FunctionArgument::Collection(x) => x.conversion(),
+            FunctionArgument::Map(x) => x.conversion(),
             FunctionArgument::Struct(x) => x.conversion(),
             FunctionArgument::StructRef(x) => x.inner.conversion(),
             FunctionArgument::ClassRef(x) => x.conversion(),
@@ -473,15 +664,29 @@ impl LifetimeInfo for PrimitiveRef {
     }
 }
 
+impl LifetimeInfo for ValueRef {
+    fn rust_requires_lifetime(&self) -> bool {
+        false
+    }
+
+    fn c_requires_lifetime(&self) -> bool {
+        false
+    }
+}
+
 impl LifetimeInfo for FunctionReturnValue {
     fn rust_requires_lifetime(&self) -> bool {
         match self {
             FunctionReturnValue::Basic(x) => x.rust_requires_lifetime(),
             FunctionReturnValue::String(x) => x.rust_requires_lifetime(),
+            FunctionReturnValue::Bytes(x) => x.rust_requires_lifetime(),
+            FunctionReturnValue::Union(x) => x.rust_requires_lifetime(),
             FunctionReturnValue::ClassRef(x) => x.rust_requires_lifetime(),
+            FunctionReturnValue::Iterator(x) => x.rust_requires_lifetime(),
             FunctionReturnValue::Struct(x) => x.rust_requires_lifetime(),
             FunctionReturnValue::StructRef(x) => x.untyped().rust_requires_lifetime(),
             FunctionReturnValue::PrimitiveRef(x) => x.rust_requires_lifetime(),
+            FunctionReturnValue::ValueRef(x) => x.rust_requires_lifetime(),
         }
     }
 
@@ -489,10 +694,14 @@ impl LifetimeInfo for FunctionReturnValue {
         match self {
             FunctionReturnValue::Basic(x) => x.c_requires_lifetime(),
             FunctionReturnValue::String(x) => x.c_requires_lifetime(),
+            FunctionReturnValue::Bytes(x) => x.c_requires_lifetime(),
+            FunctionReturnValue::Union(x) => x.c_requires_lifetime(),
             FunctionReturnValue::ClassRef(x) => x.c_requires_lifetime(),
+            FunctionReturnValue::Iterator(x) => x.c_requires_lifetime(),
             FunctionReturnValue::Struct(x) => x.c_requires_lifetime(),
             FunctionReturnValue::StructRef(x) => x.untyped().c_requires_lifetime(),
             FunctionReturnValue::PrimitiveRef(x) => x.c_requires_lifetime(),
+            FunctionReturnValue::ValueRef(x) => x.c_requires_lifetime(),
         }
     }
 }
@@ -515,15 +724,41 @@ impl RustType for PrimitiveRef {
     }
 }
 
+impl RustType for ValueRef {
+    fn as_rust_type(&self) -> String {
+        match self {
+            Self::String(x) => x.as_c_type(),
+            Self::Enum(x) => format!("*const {}", x.name.to_upper_camel_case()),
+            Self::Duration(x) => format!("*const {}", x.primitive().as_rust_type()),
+        }
+    }
+
+    fn as_c_type(&self) -> String {
+        self.as_rust_type()
+    }
+
+    fn is_copyable(&self) -> bool {
+        true
+    }
+
+    fn conversion(&self) -> Option<TypeConverter> {
+        None
+    }
+}
+
 impl RustType for FunctionReturnValue {
     fn as_rust_type(&self) -> String {
         match self {
             FunctionReturnValue::Basic(x) => x.as_rust_type(),
             FunctionReturnValue::String(x) => x.as_rust_type(),
+            FunctionReturnValue::Bytes(x) => x.as_rust_type(),
+            FunctionReturnValue::Union(x) => x.as_rust_type(),
             FunctionReturnValue::ClassRef(x) => x.as_rust_type(),
+            FunctionReturnValue::Iterator(x) => x.as_rust_type(),
             FunctionReturnValue::Struct(x) => x.as_rust_type(),
             FunctionReturnValue::StructRef(x) => x.untyped().as_rust_type(),
             FunctionReturnValue::PrimitiveRef(x) => x.as_rust_type(),
+            FunctionReturnValue::ValueRef(x) => x.as_rust_type(),
         }
     }
 
@@ -531,10 +766,14 @@ impl RustType for FunctionReturnValue {
         match self {
             FunctionReturnValue::Basic(x) => x.as_c_type(),
             FunctionReturnValue::String(x) => x.as_c_type(),
+            FunctionReturnValue::Bytes(x) => x.as_c_type(),
+            FunctionReturnValue::Union(x) => x.as_c_type(),
             FunctionReturnValue::ClassRef(x) => x.as_c_type(),
+            FunctionReturnValue::Iterator(x) => x.as_c_type(),
             FunctionReturnValue::Struct(x) => x.as_c_type(),
             FunctionReturnValue::StructRef(x) => x.untyped().as_c_type(),
             FunctionReturnValue::PrimitiveRef(x) => x.as_c_type(),
+            FunctionReturnValue::ValueRef(x) => x.as_c_type(),
         }
     }
 
@@ -542,10 +781,14 @@ impl RustType for FunctionReturnValue {
         match self {
             FunctionReturnValue::Basic(x) => x.is_copyable(),
             FunctionReturnValue::String(x) => x.is_copyable(),
+            FunctionReturnValue::Bytes(x) => x.is_copyable(),
+            FunctionReturnValue::Union(x) => x.is_copyable(),
             FunctionReturnValue::ClassRef(x) => x.is_copyable(),
+            FunctionReturnValue::Iterator(x) => x.is_copyable(),
             FunctionReturnValue::Struct(x) => x.is_copyable(),
             FunctionReturnValue::StructRef(x) => x.untyped().is_copyable(),
             FunctionReturnValue::PrimitiveRef(x) => x.is_copyable(),
+            FunctionReturnValue::ValueRef(x) => x.is_copyable(),
         }
     }
 
@@ -553,10 +796,14 @@ impl RustType for FunctionReturnValue {
         match self {
             FunctionReturnValue::Basic(x) => x.conversion(),
             FunctionReturnValue::String(x) => x.conversion(),
+            FunctionReturnValue::Bytes(x) => x.conversion(),
+            FunctionReturnValue::Union(x) => x.conversion(),
             FunctionReturnValue::ClassRef(x) => x.conversion(),
+            FunctionReturnValue::Iterator(x) => x.conversion(),
             FunctionReturnValue::Struct(x) => x.conversion(),
             FunctionReturnValue::StructRef(x) => x.untyped().conversion(),
             FunctionReturnValue::PrimitiveRef(x) => x.conversion(),
+            FunctionReturnValue::ValueRef(x) => x.conversion(),
         }
     }
 }
@@ -566,8 +813,11 @@ impl RustType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.as_rust_type(),
             FunctionArgStructField::String(x) => x.as_rust_type(),
+            FunctionArgStructField::Bytes(x) => x.as_rust_type(),
+            FunctionArgStructField::Optional(x) => x.as_rust_type(),
             FunctionArgStructField::Interface(x) => x.inner.as_rust_type(),
             FunctionArgStructField::Struct(x) => x.as_rust_type(),
+            FunctionArgStructField::List(x) => x.as_rust_type(),
         }
     }
 
@@ -575,8 +825,11 @@ impl RustType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.as_c_type(),
             FunctionArgStructField::String(x) => x.as_c_type(),
+            FunctionArgStructField::Bytes(x) => x.as_c_type(),
+            FunctionArgStructField::Optional(x) => x.as_c_type(),
             FunctionArgStructField::Interface(x) => x.inner.as_c_type(),
             FunctionArgStructField::Struct(x) => x.as_c_type(),
+            FunctionArgStructField::List(x) => x.as_c_type(),
         }
     }
 
@@ -584,8 +837,11 @@ impl RustType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.is_copyable(),
             FunctionArgStructField::String(x) => x.is_copyable(),
+            FunctionArgStructField::Bytes(x) => x.is_copyable(),
+            FunctionArgStructField::Optional(x) => x.is_copyable(),
             FunctionArgStructField::Interface(x) => x.inner.is_copyable(),
             FunctionArgStructField::Struct(x) => x.is_copyable(),
+            FunctionArgStructField::List(x) => x.is_copyable(),
         }
     }
 
@@ -593,8 +849,11 @@ impl RustType for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.conversion(),
             FunctionArgStructField::String(x) => x.conversion(),
+            FunctionArgStructField::Bytes(x) => x.conversion(),
+            FunctionArgStructField::Optional(x) => x.conversion(),
             FunctionArgStructField::Interface(x) => x.inner.conversion(),
             FunctionArgStructField::Struct(x) => x.conversion(),
+            FunctionArgStructField::List(x) => x.conversion(),
         }
     }
 }
@@ -644,6 +903,8 @@ impl RustType for CallbackArgStructField {
             Self::Iterator(x) => x.as_rust_type(),
             Self::Struct(x) => x.as_rust_type(),
             Self::String(x) => x.as_rust_type(),
+            Self::Bytes(x) => x.as_rust_type(),
+            Self::Optional(x) => x.as_rust_type(),
         }
     }
 
@@ -653,6 +914,8 @@ impl RustType for CallbackArgStructField {
             Self::Iterator(x) => x.as_c_type(),
             Self::Struct(x) => x.as_c_type(),
             Self::String(x) => x.as_c_type(),
+            Self::Bytes(x) => x.as_c_type(),
+            Self::Optional(x) => x.as_c_type(),
         }
     }
 
@@ -662,6 +925,8 @@ impl RustType for CallbackArgStructField {
             Self::Iterator(x) => x.is_copyable(),
             Self::Struct(x) => x.is_copyable(),
             Self::String(x) => x.is_copyable(),
+            Self::Bytes(x) => x.is_copyable(),
+            Self::Optional(x) => x.is_copyable(),
         }
     }
 
@@ -671,6 +936,8 @@ impl RustType for CallbackArgStructField {
             Self::Iterator(x) => x.conversion(),
             Self::Struct(x) => x.conversion(),
             Self::String(x) => x.conversion(),
+            Self::Bytes(x) => x.conversion(),
+            Self::Optional(x) => x.conversion(),
         }
     }
 }
@@ -681,6 +948,9 @@ impl RustType for UniversalStructField {
             Self::Basic(x) => x.as_rust_type(),
             Self::Struct(x) => x.as_rust_type(),
             Self::String(x) => x.as_rust_type(),
+            Self::Bytes(x) => x.as_rust_type(),
+            Self::Optional(x) => x.as_rust_type(),
+            Self::List(x) => x.as_rust_type(),
         }
     }
 
@@ -689,6 +959,9 @@ impl RustType for UniversalStructField {
             Self::Basic(x) => x.as_c_type(),
             Self::Struct(x) => x.as_c_type(),
             Self::String(x) => x.as_c_type(),
+            Self::Bytes(x) => x.as_c_type(),
+            Self::Optional(x) => x.as_c_type(),
+            Self::List(x) => x.as_c_type(),
         }
     }
 
@@ -697,6 +970,9 @@ impl RustType for UniversalStructField {
             Self::Basic(x) => x.is_copyable(),
             Self::Struct(x) => x.is_copyable(),
             Self::String(x) => x.is_copyable(),
+            Self::Bytes(x) => x.is_copyable(),
+            Self::Optional(x) => x.is_copyable(),
+            Self::List(x) => x.is_copyable(),
         }
     }
 
@@ -705,6 +981,9 @@ impl RustType for UniversalStructField {
             Self::Basic(x) => x.conversion(),
             Self::Struct(x) => x.conversion(),
             Self::String(x) => x.conversion(),
+            Self::Bytes(x) => x.conversion(),
+            Self::Optional(x) => x.conversion(),
+            Self::List(x) => x.conversion(),
         }
     }
 }
@@ -714,6 +993,9 @@ impl LifetimeInfo for CallbackArgument {
         match self {
             Self::Basic(x) => x.rust_requires_lifetime(),
             Self::String(x) => x.rust_requires_lifetime(),
+            Self::Bytes(x) => x.rust_requires_lifetime(),
+            Self::Union(x) => x.rust_requires_lifetime(),
+            Self::Optional(x) => x.rust_requires_lifetime(),
             Self::Iterator(x) => x.rust_requires_lifetime(),
             Self::Struct(x) => x.rust_requires_lifetime(),
             Self::Class(x) => x.rust_requires_lifetime(),
@@ -724,6 +1006,9 @@ impl LifetimeInfo for CallbackArgument {
         match self {
             Self::Basic(x) => x.c_requires_lifetime(),
             Self::String(x) => x.c_requires_lifetime(),
+            Self::Bytes(x) => x.c_requires_lifetime(),
+            Self::Union(x) => x.c_requires_lifetime(),
+            Self::Optional(x) => x.c_requires_lifetime(),
             Self::Iterator(x) => x.c_requires_lifetime(),
             Self::Struct(x) => x.c_requires_lifetime(),
             Self::Class(x) => x.c_requires_lifetime(),
@@ -736,6 +1021,9 @@ impl RustType for CallbackArgument {
         match self {
             Self::Basic(x) => x.as_rust_type(),
             Self::String(x) => x.as_rust_type(),
+            Self::Bytes(x) => x.as_rust_type(),
+            Self::Union(x) => x.as_rust_type(),
+            Self::Optional(x) => x.as_rust_type(),
             Self::Iterator(x) => x.as_rust_type(),
             Self::Struct(x) => x.as_rust_type(),
             Self::Class(x) => x.as_rust_type(),
@@ -746,6 +1034,9 @@ impl RustType for CallbackArgument {
         match self {
             Self::Basic(x) => x.as_c_type(),
             Self::String(x) => x.as_c_type(),
+            Self::Bytes(x) => x.as_c_type(),
+            Self::Union(x) => x.as_c_type(),
+            Self::Optional(x) => x.as_c_type(),
             Self::Iterator(x) => x.as_c_type(),
             Self::Struct(x) => x.as_c_type(),
             Self::Class(x) => x.as_c_type(),
@@ -756,6 +1047,9 @@ impl RustType for CallbackArgument {
         match self {
             Self::Basic(x) => x.is_copyable(),
             Self::String(x) => x.is_copyable(),
+            Self::Bytes(x) => x.is_copyable(),
+            Self::Union(x) => x.is_copyable(),
+            Self::Optional(x) => x.is_copyable(),
             Self::Iterator(x) => x.is_copyable(),
             Self::Struct(x) => x.is_copyable(),
             Self::Class(x) => x.is_copyable(),
@@ -766,6 +1060,9 @@ impl RustType for CallbackArgument {
         match self {
             Self::Basic(x) => x.conversion(),
             Self::String(x) => x.conversion(),
+            Self::Bytes(x) => x.conversion(),
+            Self::Union(x) => x.conversion(),
+            Self::Optional(x) => x.conversion(),
             Self::Iterator(x) => x.conversion(),
             Self::Struct(x) => x.conversion(),
             Self::Class(x) => x.conversion(),
@@ -884,8 +1181,11 @@ impl LifetimeInfo for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.rust_requires_lifetime(),
             FunctionArgStructField::String(x) => x.rust_requires_lifetime(),
+            FunctionArgStructField::Bytes(x) => x.rust_requires_lifetime(),
+            FunctionArgStructField::Optional(x) => x.rust_requires_lifetime(),
             FunctionArgStructField::Interface(x) => x.inner.rust_requires_lifetime(),
             FunctionArgStructField::Struct(x) => x.rust_requires_lifetime(),
+            FunctionArgStructField::List(x) => x.rust_requires_lifetime(),
         }
     }
 
@@ -893,8 +1193,11 @@ impl LifetimeInfo for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.c_requires_lifetime(),
             FunctionArgStructField::String(x) => x.c_requires_lifetime(),
+            FunctionArgStructField::Bytes(x) => x.c_requires_lifetime(),
+            FunctionArgStructField::Optional(x) => x.c_requires_lifetime(),
             FunctionArgStructField::Interface(x) => x.inner.c_requires_lifetime(),
             FunctionArgStructField::Struct(x) => x.c_requires_lifetime(),
+            FunctionArgStructField::List(x) => x.c_requires_lifetime(),
         }
     }
 }
@@ -906,6 +1209,8 @@ impl LifetimeInfo for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.rust_requires_lifetime(),
             CallbackArgStructField::Struct(x) => x.rust_requires_lifetime(),
             CallbackArgStructField::String(x) => x.rust_requires_lifetime(),
+            CallbackArgStructField::Bytes(x) => x.rust_requires_lifetime(),
+            CallbackArgStructField::Optional(x) => x.rust_requires_lifetime(),
         }
     }
 
@@ -915,6 +1220,8 @@ impl LifetimeInfo for CallbackArgStructField {
             CallbackArgStructField::Iterator(x) => x.c_requires_lifetime(),
             CallbackArgStructField::Struct(x) => x.c_requires_lifetime(),
             CallbackArgStructField::String(x) => x.c_requires_lifetime(),
+            CallbackArgStructField::Bytes(x) => x.c_requires_lifetime(),
+            CallbackArgStructField::Optional(x) => x.c_requires_lifetime(),
         }
     }
 }
@@ -945,6 +1252,9 @@ impl LifetimeInfo for UniversalStructField {
             Self::Basic(x) => x.rust_requires_lifetime(),
             Self::Struct(x) => x.rust_requires_lifetime(),
             Self::String(x) => x.rust_requires_lifetime(),
+            Self::Bytes(x) => x.rust_requires_lifetime(),
+            Self::Optional(x) => x.rust_requires_lifetime(),
+            Self::List(x) => x.rust_requires_lifetime(),
         }
     }
 
@@ -953,6 +1263,9 @@ impl LifetimeInfo for UniversalStructField {
             Self::Basic(x) => x.c_requires_lifetime(),
             Self::Struct(x) => x.c_requires_lifetime(),
             Self::String(x) => x.c_requires_lifetime(),
+            Self::Bytes(x) => x.c_requires_lifetime(),
+            Self::Optional(x) => x.c_requires_lifetime(),
+            Self::List(x) => x.c_requires_lifetime(),
         }
     }
 }
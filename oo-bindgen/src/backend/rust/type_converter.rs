@@ -1,5 +1,6 @@
 use crate::backend::*;
 use crate::model::*;
+use heck::ToUpperCamelCase;
 
 trait TypeConversion {
     fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()>;
@@ -11,10 +12,16 @@ trait TypeConversion {
 
 pub(crate) enum TypeConverter {
     String(StringType),
+    Bytes(BytesType),
+    List(ListHandle),
+    Optional(Optional<BasicType>),
     ValidatedEnum(Handle<Enum<Validated>>),
     UnvalidatedEnum(Handle<Enum<Unvalidated>>),
+    Flags(FlagsHandle),
     Struct(StructDeclarationHandle),
     Duration(DurationType),
+    Timestamp(TimestampType),
+    Union(UnionHandle),
 }
 
 impl TypeConverter {
@@ -26,10 +33,16 @@ impl TypeConverter {
     ) -> FormattingResult<()> {
         match self {
             TypeConverter::String(x) => x.convert_to_c(f, from, to),
+            TypeConverter::Bytes(x) => x.convert_to_c(f, from, to),
+            TypeConverter::List(x) => x.convert_to_c(f, from, to),
+            TypeConverter::Optional(x) => x.convert_to_c(f, from, to),
             TypeConverter::ValidatedEnum(x) => x.convert_to_c(f, from, to),
             TypeConverter::UnvalidatedEnum(x) => x.convert_to_c(f, from, to),
+            TypeConverter::Flags(x) => x.convert_to_c(f, from, to),
             TypeConverter::Struct(x) => x.convert_to_c(f, from, to),
             TypeConverter::Duration(x) => x.convert_to_c(f, from, to),
+            TypeConverter::Timestamp(x) => x.convert_to_c(f, from, to),
+            TypeConverter::Union(x) => x.convert_to_c(f, from, to),
         }
     }
 
@@ -41,20 +54,32 @@ impl TypeConverter {
     ) -> FormattingResult<()> {
         match self {
             TypeConverter::String(x) => x.convert_from_c(f, from, to),
+            TypeConverter::Bytes(x) => x.convert_from_c(f, from, to),
+            TypeConverter::List(x) => x.convert_from_c(f, from, to),
+            TypeConverter::Optional(x) => x.convert_from_c(f, from, to),
             TypeConverter::ValidatedEnum(x) => x.convert_from_c(f, from, to),
             TypeConverter::UnvalidatedEnum(x) => x.convert_from_c(f, from, to),
+            TypeConverter::Flags(x) => x.convert_from_c(f, from, to),
             TypeConverter::Struct(x) => x.convert_from_c(f, from, to),
             TypeConverter::Duration(x) => x.convert_from_c(f, from, to),
+            TypeConverter::Timestamp(x) => x.convert_from_c(f, from, to),
+            TypeConverter::Union(x) => x.convert_from_c(f, from, to),
         }
     }
 
     pub(crate) fn is_unsafe(&self) -> bool {
         match self {
             TypeConverter::String(x) => x.is_unsafe(),
+            TypeConverter::Bytes(x) => x.is_unsafe(),
+            TypeConverter::List(x) => x.is_unsafe(),
+            TypeConverter::Optional(x) => x.is_unsafe(),
             TypeConverter::ValidatedEnum(x) => x.is_unsafe(),
             TypeConverter::UnvalidatedEnum(x) => x.is_unsafe(),
+            TypeConverter::Flags(x) => x.is_unsafe(),
             TypeConverter::Struct(x) => x.is_unsafe(),
             TypeConverter::Duration(x) => x.is_unsafe(),
+            TypeConverter::Timestamp(x) => x.is_unsafe(),
+            TypeConverter::Union(x) => x.is_unsafe(),
         }
     }
 }
@@ -73,6 +98,74 @@ impl TypeConversion for StringType {
     }
 }
 
+impl TypeConversion for BytesType {
+    fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!("{to}ByteSlice::from({from})"))
+    }
+
+    fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!("{to}{from}.to_slice()"))
+    }
+
+    fn is_unsafe(&self) -> bool {
+        true
+    }
+}
+
+impl TypeConversion for ListHandle {
+    fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!(
+            "{to}{}::from({from})",
+            self.name.to_upper_camel_case()
+        ))
+    }
+
+    fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!("{to}{from}.to_slice()"))
+    }
+
+    fn is_unsafe(&self) -> bool {
+        true
+    }
+}
+
+impl TypeConversion for Optional<BasicType> {
+    fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        let map = match &self.inner {
+            BasicType::Primitive(_) => "".to_string(),
+            BasicType::Duration(DurationType::SignedMilliseconds) => "".to_string(),
+            BasicType::Duration(x) => format!(".map(|x| {})", x.rust_to_c_expr("x")),
+            BasicType::Timestamp(x) => format!(".map(|x| {})", x.rust_to_c_expr("x")),
+            BasicType::Enum(_) => ".map(std::os::raw::c_int::from)".to_string(),
+            BasicType::Flags(x) => format!(
+                ".map({}::from)",
+                Primitive::from(x.flags_type).get_c_rust_type()
+            ),
+        };
+        f.writeln(&format!("{to}Optional::from({from}{map})"))
+    }
+
+    fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        let map = match &self.inner {
+            BasicType::Primitive(_) => "".to_string(),
+            BasicType::Duration(DurationType::SignedMilliseconds) => "".to_string(),
+            BasicType::Duration(x) => match x.c_to_rust_constructor() {
+                Some(constructor) => format!(".map({constructor})"),
+                None => format!(".map(|x| {})", x.c_to_rust_expr("x")),
+            },
+            BasicType::Timestamp(x) => format!(".map(|x| {})", x.c_to_rust_expr("x")),
+            // fails if the value isn't a variant
+            BasicType::Enum(x) if x.unknown_value == UnknownValuePolicy::Error => format!(
+                ".map({}::try_from).transpose()",
+                x.name.to_upper_camel_case()
+            ),
+            BasicType::Enum(x) => format!(".map({}::from_c_int)", x.name.to_upper_camel_case()),
+            BasicType::Flags(x) => format!(".map({}::from)", x.name.to_upper_camel_case()),
+        };
+        f.writeln(&format!("{to}{from}.into_option(){map}"))
+    }
+}
+
 impl<D> TypeConversion for Handle<Enum<D>>
 where
     D: DocReference,
@@ -81,11 +174,56 @@ where
         f.writeln(&format!("{to}{from}.into()"))
     }
 
+    fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        match self.unknown_value {
+            // fails if the value isn't a variant
+            UnknownValuePolicy::Error => f.writeln(&format!(
+                "{to}{}::try_from({from})",
+                self.name.to_upper_camel_case()
+            )),
+            UnknownValuePolicy::Panic
+            | UnknownValuePolicy::MapTo(_)
+            | UnknownValuePolicy::Clamp => f.writeln(&format!(
+                "{to}{}::from_c_int({from})",
+                self.name.to_upper_camel_case()
+            )),
+        }
+    }
+}
+
+impl<D> TypeConversion for Handle<Flags<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!("{to}{from}.into()"))
+    }
+
     fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
         f.writeln(&format!("{to}{from}.into()"))
     }
 }
 
+impl<D> TypeConversion for Handle<Union<D>>
+where
+    D: DocReference,
+{
+    fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!(
+            "{to}{}::from({from})",
+            self.name.to_upper_camel_case()
+        ))
+    }
+
+    // fails if the tag doesn't match any variant
+    fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!(
+            "{to}{}Variant::try_from({from})",
+            self.name.to_upper_camel_case()
+        ))
+    }
+}
+
 impl TypeConversion for StructDeclarationHandle {
     fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
         f.writeln(&format!(
@@ -98,22 +236,74 @@ impl TypeConversion for StructDeclarationHandle {
     }
 }
 
+impl DurationType {
+    fn rust_to_c_expr(self, from: &str) -> String {
+        match self {
+            DurationType::Milliseconds => format!("{from}.as_millis() as u64"),
+            DurationType::Seconds => format!("{from}.as_secs()"),
+            DurationType::Microseconds => format!("{from}.as_micros() as u64"),
+            DurationType::Nanoseconds => format!("{from}.as_nanos() as u64"),
+            // the Rust API uses the signed count directly
+            DurationType::SignedMilliseconds => from.to_string(),
+            DurationType::FloatSeconds => format!("{from}.as_secs_f64()"),
+        }
+    }
+
+    fn c_to_rust_constructor(self) -> Option<&'static str> {
+        match self {
+            DurationType::Milliseconds => Some("std::time::Duration::from_millis"),
+            DurationType::Seconds => Some("std::time::Duration::from_secs"),
+            DurationType::Microseconds => Some("std::time::Duration::from_micros"),
+            DurationType::Nanoseconds => Some("std::time::Duration::from_nanos"),
+            DurationType::SignedMilliseconds | DurationType::FloatSeconds => None,
+        }
+    }
+
+    fn c_to_rust_expr(self, from: &str) -> String {
+        if let Some(constructor) = self.c_to_rust_constructor() {
+            return format!("{constructor}({from})");
+        }
+
+        match self {
+            // f64::max maps NaN to zero, so only overflowing values can fail
+            DurationType::FloatSeconds => format!(
+                "std::time::Duration::try_from_secs_f64({from}.max(0.0)).unwrap_or(std::time::Duration::MAX)"
+            ),
+            _ => from.to_string(),
+        }
+    }
+}
+
 impl TypeConversion for DurationType {
     fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!("{to}{}", self.rust_to_c_expr(from)))
+    }
+
+    fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!("{to}{}", self.c_to_rust_expr(from)))
+    }
+}
+
+impl TimestampType {
+    fn rust_to_c_expr(self, from: &str) -> String {
         match self {
-            DurationType::Milliseconds => f.writeln(&format!("{to}{from}.as_millis() as u64")),
-            DurationType::Seconds => f.writeln(&format!("{to}{from}.as_secs()")),
+            TimestampType::Milliseconds => format!("match {from}.duration_since(std::time::UNIX_EPOCH) {{ Ok(x) => x.as_millis() as i64, Err(err) => -(err.duration().as_millis() as i64) }}"),
         }
     }
 
-    fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+    fn c_to_rust_expr(self, from: &str) -> String {
         match self {
-            DurationType::Milliseconds => {
-                f.writeln(&format!("{to}std::time::Duration::from_millis({from})"))
-            }
-            DurationType::Seconds => {
-                f.writeln(&format!("{to}std::time::Duration::from_secs({from})"))
-            }
+            TimestampType::Milliseconds => format!("if {from} >= 0 {{ std::time::UNIX_EPOCH + std::time::Duration::from_millis({from} as u64) }} else {{ std::time::UNIX_EPOCH - std::time::Duration::from_millis({from}.unsigned_abs()) }}"),
         }
     }
 }
+
+impl TypeConversion for TimestampType {
+    fn convert_to_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!("{to}{}", self.rust_to_c_expr(from)))
+    }
+
+    fn convert_from_c(&self, f: &mut dyn Printer, from: &str, to: &str) -> FormattingResult<()> {
+        f.writeln(&format!("{to}{}", self.c_to_rust_expr(from)))
+    }
+}
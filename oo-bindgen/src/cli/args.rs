@@ -6,7 +6,8 @@ use std::path::PathBuf;
 impl Args {
     pub(crate) fn get() -> Self {
         let mut args = Args::parse();
-        if !(args.build_c || args.build_dotnet || args.build_java) {
+        // exporting the IR on its own doesn't build any bindings
+        if !(args.build_c || args.build_dotnet || args.build_java || args.ir_path.is_some()) {
             args.build_c = true;
             args.build_dotnet = true;
             args.build_java = true;
@@ -91,4 +92,7 @@ pub(crate) struct Args {
     /// Path(s) to extra files to include in the generated bindings
     #[arg(short = 'f', long = "extra-files")]
     pub(crate) extra_files: Vec<PathBuf>,
+    /// Write the validated model as a JSON intermediate representation to the provided path
+    #[arg(long = "ir")]
+    pub(crate) ir_path: Option<PathBuf>,
 }
@@ -15,6 +15,11 @@ use crate::cli::args::{Args, PackageOptions};
 pub fn run(settings: BindingBuilderSettings) {
     let args = Args::get();
 
+    if let Some(path) = &args.ir_path {
+        let span = tracing::info_span!("ir()");
+        span.in_scope(|| write_ir(&settings.library, path));
+    }
+
     let (options, platforms) = {
         let span = tracing::info_span!("configure()");
         span.in_scope(|| get_platforms(&args))
@@ -41,6 +46,12 @@ pub fn run(settings: BindingBuilderSettings) {
     }
 }
 
+fn write_ir(library: &Library, path: &PathBuf) {
+    tracing::info!("Write IR: {}", path.display());
+    let file = File::create(path).expect("Error creating IR file");
+    serde_json::to_writer_pretty(file, &library.to_ir()).expect("Error writing IR JSON");
+}
+
 struct LanguagePlatforms {
     cpp: PlatformLocations,
     dotnet: PlatformLocations,
@@ -0,0 +1,859 @@
+use crate::ir::*;
+use crate::model;
+use crate::model::{Handle, Library, Validated};
+
+impl Library {
+    /// Export the library as a serializable intermediate representation
+    pub fn to_ir(&self) -> Document {
+        Document {
+            format_version: FORMAT_VERSION,
+            version: self.version.to_string(),
+            info: self.info.to_ir(),
+            settings: (*self.settings).clone(),
+            constants: self.constants().map(|x| x.to_ir()).collect(),
+            enums: self.enums().map(|x| x.to_ir()).collect(),
+            flags: self.flags().map(|x| x.to_ir()).collect(),
+            unions: self.unions().map(|x| x.to_ir()).collect(),
+            structs: self.structs().map(|x| x.to_ir()).collect(),
+            lists: self.lists().map(|x| x.to_ir()).collect(),
+            iterators: self.iterators().map(|x| x.to_ir()).collect(),
+            collections: self.collections().map(|x| x.to_ir()).collect(),
+            maps: self.maps().map(|x| x.to_ir()).collect(),
+            base_exceptions: self.base_exceptions().map(|x| x.to_ir()).collect(),
+            error_types: self.error_types().map(|x| x.to_ir()).collect(),
+            interfaces: self.interfaces().map(|x| x.to_ir()).collect(),
+            classes: self.classes().map(|x| x.to_ir()).collect(),
+            static_classes: self.static_classes().map(|x| x.to_ir()).collect(),
+            functions: self.functions().map(|x| x.to_ir()).collect(),
+        }
+    }
+}
+
+/// Conversion of a model item to its intermediate representation
+trait ToIr<T> {
+    fn to_ir(&self) -> T;
+}
+
+impl<T, U> ToIr<U> for Handle<T>
+where
+    T: ToIr<U>,
+{
+    fn to_ir(&self) -> U {
+        (**self).to_ir()
+    }
+}
+
+impl<T, U> ToIr<Option<U>> for Option<T>
+where
+    T: ToIr<U>,
+{
+    fn to_ir(&self) -> Option<U> {
+        self.as_ref().map(|x| x.to_ir())
+    }
+}
+
+impl<T, U> ToIr<Vec<U>> for Vec<T>
+where
+    T: ToIr<U>,
+{
+    fn to_ir(&self) -> Vec<U> {
+        self.iter().map(|x| x.to_ir()).collect()
+    }
+}
+
+impl ToIr<Info> for model::LibraryInfo {
+    fn to_ir(&self) -> Info {
+        Info {
+            description: self.description.clone(),
+            project_url: self.project_url.clone(),
+            repository: self.repository.clone(),
+            license_name: self.license_name.clone(),
+            license_description: self.license_description.clone(),
+            developers: self.developers.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Developer> for model::DeveloperInfo {
+    fn to_ir(&self) -> Developer {
+        Developer {
+            name: self.name.clone(),
+            email: self.email.clone(),
+            organization: self.organization.clone(),
+            organization_url: self.organization_url.clone(),
+        }
+    }
+}
+
+impl ToIr<Doc> for model::Doc<Validated> {
+    fn to_ir(&self) -> Doc {
+        Doc {
+            brief: self.brief.to_ir(),
+            details: self.details.to_ir(),
+        }
+    }
+}
+
+impl ToIr<DocParagraph> for model::DocParagraph<Validated> {
+    fn to_ir(&self) -> DocParagraph {
+        match self {
+            model::DocParagraph::Details(x) => DocParagraph::Details(x.to_ir()),
+            model::DocParagraph::Warning(x) => DocParagraph::Warning(x.to_ir()),
+        }
+    }
+}
+
+impl ToIr<String> for model::DocString<Validated> {
+    fn to_ir(&self) -> String {
+        self.elements()
+            .map(|element| match element {
+                model::DocStringElement::Text(x) => x.clone(),
+                model::DocStringElement::Null => "{null}".to_string(),
+                model::DocStringElement::Iterator => "{iterator}".to_string(),
+                model::DocStringElement::Reference(x) => x.to_ir(),
+            })
+            .collect()
+    }
+}
+
+impl ToIr<String> for Validated {
+    fn to_ir(&self) -> String {
+        match self {
+            Validated::Argument(name) => format!("{{param:{name}}}"),
+            Validated::Class(class) => format!("{{class:{}}}", class.name),
+            Validated::ClassMethod(class, name, _) => {
+                format!("{{class:{}.{name}()}}", class.name())
+            }
+            Validated::ClassConstructor(class, _) => {
+                format!("{{class:{}.[constructor]}}", class.name())
+            }
+            Validated::ClassDestructor(class, _) => {
+                format!("{{class:{}.[destructor]}}", class.name())
+            }
+            Validated::Struct(st) => format!("{{struct:{}}}", st.name()),
+            Validated::StructField(st, field) => format!("{{struct:{}.{field}}}", st.name()),
+            Validated::Enum(handle) => format!("{{enum:{}}}", handle.name),
+            Validated::EnumVariant(handle, variant) => {
+                format!("{{enum:{}.{variant}}}", handle.name)
+            }
+            Validated::Interface(handle) => format!("{{interface:{}}}", handle.name),
+            Validated::InterfaceMethod(handle, callback) => {
+                format!("{{interface:{}.{callback}()}}", handle.name)
+            }
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::BasicType {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::BasicType::Primitive(x) => TypeRef::Primitive { primitive: *x },
+            model::BasicType::Duration(x) => TypeRef::Duration { duration: *x },
+            model::BasicType::Timestamp(x) => TypeRef::Timestamp { timestamp: *x },
+            model::BasicType::Enum(x) => TypeRef::Enum {
+                name: x.name.clone(),
+            },
+            model::BasicType::Flags(x) => TypeRef::Flags {
+                name: x.name.clone(),
+            },
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::Optional<model::BasicType> {
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::Optional {
+            inner: Box::new(self.inner.to_ir()),
+        }
+    }
+}
+
+impl<T> ToIr<TypeRef> for model::UniversalOr<T>
+where
+    T: model::StructFieldType,
+{
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::Struct {
+            name: self.name().clone(),
+        }
+    }
+}
+
+impl<T> ToIr<TypeRef> for model::TypedStructDeclaration<T> {
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::StructRef {
+            name: self.name().clone(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::UniversalStructHandle {
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::Struct {
+            name: self.name().clone(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::ClassDeclarationHandle {
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::Class {
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::AbstractIteratorHandle {
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::Iterator {
+            name: self.name().clone(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::UnionHandle {
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::Union {
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::InterfaceHandle {
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::Interface {
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::ListHandle {
+    fn to_ir(&self) -> TypeRef {
+        TypeRef::List {
+            name: self.name.clone(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::FunctionArgument {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::FunctionArgument::Basic(x) => x.to_ir(),
+            model::FunctionArgument::String(_) => TypeRef::String,
+            model::FunctionArgument::Bytes(_) => TypeRef::Bytes,
+            model::FunctionArgument::Optional(x) => x.to_ir(),
+            model::FunctionArgument::Union(x) => x.to_ir(),
+            model::FunctionArgument::Collection(x) => TypeRef::Collection {
+                name: x.name().clone(),
+            },
+            model::FunctionArgument::Map(x) => TypeRef::Map {
+                name: x.name().clone(),
+            },
+            model::FunctionArgument::Struct(x) => x.to_ir(),
+            model::FunctionArgument::StructRef(x) => x.to_ir(),
+            model::FunctionArgument::ClassRef(x) => x.to_ir(),
+            model::FunctionArgument::Interface(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::FunctionReturnValue {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::FunctionReturnValue::Basic(x) => x.to_ir(),
+            model::FunctionReturnValue::PrimitiveRef(x) => {
+                TypeRef::PrimitiveRef { primitive: x.inner }
+            }
+            model::FunctionReturnValue::ValueRef(x) => TypeRef::ValueRef {
+                inner: Box::new(x.to_ir()),
+            },
+            model::FunctionReturnValue::String(_) => TypeRef::String,
+            model::FunctionReturnValue::Bytes(_) => TypeRef::Bytes,
+            model::FunctionReturnValue::Union(x) => x.to_ir(),
+            model::FunctionReturnValue::ClassRef(x) => x.to_ir(),
+            model::FunctionReturnValue::Iterator(x) => x.to_ir(),
+            model::FunctionReturnValue::Struct(x) => x.to_ir(),
+            model::FunctionReturnValue::StructRef(x) => TypeRef::StructRef {
+                name: x.untyped().name.clone(),
+            },
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::ValueRef {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::ValueRef::String(_) => TypeRef::String,
+            model::ValueRef::Enum(x) => TypeRef::Enum {
+                name: x.name.clone(),
+            },
+            model::ValueRef::Duration(x) => TypeRef::Duration { duration: *x },
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::CallbackArgument {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::CallbackArgument::Basic(x) => x.to_ir(),
+            model::CallbackArgument::String(_) => TypeRef::String,
+            model::CallbackArgument::Bytes(_) => TypeRef::Bytes,
+            model::CallbackArgument::Optional(x) => x.to_ir(),
+            model::CallbackArgument::Union(x) => x.to_ir(),
+            model::CallbackArgument::Iterator(x) => x.to_ir(),
+            model::CallbackArgument::Class(x) => x.to_ir(),
+            model::CallbackArgument::Struct(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::CallbackReturnValue {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::CallbackReturnValue::Basic(x) => x.to_ir(),
+            model::CallbackReturnValue::Struct(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::FunctionArgStructField {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::FunctionArgStructField::Basic(x) => x.to_ir(),
+            model::FunctionArgStructField::String(_) => TypeRef::String,
+            model::FunctionArgStructField::Bytes(_) => TypeRef::Bytes,
+            model::FunctionArgStructField::Optional(x) => x.to_ir(),
+            model::FunctionArgStructField::List(x) => x.to_ir(),
+            model::FunctionArgStructField::Interface(x) => x.inner.to_ir(),
+            model::FunctionArgStructField::Struct(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::FunctionReturnStructField {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::FunctionReturnStructField::Basic(x) => x.to_ir(),
+            model::FunctionReturnStructField::ClassRef(x) => x.to_ir(),
+            model::FunctionReturnStructField::Iterator(x) => x.to_ir(),
+            model::FunctionReturnStructField::Struct(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::CallbackArgStructField {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::CallbackArgStructField::Basic(x) => x.to_ir(),
+            model::CallbackArgStructField::String(_) => TypeRef::String,
+            model::CallbackArgStructField::Bytes(_) => TypeRef::Bytes,
+            model::CallbackArgStructField::Optional(x) => x.to_ir(),
+            model::CallbackArgStructField::Iterator(x) => x.to_ir(),
+            model::CallbackArgStructField::Struct(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::UniversalStructField {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::UniversalStructField::Basic(x) => x.to_ir(),
+            model::UniversalStructField::String(_) => TypeRef::String,
+            model::UniversalStructField::Bytes(_) => TypeRef::Bytes,
+            model::UniversalStructField::Optional(x) => x.to_ir(),
+            model::UniversalStructField::List(x) => x.to_ir(),
+            model::UniversalStructField::Struct(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::ListItemType {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::ListItemType::Basic(x) => x.to_ir(),
+            model::ListItemType::Struct(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<TypeRef> for model::IteratorItemType {
+    fn to_ir(&self) -> TypeRef {
+        match self {
+            model::IteratorItemType::Primitive(x) => TypeRef::Primitive { primitive: *x },
+            model::IteratorItemType::String(_) => TypeRef::String,
+            model::IteratorItemType::Enum(x) => TypeRef::Enum {
+                name: x.name.clone(),
+            },
+            model::IteratorItemType::Duration(x) => TypeRef::Duration { duration: *x },
+            model::IteratorItemType::Class(x) => x.to_ir(),
+            model::IteratorItemType::Struct(x) => x.to_ir(),
+        }
+    }
+}
+
+impl ToIr<BasicValue> for model::BasicValue {
+    fn to_ir(&self) -> BasicValue {
+        match self {
+            model::BasicValue::Primitive(x) => BasicValue::Primitive { value: *x },
+            model::BasicValue::Duration(x) => BasicValue::Duration { value: *x },
+            model::BasicValue::Enum(x) => BasicValue::Enum {
+                name: x.handle.name.clone(),
+                variant: x.variant.name.clone(),
+            },
+        }
+    }
+}
+
+impl ToIr<DefaultValue> for model::ValidatedDefaultValue {
+    fn to_ir(&self) -> DefaultValue {
+        match self {
+            model::ValidatedDefaultValue::Bool(x) => DefaultValue::Bool { value: *x },
+            model::ValidatedDefaultValue::Number(x) => DefaultValue::Number { value: *x },
+            model::ValidatedDefaultValue::Duration(duration, value) => DefaultValue::Duration {
+                duration: *duration,
+                value: *value,
+            },
+            model::ValidatedDefaultValue::Enum(handle, variant) => DefaultValue::Enum {
+                name: handle.name.clone(),
+                variant: variant.clone(),
+            },
+            model::ValidatedDefaultValue::String(x) => DefaultValue::String { value: x.clone() },
+            model::ValidatedDefaultValue::DefaultStruct(st, _, initializer) => {
+                DefaultValue::DefaultStruct {
+                    name: st.name().clone(),
+                    initializer: initializer.clone(),
+                }
+            }
+        }
+    }
+}
+
+impl ToIr<ConstantSet> for model::ConstantSet<Validated> {
+    fn to_ir(&self) -> ConstantSet {
+        ConstantSet {
+            name: self.name.clone(),
+            values: self.values.to_ir(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Constant> for model::Constant<Validated> {
+    fn to_ir(&self) -> Constant {
+        let value = match &self.value {
+            model::ConstantValue::Primitive(value, representation) => ConstantValue::Primitive {
+                value: *value,
+                representation: *representation,
+            },
+            model::ConstantValue::Duration(x) => ConstantValue::Duration { value: *x },
+            model::ConstantValue::String(x) => ConstantValue::String { value: x.clone() },
+            model::ConstantValue::Enum(x) => ConstantValue::Enum {
+                name: x.handle.name.clone(),
+                variant: x.variant.name.clone(),
+            },
+        };
+
+        Constant {
+            name: self.name.clone(),
+            value,
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Enum> for model::Enum<Validated> {
+    fn to_ir(&self) -> Enum {
+        Enum {
+            name: self.name.clone(),
+            variants: self.variants.to_ir(),
+            unknown_value: self.unknown_value.clone(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<EnumVariant> for model::EnumVariant<Validated> {
+    fn to_ir(&self) -> EnumVariant {
+        EnumVariant {
+            name: self.name.clone(),
+            value: self.value,
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Flags> for model::Flags<Validated> {
+    fn to_ir(&self) -> Flags {
+        Flags {
+            name: self.name.clone(),
+            flags_type: self.flags_type,
+            bits: self.bits.to_ir(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<FlagsBit> for model::FlagsBit<Validated> {
+    fn to_ir(&self) -> FlagsBit {
+        FlagsBit {
+            name: self.name.clone(),
+            value: self.value,
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Union> for model::Union<Validated> {
+    fn to_ir(&self) -> Union {
+        Union {
+            name: self.name.clone(),
+            variants: self.variants.to_ir(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<UnionVariant> for model::UnionVariant<Validated> {
+    fn to_ir(&self) -> UnionVariant {
+        UnionVariant {
+            name: self.name.clone(),
+            value: self.value.name().clone(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Struct> for model::StructType<Validated> {
+    fn to_ir(&self) -> Struct {
+        match self {
+            model::StructType::FunctionArg(x) => convert_struct(x, StructKind::FunctionArg),
+            model::StructType::FunctionReturn(x) => convert_struct(x, StructKind::FunctionReturn),
+            model::StructType::CallbackArg(x) => convert_struct(x, StructKind::CallbackArg),
+            model::StructType::Universal(x) => convert_struct(x, StructKind::Universal),
+        }
+    }
+}
+
+fn convert_struct<T>(handle: &model::Struct<T, Validated>, kind: StructKind) -> Struct
+where
+    T: model::StructFieldType + ToIr<TypeRef>,
+{
+    Struct {
+        name: handle.name().clone(),
+        kind,
+        visibility: match handle.visibility {
+            model::Visibility::Public => Visibility::Public,
+            model::Visibility::Private => Visibility::Private,
+        },
+        fields: handle
+            .fields
+            .iter()
+            .map(|x| StructField {
+                name: x.name.clone(),
+                field_type: x.field_type.to_ir(),
+                doc: x.doc.to_ir(),
+            })
+            .collect(),
+        initializers: handle.initializers.to_ir(),
+        doc: handle.doc.to_ir(),
+    }
+}
+
+impl ToIr<Initializer> for model::Initializer<Validated> {
+    fn to_ir(&self) -> Initializer {
+        Initializer {
+            name: self.name.clone(),
+            initializer_type: self.initializer_type,
+            values: self
+                .values
+                .iter()
+                .map(|x| InitializedValue {
+                    name: x.name.clone(),
+                    value: x.value.to_ir(),
+                })
+                .collect(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<List> for model::List {
+    fn to_ir(&self) -> List {
+        List {
+            name: self.name.clone(),
+            item_type: self.item_type.to_ir(),
+        }
+    }
+}
+
+impl ToIr<AbstractIterator> for model::AbstractIterator<Validated> {
+    fn to_ir(&self) -> AbstractIterator {
+        AbstractIterator {
+            name: self.name().clone(),
+            item_type: self.item_type.to_ir(),
+            has_lifetime_annotation: self.has_lifetime_annotation,
+            next_function: self.next_function.name.clone(),
+            destroy_function: self.destroy_function.as_ref().map(|x| x.name.clone()),
+        }
+    }
+}
+
+impl ToIr<Collection> for model::Collection<Validated> {
+    fn to_ir(&self) -> Collection {
+        Collection {
+            name: self.name().clone(),
+            item_type: self.item_type.to_ir(),
+            create_function: self.create_func.name.clone(),
+            destroy_function: self.delete_func.name.clone(),
+            add_function: self.add_func.name.clone(),
+            has_reserve: self.has_reserve,
+        }
+    }
+}
+
+impl ToIr<Map> for model::Map<Validated> {
+    fn to_ir(&self) -> Map {
+        Map {
+            name: self.name().clone(),
+            key_type: self.key_type.to_ir(),
+            value_type: self.value_type.to_ir(),
+            create_function: self.create_func.name.clone(),
+            destroy_function: self.delete_func.name.clone(),
+            insert_function: self.insert_func.name.clone(),
+        }
+    }
+}
+
+impl ToIr<BaseException> for model::BaseException<Validated> {
+    fn to_ir(&self) -> BaseException {
+        BaseException {
+            name: self.name.clone(),
+            exception_type: self.exception_type,
+            parent: self.parent.as_ref().map(|x| x.name.clone()),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<ErrorType> for model::ErrorType<Validated> {
+    fn to_ir(&self) -> ErrorType {
+        ErrorType {
+            name: self.exception_name.clone(),
+            exception_type: self.exception_type,
+            error_enum: self.inner.name.clone(),
+            base: self.base.as_ref().map(|x| x.name.clone()),
+            variant_exceptions: self
+                .variant_exceptions
+                .iter()
+                .map(|x| VariantException {
+                    variant: x.variant.clone(),
+                    exception_name: x.exception_name.clone(),
+                })
+                .collect(),
+            message: self.message,
+            payload: self.payload.as_ref().map(|x| x.name().clone()),
+            drop_variant: self.drop_variant.clone(),
+            bad_argument_variant: self.bad_argument_variant.clone(),
+        }
+    }
+}
+
+impl ToIr<Interface> for model::InterfaceType<Validated> {
+    fn to_ir(&self) -> Interface {
+        let category = match self {
+            model::InterfaceType::Synchronous(_) => InterfaceCategory::Synchronous,
+            model::InterfaceType::Asynchronous(_) => InterfaceCategory::Asynchronous,
+            model::InterfaceType::Future(x) => InterfaceCategory::Future {
+                value: x.value.as_ref().map(|v| ReturnType {
+                    value_type: v.value_type.to_ir(),
+                    doc: v.doc.to_ir(),
+                }),
+                error_type: x.error_type.exception_name.clone(),
+            },
+            model::InterfaceType::Stream(x) => InterfaceCategory::Stream {
+                item: ReturnType {
+                    value_type: x.item_type.to_ir(),
+                    doc: x.item_type_doc.to_ir(),
+                },
+                error_type: x.error_type.exception_name.clone(),
+            },
+        };
+
+        let handle = self.untyped();
+        Interface {
+            name: handle.name.clone(),
+            category,
+            callbacks: handle.callbacks.to_ir(),
+            doc: handle.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Callback> for model::CallbackFunction<Validated> {
+    fn to_ir(&self) -> Callback {
+        Callback {
+            name: self.name.clone(),
+            functional: self.functional_transform.enabled(),
+            arguments: self.arguments.to_ir(),
+            return_type: self.return_type.get().map(|x| x.to_ir()),
+            default_implementation: self.default_implementation.to_ir(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<DefaultReturnValue> for model::DefaultCallbackReturnValue {
+    fn to_ir(&self) -> DefaultReturnValue {
+        match self {
+            model::DefaultCallbackReturnValue::Void => DefaultReturnValue::Void,
+            model::DefaultCallbackReturnValue::Basic(x) => {
+                DefaultReturnValue::Basic { value: x.to_ir() }
+            }
+            model::DefaultCallbackReturnValue::InitializedStruct(x) => {
+                DefaultReturnValue::InitializedStruct {
+                    name: x.handle.name().clone(),
+                    initializer: x.initializer.name.clone(),
+                }
+            }
+        }
+    }
+}
+
+impl<T> ToIr<Arg> for model::Arg<T, Validated>
+where
+    T: Clone + ToIr<TypeRef>,
+{
+    fn to_ir(&self) -> Arg {
+        Arg {
+            name: self.name.clone(),
+            arg_type: self.arg_type.to_ir(),
+            doc: self.doc.to_ir(),
+            default: self.default.to_ir(),
+            mutable: self.mutable,
+        }
+    }
+}
+
+impl<T> ToIr<ReturnType> for model::ReturnType<T, Validated>
+where
+    T: Clone + ToIr<TypeRef>,
+{
+    fn to_ir(&self) -> ReturnType {
+        ReturnType {
+            value_type: self.value.to_ir(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Function> for model::Function<Validated> {
+    fn to_ir(&self) -> Function {
+        let category = match self.category {
+            model::FunctionCategory::Native => FunctionCategory::Native,
+            model::FunctionCategory::ClassDestructor => FunctionCategory::ClassDestructor,
+            model::FunctionCategory::CollectionCreate => FunctionCategory::CollectionCreate,
+            model::FunctionCategory::CollectionDestroy => FunctionCategory::CollectionDestroy,
+            model::FunctionCategory::CollectionAdd => FunctionCategory::CollectionAdd,
+            model::FunctionCategory::MapCreate => FunctionCategory::MapCreate,
+            model::FunctionCategory::MapDestroy => FunctionCategory::MapDestroy,
+            model::FunctionCategory::MapInsert => FunctionCategory::MapInsert,
+            model::FunctionCategory::IteratorNext => FunctionCategory::IteratorNext,
+            model::FunctionCategory::IteratorDestroy => FunctionCategory::IteratorDestroy,
+        };
+
+        Function {
+            name: self.name.clone(),
+            category,
+            arguments: self.arguments.to_ir(),
+            return_type: self.return_type.get().map(|x| x.to_ir()),
+            error_type: self.error_type.get().map(|x| x.exception_name.clone()),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<Method> for model::Method<Validated> {
+    fn to_ir(&self) -> Method {
+        Method {
+            name: self.name.clone(),
+            function: self.native_function.name.clone(),
+        }
+    }
+}
+
+impl ToIr<Method> for model::StaticMethod<Validated> {
+    fn to_ir(&self) -> Method {
+        Method {
+            name: self.name.clone(),
+            function: self.native_function.name.clone(),
+        }
+    }
+}
+
+impl ToIr<Property> for model::Property<Validated> {
+    fn to_ir(&self) -> Property {
+        Property {
+            name: self.name.clone(),
+            getter: self.getter.native_function.name.clone(),
+            setter: self.setter.as_ref().map(|x| x.native_function.name.clone()),
+        }
+    }
+}
+
+impl ToIr<FutureMethod> for model::FutureMethod<Validated> {
+    fn to_ir(&self) -> FutureMethod {
+        FutureMethod {
+            name: self.name.clone(),
+            function: self.native_function.name.clone(),
+            interface: self.future.interface.name.clone(),
+            cancellation: self.cancellation.as_ref().map(|x| FutureCancellation {
+                variant: x.variant.clone(),
+                function: x.function.name.clone(),
+            }),
+        }
+    }
+}
+
+impl ToIr<StreamMethod> for model::StreamMethod<Validated> {
+    fn to_ir(&self) -> StreamMethod {
+        StreamMethod {
+            name: self.name.clone(),
+            function: self.native_function.name.clone(),
+            interface: self.stream.interface.name.clone(),
+        }
+    }
+}
+
+impl ToIr<Class> for model::Class<Validated> {
+    fn to_ir(&self) -> Class {
+        let destruction_mode = match &self.destruction_mode {
+            model::DestructionMode::Automatic => DestructionMode::Automatic,
+            model::DestructionMode::Custom(name) => DestructionMode::Custom(name.clone()),
+            model::DestructionMode::Dispose => DestructionMode::Dispose,
+        };
+
+        Class {
+            name: self.name().clone(),
+            constructor: self.constructor.as_ref().map(|x| x.function.name.clone()),
+            destructor: self.destructor.as_ref().map(|x| x.function.name.clone()),
+            destruction_mode,
+            methods: self.methods.to_ir(),
+            properties: self.properties.to_ir(),
+            static_methods: self.static_methods.to_ir(),
+            future_methods: self.future_methods.to_ir(),
+            stream_methods: self.stream_methods.to_ir(),
+            interfaces: self.interfaces.iter().map(|x| x.name.clone()).collect(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
+
+impl ToIr<StaticClass> for model::StaticClass<Validated> {
+    fn to_ir(&self) -> StaticClass {
+        StaticClass {
+            name: self.name.clone(),
+            static_methods: self.static_methods.to_ir(),
+            doc: self.doc.to_ir(),
+        }
+    }
+}
@@ -0,0 +1,827 @@
+//! Intermediate representation
+//!
+//! The intermediate representation (IR) is a serializable description of a validated `Library`. It is
+//! produced with `Library::to_ir()` and is intended for tools that need the schema without linking
+//! against the model, e.g. documentation sites, linters or custom generators.
+//!
+//! Items never embed each other. They reference other items by their name, which is unique within
+//! the library, and the referenced item can be found in the matching list of the `Document`. The
+//! lists preserve the order in which the items were defined, so every item is listed after the items
+//! it depends on.
+//!
+//! Documentation is rendered back to the markup accepted by the schema, including the references to
+//! other items, e.g. `{class:MyClass.foo()}`.
+//!
+//! The layout of the document is versioned with `FORMAT_VERSION`. The version is incremented whenever
+//! a change could break an existing consumer.
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{
+    DurationType, DurationValue, ExceptionType, FlagsType, InitializerType, LibrarySettings, Name,
+    NumberValue, Primitive, PrimitiveValue, Representation, TimestampType, UnknownValuePolicy,
+};
+
+mod convert;
+
+/// Version of the document layout
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Description of a validated library
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Document {
+    /// Version of the document layout, see `FORMAT_VERSION`
+    pub format_version: u32,
+    /// Version of the library
+    pub version: String,
+    /// Metadata of the library
+    pub info: Info,
+    /// Settings that affect the names of things
+    pub settings: LibrarySettings,
+    /// Sets of constants
+    pub constants: Vec<ConstantSet>,
+    /// Enumerations, including the ones backing error types
+    pub enums: Vec<Enum>,
+    /// Bit-flag types
+    pub flags: Vec<Flags>,
+    /// Tagged unions
+    pub unions: Vec<Union>,
+    /// Structures
+    pub structs: Vec<Struct>,
+    /// Borrowed lists of values
+    pub lists: Vec<List>,
+    /// Iterators
+    pub iterators: Vec<AbstractIterator>,
+    /// Collections built by the caller and passed to functions
+    pub collections: Vec<Collection>,
+    /// Maps built by the caller and passed to functions
+    pub maps: Vec<Map>,
+    /// Base exceptions shared by error types
+    pub base_exceptions: Vec<BaseException>,
+    /// Error types returned by functions
+    pub error_types: Vec<ErrorType>,
+    /// Interfaces implemented in the target languages
+    pub interfaces: Vec<Interface>,
+    /// Classes
+    pub classes: Vec<Class>,
+    /// Classes with only static methods
+    pub static_classes: Vec<StaticClass>,
+    /// Native functions, including the ones backing methods of classes
+    pub functions: Vec<Function>,
+}
+
+/// Metadata of the library
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Info {
+    /// Description of the library
+    pub description: String,
+    /// URL of the project
+    pub project_url: String,
+    /// GitHub organisation and repo name (e.g. stepfunc/oo_bindgen)
+    pub repository: String,
+    /// License name
+    pub license_name: String,
+    /// Short description of the license
+    pub license_description: Vec<String>,
+    /// Developers of the library
+    pub developers: Vec<Developer>,
+}
+
+/// Developer of the library
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Developer {
+    /// Full name of the developer
+    pub name: String,
+    /// Email of the developer
+    pub email: String,
+    /// Name of the organization the developer is working for
+    pub organization: String,
+    /// Organization website URL
+    pub organization_url: String,
+}
+
+/// Documentation of an item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Doc {
+    /// Brief description
+    pub brief: String,
+    /// Additional paragraphs
+    pub details: Vec<DocParagraph>,
+}
+
+/// Paragraph of documentation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "text", rename_all = "snake_case")]
+pub enum DocParagraph {
+    /// Regular paragraph
+    Details(String),
+    /// Paragraph that should be highlighted as a warning
+    Warning(String),
+}
+
+/// Reference to a type
+///
+/// The same representation is used for arguments, return values, struct fields and callback arguments,
+/// but each position only accepts a subset of these types.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TypeRef {
+    /// Primitive value
+    Primitive {
+        /// Type of the value
+        primitive: Primitive,
+    },
+    /// Duration in the provided representation
+    Duration {
+        /// Representation of the duration
+        duration: DurationType,
+    },
+    /// Absolute point in time in the provided representation
+    Timestamp {
+        /// Representation of the timestamp
+        timestamp: TimestampType,
+    },
+    /// Value of an enum
+    Enum {
+        /// Name of the enum
+        name: Name,
+    },
+    /// Set of flags
+    Flags {
+        /// Name of the flags type
+        name: Name,
+    },
+    /// String
+    String,
+    /// Borrowed sequence of bytes
+    Bytes,
+    /// Value that may be absent
+    Optional {
+        /// Type of the value when it is present
+        inner: Box<TypeRef>,
+    },
+    /// Tagged union
+    Union {
+        /// Name of the union
+        name: Name,
+    },
+    /// Structure passed by value
+    Struct {
+        /// Name of the struct
+        name: Name,
+    },
+    /// Pointer to a structure
+    StructRef {
+        /// Name of the struct
+        name: Name,
+    },
+    /// Pointer to an instance of a class
+    Class {
+        /// Name of the class
+        name: Name,
+    },
+    /// Interface implemented by the caller
+    Interface {
+        /// Name of the interface
+        name: Name,
+    },
+    /// Iterator
+    Iterator {
+        /// Name of the iterator
+        name: Name,
+    },
+    /// Collection
+    Collection {
+        /// Name of the collection
+        name: Name,
+    },
+    /// Map
+    Map {
+        /// Name of the map
+        name: Name,
+    },
+    /// Borrowed list of values
+    List {
+        /// Name of the list
+        name: Name,
+    },
+    /// Pointer to a primitive value that is null when there is no value
+    PrimitiveRef {
+        /// Type of the value
+        primitive: Primitive,
+    },
+    /// Pointer to a value that is null when there is no value
+    ValueRef {
+        /// Type of the value
+        inner: Box<TypeRef>,
+    },
+}
+
+/// Value of a basic type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BasicValue {
+    /// Primitive value
+    Primitive {
+        /// The value
+        value: PrimitiveValue,
+    },
+    /// Duration
+    Duration {
+        /// The value
+        value: DurationValue,
+    },
+    /// Variant of an enum
+    Enum {
+        /// Name of the enum
+        name: Name,
+        /// Name of the variant
+        variant: Name,
+    },
+}
+
+/// Value used when the caller doesn't provide one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DefaultValue {
+    /// Boolean value
+    Bool {
+        /// The value
+        value: bool,
+    },
+    /// Numeric value
+    Number {
+        /// The value
+        value: NumberValue,
+    },
+    /// Duration
+    Duration {
+        /// Representation of the duration
+        duration: DurationType,
+        /// The value
+        value: std::time::Duration,
+    },
+    /// Variant of an enum
+    Enum {
+        /// Name of the enum
+        name: Name,
+        /// Name of the variant
+        variant: Name,
+    },
+    /// String
+    String {
+        /// The value
+        value: String,
+    },
+    /// Struct constructed with one of its initializers
+    DefaultStruct {
+        /// Name of the struct
+        name: Name,
+        /// Name of the initializer
+        initializer: Name,
+    },
+}
+
+/// Set of constants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConstantSet {
+    /// Name of the set
+    pub name: Name,
+    /// Constants in the set
+    pub values: Vec<Constant>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Named constant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Constant {
+    /// Name of the constant
+    pub name: Name,
+    /// Value of the constant
+    pub value: ConstantValue,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Value of a constant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ConstantValue {
+    /// Primitive value
+    Primitive {
+        /// The value
+        value: PrimitiveValue,
+        /// How the value is rendered in the generated code
+        representation: Representation,
+    },
+    /// Duration
+    Duration {
+        /// The value
+        value: DurationValue,
+    },
+    /// String
+    String {
+        /// The value
+        value: String,
+    },
+    /// Variant of an enum
+    Enum {
+        /// Name of the enum
+        name: Name,
+        /// Name of the variant
+        variant: Name,
+    },
+}
+
+/// Enumeration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Enum {
+    /// Name of the enum
+    pub name: Name,
+    /// Variants of the enum
+    pub variants: Vec<EnumVariant>,
+    /// How integer values that don't match a variant are converted
+    pub unknown_value: UnknownValuePolicy,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Variant of an enum
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnumVariant {
+    /// Name of the variant
+    pub name: Name,
+    /// Integer value of the variant
+    pub value: i32,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Bit-flag type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Flags {
+    /// Name of the flags type
+    pub name: Name,
+    /// Integer type used to store the flags
+    pub flags_type: FlagsType,
+    /// Bits of the flags type
+    pub bits: Vec<FlagsBit>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Single bit of a flags type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlagsBit {
+    /// Name of the bit
+    pub name: Name,
+    /// Value of the bit
+    pub value: u64,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Tagged union
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Union {
+    /// Name of the union
+    pub name: Name,
+    /// Variants of the union
+    pub variants: Vec<UnionVariant>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Variant of a tagged union
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnionVariant {
+    /// Name of the variant
+    pub name: Name,
+    /// Name of the universal struct carried by the variant
+    pub value: Name,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Contexts in which a struct may be used
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StructKind {
+    /// Only used as a function argument
+    FunctionArg,
+    /// Only returned from functions
+    FunctionReturn,
+    /// Only used as a callback argument
+    CallbackArg,
+    /// Used in any context
+    Universal,
+}
+
+/// Visibility of the fields of a struct
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    /// Fields are public
+    Public,
+    /// Fields are private and the struct is an opaque token
+    Private,
+}
+
+/// Structure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Struct {
+    /// Name of the struct
+    pub name: Name,
+    /// Contexts in which the struct may be used
+    pub kind: StructKind,
+    /// Visibility of the fields
+    pub visibility: Visibility,
+    /// Fields of the struct
+    pub fields: Vec<StructField>,
+    /// Initializers of the struct
+    pub initializers: Vec<Initializer>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Field of a struct
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructField {
+    /// Name of the field
+    pub name: Name,
+    /// Type of the field
+    pub field_type: TypeRef,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Initializer of a struct
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Initializer {
+    /// Name of the initializer
+    pub name: Name,
+    /// Type of the initializer
+    pub initializer_type: InitializerType,
+    /// Fields initialized with a default value, the other fields are parameters
+    pub values: Vec<InitializedValue>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Field initialized with a default value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InitializedValue {
+    /// Name of the field
+    pub name: Name,
+    /// Value of the field
+    pub value: DefaultValue,
+}
+
+/// Borrowed list of values
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct List {
+    /// Name of the list
+    pub name: Name,
+    /// Type of the items
+    pub item_type: TypeRef,
+}
+
+/// Iterator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbstractIterator {
+    /// Name of the iterator class
+    pub name: Name,
+    /// Type of the items
+    pub item_type: TypeRef,
+    /// True if the iterator borrows data with a lifetime
+    pub has_lifetime_annotation: bool,
+    /// Name of the function that retrieves the next item
+    pub next_function: Name,
+    /// Name of the function that destroys the iterator if it is owned by the caller
+    pub destroy_function: Option<Name>,
+}
+
+/// Collection built by the caller and passed to functions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Collection {
+    /// Name of the collection class
+    pub name: Name,
+    /// Type of the items
+    pub item_type: TypeRef,
+    /// Name of the function that creates the collection
+    pub create_function: Name,
+    /// Name of the function that destroys the collection
+    pub destroy_function: Name,
+    /// Name of the function that adds an item to the collection
+    pub add_function: Name,
+    /// True if the create function takes the number of items to reserve
+    pub has_reserve: bool,
+}
+
+/// Map built by the caller and passed to functions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Map {
+    /// Name of the map class
+    pub name: Name,
+    /// Type of the keys
+    pub key_type: TypeRef,
+    /// Type of the values
+    pub value_type: TypeRef,
+    /// Name of the function that creates the map
+    pub create_function: Name,
+    /// Name of the function that destroys the map
+    pub destroy_function: Name,
+    /// Name of the function that inserts a key/value pair into the map
+    pub insert_function: Name,
+}
+
+/// Base exception shared by error types
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaseException {
+    /// Name of the exception
+    pub name: Name,
+    /// Type of the exception
+    pub exception_type: ExceptionType,
+    /// Name of the parent base exception
+    pub parent: Option<Name>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Error type returned by functions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorType {
+    /// Name of the exception
+    pub name: Name,
+    /// Type of the exception
+    pub exception_type: ExceptionType,
+    /// Name of the enum listing the errors
+    pub error_enum: Name,
+    /// Name of the base exception
+    pub base: Option<Name>,
+    /// Exceptions thrown for specific variants of the enum
+    pub variant_exceptions: Vec<VariantException>,
+    /// True if the error carries a detailed message
+    pub message: bool,
+    /// Name of the universal struct carried by the error
+    pub payload: Option<Name>,
+    /// Variant used to complete futures that are dropped
+    pub drop_variant: Option<Name>,
+    /// Variant returned when an argument can't be converted: a null reference, a value that isn't
+    /// a variant of an enum, or the unknown tag of a union
+    pub bad_argument_variant: Option<Name>,
+}
+
+/// Exception thrown for a specific variant of an error type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VariantException {
+    /// Name of the variant
+    pub variant: Name,
+    /// Name of the exception
+    pub exception_name: Name,
+}
+
+/// Category of an interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InterfaceCategory {
+    /// Interface only used during the function call
+    Synchronous,
+    /// Interface that may be retained and invoked later
+    Asynchronous,
+    /// Interface used to complete a future
+    Future {
+        /// Value completing the future, none for futures without a value
+        value: Option<ReturnType>,
+        /// Name of the error type used to fail the future
+        error_type: Name,
+    },
+    /// Interface delivering a sequence of items
+    Stream {
+        /// Items of the stream
+        item: ReturnType,
+        /// Name of the error type used to fail the stream
+        error_type: Name,
+    },
+}
+
+/// Interface implemented in the target languages
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interface {
+    /// Name of the interface
+    pub name: Name,
+    /// Category of the interface
+    pub category: InterfaceCategory,
+    /// Callbacks of the interface
+    pub callbacks: Vec<Callback>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Callback of an interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Callback {
+    /// Name of the callback
+    pub name: Name,
+    /// True if the interface may be created from a lambda in the target languages
+    pub functional: bool,
+    /// Arguments of the callback
+    pub arguments: Vec<Arg>,
+    /// Value returned by the callback
+    pub return_type: Option<ReturnType>,
+    /// Value returned if the target language implementation doesn't override the callback
+    pub default_implementation: Option<DefaultReturnValue>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Value returned by the default implementation of a callback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DefaultReturnValue {
+    /// The callback doesn't return a value
+    Void,
+    /// Value of a basic type
+    Basic {
+        /// The value
+        value: BasicValue,
+    },
+    /// Struct constructed with an initializer without parameters
+    InitializedStruct {
+        /// Name of the struct
+        name: Name,
+        /// Name of the initializer
+        initializer: Name,
+    },
+}
+
+/// Argument of a function or callback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Arg {
+    /// Name of the argument
+    pub name: Name,
+    /// Type of the argument
+    pub arg_type: TypeRef,
+    /// Documentation
+    pub doc: String,
+    /// Value used when the caller omits the argument in languages that allow it
+    pub default: Option<DefaultValue>,
+    /// Class references are passed to the Rust implementation as `&mut` instead of `&`
+    pub mutable: bool,
+}
+
+/// Value returned by a function or callback
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReturnType {
+    /// Type of the value
+    pub value_type: TypeRef,
+    /// Documentation
+    pub doc: String,
+}
+
+/// Role of a native function
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FunctionCategory {
+    /// Function defined in the schema
+    Native,
+    /// Destructor of a class
+    ClassDestructor,
+    /// Creates a collection
+    CollectionCreate,
+    /// Destroys a collection
+    CollectionDestroy,
+    /// Adds an item to a collection
+    CollectionAdd,
+    /// Creates a map
+    MapCreate,
+    /// Destroys a map
+    MapDestroy,
+    /// Inserts a key/value pair into a map
+    MapInsert,
+    /// Retrieves the next item of an iterator
+    IteratorNext,
+    /// Destroys an owned iterator
+    IteratorDestroy,
+}
+
+/// Native function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Function {
+    /// Name of the function
+    pub name: Name,
+    /// Role of the function
+    pub category: FunctionCategory,
+    /// Arguments of the function
+    pub arguments: Vec<Arg>,
+    /// Value returned by the function
+    pub return_type: Option<ReturnType>,
+    /// Name of the error type returned by the function
+    pub error_type: Option<Name>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// How instances of a class are destroyed in the target languages
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "name", rename_all = "snake_case")]
+pub enum DestructionMode {
+    /// Destroyed by the garbage collector
+    Automatic,
+    /// Destroyed by a method with the provided name
+    Custom(Name),
+    /// Destroyed with the idiomatic dispose mechanism of the language
+    Dispose,
+}
+
+/// Method backed by a native function
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Method {
+    /// Name of the method
+    pub name: Name,
+    /// Name of the native function
+    pub function: Name,
+}
+
+/// Property backed by getter and setter methods
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Property {
+    /// Name of the property
+    pub name: Name,
+    /// Name of the native function backing the getter
+    pub getter: Name,
+    /// Name of the native function backing the setter, none for read-only properties
+    pub setter: Option<Name>,
+}
+
+/// Method completing a future
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FutureMethod {
+    /// Name of the method
+    pub name: Name,
+    /// Name of the native function
+    pub function: Name,
+    /// Name of the future interface
+    pub interface: Name,
+    /// Cancellation of the future, if supported
+    pub cancellation: Option<FutureCancellation>,
+}
+
+/// Cancellation of a future method
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FutureCancellation {
+    /// Variant of the error type used to fail a cancelled future
+    pub variant: Name,
+    /// Name of the native function that cancels the future
+    pub function: Name,
+}
+
+/// Method delivering a stream of items
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamMethod {
+    /// Name of the method
+    pub name: Name,
+    /// Name of the native function
+    pub function: Name,
+    /// Name of the stream interface
+    pub interface: Name,
+}
+
+/// Class
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Class {
+    /// Name of the class
+    pub name: Name,
+    /// Name of the native function backing the constructor
+    pub constructor: Option<Name>,
+    /// Name of the native function backing the destructor
+    pub destructor: Option<Name>,
+    /// How instances are destroyed in the target languages
+    pub destruction_mode: DestructionMode,
+    /// Methods
+    pub methods: Vec<Method>,
+    /// Properties
+    pub properties: Vec<Property>,
+    /// Static methods
+    pub static_methods: Vec<Method>,
+    /// Methods completing a future
+    pub future_methods: Vec<FutureMethod>,
+    /// Methods delivering a stream of items
+    pub stream_methods: Vec<StreamMethod>,
+    /// Names of the interfaces implemented by the class
+    pub interfaces: Vec<Name>,
+    /// Documentation
+    pub doc: Doc,
+}
+
+/// Class with only static methods
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StaticClass {
+    /// Name of the class
+    pub name: Name,
+    /// Static methods
+    pub static_methods: Vec<Method>,
+    /// Documentation
+    pub doc: Doc,
+}
@@ -45,3 +45,6 @@ pub mod model;
 
 /// API specific to backend binding generators
 pub mod backend;
+
+/// Serializable intermediate representation of a validated library
+pub mod ir;
@@ -6,8 +6,11 @@ pub struct ClassBuilder<'a> {
     constructor: Option<ClassConstructor<Unvalidated>>,
     destructor: Option<ClassDestructor<Unvalidated>>,
     methods: Vec<Method<Unvalidated>>,
+    properties: Vec<Property<Unvalidated>>,
     static_methods: Vec<StaticMethod<Unvalidated>>,
     async_methods: Vec<FutureMethod<Unvalidated>>,
+    stream_methods: Vec<StreamMethod<Unvalidated>>,
+    interfaces: Vec<InterfaceHandle>,
     doc: Option<Doc<Unvalidated>>,
     destruction_mode: DestructionMode,
 }
@@ -20,8 +23,11 @@ impl<'a> ClassBuilder<'a> {
             constructor: None,
             destructor: None,
             methods: Vec::new(),
+            properties: Vec::new(),
             static_methods: Vec::new(),
             async_methods: Vec::new(),
+            stream_methods: Vec::new(),
+            interfaces: Vec::new(),
             doc: None,
             destruction_mode: DestructionMode::Automatic,
         }
@@ -81,6 +87,73 @@ impl<'a> ClassBuilder<'a> {
         Ok(self)
     }
 
+    /// Define a property from a getter method and an optional setter method.
+    ///
+    /// The getter must return a value without taking any arguments, and the setter must accept
+    /// a single argument of the same type. Languages with properties (C#) expose them directly,
+    /// while other languages get a pair of accessor methods named after the property.
+    ///
+    /// No other member of the class may be named like the property or its accessors, e.g. a
+    /// property `value` conflicts with methods named `value`, `get_value` or `set_value`. This is
+    /// checked when the class is built.
+    pub fn define_property<T: IntoName>(
+        mut self,
+        name: T,
+        getter: Method<Unvalidated>,
+        setter: Option<Method<Unvalidated>>,
+    ) -> BindResult<Self> {
+        let name = name.into_name()?;
+
+        if self.properties.iter().any(|x| x.name == name) {
+            return Err(BindingErrorVariant::PropertyAlreadyDefined {
+                handle: self.declaration,
+                name,
+            }
+            .into());
+        }
+
+        self.check_class(&getter.name, getter.associated_class.clone())?;
+        let getter_type = match getter.native_function.return_type.get_value() {
+            Some(x) if getter.native_function.arguments.len() == 1 => x.clone(),
+            _ => {
+                return Err(BindingErrorVariant::PropertyGetterNotValid {
+                    name,
+                    getter: getter.name,
+                }
+                .into())
+            }
+        };
+
+        if let Some(setter) = &setter {
+            self.check_class(&setter.name, setter.associated_class.clone())?;
+            let setter_type = match setter.native_function.arguments.as_slice() {
+                [_, value] if setter.native_function.return_type.is_none() => &value.arg_type,
+                _ => {
+                    return Err(BindingErrorVariant::PropertySetterNotValid {
+                        name,
+                        setter: setter.name.clone(),
+                    }
+                    .into())
+                }
+            };
+            if !is_same_type(&getter_type, setter_type) {
+                return Err(BindingErrorVariant::PropertyTypeMismatch {
+                    name,
+                    setter: setter.name.clone(),
+                }
+                .into());
+            }
+        }
+
+        self.properties.push(Property {
+            name,
+            getter,
+            setter,
+        });
+
+        Ok(self)
+    }
+
     pub fn static_method(mut self, method: StaticMethod<Unvalidated>) -> BindResult<Self> {
         self.static_methods.push(method);
         Ok(self)
@@ -98,6 +171,79 @@ impl<'a> ClassBuilder<'a> {
         Ok(())
     }
 
+    fn check_property_names(&self) -> BindResult<()> {
+        let methods = self
+            .methods
+            .iter()
+            .map(|x| &x.name)
+            .chain(self.static_methods.iter().map(|x| &x.name))
+            .chain(self.async_methods.iter().map(|x| &x.name))
+            .chain(self.stream_methods.iter().map(|x| &x.name));
+
+        for property in &self.properties {
+            let accessors = [
+                property.name.to_string(),
+                format!("get_{}", property.name),
+                format!("set_{}", property.name),
+            ];
+            let other_properties = self
+                .properties
+                .iter()
+                .map(|x| &x.name)
+                .filter(|x| **x != property.name);
+
+            if let Some(member) = methods
+                .clone()
+                .chain(other_properties)
+                .find(|x| accessors.iter().any(|a| a == x.as_ref()))
+            {
+                return Err(BindingErrorVariant::PropertyNameCollision {
+                    handle: self.declaration.clone(),
+                    name: property.name.clone(),
+                    member: member.clone(),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    fn check_interface_methods(&self, interface: &InterfaceHandle) -> BindResult<()> {
+        for cb in &interface.callbacks {
+            let method = match self.methods.iter().find(|x| x.name == cb.name) {
+                Some(x) => x,
+                None => {
+                    return Err(BindingErrorVariant::InterfaceMethodNotDefined {
+                        handle: self.declaration.clone(),
+                        interface: interface.name.clone(),
+                        callback: cb.name.clone(),
+                    }
+                    .into())
+                }
+            };
+
+            let function = &method.native_function;
+            let args = &function.arguments[1..];
+            let matches = function.error_type.get().is_none()
+                && args.len() == cb.arguments.len()
+                && args
+                    .iter()
+                    .zip(cb.arguments.iter())
+                    .all(|(arg, cb_arg)| is_same_argument(&cb_arg.arg_type, &arg.arg_type))
+                && is_same_return(cb.return_type.get_value(), function.return_type.get_value());
+
+            if !matches {
+                return Err(BindingErrorVariant::InterfaceMethodMismatch {
+                    handle: self.declaration.clone(),
+                    interface: interface.name.clone(),
+                    callback: cb.name.clone(),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+
     pub fn async_method(mut self, method: FutureMethod<Unvalidated>) -> BindResult<Self> {
         self.check_class(&method.name, method.associated_class.clone())?;
 
@@ -106,6 +252,37 @@ impl<'a> ClassBuilder<'a> {
         Ok(self)
     }
 
+    pub fn stream_method(mut self, method: StreamMethod<Unvalidated>) -> BindResult<Self> {
+        self.check_class(&method.name, method.associated_class.clone())?;
+
+        self.stream_methods.push(method);
+
+        Ok(self)
+    }
+
+    /// Declare that the class implements an interface.
+    ///
+    /// Each callback of the interface must have a method with the same name on the class. The
+    /// method must take the same arguments, return the same type and cannot fail. The C API gets
+    /// a function that fills the interface with the methods of an instance, while other languages
+    /// make the class implement the interface directly.
+    ///
+    /// Only synchronous interfaces can be implemented. The interface filled in by the C API
+    /// borrows the instance, so it must not be retained beyond the call that receives it.
+    pub fn implements(mut self, interface: &SynchronousInterface) -> BindResult<Self> {
+        let interface = &interface.inner;
+        if self.interfaces.contains(interface) {
+            return Err(BindingErrorVariant::InterfaceAlreadyImplemented {
+                handle: self.declaration,
+                interface: interface.name.clone(),
+            }
+            .into());
+        }
+
+        self.interfaces.push(interface.clone());
+        Ok(self)
+    }
+
     pub fn custom_destroy<T: IntoName>(mut self, name: T) -> BindResult<Self> {
         if self.destructor.is_none() {
             return Err(BindingErrorVariant::NoDestructorForManualDestruction {
@@ -131,6 +308,12 @@ impl<'a> ClassBuilder<'a> {
     }
 
     pub fn build(self) -> BindResult<ClassHandle> {
+        self.check_property_names()?;
+
+        for interface in &self.interfaces {
+            self.check_interface_methods(interface)?;
+        }
+
         let doc = match self.doc {
             Some(doc) => doc,
             None => {
@@ -146,8 +329,11 @@ impl<'a> ClassBuilder<'a> {
             constructor: self.constructor,
             destructor: self.destructor,
             methods: self.methods,
+            properties: self.properties,
             static_methods: self.static_methods,
             future_methods: self.async_methods,
+            stream_methods: self.stream_methods,
+            interfaces: self.interfaces,
             doc,
             destruction_mode: self.destruction_mode,
             settings: self.lib.clone_settings(),
@@ -160,6 +346,43 @@ impl<'a> ClassBuilder<'a> {
     }
 }
 
+/// true if the value returned by a getter can be passed back to a setter
+fn is_same_type(getter: &FunctionReturnValue, setter: &FunctionArgument) -> bool {
+    match (getter, setter) {
+        (FunctionReturnValue::Basic(x), FunctionArgument::Basic(y)) => x == y,
+        (FunctionReturnValue::String(_), FunctionArgument::String(_)) => true,
+        (FunctionReturnValue::Bytes(_), FunctionArgument::Bytes(_)) => true,
+        (FunctionReturnValue::Union(x), FunctionArgument::Union(y)) => x == y,
+        (FunctionReturnValue::ClassRef(x), FunctionArgument::ClassRef(y)) => x == y,
+        (
+            FunctionReturnValue::Struct(UniversalOr::Universal(x)),
+            FunctionArgument::Struct(UniversalOr::Universal(y)),
+        ) => x == y,
+        _ => false,
+    }
+}
+
+/// true if a callback argument can be forwarded to a method without conversion
+fn is_same_argument(callback: &CallbackArgument, method: &FunctionArgument) -> bool {
+    match (callback, method) {
+        (CallbackArgument::Basic(x), FunctionArgument::Basic(y)) => x == y,
+        (CallbackArgument::String(_), FunctionArgument::String(_)) => true,
+        _ => false,
+    }
+}
+
+/// true if the value returned by a method can be returned from a callback without conversion
+fn is_same_return(
+    callback: Option<&CallbackReturnValue>,
+    method: Option<&FunctionReturnValue>,
+) -> bool {
+    match (callback, method) {
+        (None, None) => true,
+        (Some(CallbackReturnValue::Basic(x)), Some(FunctionReturnValue::Basic(y))) => x == y,
+        _ => false,
+    }
+}
+
 pub struct StaticClassBuilder<'a> {
     lib: &'a mut LibraryBuilder,
     name: Name,
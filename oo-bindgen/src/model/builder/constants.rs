@@ -26,13 +26,14 @@ impl<'a> ConstantSetBuilder<'a> {
         Ok(self)
     }
 
-    pub fn add<T: IntoName, D: Into<Doc<Unvalidated>>>(
+    pub fn add<T: IntoName, V: Into<ConstantValue>, D: Into<Doc<Unvalidated>>>(
         mut self,
         name: T,
-        value: ConstantValue,
+        value: V,
         doc: D,
     ) -> BindResult<Self> {
         let name = name.into_name()?;
+        let value = value.into();
         if self.names.contains(name.as_ref()) {
             return Err(BindingErrorVariant::ConstantNameAlreadyUsed {
                 set_name: self.name,
@@ -40,6 +41,8 @@ impl<'a> ConstantSetBuilder<'a> {
             }
             .into());
         }
+        Self::check_value(&self.name, &name, &value)?;
+        self.names.insert(name.to_string());
         self.values.push(Constant {
             name,
             value,
@@ -48,6 +51,52 @@ impl<'a> ConstantSetBuilder<'a> {
         Ok(self)
     }
 
+    fn check_value(set_name: &Name, constant_name: &Name, value: &ConstantValue) -> BindResult<()> {
+        match value {
+            ConstantValue::Primitive(x, representation) => {
+                let is_integer = !matches!(
+                    x,
+                    PrimitiveValue::Bool(_) | PrimitiveValue::Float(_) | PrimitiveValue::Double(_)
+                );
+                if !is_integer && *representation != Representation::Decimal {
+                    return Err(BindingErrorVariant::ConstantRepresentationNotSupported {
+                        set_name: set_name.clone(),
+                        constant_name: constant_name.clone(),
+                    }
+                    .into());
+                }
+                let is_finite = match x {
+                    PrimitiveValue::Float(x) => x.is_finite(),
+                    PrimitiveValue::Double(x) => x.is_finite(),
+                    _ => true,
+                };
+                if !is_finite {
+                    return Err(BindingErrorVariant::ConstantNotFinite {
+                        set_name: set_name.clone(),
+                        constant_name: constant_name.clone(),
+                    }
+                    .into());
+                }
+            }
+            ConstantValue::String(x) => {
+                // the escape sequences for other control characters differ between the languages
+                if x.chars()
+                    .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+                {
+                    return Err(
+                        BindingErrorVariant::ConstantStringContainsControlCharacter {
+                            set_name: set_name.clone(),
+                            constant_name: constant_name.clone(),
+                        }
+                        .into(),
+                    );
+                }
+            }
+            ConstantValue::Duration(_) | ConstantValue::Enum(_) => {}
+        }
+        Ok(())
+    }
+
     pub fn build(self) -> BindResult<()> {
         let handle = Handle::new(ConstantSet {
             name: self.name,
@@ -9,6 +9,7 @@ pub struct EnumBuilder<'a> {
     variant_names: HashSet<String>,
     variant_values: HashSet<i32>,
     next_value: i32,
+    unknown_value: UnknownValuePolicy,
     doc: OptionalDoc,
 }
 
@@ -21,6 +22,7 @@ impl<'a> EnumBuilder<'a> {
             variant_names: HashSet::new(),
             variant_values: HashSet::new(),
             next_value: 0,
+            unknown_value: UnknownValuePolicy::default(),
             doc: OptionalDoc::new(name),
         }
     }
@@ -62,6 +64,14 @@ impl<'a> EnumBuilder<'a> {
         self.variant(name.into_name()?, value, doc)
     }
 
+    /// Set how the Rust FFI converts integers that don't match any variant.
+    ///
+    /// Defaults to [UnknownValuePolicy::Panic]
+    pub fn unknown_value_policy(mut self, policy: UnknownValuePolicy) -> BindResult<Self> {
+        self.unknown_value = policy;
+        Ok(self)
+    }
+
     pub fn doc<D: Into<Doc<Unvalidated>>>(mut self, doc: D) -> BindResult<Self> {
         self.doc.set(doc.into())?;
         Ok(self)
@@ -70,10 +80,21 @@ impl<'a> EnumBuilder<'a> {
     pub(crate) fn build_and_release(
         self,
     ) -> BindResult<(Handle<Enum<Unvalidated>>, &'a mut LibraryBuilder)> {
+        if let UnknownValuePolicy::MapTo(variant) = &self.unknown_value {
+            if !self.variant_names.contains(variant.as_ref()) {
+                return Err(BindingErrorVariant::UnknownEnumVariant {
+                    name: self.name,
+                    variant_name: variant.to_string(),
+                }
+                .into());
+            }
+        }
+
         let handle = Handle::new(Enum {
             name: self.name,
             settings: self.lib.clone_settings(),
             variants: self.variants,
+            unknown_value: self.unknown_value,
             doc: self.doc.extract()?,
         });
 
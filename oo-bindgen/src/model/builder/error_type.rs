@@ -1,8 +1,69 @@
 use crate::model::*;
 
+pub struct BaseExceptionBuilder<'a> {
+    lib: &'a mut LibraryBuilder,
+    name: Name,
+    exception_type: ExceptionType,
+    parent: Option<BaseExceptionHandle>,
+    doc: OptionalDoc,
+}
+
+impl<'a> BaseExceptionBuilder<'a> {
+    pub(crate) fn new(
+        lib: &'a mut LibraryBuilder,
+        name: Name,
+        exception_type: ExceptionType,
+    ) -> Self {
+        Self {
+            lib,
+            name: name.clone(),
+            exception_type,
+            parent: None,
+            doc: OptionalDoc::new(name),
+        }
+    }
+
+    /// Derive this base exception from another base exception with the same exception type
+    pub fn extends(mut self, parent: &BaseExceptionHandle) -> BindResult<Self> {
+        self.parent = Some(check_base(
+            &self.name,
+            self.exception_type,
+            &self.parent,
+            parent,
+        )?);
+        Ok(self)
+    }
+
+    pub fn doc<D: Into<Doc<Unvalidated>>>(mut self, doc: D) -> BindResult<Self> {
+        self.doc.set(doc.into())?;
+        Ok(self)
+    }
+
+    pub fn build(self) -> BindResult<BaseExceptionHandle> {
+        let handle = Handle::new(BaseException {
+            name: self.name,
+            exception_type: self.exception_type,
+            parent: self.parent,
+            doc: self.doc.extract()?,
+        });
+
+        self.lib
+            .add_statement(Statement::BaseException(handle.clone()))?;
+
+        Ok(handle)
+    }
+}
+
 pub struct ErrorTypeBuilder<'a> {
     exception_name: Name,
     exception_type: ExceptionType,
+    base: Option<BaseExceptionHandle>,
+    variant_exceptions: Vec<VariantException>,
+    message: bool,
+    payload: Option<UniversalStructHandle>,
+    drop_variant: Option<Name>,
+    bad_argument_variant: Option<Name>,
+    panic_variant: Option<Name>,
     inner: EnumBuilder<'a>,
 }
 
@@ -10,11 +71,19 @@ impl<'a> ErrorTypeBuilder<'a> {
     pub(crate) fn new(
         exception_name: Name,
         exception_type: ExceptionType,
+        panic_variant: Option<Name>,
         inner: EnumBuilder<'a>,
     ) -> Self {
         Self {
             exception_name,
             exception_type,
+            base: None,
+            variant_exceptions: Vec::new(),
+            message: false,
+            payload: None,
+            drop_variant: None,
+            bad_argument_variant: None,
+            panic_variant,
             inner,
         }
     }
@@ -30,6 +99,111 @@ impl<'a> ErrorTypeBuilder<'a> {
         })
     }
 
+    /// Add an error variant that is reported using its own exception.
+    ///
+    /// The exception derives from the exception of the error type, so handlers for the
+    /// error type still catch it.
+    pub fn add_error_with_exception<T: IntoName, D: Into<Doc<Unvalidated>>>(
+        mut self,
+        name: T,
+        exception_name: T,
+        doc: D,
+    ) -> BindResult<Self> {
+        let name = name.into_name()?;
+        self.variant_exceptions.push(VariantException {
+            variant: name.clone(),
+            exception_name: exception_name.into_name()?,
+        });
+        self.add_error(name, doc)
+    }
+
+    /// Add the error variant with which futures are completed when the Rust side drops them.
+    ///
+    /// The Rust backend generates a promise for each future interface using this error type.
+    /// Dropping the promise without completing it invokes the failure callback with this variant.
+    pub fn add_drop_error<T: IntoName, D: Into<Doc<Unvalidated>>>(
+        mut self,
+        name: T,
+        doc: D,
+    ) -> BindResult<Self> {
+        if self.drop_variant.is_some() {
+            return Err(BindingErrorVariant::DropErrorAlreadyDefined {
+                name: self.exception_name,
+            }
+            .into());
+        }
+        let name = name.into_name()?;
+        self.drop_variant = Some(name.clone());
+        self.add_error(name, doc)
+    }
+
+    /// Add the error variant returned by functions when the Rust backend can't convert an argument.
+    ///
+    /// For example, an integer that doesn't match any variant of an enum using
+    /// [UnknownValuePolicy::Error] is reported with this variant instead of a panic.
+    pub fn add_bad_argument_error<T: IntoName, D: Into<Doc<Unvalidated>>>(
+        mut self,
+        name: T,
+        doc: D,
+    ) -> BindResult<Self> {
+        if self.bad_argument_variant.is_some() {
+            return Err(BindingErrorVariant::BadArgumentErrorAlreadyDefined {
+                name: self.exception_name,
+            }
+            .into());
+        }
+        let name = name.into_name()?;
+        self.bad_argument_variant = Some(name.clone());
+        self.add_error(name, doc)
+    }
+
+    /// Derive the exception from a base exception with the same exception type
+    pub fn extends(mut self, base: &BaseExceptionHandle) -> BindResult<Self> {
+        self.base = Some(check_base(
+            &self.exception_name,
+            self.exception_type,
+            &self.base,
+            base,
+        )?);
+        Ok(self)
+    }
+
+    /// Allow the Rust implementation to attach a detailed message to errors of this type.
+    ///
+    /// The message of the last error on the calling thread is retrieved by the bindings
+    /// and becomes the message of the exception.
+    pub fn with_message(self) -> BindResult<Self> {
+        Ok(Self {
+            message: true,
+            ..self
+        })
+    }
+
+    /// Allow the Rust implementation to attach a universal struct to errors of this type.
+    ///
+    /// The struct may only contain fields that own their data, i.e. no strings, bytes, or lists.
+    pub fn with_payload(self, payload: UniversalStructHandle) -> BindResult<Self> {
+        if self.payload.is_some() {
+            return Err(BindingErrorVariant::ErrorPayloadAlreadyDefined {
+                name: self.exception_name,
+            }
+            .into());
+        }
+
+        if !is_plain_data(&payload) {
+            return Err(BindingErrorVariant::ErrorPayloadNotPlainData {
+                name: self.exception_name,
+                struct_name: payload.name().clone(),
+            }
+            .into());
+        }
+
+        Ok(Self {
+            payload: Some(payload),
+            ..self
+        })
+    }
+
     pub fn doc<D: Into<Doc<Unvalidated>>>(self, doc: D) -> BindResult<Self> {
         Ok(Self {
             inner: self.inner.doc(doc)?,
@@ -38,12 +212,30 @@ impl<'a> ErrorTypeBuilder<'a> {
     }
 
     pub fn build(self) -> BindResult<ErrorType<Unvalidated>> {
-        let (inner, lib) = self.inner.build_and_release()?;
+        // the panic variant comes last so that it doesn't change the values of the other variants
+        let inner = match self.panic_variant {
+            Some(name) => self
+                .inner
+                .push(name, "A panic occurred in the Rust implementation")?,
+            None => self.inner,
+        };
+
+        let (inner, lib) = inner.build_and_release()?;
+
+        for x in self.variant_exceptions.iter() {
+            lib.check_unique_symbol(&x.exception_name)?;
+        }
 
         let err = ErrorType {
             exception_name: self.exception_name,
             exception_type: self.exception_type,
             inner,
+            base: self.base,
+            variant_exceptions: self.variant_exceptions,
+            message: self.message,
+            payload: self.payload,
+            drop_variant: self.drop_variant,
+            bad_argument_variant: self.bad_argument_variant,
         };
 
         lib.add_statement(Statement::ErrorType(err.clone()))?;
@@ -51,3 +243,36 @@ impl<'a> ErrorTypeBuilder<'a> {
         Ok(err)
     }
 }
+
+fn check_base(
+    name: &Name,
+    exception_type: ExceptionType,
+    current: &Option<BaseExceptionHandle>,
+    base: &BaseExceptionHandle,
+) -> BindResult<BaseExceptionHandle> {
+    if current.is_some() {
+        return Err(BindingErrorVariant::BaseExceptionAlreadyDefined { name: name.clone() }.into());
+    }
+
+    if base.exception_type != exception_type {
+        return Err(BindingErrorVariant::BaseExceptionTypeMismatch {
+            name: name.clone(),
+            base: base.name.clone(),
+        }
+        .into());
+    }
+
+    Ok(base.clone())
+}
+
+/// payloads are retained after the call returns, so they can't borrow anything
+fn is_plain_data(handle: &UniversalStructHandle) -> bool {
+    handle.fields.iter().all(|field| match &field.field_type {
+        UniversalStructField::Basic(_) => true,
+        UniversalStructField::Optional(_) => true,
+        UniversalStructField::Struct(x) => is_plain_data(x),
+        UniversalStructField::String(_) => false,
+        UniversalStructField::Bytes(_) => false,
+        UniversalStructField::List(_) => false,
+    })
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use crate::model::*;
+
+pub struct FlagsBuilder<'a> {
+    lib: &'a mut LibraryBuilder,
+    name: Name,
+    flags_type: FlagsType,
+    bits: Vec<FlagsBit<Unvalidated>>,
+    bit_names: HashSet<String>,
+    used_bits: u64,
+    next_bit: u32,
+    doc: OptionalDoc,
+}
+
+impl<'a> FlagsBuilder<'a> {
+    pub(crate) fn new(lib: &'a mut LibraryBuilder, name: Name, flags_type: FlagsType) -> Self {
+        Self {
+            lib,
+            name: name.clone(),
+            flags_type,
+            bits: Vec::new(),
+            bit_names: HashSet::new(),
+            used_bits: 0,
+            next_bit: 0,
+            doc: OptionalDoc::new(name),
+        }
+    }
+
+    /// Add a named bit, or group of bits, with an explicit mask
+    pub fn bit<T: IntoName, D: Into<Doc<Unvalidated>>>(
+        mut self,
+        name: T,
+        value: u64,
+        doc: D,
+    ) -> BindResult<Self> {
+        let name = name.into_name()?;
+        if !self.bit_names.insert(name.to_string()) {
+            return Err(BindingErrorVariant::DuplicateFlagsBitName {
+                name: self.name,
+                bit_name: name,
+            }
+            .into());
+        }
+        if value == 0 || value & !self.flags_type.mask() != 0 {
+            return Err(BindingErrorVariant::FlagsBitOutOfRange {
+                name: self.name,
+                bit_name: name,
+                value,
+            }
+            .into());
+        }
+        if value & self.used_bits != 0 {
+            return Err(BindingErrorVariant::FlagsBitOverlap {
+                name: self.name,
+                bit_name: name,
+                value,
+            }
+            .into());
+        }
+
+        self.used_bits |= value;
+        self.next_bit = u64::BITS - value.leading_zeros();
+        self.bits.push(FlagsBit {
+            name,
+            value,
+            doc: doc.into(),
+        });
+        Ok(self)
+    }
+
+    /// Add a named bit using the bit following the highest bit defined so far
+    pub fn push<T: IntoName, D: Into<Doc<Unvalidated>>>(self, name: T, doc: D) -> BindResult<Self> {
+        let name = name.into_name()?;
+        if self.next_bit >= self.flags_type.bits() {
+            return Err(BindingErrorVariant::FlagsFull {
+                name: self.name,
+                bit_name: name,
+            }
+            .into());
+        }
+        let value = 1u64 << self.next_bit;
+        self.bit(name, value, doc)
+    }
+
+    pub fn doc<D: Into<Doc<Unvalidated>>>(mut self, doc: D) -> BindResult<Self> {
+        self.doc.set(doc.into())?;
+        Ok(self)
+    }
+
+    pub fn build(self) -> BindResult<FlagsHandle> {
+        let handle = Handle::new(Flags {
+            name: self.name,
+            flags_type: self.flags_type,
+            settings: self.lib.clone_settings(),
+            bits: self.bits,
+            doc: self.doc.extract()?,
+        });
+
+        self.lib
+            .add_statement(Statement::FlagsDefinition(handle.clone()))?;
+
+        Ok(handle)
+    }
+}
@@ -35,10 +35,66 @@ impl<'a> FunctionBuilder<'a> {
     ) -> BindResult<Self> {
         let param_type = param_type.into();
         let name = name.into_name()?;
+        if self.params.iter().any(|x| x.default.is_some()) {
+            return Err(BindingErrorVariant::FunctionParamWithoutDefault {
+                func_name: self.name,
+                param_name: name,
+            }
+            .into());
+        }
         self.params.push(Arg {
             name,
             arg_type: param_type,
             doc: doc.into(),
+            default: None,
+            mutable: false,
+        });
+        Ok(self)
+    }
+
+    /// Define a class reference parameter that the Rust implementation receives as `&mut`.
+    ///
+    /// Class references are otherwise received as `&`, because C callers may use an instance
+    /// from several threads at once or pass it as more than one argument. Only opt in when the
+    /// documentation of the function requires exclusive access to the instance.
+    pub fn param_mut<T: IntoName, D: Into<DocString<Unvalidated>>>(
+        self,
+        name: T,
+        class: ClassDeclarationHandle,
+        doc: D,
+    ) -> BindResult<Self> {
+        let mut builder = self.param(name, class, doc)?;
+        if let Some(param) = builder.params.last_mut() {
+            param.mutable = true;
+        }
+        Ok(builder)
+    }
+
+    /// Define a parameter that may be omitted by the caller in languages that support it.
+    ///
+    /// The default is validated the same way as struct initializer defaults. All parameters
+    /// following a defaulted parameter must also have a default. The C function always takes
+    /// the full list of arguments.
+    pub fn param_with_default<
+        T: IntoName,
+        D: Into<DocString<Unvalidated>>,
+        P: Into<FunctionArgument>,
+        V: Into<InitializerDefault>,
+    >(
+        mut self,
+        name: T,
+        param_type: P,
+        default: V,
+        doc: D,
+    ) -> BindResult<Self> {
+        let param_type = param_type.into();
+        let default = param_type.validate_default_value(&default.into())?;
+        self.params.push(Arg {
+            name: name.into_name()?,
+            arg_type: param_type,
+            doc: doc.into(),
+            default: Some(default),
+            mutable: false,
         });
         Ok(self)
     }
@@ -48,8 +104,16 @@ impl<'a> FunctionBuilder<'a> {
         return_type: T,
         doc: D,
     ) -> BindResult<Self> {
-        self.return_type
-            .set(&self.name, return_type.into(), doc.into())?;
+        let return_type = return_type.into();
+        if let FunctionReturnValue::Iterator(x) = &return_type {
+            if !x.is_owned() {
+                return Err(BindingErrorVariant::IteratorNotOwned {
+                    name: x.name().clone(),
+                }
+                .into());
+            }
+        }
+        self.return_type.set(&self.name, return_type, doc.into())?;
         Ok(self)
     }
 
@@ -64,6 +128,45 @@ impl<'a> FunctionBuilder<'a> {
     }
 
     pub fn build(self) -> BindResult<FunctionHandle> {
+        let reports_bad_arguments = self
+            .error_type
+            .get()
+            .map(|x| x.bad_argument_variant.is_some())
+            .unwrap_or(false);
+
+        if let Some(param) = self
+            .params
+            .iter()
+            .find(|x| x.arg_type.can_be_invalid() && !reports_bad_arguments)
+        {
+            return Err(BindingErrorVariant::BadArgumentNotReported {
+                func_name: self.name,
+                param_name: param.name.clone(),
+            }
+            .into());
+        }
+
+        // functions that can't report a null argument return a value in place of the implementation
+        let returns_null_argument_value = self
+            .return_type
+            .get_value()
+            .map(|x| x.null_argument_value().is_some())
+            .unwrap_or(true);
+        let aborts_on_null = self.lib.settings().null_argument == NullArgumentFallback::Abort;
+
+        if let Some(param) = self.params.iter().find(|x| {
+            x.arg_type.is_null_checked(self.function_type)
+                && !reports_bad_arguments
+                && !returns_null_argument_value
+                && !aborts_on_null
+        }) {
+            return Err(BindingErrorVariant::NullArgumentNotReported {
+                func_name: self.name,
+                param_name: param.name.clone(),
+            }
+            .into());
+        }
+
         let handle = Handle::new(Function {
             name: self.name,
             category: self.function_type,
@@ -145,6 +248,51 @@ impl<'a> ClassMethodBuilder<'a> {
         })
     }
 
+    pub fn param_with_default<
+        T: IntoName,
+        D: Into<DocString<Unvalidated>>,
+        P: Into<FunctionArgument>,
+        V: Into<InitializerDefault>,
+    >(
+        self,
+        name: T,
+        param_type: P,
+        default: V,
+        doc: D,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            method_name: self.method_name,
+            class: self.class,
+            inner: self
+                .inner
+                .param_with_default(name, param_type, default, doc)?,
+        })
+    }
+
+    /// Define a class reference parameter that the Rust implementation receives as `&mut`, see
+    /// [FunctionBuilder::param_mut]
+    pub fn param_mut<T: IntoName, D: Into<DocString<Unvalidated>>>(
+        self,
+        name: T,
+        class: ClassDeclarationHandle,
+        doc: D,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            method_name: self.method_name,
+            class: self.class,
+            inner: self.inner.param_mut(name, class, doc)?,
+        })
+    }
+
+    /// Pass the instance to the Rust implementation as `&mut` instead of `&`, see
+    /// [FunctionBuilder::param_mut]
+    pub fn mutable_instance(mut self) -> BindResult<Self> {
+        if let Some(instance) = self.inner.params.first_mut() {
+            instance.mutable = true;
+        }
+        Ok(self)
+    }
+
     pub fn returns<D: Into<DocString<Unvalidated>>, T: Into<FunctionReturnValue>>(
         self,
         return_type: T,
@@ -220,6 +368,26 @@ impl<'a> ClassConstructorBuilder<'a> {
         })
     }
 
+    pub fn param_with_default<
+        T: IntoName,
+        D: Into<DocString<Unvalidated>>,
+        P: Into<FunctionArgument>,
+        V: Into<InitializerDefault>,
+    >(
+        self,
+        name: T,
+        param_type: P,
+        default: V,
+        doc: D,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            class: self.class,
+            inner: self
+                .inner
+                .param_with_default(name, param_type, default, doc)?,
+        })
+    }
+
     pub fn fails_with(self, err: ErrorType<Unvalidated>) -> BindResult<Self> {
         Ok(Self {
             class: self.class,
@@ -241,6 +409,7 @@ impl<'a> ClassConstructorBuilder<'a> {
 
 pub struct FutureMethodBuilder<'a> {
     future: FutureInterface<Unvalidated>,
+    cancel_variant: Option<Name>,
     inner: ClassMethodBuilder<'a>,
 }
 
@@ -255,6 +424,7 @@ impl<'a> FutureMethodBuilder<'a> {
 
         Ok(Self {
             future,
+            cancel_variant: None,
             inner: builder,
         })
     }
@@ -270,6 +440,7 @@ impl<'a> FutureMethodBuilder<'a> {
         let builder = self.inner.param(name, param_type, doc)?;
         Ok(Self {
             future: self.future,
+            cancel_variant: self.cancel_variant,
             inner: builder,
         })
     }
@@ -277,6 +448,7 @@ impl<'a> FutureMethodBuilder<'a> {
     pub fn fails_with(self, err: ErrorType<Unvalidated>) -> BindResult<Self> {
         Ok(Self {
             future: self.future,
+            cancel_variant: self.cancel_variant,
             inner: self.inner.fails_with(err)?,
         })
     }
@@ -284,12 +456,38 @@ impl<'a> FutureMethodBuilder<'a> {
     pub fn doc<D: Into<Doc<Unvalidated>>>(self, doc: D) -> BindResult<Self> {
         Ok(Self {
             future: self.future,
+            cancel_variant: self.cancel_variant,
             inner: self.inner.doc(doc)?,
         })
     }
 
-    pub fn build(self) -> BindResult<FutureMethod<Unvalidated>> {
+    /// Generate a cancel function for the operation
+    ///
+    /// The native function returns an identifier of the operation. Passing it to the cancel
+    /// function completes the future with the supplied variant of the future's error type.
+    /// Any later completion by the implementation is ignored.
+    pub fn cancellable<S: IntoName>(mut self, variant: S) -> BindResult<Self> {
+        let variant = variant.into_name()?;
+        if self.cancel_variant.is_some() {
+            return Err(BindingErrorVariant::FutureCancellationAlreadyDefined {
+                func_name: self.inner.inner.name,
+            }
+            .into());
+        }
+        self.future
+            .error_type
+            .inner
+            .validate_contains_variant_name(variant.as_ref())?;
+        self.cancel_variant = Some(variant);
+        Ok(self)
+    }
+
+    pub fn build(mut self) -> BindResult<FutureMethod<Unvalidated>> {
         let future = self.future.clone();
+        let cancellation = match self.cancel_variant.take() {
+            None => None,
+            Some(variant) => Some(self.define_cancel_function(variant)?),
+        };
         let callback_parameter_name = self
             .inner
             .inner
@@ -298,19 +496,118 @@ impl<'a> FutureMethodBuilder<'a> {
             .future
             .async_method_callback_parameter_name
             .clone();
+        let method = self.inner.param(
+            callback_parameter_name,
+            FunctionArgument::Interface(self.future.interface),
+            "callback invoked when the operation completes",
+        )?;
+        let method = match cancellation {
+            None => method,
+            Some(_) => method.returns(
+                Primitive::U64,
+                "identifier of the operation which may be used to cancel it",
+            )?,
+        }
+        .build()?;
+
+        Ok(FutureMethod {
+            name: method.name,
+            associated_class: method.associated_class,
+            future,
+            native_function: method.native_function,
+            cancellation,
+        })
+    }
+
+    fn define_cancel_function(
+        &mut self,
+        variant: Name,
+    ) -> BindResult<FutureCancellation<Unvalidated>> {
+        let class = self.inner.class.name.clone();
+        let method = self.inner.method_name.clone();
+        let error = self.future.error_type.inner.name.clone();
+        let function = self
+            .inner
+            .inner
+            .lib
+            .define_function(self.inner.inner.name.append(&Name::create("cancel")?))?
+            .param(
+                "operation",
+                Primitive::U64,
+                format!("identifier returned by {{class:{class}.{method}()}}"),
+            )?
+            .doc(
+                doc(format!("Cancel an operation started by {{class:{class}.{method}()}}"))
+                    .details(format!("The operation completes with {{enum:{error}.{variant}}} unless it already completed. Cancelling an unknown or completed operation has no effect.")),
+            )?
+            .build()?;
+
+        Ok(FutureCancellation { variant, function })
+    }
+}
+
+pub struct StreamMethodBuilder<'a> {
+    stream: StreamInterface<Unvalidated>,
+    inner: ClassMethodBuilder<'a>,
+}
+
+impl<'a> StreamMethodBuilder<'a> {
+    pub(crate) fn new(
+        lib: &'a mut LibraryBuilder,
+        method_name: Name,
+        class: ClassDeclarationHandle,
+        stream: StreamInterface<Unvalidated>,
+    ) -> BindResult<Self> {
+        let builder = lib.define_method(method_name, class)?;
+
+        Ok(Self {
+            stream,
+            inner: builder,
+        })
+    }
+
+    pub fn param<T: IntoName, D: Into<DocString<Unvalidated>>, P: Into<FunctionArgument>>(
+        self,
+        name: T,
+        param_type: P,
+        doc: D,
+    ) -> BindResult<Self> {
+        Ok(Self {
+            stream: self.stream,
+            inner: self.inner.param(name, param_type, doc)?,
+        })
+    }
+
+    pub fn doc<D: Into<Doc<Unvalidated>>>(self, doc: D) -> BindResult<Self> {
+        Ok(Self {
+            stream: self.stream,
+            inner: self.inner.doc(doc)?,
+        })
+    }
+
+    pub fn build(self) -> BindResult<StreamMethod<Unvalidated>> {
+        let stream = self.stream.clone();
+        let callback_parameter_name = self
+            .inner
+            .inner
+            .lib
+            .settings()
+            .stream
+            .stream_method_callback_parameter_name
+            .clone();
         let method = self
             .inner
             .param(
                 callback_parameter_name,
-                FunctionArgument::Interface(self.future.interface),
-                "callback invoked when the operation completes",
+                FunctionArgument::Interface(self.stream.interface),
+                "callback receiving the items of the stream",
             )?
             .build()?;
 
-        Ok(FutureMethod {
+        Ok(StreamMethod {
             name: method.name,
             associated_class: method.associated_class,
-            future,
+            stream,
             native_function: method.native_function,
         })
     }
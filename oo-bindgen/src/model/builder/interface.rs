@@ -162,7 +162,17 @@ impl<'a> CallbackFunctionBuilder<'a> {
         t: T,
         d: D,
     ) -> BindResult<Self> {
-        self.return_type.set(&self.name, t.into(), d.into())?;
+        let t = t.into();
+        if let CallbackReturnValue::Struct(x) = &t {
+            if x.contains_list() {
+                return Err(BindingErrorVariant::CallbackReturnStructContainsList {
+                    callback_name: self.name,
+                    struct_name: x.name().clone(),
+                }
+                .into());
+            }
+        }
+        self.return_type.set(&self.name, t, d.into())?;
         Ok(self)
     }
 
@@ -12,6 +12,9 @@ pub(crate) struct LibraryFields {
     pub(crate) structs: HashMap<StructDeclarationHandle, StructType<Unvalidated>>,
     pub(crate) functions: HashSet<Handle<Function<Unvalidated>>>,
     pub(crate) enums: HashSet<Handle<Enum<Unvalidated>>>,
+    pub(crate) flags: HashSet<FlagsHandle>,
+    pub(crate) unions: HashSet<Handle<Union<Unvalidated>>>,
+    pub(crate) base_exceptions: HashSet<BaseExceptionHandle>,
 
     // oo stuff
     pub(crate) class_declarations: HashSet<ClassDeclarationHandle>,
@@ -22,6 +25,8 @@ pub(crate) struct LibraryFields {
     // specialized types
     pub(crate) iterators: HashSet<Handle<AbstractIterator<Unvalidated>>>,
     pub(crate) collections: HashSet<Handle<Collection<Unvalidated>>>,
+    pub(crate) maps: HashSet<Handle<Map<Unvalidated>>>,
+    pub(crate) lists: HashSet<ListHandle>,
 }
 
 impl LibraryFields {
@@ -61,6 +66,9 @@ impl LibraryFields {
             structs: HashMap::new(),
 
             enums: HashSet::new(),
+            flags: HashSet::new(),
+            unions: HashSet::new(),
+            base_exceptions: HashSet::new(),
 
             class_declarations: HashSet::new(),
             classes: HashMap::new(),
@@ -70,6 +78,8 @@ impl LibraryFields {
 
             iterators: HashSet::new(),
             collections: HashSet::new(),
+            maps: HashSet::new(),
+            lists: HashSet::new(),
 
             functions: HashSet::new(),
         }
@@ -127,6 +137,15 @@ impl LibraryBuilder {
             Statement::EnumDefinition(x) => {
                 self.fields.enums.insert(x);
             }
+            Statement::FlagsDefinition(x) => {
+                self.fields.flags.insert(x);
+            }
+            Statement::UnionDefinition(x) => {
+                self.fields.unions.insert(x);
+            }
+            Statement::BaseException(x) => {
+                self.fields.base_exceptions.insert(x);
+            }
             Statement::ErrorType(_) => {}
             Statement::ClassDeclaration(x) => {
                 self.fields.class_declarations.insert(x);
@@ -146,6 +165,12 @@ impl LibraryBuilder {
             Statement::CollectionDeclaration(x) => {
                 self.fields.collections.insert(x);
             }
+            Statement::MapDeclaration(x) => {
+                self.fields.maps.insert(x);
+            }
+            Statement::ListDeclaration(x) => {
+                self.fields.lists.insert(x);
+            }
             Statement::FunctionDefinition(x) => {
                 self.fields.functions.insert(x);
             }
@@ -167,6 +192,13 @@ impl LibraryBuilder {
             Statement::EnumDefinition(x) => {
                 Ok(Statement::EnumDefinition(x.validate(&self.fields)?))
             }
+            Statement::FlagsDefinition(x) => {
+                Ok(Statement::FlagsDefinition(x.validate(&self.fields)?))
+            }
+            Statement::UnionDefinition(x) => {
+                Ok(Statement::UnionDefinition(x.validate(&self.fields)?))
+            }
+            Statement::BaseException(x) => Ok(Statement::BaseException(x.validate(&self.fields)?)),
             Statement::ErrorType(x) => Ok(Statement::ErrorType(x.validate(&self.fields)?)),
             Statement::ClassDeclaration(x) => Ok(Statement::ClassDeclaration(x.clone())),
             Statement::ClassDefinition(x) => {
@@ -184,6 +216,10 @@ impl LibraryBuilder {
             Statement::CollectionDeclaration(x) => {
                 Ok(Statement::CollectionDeclaration(x.validate(&self.fields)?))
             }
+            Statement::MapDeclaration(x) => {
+                Ok(Statement::MapDeclaration(x.validate(&self.fields)?))
+            }
+            Statement::ListDeclaration(x) => Ok(Statement::ListDeclaration(x.clone())),
             Statement::FunctionDefinition(x) => {
                 Ok(Statement::FunctionDefinition(x.validate(&self.fields)?))
             }
@@ -219,6 +255,11 @@ impl LibraryBuilder {
         exception_type: ExceptionType,
     ) -> BindResult<ErrorTypeBuilder> {
         let exception_name = exception_name.into_name()?;
+        let panic_variant = if self.settings.panic.catch_unwind {
+            Some(self.settings.panic.error_variant_name.clone())
+        } else {
+            None
+        };
         let builder = self
             .define_enum(error_name)?
             .push("ok", "Success, i.e. no error occurred")?;
@@ -226,10 +267,24 @@ impl LibraryBuilder {
         Ok(ErrorTypeBuilder::new(
             exception_name,
             exception_type,
+            panic_variant,
             builder,
         ))
     }
 
+    /// Define an exception from which the exceptions of error types, or other base exceptions, may derive
+    pub fn define_base_exception<T: IntoName>(
+        &mut self,
+        name: T,
+        exception_type: ExceptionType,
+    ) -> BindResult<BaseExceptionBuilder> {
+        Ok(BaseExceptionBuilder::new(
+            self,
+            name.into_name()?,
+            exception_type,
+        ))
+    }
+
     pub fn define_constants<T: IntoName>(&mut self, name: T) -> BindResult<ConstantSetBuilder> {
         Ok(ConstantSetBuilder::new(self, name.into_name()?))
     }
@@ -375,6 +430,23 @@ impl LibraryBuilder {
         Ok(EnumBuilder::new(self, name.into_name()?))
     }
 
+    /// Define a set of bit flags stored in an unsigned integer
+    pub fn define_flags<T: IntoName>(
+        &mut self,
+        name: T,
+        flags_type: FlagsType,
+    ) -> BindResult<FlagsBuilder> {
+        Ok(FlagsBuilder::new(self, name.into_name()?, flags_type))
+    }
+
+    /// Define a tagged union whose variants each carry a universal struct
+    ///
+    /// Functions that take the union as an argument must fail with an error type that defines a
+    /// bad argument error, which is returned when the tag doesn't match any variant.
+    pub fn define_union<T: IntoName>(&mut self, name: T) -> BindResult<UnionBuilder> {
+        Ok(UnionBuilder::new(self, name.into_name()?))
+    }
+
     pub fn define_function<T: IntoName>(&mut self, name: T) -> BindResult<FunctionBuilder> {
         self.define_function_with_category(name, FunctionCategory::Native)
     }
@@ -396,6 +468,15 @@ impl LibraryBuilder {
         FutureMethodBuilder::new(self, name.into_name()?, class, future)
     }
 
+    pub fn define_stream_method<T: IntoName>(
+        &mut self,
+        name: T,
+        class: ClassDeclarationHandle,
+        stream: StreamInterface<Unvalidated>,
+    ) -> BindResult<StreamMethodBuilder> {
+        StreamMethodBuilder::new(self, name.into_name()?, class, stream)
+    }
+
     pub fn define_constructor(
         &mut self,
         class: ClassDeclarationHandle,
@@ -439,6 +520,12 @@ impl LibraryBuilder {
         )?))
     }
 
+    fn declare_map<T: IntoName>(&mut self, name: T) -> BindResult<MapClassDeclaration> {
+        Ok(MapClassDeclaration::new(
+            self.declare_any_class(name.into_name()?, ClassType::Map)?,
+        ))
+    }
+
     fn declare_any_class<T: IntoName>(
         &mut self,
         name: T,
@@ -490,9 +577,42 @@ impl LibraryBuilder {
         value_type_docs: E,
         error_type: ErrorType<Unvalidated>,
     ) -> BindResult<FutureInterface<Unvalidated>> {
-        let value_type = value_type.into();
-        let value_type_docs = value_type_docs.into();
-        let name = name.into_name()?;
+        let value = FutureValue {
+            value_type: value_type.into(),
+            doc: value_type_docs.into(),
+        };
+        self.define_future_interface_impl(
+            name.into_name()?,
+            interface_docs.into(),
+            Some(value),
+            error_type,
+        )
+    }
+
+    /// A void future interface only signals the completion of an operation.
+    /// The success callback takes no parameters and backends map it to a
+    /// future without a value.
+    pub fn define_void_future_interface<T: IntoName, D: Into<Doc<Unvalidated>>>(
+        &mut self,
+        name: T,
+        interface_docs: D,
+        error_type: ErrorType<Unvalidated>,
+    ) -> BindResult<FutureInterface<Unvalidated>> {
+        self.define_future_interface_impl(
+            name.into_name()?,
+            interface_docs.into(),
+            None,
+            error_type,
+        )
+    }
+
+    fn define_future_interface_impl(
+        &mut self,
+        name: Name,
+        interface_docs: Doc<Unvalidated>,
+        value: Option<FutureValue<Unvalidated>>,
+        error_type: ErrorType<Unvalidated>,
+    ) -> BindResult<FutureInterface<Unvalidated>> {
         let success_callback_name = self.settings.future.success_callback_method_name.clone();
         let success_parameter_name = self.settings.future.success_single_parameter_name.clone();
         let failure_callback_name = self.settings.future.failure_callback_method_name.clone();
@@ -503,14 +623,17 @@ impl LibraryBuilder {
             .begin_callback(
                 success_callback_name,
                 "Invoked when the asynchronous operation completes successfully",
-            )?
-            .param(
+            )?;
+        let builder = match &value {
+            None => builder,
+            Some(value) => builder.param(
                 success_parameter_name,
-                value_type.clone(),
-                value_type_docs.clone(),
-            )?
-            .enable_functional_transform()
-            .end_callback()?;
+                value.value_type.clone(),
+                value.doc.clone(),
+            )?,
+        }
+        .enable_functional_transform()
+        .end_callback()?;
 
         let builder = builder
             .begin_callback(
@@ -526,7 +649,7 @@ impl LibraryBuilder {
             .end_callback()?;
 
         let (interface, lib) = builder.build(InterfaceCategory::Future);
-        let ret = FutureInterface::new(value_type, error_type, interface, value_type_docs);
+        let ret = FutureInterface::new(value, error_type, interface);
         lib.add_statement(Statement::InterfaceDefinition(InterfaceType::Future(
             ret.clone(),
         )))?;
@@ -534,6 +657,75 @@ impl LibraryBuilder {
         Ok(ret)
     }
 
+    /// A stream interface delivers any number of items followed by either a completion
+    /// or a failure. Backends map it to an asynchronous sequence.
+    ///
+    /// Items are queued by the backends until they are consumed, so they are restricted
+    /// to types the backends copy: basic types, strings and universal structs.
+    pub fn define_stream_interface<
+        T: IntoName,
+        D: Into<Doc<Unvalidated>>,
+        U: Into<CallbackArgument>,
+        V: Into<DocString<Unvalidated>>,
+    >(
+        &mut self,
+        name: T,
+        interface_docs: D,
+        item_type: U,
+        item_type_docs: V,
+        error_type: ErrorType<Unvalidated>,
+    ) -> BindResult<StreamInterface<Unvalidated>> {
+        let name = name.into_name()?;
+        let item_type = item_type.into();
+        let item_type_docs = item_type_docs.into();
+
+        match &item_type {
+            CallbackArgument::Basic(_)
+            | CallbackArgument::String(_)
+            | CallbackArgument::Struct(UniversalOr::Universal(_)) => {}
+            _ => return Err(BindingErrorVariant::StreamItemTypeNotSupported { name }.into()),
+        }
+
+        let item_callback_name = self.settings.stream.item_callback_method_name.clone();
+        let item_parameter_name = self.settings.stream.item_parameter_name.clone();
+        let complete_callback_name = self.settings.stream.complete_callback_method_name.clone();
+        let failure_callback_name = self.settings.stream.failure_callback_method_name.clone();
+        let failure_parameter_name = self.settings.stream.failure_parameter_name.clone();
+
+        let builder = self
+            .define_interface(name, interface_docs)?
+            .begin_callback(item_callback_name, "Invoked for each item of the stream")?
+            .param(
+                item_parameter_name,
+                item_type.clone(),
+                item_type_docs.clone(),
+            )?
+            .end_callback()?
+            .begin_callback(
+                complete_callback_name,
+                "Invoked once after the last item when the stream ends successfully",
+            )?
+            .end_callback()?
+            .begin_callback(
+                failure_callback_name,
+                "Invoked once when the stream ends with an error",
+            )?
+            .param(
+                failure_parameter_name,
+                CallbackArgument::Basic(BasicType::Enum(error_type.clone_enum())),
+                "Enumeration indicating which error occurred",
+            )?
+            .end_callback()?;
+
+        let (interface, lib) = builder.build(InterfaceCategory::Stream);
+        let ret = StreamInterface::new(item_type, item_type_docs, error_type, interface);
+        lib.add_statement(Statement::InterfaceDefinition(InterfaceType::Stream(
+            ret.clone(),
+        )))?;
+
+        Ok(ret)
+    }
+
     pub fn define_interface<T: IntoName, D: Into<Doc<Unvalidated>>>(
         &mut self,
         name: T,
@@ -547,7 +739,19 @@ impl LibraryBuilder {
         class_name: N,
         item_type: T,
     ) -> BindResult<AbstractIteratorHandle> {
-        self.define_iterator_impl(class_name, false, item_type)
+        self.define_iterator_impl(class_name, false, false, item_type)
+    }
+
+    /// Define an iterator that can be returned from functions
+    ///
+    /// The caller takes ownership of the returned iterator and releases it
+    /// with an automatically generated destroy function
+    pub fn define_owned_iterator<N: IntoName, T: Into<IteratorItemType>>(
+        &mut self,
+        class_name: N,
+        item_type: T,
+    ) -> BindResult<AbstractIteratorHandle> {
+        self.define_iterator_impl(class_name, false, true, item_type)
     }
 
     pub fn define_iterator_with_lifetime<N: IntoName, T: Into<IteratorItemType>>(
@@ -555,13 +759,14 @@ impl LibraryBuilder {
         class_name: N,
         item_type: T,
     ) -> BindResult<AbstractIteratorHandle> {
-        self.define_iterator_impl(class_name, true, item_type)
+        self.define_iterator_impl(class_name, true, false, item_type)
     }
 
     fn define_iterator_impl<N: IntoName, T: Into<IteratorItemType>>(
         &mut self,
         class_name: N,
         has_lifetime: bool,
+        is_owned: bool,
         item_type: T,
     ) -> BindResult<AbstractIteratorHandle> {
         let class_name = class_name.into_name()?;
@@ -573,19 +778,34 @@ impl LibraryBuilder {
                 class_name.append(&self.settings.iterator.next_function_suffix),
                 FunctionCategory::IteratorNext,
             )?
-            .param(
+            .param_mut(
                 "iter",
-                class.clone(),
+                class.inner.clone(),
                 "opaque iterator on which to retrieve the next value",
             )?
             .doc("returns a pointer to the next value or NULL")?
             .returns(item_type.get_function_return_value(), "next value or NULL")?
             .build()?;
 
+        let destroy_function = if is_owned {
+            let destroy_function = self
+                .define_function_with_category(
+                    class_name.append(&self.settings.iterator.destroy_function_suffix),
+                    FunctionCategory::IteratorDestroy,
+                )?
+                .param("iter", class.clone(), "opaque iterator to destroy")?
+                .doc("Destroys an iterator returned from a function")?
+                .build()?;
+            Some(destroy_function)
+        } else {
+            None
+        };
+
         let iter = AbstractIteratorHandle::new(AbstractIterator::new(
             has_lifetime,
             class.inner,
             next_function,
+            destroy_function,
             item_type,
             self.settings.clone(),
         ));
@@ -640,9 +860,9 @@ impl LibraryBuilder {
                 FunctionCategory::CollectionAdd,
             )?
             .doc("Add a value to the collection")?
-            .param(
+            .param_mut(
                 "instance",
-                class_decl.clone(),
+                class_decl.inner.clone(),
                 "instance to which to add the value",
             )?
             .param("value", value_type.clone(), "value to add to the instance")?
@@ -661,7 +881,81 @@ impl LibraryBuilder {
         Ok(collection)
     }
 
-    fn check_unique_symbol(&mut self, name: &Name) -> BindResult<()> {
+    pub fn define_map<N: IntoName, K: Into<FunctionArgument>, V: Into<FunctionArgument>>(
+        &mut self,
+        class_name: N,
+        key_type: K,
+        value_type: V,
+    ) -> BindResult<MapHandle> {
+        let class_name = class_name.into_name()?;
+        let key_type = key_type.into();
+        let value_type = value_type.into();
+
+        let class_decl = self.declare_map(&class_name)?;
+
+        let create_func = self
+            .define_function_with_category(
+                class_name.append(&self.settings.collection.create_function_suffix),
+                FunctionCategory::MapCreate,
+            )?
+            .doc("Creates an instance of the map")?
+            .returns(class_decl.clone(), "Allocated opaque map instance")?
+            .build()?;
+
+        let destroy_func = self
+            .define_function_with_category(
+                class_name.append(&self.settings.collection.destroy_function_suffix),
+                FunctionCategory::MapDestroy,
+            )?
+            .doc("Destroys an instance of the map")?
+            .param("instance", class_decl.clone(), "instance to destroy")?
+            .build()?;
+
+        let insert_func = self
+            .define_function_with_category(
+                class_name.append(&self.settings.collection.insert_function_suffix),
+                FunctionCategory::MapInsert,
+            )?
+            .doc("Insert a key/value pair into the map, replacing any existing value for the key")?
+            .param_mut(
+                "instance",
+                class_decl.inner.clone(),
+                "instance into which to insert the pair",
+            )?
+            .param("key", key_type.clone(), "key of the pair")?
+            .param("value", value_type.clone(), "value associated with the key")?
+            .build()?;
+
+        let map = Handle::new(Map::new(
+            class_decl.inner,
+            key_type,
+            value_type,
+            create_func,
+            destroy_func,
+            insert_func,
+        ));
+
+        self.add_statement(Statement::MapDeclaration(map.clone()))?;
+        Ok(map)
+    }
+
+    /// Define a list of values that may be used as a field of universal and function argument structs
+    pub fn define_list<N: IntoName, T: Into<ListItemType>>(
+        &mut self,
+        name: N,
+        item_type: T,
+    ) -> BindResult<ListHandle> {
+        let list = Handle::new(List::new(
+            name.into_name()?,
+            item_type.into(),
+            self.settings.clone(),
+        ));
+
+        self.add_statement(Statement::ListDeclaration(list.clone()))?;
+        Ok(list)
+    }
+
+    pub(crate) fn check_unique_symbol(&mut self, name: &Name) -> BindResult<()> {
         if self.symbol_names.insert(name.to_string()) {
             Ok(())
         } else {
@@ -672,13 +966,40 @@ impl LibraryBuilder {
     fn check_statement(&self, statement: &Statement<Unvalidated>) -> BindResult<()> {
         match statement {
             // no internals that can be from another library
-            Statement::Constants(_) => Ok(()),
             Statement::StructDeclaration(_) => Ok(()),
             Statement::EnumDefinition(_) => Ok(()),
+            Statement::FlagsDefinition(_) => Ok(()),
             Statement::ClassDeclaration(_) => Ok(()),
             // these types have internals that must be checked
             Statement::StructDefinition(x) => self.check_struct_declaration(&x.declaration()),
-            Statement::ErrorType(x) => self.check_enum(&x.inner),
+            Statement::UnionDefinition(x) => {
+                for v in x.variants.iter() {
+                    self.check_struct_declaration(&v.value.declaration())?;
+                }
+                Ok(())
+            }
+            Statement::Constants(x) => {
+                for c in x.values.iter() {
+                    if let ConstantValue::Enum(x) = &c.value {
+                        self.check_enum(&x.handle)?;
+                    }
+                }
+                Ok(())
+            }
+            Statement::BaseException(x) => match &x.parent {
+                None => Ok(()),
+                Some(parent) => self.check_base_exception(parent),
+            },
+            Statement::ErrorType(x) => {
+                self.check_enum(&x.inner)?;
+                if let Some(base) = &x.base {
+                    self.check_base_exception(base)?;
+                }
+                if let Some(payload) = &x.payload {
+                    self.check_struct_declaration(&payload.declaration())?;
+                }
+                Ok(())
+            }
             Statement::ClassDefinition(x) => {
                 self.check_class_declaration(&x.declaration)?;
                 if let Some(x) = &x.constructor {
@@ -696,6 +1017,9 @@ impl LibraryBuilder {
                 for x in x.future_methods.iter() {
                     self.check_function(&x.native_function)?
                 }
+                for x in x.stream_methods.iter() {
+                    self.check_function(&x.native_function)?
+                }
                 Ok(())
             }
             Statement::StaticClassDefinition(x) => {
@@ -718,9 +1042,16 @@ impl LibraryBuilder {
             Statement::IteratorDeclaration(x) => {
                 self.check_class_declaration(&x.iter_class)?;
                 self.check_function(&x.next_function)?;
+                if let Some(destroy) = &x.destroy_function {
+                    self.check_function(destroy)?;
+                }
                 match &x.item_type {
                     IteratorItemType::Struct(x) => self.check_struct_declaration(&x.declaration()),
                     IteratorItemType::Primitive(_) => Ok(()),
+                    IteratorItemType::String(_) => Ok(()),
+                    IteratorItemType::Enum(x) => self.check_enum(x),
+                    IteratorItemType::Duration(_) => Ok(()),
+                    IteratorItemType::Class(x) => self.check_class_declaration(x),
                 }
             }
             Statement::CollectionDeclaration(x) => {
@@ -731,6 +1062,19 @@ impl LibraryBuilder {
                 self.check_function_argument(&x.item_type)?;
                 Ok(())
             }
+            Statement::MapDeclaration(x) => {
+                self.check_class_declaration(&x.map_class)?;
+                self.check_function(&x.create_func)?;
+                self.check_function(&x.insert_func)?;
+                self.check_function(&x.delete_func)?;
+                self.check_function_argument(&x.key_type)?;
+                self.check_function_argument(&x.value_type)?;
+                Ok(())
+            }
+            Statement::ListDeclaration(x) => match &x.item_type {
+                ListItemType::Basic(x) => self.check_basic_type(x),
+                ListItemType::Struct(x) => self.check_struct_declaration(&x.declaration()),
+            },
             Statement::FunctionDefinition(x) => {
                 for p in x.arguments.iter() {
                     self.check_function_argument(&p.arg_type)?;
@@ -779,6 +1123,39 @@ impl LibraryBuilder {
         }
     }
 
+    fn check_base_exception(&self, base: &BaseExceptionHandle) -> BindResult<()> {
+        if self.fields.base_exceptions.contains(base) {
+            Ok(())
+        } else {
+            Err(BindingErrorVariant::NotPartOfThisLibrary {
+                name: base.name.clone(),
+            }
+            .into())
+        }
+    }
+
+    fn check_flags(&self, flags: &FlagsHandle) -> BindResult<()> {
+        if self.fields.flags.contains(flags) {
+            Ok(())
+        } else {
+            Err(BindingErrorVariant::NotPartOfThisLibrary {
+                name: flags.name.clone(),
+            }
+            .into())
+        }
+    }
+
+    fn check_union(&self, union: &UnionHandle) -> BindResult<()> {
+        if self.fields.unions.contains(union) {
+            Ok(())
+        } else {
+            Err(BindingErrorVariant::NotPartOfThisLibrary {
+                name: union.name.clone(),
+            }
+            .into())
+        }
+    }
+
     fn check_interface(&self, interface: &InterfaceHandle) -> BindResult<()> {
         if self.fields.interfaces.contains(interface) {
             Ok(())
@@ -808,7 +1185,11 @@ impl LibraryBuilder {
         match arg {
             FunctionArgument::Basic(x) => self.check_basic_type(x),
             FunctionArgument::String(_) => Ok(()),
+            FunctionArgument::Bytes(_) => Ok(()),
+            FunctionArgument::Optional(x) => self.check_basic_type(&x.inner),
+            FunctionArgument::Union(x) => self.check_union(x),
             FunctionArgument::Collection(x) => self.check_collection(x),
+            FunctionArgument::Map(x) => self.check_map(x),
             FunctionArgument::Struct(x) => self.check_struct_declaration(&x.declaration()),
             FunctionArgument::StructRef(x) => self.check_struct_declaration(&x.inner),
             FunctionArgument::ClassRef(x) => self.check_class_declaration(x),
@@ -820,6 +1201,9 @@ impl LibraryBuilder {
         match arg {
             CallbackArgument::Basic(x) => self.check_basic_type(x),
             CallbackArgument::String(_) => Ok(()),
+            CallbackArgument::Bytes(_) => Ok(()),
+            CallbackArgument::Optional(x) => self.check_basic_type(&x.inner),
+            CallbackArgument::Union(x) => self.check_union(x),
             CallbackArgument::Iterator(x) => self.check_iterator(x),
             CallbackArgument::Class(x) => self.check_class_declaration(x),
             CallbackArgument::Struct(x) => self.check_struct_declaration(&x.declaration()),
@@ -837,7 +1221,9 @@ impl LibraryBuilder {
         match arg {
             BasicType::Primitive(_) => Ok(()),
             BasicType::Duration(_) => Ok(()),
+            BasicType::Timestamp(_) => Ok(()),
             BasicType::Enum(x) => self.check_enum(x),
+            BasicType::Flags(x) => self.check_flags(x),
         }
     }
 
@@ -845,8 +1231,16 @@ impl LibraryBuilder {
         match value {
             FunctionReturnValue::Basic(x) => self.check_basic_type(x),
             FunctionReturnValue::PrimitiveRef(_) => Ok(()),
+            FunctionReturnValue::ValueRef(x) => match x {
+                ValueRef::String(_) => Ok(()),
+                ValueRef::Enum(x) => self.check_enum(x),
+                ValueRef::Duration(_) => Ok(()),
+            },
             FunctionReturnValue::String(_) => Ok(()),
+            FunctionReturnValue::Bytes(_) => Ok(()),
+            FunctionReturnValue::Union(x) => self.check_union(x),
             FunctionReturnValue::ClassRef(x) => self.check_class_declaration(x),
+            FunctionReturnValue::Iterator(x) => self.check_iterator(x),
             FunctionReturnValue::Struct(x) => self.check_struct_declaration(&x.declaration()),
             FunctionReturnValue::StructRef(x) => self.check_struct_declaration(x.untyped()),
         }
@@ -873,4 +1267,15 @@ impl LibraryBuilder {
             .into())
         }
     }
+
+    fn check_map(&self, map: &MapHandle) -> BindResult<()> {
+        if self.fields.maps.contains(map) {
+            Ok(())
+        } else {
+            Err(BindingErrorVariant::NotPartOfThisLibrary {
+                name: map.map_class.name.clone(),
+            }
+            .into())
+        }
+    }
 }
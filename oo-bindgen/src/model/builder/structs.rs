@@ -59,6 +59,15 @@ where
         let name = name.into_name()?;
         let field_type = field_type.into();
 
+        // accessors of the Rust FFI have no way to report values that can't be converted
+        if field_type.can_be_invalid() {
+            return Err(BindingErrorVariant::StructFieldCanBeInvalid {
+                handle: self.declaration.inner.clone(),
+                field_name: name,
+            }
+            .into());
+        }
+
         if self.field_names.insert(name.to_string()) {
             self.fields.push(StructField {
                 name,
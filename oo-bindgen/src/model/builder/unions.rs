@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use crate::model::*;
+
+pub struct UnionBuilder<'a> {
+    lib: &'a mut LibraryBuilder,
+    name: Name,
+    variants: Vec<UnionVariant<Unvalidated>>,
+    variant_names: HashSet<String>,
+    doc: OptionalDoc,
+}
+
+impl<'a> UnionBuilder<'a> {
+    pub(crate) fn new(lib: &'a mut LibraryBuilder, name: Name) -> Self {
+        Self {
+            lib,
+            name: name.clone(),
+            variants: Vec::new(),
+            variant_names: HashSet::new(),
+            doc: OptionalDoc::new(name),
+        }
+    }
+
+    /// Add a variant that carries a universal struct as its payload
+    pub fn variant<T: IntoName, D: Into<Doc<Unvalidated>>>(
+        mut self,
+        name: T,
+        value: UniversalStructHandle,
+        doc: D,
+    ) -> BindResult<Self> {
+        let name = name.into_name()?;
+        if self.variant_names.insert(name.to_string()) {
+            self.variants.push(UnionVariant {
+                name,
+                value,
+                doc: doc.into(),
+            });
+            Ok(self)
+        } else {
+            Err(BindingErrorVariant::DuplicateUnionVariantName {
+                name: self.name,
+                variant_name: name,
+            }
+            .into())
+        }
+    }
+
+    pub fn doc<D: Into<Doc<Unvalidated>>>(mut self, doc: D) -> BindResult<Self> {
+        self.doc.set(doc.into())?;
+        Ok(self)
+    }
+
+    pub fn build(self) -> BindResult<UnionHandle> {
+        if self.variants.is_empty() {
+            return Err(BindingErrorVariant::UnionWithoutVariants { name: self.name }.into());
+        }
+
+        let handle = Handle::new(Union {
+            name: self.name,
+            settings: self.lib.clone_settings(),
+            variants: self.variants,
+            doc: self.doc.extract()?,
+        });
+
+        self.lib
+            .add_statement(Statement::UnionDefinition(handle.clone()))?;
+
+        Ok(handle)
+    }
+}
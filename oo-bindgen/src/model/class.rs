@@ -11,6 +11,8 @@ pub(crate) enum ClassType {
     Iterator,
     /// A collection class
     Collection,
+    /// A map class
+    Map,
 }
 
 /// C-style structure forward declaration
@@ -43,6 +45,17 @@ impl CollectionClassDeclaration {
     }
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct MapClassDeclaration {
+    pub(crate) inner: ClassDeclarationHandle,
+}
+
+impl MapClassDeclaration {
+    pub(crate) fn new(inner: ClassDeclarationHandle) -> Self {
+        Self { inner }
+    }
+}
+
 impl ClassDeclaration {
     pub(crate) fn new(name: Name, class_type: ClassType, settings: Rc<LibrarySettings>) -> Self {
         Self {
@@ -90,6 +103,60 @@ impl Method<Unvalidated> {
 
 pub type MethodHandle = Method<Unvalidated>;
 
+/// Property of a class backed by a getter method and, optionally, a setter method
+#[derive(Debug, Clone)]
+pub struct Property<T>
+where
+    T: DocReference,
+{
+    pub(crate) name: Name,
+    pub(crate) getter: Method<T>,
+    pub(crate) setter: Option<Method<T>>,
+}
+
+impl Property<Unvalidated> {
+    pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<Property<Validated>> {
+        Ok(Property {
+            name: self.name.clone(),
+            getter: self.getter.validate(lib)?,
+            setter: match &self.setter {
+                None => None,
+                Some(x) => Some(x.validate(lib)?),
+            },
+        })
+    }
+}
+
+impl<T> Property<T>
+where
+    T: DocReference,
+{
+    /// getter method renamed for languages that expose properties as a pair of methods
+    pub(crate) fn getter_named(&self, prefix: Option<&str>) -> Method<T> {
+        Method {
+            name: self.accessor_name(prefix),
+            ..self.getter.clone()
+        }
+    }
+
+    /// setter method renamed for languages that expose properties as a pair of methods
+    pub(crate) fn setter_named(&self, prefix: &str) -> Option<Method<T>> {
+        self.setter.as_ref().map(|setter| Method {
+            name: self.accessor_name(Some(prefix)),
+            ..setter.clone()
+        })
+    }
+
+    fn accessor_name(&self, prefix: Option<&str>) -> Name {
+        match prefix {
+            None => self.name.clone(),
+            Some(prefix) => Name::create(prefix)
+                .expect("accessor prefixes are valid names")
+                .append(&self.name),
+        }
+    }
+}
+
 /// represents a static method associated with a class
 ///
 /// name given to the class method may differ from the name of the native function
@@ -149,8 +216,11 @@ where
     pub(crate) constructor: Option<ClassConstructor<T>>,
     pub(crate) destructor: Option<ClassDestructor<T>>,
     pub(crate) methods: Vec<Method<T>>,
+    pub(crate) properties: Vec<Property<T>>,
     pub(crate) static_methods: Vec<StaticMethod<T>>,
     pub(crate) future_methods: Vec<FutureMethod<T>>,
+    pub(crate) stream_methods: Vec<StreamMethod<T>>,
+    pub(crate) interfaces: Vec<Handle<Interface<T>>>,
     pub(crate) doc: Doc<T>,
     pub(crate) destruction_mode: DestructionMode,
     pub(crate) settings: Rc<LibrarySettings>,
@@ -168,6 +238,8 @@ impl Class<Unvalidated> {
         };
         let methods: BindResult<Vec<Method<Validated>>> =
             self.methods.iter().map(|x| x.validate(lib)).collect();
+        let properties: BindResult<Vec<Property<Validated>>> =
+            self.properties.iter().map(|x| x.validate(lib)).collect();
         let static_methods: BindResult<Vec<StaticMethod<Validated>>> = self
             .static_methods
             .iter()
@@ -178,14 +250,24 @@ impl Class<Unvalidated> {
             .iter()
             .map(|x| x.validate(lib))
             .collect();
+        let stream_methods: BindResult<Vec<StreamMethod<Validated>>> = self
+            .stream_methods
+            .iter()
+            .map(|x| x.validate(lib))
+            .collect();
+        let interfaces: BindResult<Vec<Handle<Interface<Validated>>>> =
+            self.interfaces.iter().map(|x| x.validate(lib)).collect();
 
         Ok(Handle::new(Class {
             declaration: self.declaration.clone(),
             constructor,
             destructor,
             methods: methods?,
+            properties: properties?,
             static_methods: static_methods?,
             future_methods: async_methods?,
+            stream_methods: stream_methods?,
+            interfaces: interfaces?,
             doc: self.doc.validate(self.name(), lib)?,
             destruction_mode: self.destruction_mode.clone(),
             settings: self.settings.clone(),
@@ -204,6 +286,29 @@ where
     pub fn declaration(&self) -> ClassDeclarationHandle {
         self.declaration.clone()
     }
+
+    /// getters and setters of the properties as methods, for languages without properties
+    pub(crate) fn property_accessors(&self, getter_prefix: Option<&str>) -> Vec<Method<T>> {
+        self.properties
+            .iter()
+            .flat_map(|x| {
+                std::iter::once(x.getter_named(getter_prefix)).chain(x.setter_named("set"))
+            })
+            .collect()
+    }
+
+    /// methods invoked by the callbacks of an implemented interface
+    pub(crate) fn interface_methods<'a>(
+        &'a self,
+        interface: &'a Interface<T>,
+    ) -> impl Iterator<Item = (&'a CallbackFunction<T>, &'a Method<T>)> + 'a {
+        interface.callbacks.iter().filter_map(move |cb| {
+            self.methods
+                .iter()
+                .find(|m| m.name == cb.name)
+                .map(|m| (cb, m))
+        })
+    }
 }
 
 impl Class<Unvalidated> {
@@ -219,6 +324,14 @@ impl Class<Unvalidated> {
             }
         }
 
+        for property in &self.properties {
+            for method in std::iter::once(&property.getter).chain(property.setter.iter()) {
+                if method.name.as_ref() == method_name {
+                    return Some((method.name.clone(), method.native_function.clone()));
+                }
+            }
+        }
+
         for method in &self.static_methods {
             if method.name.as_ref() == method_name {
                 return Some((method.name.clone(), method.native_function.clone()));
@@ -234,6 +347,15 @@ impl Class<Unvalidated> {
             }
         }
 
+        for stream_method in &self.stream_methods {
+            if stream_method.name.as_ref() == method_name {
+                return Some((
+                    stream_method.name.clone(),
+                    stream_method.native_function.clone(),
+                ));
+            }
+        }
+
         None
     }
 }
@@ -1,19 +1,60 @@
 use std::rc::Rc;
 
+use serde::{Deserialize, Serialize};
+
 use crate::model::*;
 
 /// How to render a numeric constant
+///
+/// Only integers may use a representation other than `Decimal`. C and C++ lack
+/// binary literals, so `Binary` constants are rendered in hex in those languages.
 #[non_exhaustive]
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Representation {
+    Decimal,
     Hex,
+    Binary,
 }
 
 /// Types of constants available
 #[non_exhaustive]
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub enum ConstantValue {
-    U8(u8, Representation),
+    Primitive(PrimitiveValue, Representation),
+    Duration(DurationValue),
+    String(String),
+    Enum(EnumValue),
+}
+
+impl From<PrimitiveValue> for ConstantValue {
+    fn from(x: PrimitiveValue) -> Self {
+        Self::Primitive(x, Representation::Decimal)
+    }
+}
+
+impl From<DurationValue> for ConstantValue {
+    fn from(x: DurationValue) -> Self {
+        Self::Duration(x)
+    }
+}
+
+impl From<&str> for ConstantValue {
+    fn from(x: &str) -> Self {
+        Self::String(x.to_string())
+    }
+}
+
+impl From<String> for ConstantValue {
+    fn from(x: String) -> Self {
+        Self::String(x)
+    }
+}
+
+impl From<EnumValue> for ConstantValue {
+    fn from(x: EnumValue) -> Self {
+        Self::Enum(x)
+    }
 }
 
 /// Constant belonging to a set of constants
@@ -31,7 +72,7 @@ impl Constant<Unvalidated> {
     pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<Constant<Validated>> {
         Ok(Constant {
             name: self.name.clone(),
-            value: self.value,
+            value: self.value.clone(),
             doc: self.doc.validate(&self.name, lib)?,
         })
     }
@@ -1,5 +1,7 @@
 use std::rc::Rc;
 
+use serde::{Deserialize, Serialize};
+
 use crate::model::*;
 
 #[derive(Debug, Clone)]
@@ -22,6 +24,25 @@ impl EnumVariant<Unvalidated> {
     }
 }
 
+/// How the Rust FFI converts an integer that doesn't match any variant of an enum
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownValuePolicy {
+    /// The conversion panics, which is handled like any other panic of the implementation
+    /// according to the [PanicSettings](crate::model::PanicSettings) of the library
+    #[default]
+    Panic,
+    /// The conversion fails. Functions that take the enum as an argument must fail with an error
+    /// type that defines a bad argument error, which they return. Callback helpers return `None`
+    /// as if the callback wasn't implemented. The enum can't be used as a struct field.
+    Error,
+    /// The value is converted to the named variant, e.g. an `unknown` variant
+    MapTo(Name),
+    /// The value is converted to the variant with the greatest value below it, or to the
+    /// variant with the lowest value if there is no such variant
+    Clamp,
+}
+
 pub type EnumHandle = Handle<Enum<Unvalidated>>;
 
 impl Handle<Enum<Unvalidated>> {
@@ -38,6 +59,7 @@ where
     pub(crate) name: Name,
     pub(crate) settings: Rc<LibrarySettings>,
     pub(crate) variants: Vec<EnumVariant<T>>,
+    pub(crate) unknown_value: UnknownValuePolicy,
     pub(crate) doc: Doc<T>,
 }
 
@@ -50,6 +72,7 @@ impl Enum<Unvalidated> {
             name: self.name.clone(),
             settings: self.settings.clone(),
             variants: variants?,
+            unknown_value: self.unknown_value.clone(),
             doc: self.doc.validate(&self.name, lib)?,
         }))
     }
@@ -1,8 +1,11 @@
+use serde::{Deserialize, Serialize};
+
 use crate::model::*;
 
 /// Type of exception to generate (only used in Java atm)
 #[non_exhaustive]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ExceptionType {
     /// Statically checked exceptions
     CheckedException,
@@ -10,6 +13,47 @@ pub enum ExceptionType {
     UncheckedException,
 }
 
+/// Exception from which the exceptions of error types, or other base exceptions, derive
+///
+/// Base exceptions allow users to catch every error of a library, or a group of related errors,
+/// with a single handler. The exception type of a base exception is shared by everything derived from it.
+#[derive(Debug)]
+pub struct BaseException<D>
+where
+    D: DocReference,
+{
+    pub(crate) name: Name,
+    pub(crate) exception_type: ExceptionType,
+    pub(crate) parent: Option<Handle<BaseException<D>>>,
+    pub(crate) doc: Doc<D>,
+}
+
+impl BaseException<Unvalidated> {
+    pub(crate) fn validate(
+        &self,
+        lib: &LibraryFields,
+    ) -> BindResult<Handle<BaseException<Validated>>> {
+        Ok(Handle::new(BaseException {
+            name: self.name.clone(),
+            exception_type: self.exception_type,
+            parent: match &self.parent {
+                None => None,
+                Some(x) => Some(x.validate(lib)?),
+            },
+            doc: self.doc.validate(&self.name, lib)?,
+        }))
+    }
+}
+
+pub type BaseExceptionHandle = Handle<BaseException<Unvalidated>>;
+
+/// Exception thrown in place of the exception of an error type for one particular variant
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub(crate) struct VariantException {
+    pub(crate) variant: Name,
+    pub(crate) exception_name: Name,
+}
+
 /// A type that wraps an inner enum and provides
 /// information on how it maps to exceptions in
 /// languages that support them
@@ -21,6 +65,58 @@ where
     pub(crate) exception_name: Name,
     pub(crate) exception_type: ExceptionType,
     pub(crate) inner: Handle<Enum<D>>,
+    /// base exception from which the exception derives
+    pub(crate) base: Option<Handle<BaseException<D>>>,
+    /// subclasses of the exception thrown for particular variants
+    pub(crate) variant_exceptions: Vec<VariantException>,
+    /// a detailed message may accompany the error
+    pub(crate) message: bool,
+    /// a universal struct may accompany the error
+    pub(crate) payload: Option<Handle<Struct<UniversalStructField, D>>>,
+    /// variant with which futures are completed when Rust drops them without completing them
+    pub(crate) drop_variant: Option<Name>,
+    /// variant returned by functions when an argument can't be converted
+    pub(crate) bad_argument_variant: Option<Name>,
+}
+
+impl<D> ErrorType<D>
+where
+    D: DocReference,
+{
+    /// true if a message or a payload may accompany the error
+    pub(crate) fn has_detail(&self) -> bool {
+        self.message || self.payload.is_some()
+    }
+
+    /// iterate over the variants that have their own exception, along with their value
+    pub(crate) fn variant_exceptions(&self) -> impl Iterator<Item = (&VariantException, i32)> {
+        self.variant_exceptions.iter().map(|x| {
+            let value = self
+                .inner
+                .variants
+                .iter()
+                .find(|v| v.name == x.variant)
+                .map(|v| v.value)
+                .expect("variant exceptions always refer to a variant of the enum");
+            (x, value)
+        })
+    }
+
+    /// name of the C function that retrieves the message of the last error on the calling thread
+    pub(crate) fn last_message_function_name(&self) -> String {
+        format!(
+            "{}_{}_last_message",
+            self.inner.settings.c_ffi_prefix, self.inner.name
+        )
+    }
+
+    /// name of the C function that retrieves the payload of the last error on the calling thread
+    pub(crate) fn last_payload_function_name(&self) -> String {
+        format!(
+            "{}_{}_last_payload",
+            self.inner.settings.c_ffi_prefix, self.inner.name
+        )
+    }
 }
 
 impl ErrorType<Unvalidated> {
@@ -34,6 +130,18 @@ impl ErrorType<Unvalidated> {
             exception_name: self.exception_name.clone(),
             exception_type: self.exception_type,
             inner: self.inner.validate(lib)?,
+            base: match &self.base {
+                None => None,
+                Some(x) => Some(x.validate(lib)?),
+            },
+            variant_exceptions: self.variant_exceptions.clone(),
+            message: self.message,
+            payload: match &self.payload {
+                None => None,
+                Some(x) => Some(x.validate(lib)?),
+            },
+            drop_variant: self.drop_variant.clone(),
+            bad_argument_variant: self.bad_argument_variant.clone(),
         })
     }
 }
@@ -80,6 +80,70 @@ pub(crate) enum BindingErrorVariant {
     },
     #[error("No destructor defined for class '{}', but asking for manual/disposable destruction", handle.name)]
     NoDestructorForManualDestruction { handle: ClassDeclarationHandle },
+    #[error("Class '{}' already has a property named '{}'", handle.name, name)]
+    PropertyAlreadyDefined {
+        handle: ClassDeclarationHandle,
+        name: Name,
+    },
+    #[error(
+        "Property '{}' of class '{}' collides with member '{}', the property is accessed as '{}', 'get_{}' or 'set_{}' depending on the language",
+        name,
+        handle.name,
+        member,
+        name,
+        name,
+        name
+    )]
+    PropertyNameCollision {
+        handle: ClassDeclarationHandle,
+        name: Name,
+        member: Name,
+    },
+    #[error(
+        "Getter '{}' of property '{}' must return a value and take no arguments besides the instance",
+        getter,
+        name
+    )]
+    PropertyGetterNotValid { name: Name, getter: Name },
+    #[error(
+        "Setter '{}' of property '{}' must return nothing and take a single argument besides the instance",
+        setter,
+        name
+    )]
+    PropertySetterNotValid { name: Name, setter: Name },
+    #[error(
+        "Setter '{}' of property '{}' does not accept the type returned by the getter",
+        setter,
+        name
+    )]
+    PropertyTypeMismatch { name: Name, setter: Name },
+    #[error("Class '{}' already implements interface '{}'", handle.name, interface)]
+    InterfaceAlreadyImplemented {
+        handle: ClassDeclarationHandle,
+        interface: Name,
+    },
+    #[error(
+        "Class '{}' does not define method '{}' required by interface '{}'",
+        handle.name,
+        callback,
+        interface
+    )]
+    InterfaceMethodNotDefined {
+        handle: ClassDeclarationHandle,
+        interface: Name,
+        callback: Name,
+    },
+    #[error(
+        "Method '{}' of class '{}' does not match the signature required by interface '{}'",
+        callback,
+        handle.name,
+        interface
+    )]
+    InterfaceMethodMismatch {
+        handle: ClassDeclarationHandle,
+        interface: Name,
+        callback: Name,
+    },
     // ----------------- constant definition errors -------------------
     #[error(
         "ConstantSet '{}' already contains constant name  '{}'",
@@ -87,6 +151,24 @@ pub(crate) enum BindingErrorVariant {
         constant_name
     )]
     ConstantNameAlreadyUsed { set_name: Name, constant_name: Name },
+    #[error(
+        "Constant '{}' in set '{}' is not an integer and may only use the decimal representation",
+        constant_name,
+        set_name
+    )]
+    ConstantRepresentationNotSupported { set_name: Name, constant_name: Name },
+    #[error(
+        "Constant '{}' in set '{}' is not a finite floating point value",
+        constant_name,
+        set_name
+    )]
+    ConstantNotFinite { set_name: Name, constant_name: Name },
+    #[error(
+        "Constant '{}' in set '{}' contains a control character other than a newline, carriage return or tab",
+        constant_name,
+        set_name
+    )]
+    ConstantStringContainsControlCharacter { set_name: Name, constant_name: Name },
     // ----------------- enum errors -------------------
     #[error("Enum '{}' does not contain a variant named '{}'", name, variant_name)]
     UnknownEnumVariant { name: Name, variant_name: String },
@@ -102,6 +184,73 @@ pub(crate) enum BindingErrorVariant {
         variant_value
     )]
     DuplicateEnumVariantValue { name: Name, variant_value: i32 },
+    // ----------------- error type errors -------------------
+    #[error("Error type '{}' already has a payload", name)]
+    ErrorPayloadAlreadyDefined { name: Name },
+    #[error(
+        "Payload '{}' of error type '{}' contains a string, bytes or list that cannot outlive the failed call",
+        struct_name,
+        name
+    )]
+    ErrorPayloadNotPlainData { name: Name, struct_name: Name },
+    #[error("Error type '{}' already has a drop error", name)]
+    DropErrorAlreadyDefined { name: Name },
+    #[error("Error type '{}' already has a bad argument error", name)]
+    BadArgumentErrorAlreadyDefined { name: Name },
+    #[error("Exception '{}' already extends a base exception", name)]
+    BaseExceptionAlreadyDefined { name: Name },
+    #[error(
+        "Exception '{}' must use the same exception type as its base exception '{}'",
+        name,
+        base
+    )]
+    BaseExceptionTypeMismatch { name: Name, base: Name },
+    // ----------------- flags errors -------------------
+    #[error("Flags '{}' already contains a bit with name '{}'", name, bit_name)]
+    DuplicateFlagsBitName { name: Name, bit_name: Name },
+    #[error(
+        "Bit '{}' of flags '{}' has value {:#X} which is zero or does not fit in the underlying type",
+        bit_name,
+        name,
+        value
+    )]
+    FlagsBitOutOfRange {
+        name: Name,
+        bit_name: Name,
+        value: u64,
+    },
+    #[error(
+        "Bit '{}' of flags '{}' has value {:#X} which overlaps a previously defined bit",
+        bit_name,
+        name,
+        value
+    )]
+    FlagsBitOverlap {
+        name: Name,
+        bit_name: Name,
+        value: u64,
+    },
+    #[error(
+        "Cannot push bit '{}' because there are no bits left in flags '{}'",
+        bit_name,
+        name
+    )]
+    FlagsFull { name: Name, bit_name: Name },
+    // ----------------- union errors -------------------
+    #[error(
+        "Union '{}' already contains a variant with name '{}'",
+        name,
+        variant_name
+    )]
+    DuplicateUnionVariantName { name: Name, variant_name: Name },
+    #[error("Union '{}' does not define any variants", name)]
+    UnionWithoutVariants { name: Name },
+    // ----------------- iterator errors -------------------
+    #[error(
+        "Iterator '{}' is not owned and cannot be returned from a function",
+        name
+    )]
+    IteratorNotOwned { name: Name },
     // ----------------- function errors -------------------
     #[error("Return type of native function '{}' was already defined", func_name)]
     ReturnTypeAlreadyDefined { func_name: Name },
@@ -111,6 +260,31 @@ pub(crate) enum BindingErrorVariant {
         error_type
     )]
     ErrorTypeAlreadyDefined { function: Name, error_type: Name },
+    #[error(
+        "Parameter '{}' of function '{}' follows a parameter with a default value and must also have one",
+        param_name,
+        func_name
+    )]
+    FunctionParamWithoutDefault { func_name: Name, param_name: Name },
+    #[error(
+        "Parameter '{}' of function '{}' may receive a value that can't be converted, but the function doesn't fail with an error type that defines a bad argument error",
+        param_name,
+        func_name
+    )]
+    BadArgumentNotReported { func_name: Name, param_name: Name },
+    #[error(
+        "Parameter '{}' of function '{}' may be null, but the function has no bad argument error to report it and its return type has no default to return instead",
+        param_name,
+        func_name
+    )]
+    NullArgumentNotReported { func_name: Name, param_name: Name },
+    #[error("Future method '{}' is already cancellable", func_name)]
+    FutureCancellationAlreadyDefined { func_name: Name },
+    #[error(
+        "Items of stream interface '{}' must be a basic type, a string or a universal struct",
+        name
+    )]
+    StreamItemTypeNotSupported { name: Name },
     // ----------------- interface errors -------------------
     #[error(
         "Symbol '{}' is reserved and cannot be used as an interface method name",
@@ -131,6 +305,15 @@ pub(crate) enum BindingErrorVariant {
         name
     )]
     CallbackMethodArgumentWithReservedName { name: Name },
+    #[error(
+        "Callback '{}' cannot return struct '{}' because it contains a list that would not outlive the callback",
+        callback_name,
+        struct_name
+    )]
+    CallbackReturnStructContainsList {
+        callback_name: Name,
+        struct_name: Name,
+    },
     #[error(
         "Initializer '{}' does not exist within struct '{}'",
         name,
@@ -164,6 +347,15 @@ pub(crate) enum BindingErrorVariant {
     },
     #[error("Initializer contains a default struct field but struct '{}' doesn't have a default initializer", struct_name)]
     StructInitializerStructFieldWithoutDefaultInitializer { struct_name: String },
+    #[error(
+        "Field '{}' of native struct '{}' may hold an enum value that can't be converted. Use an enum with an unknown value policy other than 'Error'",
+        field_name,
+        handle.name
+    )]
+    StructFieldCanBeInvalid {
+        handle: StructDeclarationHandle,
+        field_name: Name,
+    },
     #[error("Native struct '{}' already contains field with name '{}'", handle.name, field_name)]
     StructFieldDuplicateName {
         handle: StructDeclarationHandle,
@@ -0,0 +1,106 @@
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::*;
+
+/// Integer type underlying a set of flags
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlagsType {
+    U8,
+    U16,
+    U32,
+    U64,
+}
+
+impl FlagsType {
+    /// number of bits available in the type
+    pub(crate) fn bits(&self) -> u32 {
+        match self {
+            Self::U8 => u8::BITS,
+            Self::U16 => u16::BITS,
+            Self::U32 => u32::BITS,
+            Self::U64 => u64::BITS,
+        }
+    }
+
+    /// mask of all the bits available in the type
+    pub(crate) fn mask(&self) -> u64 {
+        match self {
+            Self::U8 => u8::MAX.into(),
+            Self::U16 => u16::MAX.into(),
+            Self::U32 => u32::MAX.into(),
+            Self::U64 => u64::MAX,
+        }
+    }
+}
+
+impl From<FlagsType> for Primitive {
+    fn from(x: FlagsType) -> Self {
+        match x {
+            FlagsType::U8 => Primitive::U8,
+            FlagsType::U16 => Primitive::U16,
+            FlagsType::U32 => Primitive::U32,
+            FlagsType::U64 => Primitive::U64,
+        }
+    }
+}
+
+/// Named bit, or group of bits, within a set of flags
+#[derive(Debug, Clone)]
+pub(crate) struct FlagsBit<T>
+where
+    T: DocReference,
+{
+    pub(crate) name: Name,
+    pub(crate) value: u64,
+    pub(crate) doc: Doc<T>,
+}
+
+impl FlagsBit<Unvalidated> {
+    pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<FlagsBit<Validated>> {
+        Ok(FlagsBit {
+            name: self.name.clone(),
+            value: self.value,
+            doc: self.doc.validate(&self.name, lib)?,
+        })
+    }
+}
+
+/// Set of non-overlapping bits stored in an unsigned integer
+#[derive(Debug)]
+pub struct Flags<T>
+where
+    T: DocReference,
+{
+    pub(crate) name: Name,
+    pub(crate) flags_type: FlagsType,
+    pub(crate) settings: Rc<LibrarySettings>,
+    pub(crate) bits: Vec<FlagsBit<T>>,
+    pub(crate) doc: Doc<T>,
+}
+
+impl Flags<Unvalidated> {
+    pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<Handle<Flags<Validated>>> {
+        let bits: BindResult<Vec<FlagsBit<Validated>>> =
+            self.bits.iter().map(|x| x.validate(lib)).collect();
+
+        Ok(Handle::new(Flags {
+            name: self.name.clone(),
+            flags_type: self.flags_type,
+            settings: self.settings.clone(),
+            bits: bits?,
+            doc: self.doc.validate(&self.name, lib)?,
+        }))
+    }
+}
+
+pub type FlagsHandle = Handle<Flags<Unvalidated>>;
+
+impl From<FlagsHandle> for BasicType {
+    fn from(x: FlagsHandle) -> Self {
+        BasicType::Flags(x)
+    }
+}
@@ -14,24 +14,100 @@ impl PrimitiveRef {
     }
 }
 
+/// Used for iterator "next" functions to get an optional string, enum or duration
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueRef {
+    String(StringType),
+    Enum(EnumHandle),
+    Duration(DurationType),
+}
+
+impl From<StringType> for ValueRef {
+    fn from(x: StringType) -> Self {
+        Self::String(x)
+    }
+}
+
+impl From<EnumHandle> for ValueRef {
+    fn from(x: EnumHandle) -> Self {
+        Self::Enum(x)
+    }
+}
+
+impl From<DurationType> for ValueRef {
+    fn from(x: DurationType) -> Self {
+        Self::Duration(x)
+    }
+}
+
 /// types that can be returns from native functions
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq)]
 pub enum FunctionReturnValue {
     Basic(BasicType),
     PrimitiveRef(PrimitiveRef),
+    ValueRef(ValueRef),
     String(StringType),
+    Bytes(BytesType),
+    Union(UnionHandle),
     ClassRef(ClassDeclarationHandle),
+    Iterator(AbstractIteratorHandle),
     Struct(UniversalOr<FunctionReturnStructField>),
     StructRef(UniversalDeclarationOr<FunctionReturnStructField>),
 }
 
+/// Default of a return value that C callers always handle
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ReturnDefault {
+    /// zero, false, or an empty set of flags
+    Zero,
+    /// null reference
+    Null,
+    /// null pointer to a value owned by the caller, e.g. a class instance
+    NullMut,
+}
+
+impl FunctionReturnValue {
+    /// value returned when the implementation can't be invoked, if the type has one
+    pub(crate) fn default_value(&self) -> Option<ReturnDefault> {
+        match self {
+            Self::Basic(BasicType::Enum(_)) => None,
+            Self::Basic(_) => Some(ReturnDefault::Zero),
+            Self::PrimitiveRef(_) | Self::ValueRef(_) | Self::StructRef(_) => {
+                Some(ReturnDefault::Null)
+            }
+            Self::String(_)
+            | Self::Bytes(_)
+            | Self::Union(_)
+            | Self::ClassRef(_)
+            | Self::Iterator(_)
+            | Self::Struct(_) => None,
+        }
+    }
+
+    /// value returned when a null argument prevents the implementation from being invoked, if the
+    /// type has one
+    pub(crate) fn null_argument_value(&self) -> Option<ReturnDefault> {
+        match self {
+            Self::String(_) => Some(ReturnDefault::Null),
+            Self::ClassRef(_) | Self::Iterator(_) => Some(ReturnDefault::NullMut),
+            _ => self.default_value(),
+        }
+    }
+}
+
 impl From<PrimitiveRef> for FunctionReturnValue {
     fn from(x: PrimitiveRef) -> Self {
         FunctionReturnValue::PrimitiveRef(x)
     }
 }
 
+impl From<ValueRef> for FunctionReturnValue {
+    fn from(x: ValueRef) -> Self {
+        FunctionReturnValue::ValueRef(x)
+    }
+}
+
 impl From<Primitive> for FunctionReturnValue {
     fn from(x: Primitive) -> Self {
         FunctionReturnValue::Basic(x.into())
@@ -50,6 +126,12 @@ impl From<DurationType> for FunctionReturnValue {
     }
 }
 
+impl From<TimestampType> for FunctionReturnValue {
+    fn from(x: TimestampType) -> Self {
+        BasicType::Timestamp(x).into()
+    }
+}
+
 impl From<ClassDeclarationHandle> for FunctionReturnValue {
     fn from(x: ClassDeclarationHandle) -> Self {
         FunctionReturnValue::ClassRef(x)
@@ -62,6 +144,24 @@ impl From<StringType> for FunctionReturnValue {
     }
 }
 
+impl From<BytesType> for FunctionReturnValue {
+    fn from(x: BytesType) -> Self {
+        FunctionReturnValue::Bytes(x)
+    }
+}
+
+impl From<UnionHandle> for FunctionReturnValue {
+    fn from(x: UnionHandle) -> Self {
+        FunctionReturnValue::Union(x)
+    }
+}
+
+impl From<AbstractIteratorHandle> for FunctionReturnValue {
+    fn from(x: AbstractIteratorHandle) -> Self {
+        FunctionReturnValue::Iterator(x)
+    }
+}
+
 impl From<FunctionReturnStructHandle> for FunctionReturnValue {
     fn from(x: FunctionReturnStructHandle) -> Self {
         FunctionReturnValue::Struct(x.into())
@@ -86,6 +186,12 @@ impl From<Handle<Enum<Unvalidated>>> for FunctionReturnValue {
     }
 }
 
+impl From<FlagsHandle> for FunctionReturnValue {
+    fn from(x: FlagsHandle) -> Self {
+        BasicType::Flags(x).into()
+    }
+}
+
 impl From<UniversalStructHandle> for FunctionReturnValue {
     fn from(x: UniversalStructHandle) -> Self {
         Self::Struct(UniversalOr::Universal(x))
@@ -98,6 +204,12 @@ impl From<CollectionClassDeclaration> for FunctionReturnValue {
     }
 }
 
+impl From<MapClassDeclaration> for FunctionReturnValue {
+    fn from(x: MapClassDeclaration) -> Self {
+        Self::ClassRef(x.inner)
+    }
+}
+
 pub type FunctionReturnType<D> = ReturnType<FunctionReturnValue, D>;
 
 /// Types that can be used as native function arguments
@@ -106,13 +218,47 @@ pub type FunctionReturnType<D> = ReturnType<FunctionReturnValue, D>;
 pub enum FunctionArgument {
     Basic(BasicType),
     String(StringType),
+    Bytes(BytesType),
+    Optional(Optional<BasicType>),
+    Union(UnionHandle),
     Collection(CollectionHandle),
+    Map(MapHandle),
     Struct(UniversalOr<FunctionArgStructField>),
     StructRef(FunctionArgStructDeclaration),
     ClassRef(ClassDeclarationHandle),
     Interface(InterfaceHandle),
 }
 
+impl FunctionArgument {
+    /// true if the Rust FFI may receive a value of this type that it can't convert, which the
+    /// function reports with the bad argument error of its error type
+    pub(crate) fn can_be_invalid(&self) -> bool {
+        match self {
+            FunctionArgument::Basic(x) => x.can_be_invalid(),
+            FunctionArgument::Optional(x) => x.can_be_invalid(),
+            // the tag of a union may not match any variant
+            FunctionArgument::Union(_) => true,
+            FunctionArgument::String(_)
+            | FunctionArgument::Bytes(_)
+            | FunctionArgument::Collection(_)
+            | FunctionArgument::Map(_)
+            | FunctionArgument::Struct(_)
+            | FunctionArgument::StructRef(_)
+            | FunctionArgument::ClassRef(_)
+            | FunctionArgument::Interface(_) => false,
+        }
+    }
+
+    /// true if the Rust FFI checks this pointer for null before calling the implementation
+    pub(crate) fn is_null_checked(&self, category: FunctionCategory) -> bool {
+        match self {
+            FunctionArgument::ClassRef(_) => !category.is_destroy(),
+            FunctionArgument::StructRef(_) => true,
+            _ => false,
+        }
+    }
+}
+
 impl From<UniversalStructHandle> for FunctionArgument {
     fn from(x: UniversalStructHandle) -> Self {
         Self::Struct(UniversalOr::Universal(x))
@@ -155,12 +301,24 @@ impl From<StringType> for FunctionArgument {
     }
 }
 
+impl From<BytesType> for FunctionArgument {
+    fn from(x: BytesType) -> Self {
+        FunctionArgument::Bytes(x)
+    }
+}
+
 impl From<CollectionHandle> for FunctionArgument {
     fn from(x: CollectionHandle) -> Self {
         FunctionArgument::Collection(x)
     }
 }
 
+impl From<MapHandle> for FunctionArgument {
+    fn from(x: MapHandle) -> Self {
+        FunctionArgument::Map(x)
+    }
+}
+
 impl From<FunctionArgStructDeclaration> for FunctionArgument {
     fn from(x: FunctionArgStructDeclaration) -> Self {
         FunctionArgument::StructRef(x)
@@ -173,6 +331,18 @@ impl From<UniversalStructDeclaration> for FunctionArgument {
     }
 }
 
+impl From<Optional<BasicType>> for FunctionArgument {
+    fn from(x: Optional<BasicType>) -> Self {
+        FunctionArgument::Optional(x)
+    }
+}
+
+impl From<UnionHandle> for FunctionArgument {
+    fn from(x: UnionHandle) -> Self {
+        FunctionArgument::Union(x)
+    }
+}
+
 impl From<BasicType> for FunctionArgument {
     fn from(x: BasicType) -> Self {
         FunctionArgument::Basic(x)
@@ -185,12 +355,24 @@ impl From<DurationType> for FunctionArgument {
     }
 }
 
+impl From<TimestampType> for FunctionArgument {
+    fn from(x: TimestampType) -> Self {
+        BasicType::Timestamp(x).into()
+    }
+}
+
 impl From<Handle<Enum<Unvalidated>>> for FunctionArgument {
     fn from(x: Handle<Enum<Unvalidated>>) -> Self {
         BasicType::Enum(x).into()
     }
 }
 
+impl From<FlagsHandle> for FunctionArgument {
+    fn from(x: FlagsHandle) -> Self {
+        BasicType::Flags(x).into()
+    }
+}
+
 impl From<IteratorClassDeclaration> for FunctionArgument {
     fn from(x: IteratorClassDeclaration) -> Self {
         Self::ClassRef(x.inner)
@@ -203,13 +385,62 @@ impl From<CollectionClassDeclaration> for FunctionArgument {
     }
 }
 
+impl From<MapClassDeclaration> for FunctionArgument {
+    fn from(x: MapClassDeclaration) -> Self {
+        Self::ClassRef(x.inner)
+    }
+}
+
+impl InitializerValidator for FunctionArgument {
+    fn validate_default_value(
+        &self,
+        value: &InitializerDefault,
+    ) -> BindResult<ValidatedDefaultValue> {
+        match self {
+            FunctionArgument::Basic(x) => x.validate_default_value(value),
+            FunctionArgument::String(x) => x.validate_default_value(value),
+            FunctionArgument::Bytes(x) => x.validate_default_value(value),
+            FunctionArgument::Optional(x) => x.validate_default_value(value),
+            FunctionArgument::Union(x) => {
+                Self::bad_initializer_value(format!("Union({})", x.name), value)
+            }
+            FunctionArgument::Collection(x) => x.validate_default_value(value),
+            FunctionArgument::Map(x) => x.validate_default_value(value),
+            FunctionArgument::Struct(x) => x.validate_default_value(value),
+            FunctionArgument::StructRef(x) => {
+                Self::bad_initializer_value(format!("StructRef({})", x.name()), value)
+            }
+            FunctionArgument::ClassRef(x) => x.validate_default_value(value),
+            FunctionArgument::Interface(x) => x.validate_default_value(value),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub(crate) enum FunctionCategory {
     Native,
+    ClassDestructor,
     CollectionCreate,
     CollectionDestroy,
     CollectionAdd,
+    MapCreate,
+    MapDestroy,
+    MapInsert,
     IteratorNext,
+    IteratorDestroy,
+}
+
+impl FunctionCategory {
+    /// destroying a null instance does nothing, so the pointer is passed to the implementation as is
+    pub(crate) fn is_destroy(&self) -> bool {
+        matches!(
+            self,
+            Self::ClassDestructor
+                | Self::IteratorDestroy
+                | Self::CollectionDestroy
+                | Self::MapDestroy
+        )
+    }
 }
 
 /// C function
@@ -295,6 +526,32 @@ where
     pub(crate) associated_class: Handle<ClassDeclaration>,
     pub(crate) future: FutureInterface<T>,
     pub(crate) native_function: Handle<Function<T>>,
+    pub(crate) cancellation: Option<FutureCancellation<T>>,
+}
+
+/// cancellation of a pending future method
+///
+/// the native function of a cancellable future method returns an operation identifier that
+/// may be passed to the cancel function. Cancelling completes the future with the error variant.
+#[derive(Debug, Clone)]
+pub struct FutureCancellation<T>
+where
+    T: DocReference,
+{
+    pub(crate) variant: Name,
+    pub(crate) function: Handle<Function<T>>,
+}
+
+impl FutureCancellation<Unvalidated> {
+    pub(crate) fn validate(
+        &self,
+        lib: &LibraryFields,
+    ) -> BindResult<FutureCancellation<Validated>> {
+        Ok(FutureCancellation {
+            variant: self.variant.clone(),
+            function: self.function.validate(lib)?,
+        })
+    }
 }
 
 impl FutureMethod<Validated> {
@@ -310,6 +567,16 @@ impl FutureMethod<Validated> {
             _ => true,
         })
     }
+
+    /// cancel function exposed as a static method of the class, for languages without cancellation tokens
+    pub(crate) fn cancel_method(&self) -> Option<StaticMethod<Validated>> {
+        self.cancellation.as_ref().map(|x| StaticMethod {
+            name: self
+                .name
+                .append(&Name::create("cancel").expect("'cancel' is a valid name")),
+            native_function: x.function.clone(),
+        })
+    }
 }
 
 impl FutureMethod<Unvalidated> {
@@ -319,12 +586,57 @@ impl FutureMethod<Unvalidated> {
             associated_class: self.associated_class.clone(),
             future: self.future.validate(lib)?,
             native_function: self.native_function.validate(lib)?,
+            cancellation: match &self.cancellation {
+                None => None,
+                Some(x) => Some(x.validate(lib)?),
+            },
         })
     }
 }
 
 pub type FutureMethodHandle = FutureMethod<Unvalidated>;
 
+/// represents a method that initiates an operation producing
+/// a sequence of items delivered to an abstract stream
+#[derive(Debug, Clone)]
+pub struct StreamMethod<T>
+where
+    T: DocReference,
+{
+    pub(crate) name: Name,
+    pub(crate) associated_class: Handle<ClassDeclaration>,
+    pub(crate) stream: StreamInterface<T>,
+    pub(crate) native_function: Handle<Function<T>>,
+}
+
+impl StreamMethod<Validated> {
+    pub fn arguments(&self) -> impl Iterator<Item = &Arg<FunctionArgument, Validated>> {
+        self.native_function.arguments.iter().skip(1)
+    }
+
+    pub fn arguments_without_callback(
+        &self,
+    ) -> impl Iterator<Item = &Arg<FunctionArgument, Validated>> {
+        self.arguments().filter(|param| match &param.arg_type {
+            FunctionArgument::Interface(handle) => handle.name != self.stream.interface.name,
+            _ => true,
+        })
+    }
+}
+
+impl StreamMethod<Unvalidated> {
+    pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<StreamMethod<Validated>> {
+        Ok(StreamMethod {
+            name: self.name.clone(),
+            associated_class: self.associated_class.clone(),
+            stream: self.stream.validate(lib)?,
+            native_function: self.native_function.validate(lib)?,
+        })
+    }
+}
+
+pub type StreamMethodHandle = StreamMethod<Unvalidated>;
+
 #[derive(Debug, Clone)]
 pub struct ClassDestructor<T>
 where
@@ -346,7 +658,10 @@ impl ClassDestructor<Unvalidated> {
         let instance_name = lib.settings().class.method_instance_argument_name.clone();
 
         let function = lib
-            .define_function(destructor_function_name)?
+            .define_function_with_category(
+                destructor_function_name,
+                FunctionCategory::ClassDestructor,
+            )?
             .param(
                 instance_name,
                 class.clone(),
@@ -8,6 +8,9 @@ use crate::model::*;
 pub enum CallbackArgument {
     Basic(BasicType),
     String(StringType),
+    Bytes(BytesType),
+    Optional(Optional<BasicType>),
+    Union(UnionHandle),
     Iterator(AbstractIteratorHandle),
     Class(ClassDeclarationHandle),
     Struct(UniversalOr<CallbackArgStructField>),
@@ -31,12 +34,24 @@ impl From<Handle<Enum<Unvalidated>>> for CallbackArgument {
     }
 }
 
+impl From<FlagsHandle> for CallbackArgument {
+    fn from(x: FlagsHandle) -> Self {
+        Self::Basic(BasicType::Flags(x))
+    }
+}
+
 impl From<DurationType> for CallbackArgument {
     fn from(x: DurationType) -> Self {
         CallbackArgument::Basic(BasicType::Duration(x))
     }
 }
 
+impl From<TimestampType> for CallbackArgument {
+    fn from(x: TimestampType) -> Self {
+        CallbackArgument::Basic(BasicType::Timestamp(x))
+    }
+}
+
 impl From<AbstractIteratorHandle> for CallbackArgument {
     fn from(x: AbstractIteratorHandle) -> Self {
         Self::Iterator(x)
@@ -61,6 +76,24 @@ impl From<StringType> for CallbackArgument {
     }
 }
 
+impl From<BytesType> for CallbackArgument {
+    fn from(x: BytesType) -> Self {
+        Self::Bytes(x)
+    }
+}
+
+impl From<Optional<BasicType>> for CallbackArgument {
+    fn from(x: Optional<BasicType>) -> Self {
+        Self::Optional(x)
+    }
+}
+
+impl From<UnionHandle> for CallbackArgument {
+    fn from(x: UnionHandle) -> Self {
+        Self::Union(x)
+    }
+}
+
 impl From<ClassDeclarationHandle> for CallbackArgument {
     fn from(x: ClassDeclarationHandle) -> Self {
         Self::Class(x)
@@ -162,6 +195,17 @@ pub enum CallbackReturnValue {
     Struct(UniversalStructHandle),
 }
 
+impl CallbackReturnValue {
+    /// true if the Rust FFI may receive a value that it can't convert
+    pub(crate) fn can_be_invalid(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.can_be_invalid(),
+            // fields that can be invalid are rejected when the struct is defined
+            Self::Struct(_) => false,
+        }
+    }
+}
+
 /// Like CallbackReturnValue, but with a value
 #[non_exhaustive]
 #[derive(Debug, Clone)]
@@ -233,12 +277,24 @@ impl From<DurationType> for CallbackReturnValue {
     }
 }
 
+impl From<TimestampType> for CallbackReturnValue {
+    fn from(x: TimestampType) -> Self {
+        BasicType::Timestamp(x).into()
+    }
+}
+
 impl From<Handle<Enum<Unvalidated>>> for CallbackReturnValue {
     fn from(x: Handle<Enum<Unvalidated>>) -> Self {
         Self::Basic(BasicType::Enum(x))
     }
 }
 
+impl From<FlagsHandle> for CallbackReturnValue {
+    fn from(x: FlagsHandle) -> Self {
+        Self::Basic(BasicType::Flags(x))
+    }
+}
+
 pub type CallbackReturnType<T> = ReturnType<CallbackReturnValue, T>;
 
 /// A flag to the backend that tells it whether or not
@@ -308,6 +364,8 @@ pub(crate) enum InterfaceCategory {
     Asynchronous,
     /// An asynchronous interface which has particular properties
     Future,
+    /// An asynchronous interface which delivers a sequence of items followed by completion or failure
+    Stream,
 }
 
 #[derive(Debug, Clone)]
@@ -318,6 +376,7 @@ where
     Synchronous(Handle<Interface<D>>),
     Asynchronous(Handle<Interface<D>>),
     Future(FutureInterface<D>),
+    Stream(StreamInterface<D>),
 }
 
 impl InterfaceType<Unvalidated> {
@@ -326,6 +385,7 @@ impl InterfaceType<Unvalidated> {
             InterfaceType::Synchronous(x) => Ok(InterfaceType::Synchronous(x.validate(lib)?)),
             InterfaceType::Asynchronous(x) => Ok(InterfaceType::Asynchronous(x.validate(lib)?)),
             InterfaceType::Future(x) => Ok(InterfaceType::Future(x.validate(lib)?)),
+            InterfaceType::Stream(x) => Ok(InterfaceType::Stream(x.validate(lib)?)),
         }
     }
 }
@@ -339,6 +399,7 @@ where
             InterfaceType::Synchronous(x) => &x.name,
             InterfaceType::Asynchronous(x) => &x.name,
             InterfaceType::Future(x) => &x.interface.name,
+            InterfaceType::Stream(x) => &x.interface.name,
         }
     }
 
@@ -347,6 +408,7 @@ where
             InterfaceType::Synchronous(_) => InterfaceCategory::Synchronous,
             InterfaceType::Asynchronous(_) => InterfaceCategory::Asynchronous,
             InterfaceType::Future(_) => InterfaceCategory::Future,
+            InterfaceType::Stream(_) => InterfaceCategory::Stream,
         }
     }
 
@@ -355,6 +417,7 @@ where
             InterfaceType::Synchronous(x) => &x.doc,
             InterfaceType::Asynchronous(x) => &x.doc,
             InterfaceType::Future(x) => &x.interface.doc,
+            InterfaceType::Stream(x) => &x.interface.doc,
         }
     }
 
@@ -363,6 +426,7 @@ where
             InterfaceType::Synchronous(x) => x,
             InterfaceType::Asynchronous(x) => x,
             InterfaceType::Future(x) => &x.interface,
+            InterfaceType::Stream(x) => &x.interface,
         }
     }
 }
@@ -442,35 +506,87 @@ pub struct FutureInterface<D>
 where
     D: DocReference,
 {
-    pub(crate) value_type: CallbackArgument,
-    pub(crate) value_type_doc: DocString<D>,
+    /// value provided on success, if any
+    pub(crate) value: Option<FutureValue<D>>,
     pub(crate) error_type: ErrorType<D>,
     pub(crate) interface: Handle<Interface<D>>,
 }
 
+/// value provided by the success callback of a future interface
+#[derive(Debug, Clone)]
+pub struct FutureValue<D>
+where
+    D: DocReference,
+{
+    pub(crate) value_type: CallbackArgument,
+    pub(crate) doc: DocString<D>,
+}
+
 impl FutureInterface<Unvalidated> {
     pub(crate) fn new(
-        value_type: CallbackArgument,
+        value: Option<FutureValue<Unvalidated>>,
         error_type: ErrorType<Unvalidated>,
         interface: Handle<Interface<Unvalidated>>,
-        value_type_doc: DocString<Unvalidated>,
     ) -> Self {
         Self {
-            value_type,
+            value,
             error_type,
-            value_type_doc,
             interface,
         }
     }
 
     pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<FutureInterface<Validated>> {
         Ok(FutureInterface {
-            value_type: self.value_type.clone(),
+            value: match &self.value {
+                None => None,
+                Some(x) => Some(FutureValue {
+                    value_type: x.value_type.clone(),
+                    doc: x.doc.validate(&self.interface.name, lib)?,
+                }),
+            },
             error_type: self.error_type.validate(lib)?,
-            value_type_doc: self.value_type_doc.validate(&self.interface.name, lib)?,
             interface: self.interface.validate(lib)?,
         })
     }
 }
 
 pub type FutureInterfaceHandle = FutureInterface<Unvalidated>;
+
+#[derive(Debug, Clone)]
+pub struct StreamInterface<D>
+where
+    D: DocReference,
+{
+    /// type of the items delivered by the stream
+    pub(crate) item_type: CallbackArgument,
+    pub(crate) item_type_doc: DocString<D>,
+    pub(crate) error_type: ErrorType<D>,
+    pub(crate) interface: Handle<Interface<D>>,
+}
+
+impl StreamInterface<Unvalidated> {
+    pub(crate) fn new(
+        item_type: CallbackArgument,
+        item_type_doc: DocString<Unvalidated>,
+        error_type: ErrorType<Unvalidated>,
+        interface: Handle<Interface<Unvalidated>>,
+    ) -> Self {
+        Self {
+            item_type,
+            item_type_doc,
+            error_type,
+            interface,
+        }
+    }
+
+    pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<StreamInterface<Validated>> {
+        Ok(StreamInterface {
+            item_type: self.item_type.clone(),
+            item_type_doc: self.item_type_doc.validate(&self.interface.name, lib)?,
+            error_type: self.error_type.validate(lib)?,
+            interface: self.interface.validate(lib)?,
+        })
+    }
+}
+
+pub type StreamInterfaceHandle = StreamInterface<Unvalidated>;
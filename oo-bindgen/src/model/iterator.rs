@@ -6,6 +6,11 @@ use crate::model::*;
 #[non_exhaustive]
 pub enum IteratorItemType {
     Primitive(Primitive),
+    String(StringType),
+    Enum(EnumHandle),
+    Duration(DurationType),
+    /// instances are borrowed from the iterator and may not be destroyed by the caller
+    Class(ClassDeclarationHandle),
     Struct(UniversalOr<FunctionReturnStructField>),
 }
 
@@ -33,6 +38,30 @@ impl From<Primitive> for IteratorItemType {
     }
 }
 
+impl From<StringType> for IteratorItemType {
+    fn from(x: StringType) -> Self {
+        Self::String(x)
+    }
+}
+
+impl From<EnumHandle> for IteratorItemType {
+    fn from(x: EnumHandle) -> Self {
+        Self::Enum(x)
+    }
+}
+
+impl From<DurationType> for IteratorItemType {
+    fn from(x: DurationType) -> Self {
+        Self::Duration(x)
+    }
+}
+
+impl From<ClassDeclarationHandle> for IteratorItemType {
+    fn from(x: ClassDeclarationHandle) -> Self {
+        Self::Class(x)
+    }
+}
+
 impl IteratorItemType {
     pub(crate) fn get_function_return_value(&self) -> FunctionReturnValue {
         match self {
@@ -40,6 +69,11 @@ impl IteratorItemType {
             IteratorItemType::Primitive(x) => {
                 FunctionReturnValue::PrimitiveRef(PrimitiveRef::new(*x))
             }
+            IteratorItemType::String(x) => FunctionReturnValue::ValueRef(ValueRef::String(*x)),
+            IteratorItemType::Enum(x) => FunctionReturnValue::ValueRef(ValueRef::Enum(x.clone())),
+            IteratorItemType::Duration(x) => FunctionReturnValue::ValueRef(ValueRef::Duration(*x)),
+            // class pointers are already nullable
+            IteratorItemType::Class(x) => FunctionReturnValue::ClassRef(x.clone()),
         }
     }
 }
@@ -54,6 +88,10 @@ where
     /// function used to retrieve the next value
     /// it takes the `iter_class` and returns a pointer to the `iter_type`
     pub(crate) next_function: Handle<Function<D>>,
+    /// function used to destroy the iterator when it is owned by the caller
+    ///
+    /// only present for iterators that may be returned from functions
+    pub(crate) destroy_function: Option<Handle<Function<D>>>,
     /// opaque c struct type for the iterator
     pub(crate) iter_class: ClassDeclarationHandle,
     /// type of the value returned as a possibly null pointer
@@ -70,6 +108,10 @@ impl AbstractIterator<Unvalidated> {
         Ok(Handle::new(AbstractIterator {
             has_lifetime_annotation: self.has_lifetime_annotation,
             next_function: self.next_function.validate(lib)?,
+            destroy_function: match &self.destroy_function {
+                None => None,
+                Some(x) => Some(x.validate(lib)?),
+            },
             iter_class: self.iter_class.clone(),
             item_type: self.item_type.clone(),
             settings: self.settings.clone(),
@@ -85,12 +127,14 @@ where
         has_lifetime_annotation: bool,
         iter_class: ClassDeclarationHandle,
         next_function: Handle<Function<D>>,
+        destroy_function: Option<Handle<Function<D>>>,
         item_type: IteratorItemType,
         settings: Rc<LibrarySettings>,
     ) -> AbstractIterator<D> {
         AbstractIterator {
             has_lifetime_annotation,
             next_function,
+            destroy_function,
             iter_class,
             item_type,
             settings,
@@ -100,6 +144,11 @@ where
     pub(crate) fn name(&self) -> &Name {
         &self.iter_class.name
     }
+
+    /// true if the iterator can be returned from a function and destroyed by the caller
+    pub(crate) fn is_owned(&self) -> bool {
+        self.destroy_function.is_some()
+    }
 }
 
 pub type AbstractIteratorHandle = Handle<AbstractIterator<Unvalidated>>;
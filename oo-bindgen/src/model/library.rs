@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 use std::rc::Rc;
 
+use serde::{Deserialize, Serialize};
+
 use crate::model::*;
 
 #[derive(Clone, Debug)]
@@ -12,6 +14,9 @@ where
     StructDeclaration(StructDeclarationHandle),
     StructDefinition(StructType<D>),
     EnumDefinition(Handle<Enum<D>>),
+    FlagsDefinition(Handle<Flags<D>>),
+    UnionDefinition(Handle<Union<D>>),
+    BaseException(Handle<BaseException<D>>),
     ErrorType(ErrorType<D>),
     ClassDeclaration(ClassDeclarationHandle),
     ClassDefinition(Handle<Class<D>>),
@@ -19,6 +24,8 @@ where
     InterfaceDefinition(InterfaceType<D>),
     IteratorDeclaration(Handle<AbstractIterator<D>>),
     CollectionDeclaration(Handle<Collection<D>>),
+    MapDeclaration(Handle<Map<D>>),
+    ListDeclaration(ListHandle),
     FunctionDefinition(Handle<Function<D>>),
 }
 
@@ -32,6 +39,9 @@ impl Statement<Unvalidated> {
                 None
             }
             Statement::EnumDefinition(x) => Some(&x.name),
+            Statement::FlagsDefinition(x) => Some(&x.name),
+            Statement::UnionDefinition(x) => Some(&x.name),
+            Statement::BaseException(x) => Some(&x.name),
             Statement::ErrorType(x) => Some(&x.exception_name),
             Statement::ClassDeclaration(x) => Some(&x.name),
             Statement::ClassDefinition(_) => {
@@ -48,6 +58,11 @@ impl Statement<Unvalidated> {
                 // the name is derived in a language specific way
                 None
             }
+            Statement::MapDeclaration(_) => {
+                // the name is derived in a language specific way
+                None
+            }
+            Statement::ListDeclaration(x) => Some(&x.name),
             Statement::FunctionDefinition(x) => Some(&x.name),
         }
     }
@@ -87,17 +102,29 @@ pub struct LibraryInfo {
 }
 
 /// Settings that affect iterator function naming
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IteratorSettings {
     /// name of the C function which retrieve's the iterator's next value
     /// is automatically generated as `<c_ffi_prefix>_<iterator_class_name>_<next_function_suffix>`
     pub next_function_suffix: Name,
+    /// name of the C function which destroys an owned iterator
+    /// is automatically generated as `<c_ffi_prefix>_<iterator_class_name>_<destroy_function_suffix>`
+    pub destroy_function_suffix: Name,
 }
 
 impl IteratorSettings {
     pub fn new(next_function_suffix: Name) -> IteratorSettings {
         Self {
             next_function_suffix,
+            ..Default::default()
+        }
+    }
+
+    /// replace the suffix of the function which destroys an owned iterator, which defaults to 'destroy'
+    pub fn with_destroy_function_suffix(self, destroy_function_suffix: Name) -> IteratorSettings {
+        Self {
+            destroy_function_suffix,
+            ..self
         }
     }
 }
@@ -106,12 +133,13 @@ impl Default for IteratorSettings {
     fn default() -> Self {
         Self {
             next_function_suffix: Name::create("next").unwrap(),
+            destroy_function_suffix: Name::create("destroy").unwrap(),
         }
     }
 }
 
 /// Settings that affect C interface member naming
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InterfaceSettings {
     /// Name of the C void* context variable, defaults to "ctx"
     pub context_variable_name: Name,
@@ -138,7 +166,7 @@ impl Default for InterfaceSettings {
 }
 
 /// Settings that affect class method naming
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClassSettings {
     /// Methods in C always take an instance of the class at the first parameter.
     /// This setting controls the name automatically assigned to this parameter.
@@ -182,7 +210,7 @@ impl Default for ClassSettings {
 }
 
 /// Settings that affect how things are named in future-style callback interfaces
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FutureSettings {
     /// The name given to the success completion method on interface
     pub success_callback_method_name: Name,
@@ -226,15 +254,139 @@ impl Default for FutureSettings {
     }
 }
 
+/// Settings that affect how things are named in stream-style callback interfaces
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StreamSettings {
+    /// The name given to the method on the interface receiving each item
+    pub item_callback_method_name: Name,
+    /// The name given to the item parameter of the item method
+    pub item_parameter_name: Name,
+    /// The name given to the method on the interface invoked when the stream ends
+    pub complete_callback_method_name: Name,
+    /// The name given to the method on the interface invoked when the stream fails
+    pub failure_callback_method_name: Name,
+    /// The name given to the error parameter of the failure method
+    pub failure_parameter_name: Name,
+    /// The name given to the final callback parameter of the stream methods
+    pub stream_method_callback_parameter_name: Name,
+}
+
+impl StreamSettings {
+    pub fn new(
+        item_callback_method_name: Name,
+        item_parameter_name: Name,
+        complete_callback_method_name: Name,
+        failure_callback_method_name: Name,
+        failure_parameter_name: Name,
+        stream_method_callback_parameter_name: Name,
+    ) -> Self {
+        Self {
+            item_callback_method_name,
+            item_parameter_name,
+            complete_callback_method_name,
+            failure_callback_method_name,
+            failure_parameter_name,
+            stream_method_callback_parameter_name,
+        }
+    }
+}
+
+impl Default for StreamSettings {
+    fn default() -> Self {
+        Self {
+            item_callback_method_name: Name::create("on_item").unwrap(),
+            item_parameter_name: Name::create("item").unwrap(),
+            complete_callback_method_name: Name::create("on_complete").unwrap(),
+            failure_callback_method_name: Name::create("on_failure").unwrap(),
+            failure_parameter_name: Name::create("error").unwrap(),
+            stream_method_callback_parameter_name: Name::create("callback").unwrap(),
+        }
+    }
+}
+
+/// Value returned by a function without an error type when its implementation panics
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PanicFallback {
+    /// Return the default value of the return type: zero, false, an empty set of flags, or a null
+    /// reference. Functions returning any other type abort the process.
+    DefaultOrAbort,
+    /// Abort the process
+    Abort,
+}
+
+/// Settings that control how panics in the Rust implementation are handled by the FFI layer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PanicSettings {
+    /// If true, the body of every generated FFI function and callback helper is wrapped in
+    /// `std::panic::catch_unwind` so that panics never unwind into the calling language
+    pub catch_unwind: bool,
+    /// The name of the variant added to every error type when `catch_unwind` is enabled.
+    /// Functions with an error type return this variant when they panic.
+    pub error_variant_name: Name,
+    /// Path of a Rust function with the signature `fn(&str)` that is invoked with a description of
+    /// the panic. If not set, the description is printed to stderr.
+    pub log_function: Option<String>,
+    /// What functions without an error type do after logging the panic.
+    ///
+    /// This value defaults to [PanicFallback::DefaultOrAbort]
+    pub fallback: PanicFallback,
+}
+
+impl PanicSettings {
+    pub fn new(catch_unwind: bool, error_variant_name: Name, log_function: Option<String>) -> Self {
+        Self {
+            catch_unwind,
+            error_variant_name,
+            log_function,
+            fallback: PanicFallback::DefaultOrAbort,
+        }
+    }
+
+    /// replace what functions without an error type do when their implementation panics
+    pub fn with_fallback(self, fallback: PanicFallback) -> Self {
+        Self { fallback, ..self }
+    }
+}
+
+impl Default for PanicSettings {
+    fn default() -> Self {
+        Self {
+            catch_unwind: false,
+            error_variant_name: Name::create("panic").unwrap(),
+            log_function: None,
+            fallback: PanicFallback::DefaultOrAbort,
+        }
+    }
+}
+
+/// What a function does when it receives a null class or struct reference, without calling the
+/// implementation
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NullArgumentFallback {
+    /// Return the bad argument error if the error type of the function defines one. Otherwise
+    /// return the default of the return type: zero, false, an empty set of flags, or a null
+    /// reference, class instance, string or iterator. Functions with a null checked argument that
+    /// return any other type are rejected when they are built.
+    #[default]
+    Default,
+    /// Abort the process
+    Abort,
+}
+
 /// Settings that affect collection function naming
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CollectionSettings {
     /// name of the C function which creates a collection
     /// is automatically generated as `<c_ffi_prefix>_<collection_class_name>_<create_function_suffix>`
     pub create_function_suffix: Name,
-    /// name of the C function which creates a collection
+    /// name of the C function which adds a value to a collection
     /// is automatically generated as `<c_ffi_prefix>_<collection_class_name>_<add_function_suffix>`
     pub add_function_suffix: Name,
+    /// name of the C function which inserts a key/value pair into a map
+    /// is automatically generated as `<c_ffi_prefix>_<map_class_name>_<insert_function_suffix>`
+    pub insert_function_suffix: Name,
     /// name of the C function which destroys a collection
     /// is automatically generated as `<c_ffi_prefix>_<collection_class_name>_<destroy_function_suffix>`
     pub destroy_function_suffix: Name,
@@ -250,6 +402,15 @@ impl CollectionSettings {
             create_function_suffix,
             add_function_suffix,
             destroy_function_suffix,
+            ..Default::default()
+        }
+    }
+
+    /// replace the suffix of the function which inserts into a map, which defaults to 'insert'
+    pub fn with_insert_function_suffix(self, insert_function_suffix: Name) -> CollectionSettings {
+        Self {
+            insert_function_suffix,
+            ..self
         }
     }
 }
@@ -259,13 +420,14 @@ impl Default for CollectionSettings {
         Self {
             create_function_suffix: Name::create("create").unwrap(),
             add_function_suffix: Name::create("add").unwrap(),
+            insert_function_suffix: Name::create("insert").unwrap(),
             destroy_function_suffix: Name::create("destroy").unwrap(),
         }
     }
 }
 
 /// Settings that affect the names of things
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LibrarySettings {
     /// name of the library
     pub name: Name,
@@ -279,8 +441,14 @@ pub struct LibrarySettings {
     pub collection: CollectionSettings,
     /// settings that control future-style interface generation
     pub future: FutureSettings,
+    /// settings that control stream-style interface generation
+    pub stream: StreamSettings,
     /// settings that control C interface member naming
     pub interface: InterfaceSettings,
+    /// settings that control how panics are handled by the FFI functions
+    pub panic: PanicSettings,
+    /// what the FFI functions do when they receive a null class or struct reference
+    pub null_argument: NullArgumentFallback,
 }
 
 impl LibrarySettings {
@@ -301,9 +469,30 @@ impl LibrarySettings {
             iterator,
             collection,
             future,
+            stream: StreamSettings::default(),
             interface,
+            panic: PanicSettings::default(),
+            null_argument: NullArgumentFallback::default(),
         }))
     }
+
+    /// replace the settings that control stream-style interface generation
+    pub fn with_stream(mut self: Rc<Self>, stream: StreamSettings) -> Rc<Self> {
+        Rc::make_mut(&mut self).stream = stream;
+        self
+    }
+
+    /// replace the settings that control how panics are handled by the FFI functions
+    pub fn with_panic(mut self: Rc<Self>, panic: PanicSettings) -> Rc<Self> {
+        Rc::make_mut(&mut self).panic = panic;
+        self
+    }
+
+    /// replace what the FFI functions do when they receive a null class or struct reference
+    pub fn with_null_argument(mut self: Rc<Self>, null_argument: NullArgumentFallback) -> Rc<Self> {
+        Rc::make_mut(&mut self).null_argument = null_argument;
+        self
+    }
 }
 
 pub struct Library {
@@ -363,6 +552,20 @@ impl Library {
         })
     }
 
+    pub(crate) fn flags(&self) -> impl Iterator<Item = &Handle<Flags<Validated>>> {
+        self.statements().filter_map(|statement| match statement {
+            Statement::FlagsDefinition(handle) => Some(handle),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn unions(&self) -> impl Iterator<Item = &Handle<Union<Validated>>> {
+        self.statements().filter_map(|statement| match statement {
+            Statement::UnionDefinition(handle) => Some(handle),
+            _ => None,
+        })
+    }
+
     pub(crate) fn classes(&self) -> impl Iterator<Item = &Handle<Class<Validated>>> {
         self.statements().filter_map(|statement| match statement {
             Statement::ClassDefinition(handle) => Some(handle),
@@ -370,6 +573,15 @@ impl Library {
         })
     }
 
+    pub(crate) fn base_exceptions(
+        &self,
+    ) -> impl Iterator<Item = &Handle<BaseException<Validated>>> {
+        self.statements().filter_map(|statement| match statement {
+            Statement::BaseException(handle) => Some(handle),
+            _ => None,
+        })
+    }
+
     pub(crate) fn error_types(&self) -> impl Iterator<Item = &ErrorType<Validated>> {
         self.statements().filter_map(|statement| match statement {
             Statement::ErrorType(err) => Some(err),
@@ -397,6 +609,11 @@ impl Library {
             })
     }
 
+    pub(crate) fn has_stream_interfaces(&self) -> bool {
+        self.interfaces()
+            .any(|x| matches!(x, InterfaceType::Stream(_)))
+    }
+
     pub(crate) fn iterators(&self) -> impl Iterator<Item = &Handle<AbstractIterator<Validated>>> {
         self.statements().filter_map(|statement| match statement {
             Statement::IteratorDeclaration(handle) => Some(handle),
@@ -410,6 +627,20 @@ impl Library {
             _ => None,
         })
     }
+
+    pub(crate) fn maps(&self) -> impl Iterator<Item = &Handle<Map<Validated>>> {
+        self.statements().filter_map(|statement| match statement {
+            Statement::MapDeclaration(handle) => Some(handle),
+            _ => None,
+        })
+    }
+
+    pub(crate) fn lists(&self) -> impl Iterator<Item = &ListHandle> {
+        self.statements().filter_map(|statement| match statement {
+            Statement::ListDeclaration(handle) => Some(handle),
+            _ => None,
+        })
+    }
 }
 
 impl From<UniversalStructDeclaration> for FunctionReturnStructDeclaration {
@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use crate::model::*;
+
+/// Types that can be stored in a list
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ListItemType {
+    Basic(BasicType),
+    Struct(UniversalStructHandle),
+}
+
+impl From<Primitive> for ListItemType {
+    fn from(x: Primitive) -> Self {
+        Self::Basic(x.into())
+    }
+}
+
+impl From<BasicType> for ListItemType {
+    fn from(x: BasicType) -> Self {
+        Self::Basic(x)
+    }
+}
+
+impl From<DurationType> for ListItemType {
+    fn from(x: DurationType) -> Self {
+        Self::Basic(BasicType::Duration(x))
+    }
+}
+
+impl From<TimestampType> for ListItemType {
+    fn from(x: TimestampType) -> Self {
+        Self::Basic(BasicType::Timestamp(x))
+    }
+}
+
+impl From<EnumHandle> for ListItemType {
+    fn from(x: EnumHandle) -> Self {
+        Self::Basic(BasicType::Enum(x))
+    }
+}
+
+impl From<FlagsHandle> for ListItemType {
+    fn from(x: FlagsHandle) -> Self {
+        Self::Basic(BasicType::Flags(x))
+    }
+}
+
+impl From<UniversalStructHandle> for ListItemType {
+    fn from(x: UniversalStructHandle) -> Self {
+        Self::Struct(x)
+    }
+}
+
+/// Contiguous sequence of values that may be used as a struct field
+///
+/// Unlike collections, lists are not backed by an opaque class. They are passed
+/// in C as a pointer to the first item and a count of items.
+#[derive(Debug)]
+pub struct List {
+    pub(crate) name: Name,
+    pub(crate) item_type: ListItemType,
+    pub(crate) settings: Rc<LibrarySettings>,
+}
+
+impl List {
+    pub(crate) fn new(name: Name, item_type: ListItemType, settings: Rc<LibrarySettings>) -> Self {
+        Self {
+            name,
+            item_type,
+            settings,
+        }
+    }
+}
+
+pub type ListHandle = Handle<List>;
@@ -0,0 +1,56 @@
+use crate::model::*;
+
+#[derive(Debug)]
+pub struct Map<D>
+where
+    D: DocReference,
+{
+    pub(crate) map_class: ClassDeclarationHandle,
+    pub(crate) key_type: FunctionArgument,
+    pub(crate) value_type: FunctionArgument,
+    pub(crate) create_func: Handle<Function<D>>,
+    pub(crate) delete_func: Handle<Function<D>>,
+    pub(crate) insert_func: Handle<Function<D>>,
+}
+
+impl<D> Map<D>
+where
+    D: DocReference,
+{
+    pub(crate) fn new(
+        map_class: ClassDeclarationHandle,
+        key_type: FunctionArgument,
+        value_type: FunctionArgument,
+        create_func: Handle<Function<D>>,
+        delete_func: Handle<Function<D>>,
+        insert_func: Handle<Function<D>>,
+    ) -> Map<D> {
+        Map {
+            map_class,
+            key_type,
+            value_type,
+            create_func,
+            delete_func,
+            insert_func,
+        }
+    }
+
+    pub fn name(&self) -> &Name {
+        &self.map_class.name
+    }
+}
+
+impl Map<Unvalidated> {
+    pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<Handle<Map<Validated>>> {
+        Ok(Handle::new(Map {
+            map_class: self.map_class.clone(),
+            key_type: self.key_type.clone(),
+            value_type: self.value_type.clone(),
+            create_func: self.create_func.validate(lib)?,
+            delete_func: self.delete_func.validate(lib)?,
+            insert_func: self.insert_func.validate(lib)?,
+        }))
+    }
+}
+
+pub type MapHandle = Handle<Map<Unvalidated>>;
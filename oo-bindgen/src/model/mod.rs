@@ -6,10 +6,12 @@ pub use builder::class::*;
 pub use builder::constants::*;
 pub use builder::enums::*;
 pub use builder::error_type::*;
+pub use builder::flags::*;
 pub use builder::function::*;
 pub use builder::interface::*;
 pub use builder::library::*;
 pub use builder::structs::*;
+pub use builder::unions::*;
 pub use class::*;
 pub use collection::*;
 pub use constants::*;
@@ -17,11 +19,14 @@ pub use doc::*;
 pub use enum_type::*;
 pub use error_type::*;
 pub use errors::*;
+pub use flags::*;
 pub use function::*;
 pub use handle::*;
 pub use interface::*;
 pub use iterator::*;
 pub use library::*;
+pub use list::*;
+pub use map::*;
 pub use name::*;
 pub use return_type::*;
 pub use structs::callback_argument_struct::*;
@@ -30,6 +35,7 @@ pub use structs::function_argument_struct::*;
 pub use structs::function_return_struct::*;
 pub use structs::universal_struct::*;
 pub use types::*;
+pub use union_type::*;
 
 mod back_traced;
 mod builder {
@@ -37,10 +43,12 @@ mod builder {
     pub(crate) mod constants;
     pub(crate) mod enums;
     pub(crate) mod error_type;
+    pub(crate) mod flags;
     pub(crate) mod function;
     pub(crate) mod interface;
     pub(crate) mod library;
     pub(crate) mod structs;
+    pub(crate) mod unions;
 }
 mod class;
 mod collection;
@@ -48,11 +56,14 @@ mod constants;
 mod enum_type;
 mod error_type;
 mod errors;
+mod flags;
 mod function;
 mod handle;
 mod interface;
 mod iterator;
 mod library;
+mod list;
+mod map;
 mod name;
 mod return_type;
 mod structs {
@@ -64,3 +75,4 @@ mod structs {
 }
 mod doc;
 mod types;
+mod union_type;
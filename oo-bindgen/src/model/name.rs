@@ -4,6 +4,7 @@ use std::rc::Rc;
 
 use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToUpperCamelCase};
 use lazy_static::lazy_static;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
 /// Names in oo_bindgen are subset of allowed C-style identifiers. They are
@@ -49,6 +50,21 @@ impl Display for Name {
     }
 }
 
+/// Names are serialized as plain strings
+impl Serialize for Name {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_ref())
+    }
+}
+
+/// Deserialized names are validated with the same rules as `Name::create`
+impl<'de> Deserialize<'de> for Name {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Name::create(value).map_err(serde::de::Error::custom)
+    }
+}
+
 impl PartialEq<&str> for Name {
     fn eq(&self, other: &&str) -> bool {
         &self.as_ref() == other
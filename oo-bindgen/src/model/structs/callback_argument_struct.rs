@@ -6,6 +6,8 @@ use crate::model::*;
 pub enum CallbackArgStructField {
     Basic(BasicType),
     String(StringType),
+    Bytes(BytesType),
+    Optional(Optional<BasicType>),
     Iterator(AbstractIteratorHandle),
     Struct(UniversalOr<CallbackArgStructField>),
 }
@@ -18,6 +20,14 @@ impl StructFieldType for CallbackArgStructField {
     ) -> StructType<Unvalidated> {
         StructType::CallbackArg(v)
     }
+
+    fn can_be_invalid(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.can_be_invalid(),
+            Self::Optional(x) => x.can_be_invalid(),
+            Self::String(_) | Self::Bytes(_) | Self::Iterator(_) | Self::Struct(_) => false,
+        }
+    }
 }
 
 impl InitializerValidator for CallbackArgStructField {
@@ -33,6 +43,8 @@ impl InitializerValidator for CallbackArgStructField {
                 UniversalOr::Universal(x) => x.validate_default_value(value),
             },
             CallbackArgStructField::String(x) => x.validate_default_value(value),
+            CallbackArgStructField::Bytes(x) => x.validate_default_value(value),
+            CallbackArgStructField::Optional(x) => x.validate_default_value(value),
         }
     }
 }
@@ -43,6 +55,12 @@ impl From<Primitive> for CallbackArgStructField {
     }
 }
 
+impl From<Optional<BasicType>> for CallbackArgStructField {
+    fn from(x: Optional<BasicType>) -> Self {
+        Self::Optional(x)
+    }
+}
+
 impl From<BasicType> for CallbackArgStructField {
     fn from(x: BasicType) -> Self {
         CallbackArgStructField::Basic(x)
@@ -55,18 +73,36 @@ impl From<StringType> for CallbackArgStructField {
     }
 }
 
+impl From<BytesType> for CallbackArgStructField {
+    fn from(x: BytesType) -> Self {
+        Self::Bytes(x)
+    }
+}
+
 impl From<Handle<Enum<Unvalidated>>> for CallbackArgStructField {
     fn from(x: Handle<Enum<Unvalidated>>) -> Self {
         CallbackArgStructField::Basic(BasicType::Enum(x))
     }
 }
 
+impl From<FlagsHandle> for CallbackArgStructField {
+    fn from(x: FlagsHandle) -> Self {
+        CallbackArgStructField::Basic(BasicType::Flags(x))
+    }
+}
+
 impl From<DurationType> for CallbackArgStructField {
     fn from(x: DurationType) -> Self {
         CallbackArgStructField::Basic(BasicType::Duration(x))
     }
 }
 
+impl From<TimestampType> for CallbackArgStructField {
+    fn from(x: TimestampType) -> Self {
+        CallbackArgStructField::Basic(BasicType::Timestamp(x))
+    }
+}
+
 impl From<AbstractIteratorHandle> for CallbackArgStructField {
     fn from(x: AbstractIteratorHandle) -> Self {
         CallbackArgStructField::Iterator(x)
@@ -4,11 +4,14 @@ use std::marker::PhantomData;
 use std::rc::Rc;
 use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
+
 use crate::model::*;
 
 /// A numeric value
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum NumberValue {
     U8(u8),
     S8(i8),
@@ -136,10 +139,7 @@ impl std::fmt::Display for ValidatedDefaultValue {
                 write!(f, "{x}")
             }
             Self::Number(x) => write!(f, "{x}"),
-            Self::Duration(t, x) => match t {
-                DurationType::Milliseconds => write!(f, "{} milliseconds", x.as_millis()),
-                DurationType::Seconds => write!(f, "{} seconds", x.as_secs()),
-            },
+            Self::Duration(t, x) => write!(f, "{} {}", t.get_value_string(*x), t.unit()),
             Self::Enum(handle, x) => {
                 write!(f, "{}::{}", handle.name, x)
             }
@@ -269,6 +269,15 @@ impl InitializerValidator for CollectionHandle {
     }
 }
 
+impl InitializerValidator for MapHandle {
+    fn validate_default_value(
+        &self,
+        value: &InitializerDefault,
+    ) -> BindResult<ValidatedDefaultValue> {
+        Self::bad_initializer_value("MapHandle".to_string(), value)
+    }
+}
+
 impl InitializerValidator for StringType {
     fn validate_default_value(
         &self,
@@ -281,9 +290,39 @@ impl InitializerValidator for StringType {
     }
 }
 
+impl InitializerValidator for Optional<BasicType> {
+    fn validate_default_value(
+        &self,
+        value: &InitializerDefault,
+    ) -> BindResult<ValidatedDefaultValue> {
+        Self::bad_initializer_value("Optional".to_string(), value)
+    }
+}
+
+impl InitializerValidator for BytesType {
+    fn validate_default_value(
+        &self,
+        value: &InitializerDefault,
+    ) -> BindResult<ValidatedDefaultValue> {
+        Self::bad_initializer_value("Bytes".to_string(), value)
+    }
+}
+
+impl InitializerValidator for ListHandle {
+    fn validate_default_value(
+        &self,
+        value: &InitializerDefault,
+    ) -> BindResult<ValidatedDefaultValue> {
+        Self::bad_initializer_value(format!("List({})", self.name), value)
+    }
+}
+
 pub trait StructFieldType: Clone + Sized + InitializerValidator {
     /// convert a structure to a StructType
     fn create_struct_type(v: Handle<Struct<Self, Unvalidated>>) -> StructType<Unvalidated>;
+
+    /// true if the field may hold a value that the Rust FFI can't convert
+    fn can_be_invalid(&self) -> bool;
 }
 
 #[derive(Debug)]
@@ -443,7 +482,8 @@ pub(crate) struct InitializedValue {
 }
 
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum InitializerType {
     /// Normal initializers map to actual language constructors
     /// A name is still required as some languages (e.g. C) don't support
@@ -706,6 +746,16 @@ where
         }
     }
 
+    /// true if the struct may be converted to a native struct that contains a list
+    pub(crate) fn contains_list(&self) -> bool {
+        match self {
+            StructType::FunctionArg(x) => x.contains_list(),
+            StructType::FunctionReturn(_) => false,
+            StructType::CallbackArg(_) => false,
+            StructType::Universal(x) => x.contains_list(),
+        }
+    }
+
     pub fn name(&self) -> &Name {
         match self {
             StructType::FunctionArg(x) => x.name(),
@@ -6,6 +6,9 @@ use crate::model::*;
 pub enum FunctionArgStructField {
     Basic(BasicType),
     String(StringType),
+    Bytes(BytesType),
+    Optional(Optional<BasicType>),
+    List(ListHandle),
     Interface(AsynchronousInterface),
     Struct(UniversalOr<FunctionArgStructField>),
 }
@@ -18,6 +21,33 @@ impl StructFieldType for FunctionArgStructField {
     ) -> StructType<Unvalidated> {
         StructType::FunctionArg(v)
     }
+
+    fn can_be_invalid(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.can_be_invalid(),
+            Self::Optional(x) => x.can_be_invalid(),
+            Self::String(_)
+            | Self::Bytes(_)
+            | Self::List(_)
+            | Self::Interface(_)
+            | Self::Struct(_) => false,
+        }
+    }
+}
+
+impl<D> Struct<FunctionArgStructField, D>
+where
+    D: DocReference,
+{
+    /// true if any of the fields, including those of nested structs, is a list
+    pub(crate) fn contains_list(&self) -> bool {
+        self.fields.iter().any(|f| match &f.field_type {
+            FunctionArgStructField::List(_) => true,
+            FunctionArgStructField::Struct(UniversalOr::Specific(x)) => x.contains_list(),
+            FunctionArgStructField::Struct(UniversalOr::Universal(x)) => x.contains_list(),
+            _ => false,
+        })
+    }
 }
 
 impl InitializerValidator for FunctionArgStructField {
@@ -28,6 +58,9 @@ impl InitializerValidator for FunctionArgStructField {
         match self {
             FunctionArgStructField::Basic(x) => x.validate_default_value(value),
             FunctionArgStructField::String(x) => x.validate_default_value(value),
+            FunctionArgStructField::Bytes(x) => x.validate_default_value(value),
+            FunctionArgStructField::Optional(x) => x.validate_default_value(value),
+            FunctionArgStructField::List(x) => x.validate_default_value(value),
             FunctionArgStructField::Interface(x) => x.inner.validate_default_value(value),
             FunctionArgStructField::Struct(x) => x.validate_default_value(value),
         }
@@ -40,6 +73,12 @@ impl From<Primitive> for FunctionArgStructField {
     }
 }
 
+impl From<Optional<BasicType>> for FunctionArgStructField {
+    fn from(x: Optional<BasicType>) -> Self {
+        Self::Optional(x)
+    }
+}
+
 impl From<BasicType> for FunctionArgStructField {
     fn from(x: BasicType) -> Self {
         FunctionArgStructField::Basic(x)
@@ -52,6 +91,12 @@ impl From<StringType> for FunctionArgStructField {
     }
 }
 
+impl From<BytesType> for FunctionArgStructField {
+    fn from(x: BytesType) -> Self {
+        FunctionArgStructField::Bytes(x)
+    }
+}
+
 impl From<FunctionArgStructHandle> for FunctionArgStructField {
     fn from(x: FunctionArgStructHandle) -> Self {
         FunctionArgStructField::Struct(x.into())
@@ -76,8 +121,26 @@ impl From<DurationType> for FunctionArgStructField {
     }
 }
 
+impl From<TimestampType> for FunctionArgStructField {
+    fn from(x: TimestampType) -> Self {
+        FunctionArgStructField::Basic(BasicType::Timestamp(x))
+    }
+}
+
 impl From<Handle<Enum<Unvalidated>>> for FunctionArgStructField {
     fn from(x: Handle<Enum<Unvalidated>>) -> Self {
         BasicType::Enum(x).into()
     }
 }
+
+impl From<FlagsHandle> for FunctionArgStructField {
+    fn from(x: FlagsHandle) -> Self {
+        BasicType::Flags(x).into()
+    }
+}
+
+impl From<ListHandle> for FunctionArgStructField {
+    fn from(value: ListHandle) -> Self {
+        Self::List(value)
+    }
+}
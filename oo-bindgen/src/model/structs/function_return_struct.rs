@@ -17,6 +17,13 @@ impl StructFieldType for FunctionReturnStructField {
     fn create_struct_type(v: Handle<Struct<Self, Unvalidated>>) -> StructType<Unvalidated> {
         StructType::FunctionReturn(v)
     }
+
+    fn can_be_invalid(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.can_be_invalid(),
+            Self::ClassRef(_) | Self::Iterator(_) | Self::Struct(_) => false,
+        }
+    }
 }
 
 impl InitializerValidator for FunctionReturnStructField {
@@ -6,6 +6,9 @@ use crate::model::*;
 pub enum UniversalStructField {
     Basic(BasicType),
     String(StringType),
+    Bytes(BytesType),
+    Optional(Optional<BasicType>),
+    List(ListHandle),
     Struct(UniversalStructHandle),
 }
 
@@ -17,6 +20,28 @@ impl StructFieldType for UniversalStructField {
     ) -> StructType<Unvalidated> {
         StructType::Universal(v)
     }
+
+    fn can_be_invalid(&self) -> bool {
+        match self {
+            Self::Basic(x) => x.can_be_invalid(),
+            Self::Optional(x) => x.can_be_invalid(),
+            Self::String(_) | Self::Bytes(_) | Self::List(_) | Self::Struct(_) => false,
+        }
+    }
+}
+
+impl<D> Struct<UniversalStructField, D>
+where
+    D: DocReference,
+{
+    /// true if any of the fields, including those of nested structs, is a list
+    pub(crate) fn contains_list(&self) -> bool {
+        self.fields.iter().any(|f| match &f.field_type {
+            UniversalStructField::List(_) => true,
+            UniversalStructField::Struct(x) => x.contains_list(),
+            _ => false,
+        })
+    }
 }
 
 impl InitializerValidator for UniversalStructField {
@@ -28,6 +53,9 @@ impl InitializerValidator for UniversalStructField {
             UniversalStructField::Basic(x) => x.validate_default_value(value),
             UniversalStructField::Struct(x) => x.validate_default_value(value),
             UniversalStructField::String(x) => x.validate_default_value(value),
+            UniversalStructField::Bytes(x) => x.validate_default_value(value),
+            UniversalStructField::Optional(x) => x.validate_default_value(value),
+            UniversalStructField::List(x) => x.validate_default_value(value),
         }
     }
 }
@@ -38,6 +66,12 @@ impl From<Primitive> for UniversalStructField {
     }
 }
 
+impl From<Optional<BasicType>> for UniversalStructField {
+    fn from(x: Optional<BasicType>) -> Self {
+        Self::Optional(x)
+    }
+}
+
 impl From<BasicType> for UniversalStructField {
     fn from(x: BasicType) -> Self {
         UniversalStructField::Basic(x)
@@ -50,12 +84,24 @@ impl From<DurationType> for UniversalStructField {
     }
 }
 
+impl From<TimestampType> for UniversalStructField {
+    fn from(x: TimestampType) -> Self {
+        BasicType::Timestamp(x).into()
+    }
+}
+
 impl From<Handle<Enum<Unvalidated>>> for UniversalStructField {
     fn from(x: Handle<Enum<Unvalidated>>) -> Self {
         Self::Basic(BasicType::Enum(x))
     }
 }
 
+impl From<FlagsHandle> for UniversalStructField {
+    fn from(x: FlagsHandle) -> Self {
+        Self::Basic(BasicType::Flags(x))
+    }
+}
+
 impl From<UniversalStructHandle> for UniversalStructField {
     fn from(x: UniversalStructHandle) -> Self {
         UniversalStructField::Struct(x)
@@ -67,3 +113,15 @@ impl From<StringType> for UniversalStructField {
         Self::String(value)
     }
 }
+
+impl From<BytesType> for UniversalStructField {
+    fn from(value: BytesType) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<ListHandle> for UniversalStructField {
+    fn from(value: ListHandle) -> Self {
+        Self::List(value)
+    }
+}
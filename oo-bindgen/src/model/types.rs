@@ -1,29 +1,83 @@
 use std::time::Duration;
 
+use serde::{Deserialize, Serialize};
+
 use crate::model::*;
 
 /// Marker class used to denote the String type with conversions to more specialized types
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct StringType;
 
+/// Marker class used to denote a borrowed sequence of bytes
+///
+/// In the C API this is a pointer and a length, in the other languages it maps to the
+/// native byte array type
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BytesType;
+
+/// Wrapper used to denote a value that may be absent
+///
+/// In the C API this is a struct with a `has_value` flag and a `value`, in the other
+/// languages it maps to the nullable or optional form of the inner type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Optional<T> {
+    pub(crate) inner: T,
+}
+
+impl<T> Optional<T> {
+    pub fn new<X: Into<T>>(inner: X) -> Self {
+        Self {
+            inner: inner.into(),
+        }
+    }
+}
+
+impl Optional<BasicType> {
+    /// true if the Rust FFI may receive a value that it can't convert
+    pub(crate) fn can_be_invalid(&self) -> bool {
+        self.inner.can_be_invalid()
+    }
+}
+
 /// Durations may be represented in multiple ways in the underlying C API
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DurationType {
     /// Duration is represented as a count of milliseconds in a u64 value
     Milliseconds,
     /// Duration is represented as a count of seconds in a u64 value
     Seconds,
+    /// Duration is represented as a count of microseconds in a u64 value
+    Microseconds,
+    /// Duration is represented as a count of nanoseconds in a u64 value
+    Nanoseconds,
+    /// Duration is represented as a signed count of milliseconds in an i64 value
+    ///
+    /// Rust has no signed duration type, so the Rust API receives the count as an i64
+    SignedMilliseconds,
+    /// Duration is represented as a fractional count of seconds in an f64 value
+    ///
+    /// Values received by Rust are clamped: negative and NaN values become a zero duration
+    /// and values too large for a Rust duration become the maximum duration
+    FloatSeconds,
 }
 
 /// Same as DurationType but with an associated value
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DurationValue {
     /// Duration is represented as a count of milliseconds in a u64 value
     Milliseconds(u64),
     /// Duration is represented as a count of seconds in a u64 value
     Seconds(u64),
+    /// Duration is represented as a count of microseconds in a u64 value
+    Microseconds(u64),
+    /// Duration is represented as a count of nanoseconds in a u64 value
+    Nanoseconds(u64),
+    /// Duration is represented as a signed count of milliseconds in an i64 value
+    SignedMilliseconds(i64),
 }
 
 impl From<DurationValue> for DurationType {
@@ -31,6 +85,9 @@ impl From<DurationValue> for DurationType {
         match x {
             DurationValue::Milliseconds(_) => DurationType::Milliseconds,
             DurationValue::Seconds(_) => DurationType::Seconds,
+            DurationValue::Microseconds(_) => DurationType::Microseconds,
+            DurationValue::Nanoseconds(_) => DurationType::Nanoseconds,
+            DurationValue::SignedMilliseconds(_) => DurationType::SignedMilliseconds,
         }
     }
 }
@@ -40,19 +97,97 @@ impl DurationType {
         match self {
             DurationType::Milliseconds => "milliseconds",
             DurationType::Seconds => "seconds",
+            DurationType::Microseconds => "microseconds",
+            DurationType::Nanoseconds => "nanoseconds",
+            DurationType::SignedMilliseconds => "milliseconds",
+            DurationType::FloatSeconds => "seconds",
+        }
+    }
+
+    /// how values received from the C API are clamped, if at all
+    pub(crate) fn clamping(&self) -> Option<&'static str> {
+        match self {
+            DurationType::FloatSeconds => Some("Negative and NaN values are treated as a zero duration and values that are too large as the maximum duration"),
+            DurationType::Milliseconds
+            | DurationType::Seconds
+            | DurationType::Microseconds
+            | DurationType::Nanoseconds
+            | DurationType::SignedMilliseconds => None,
+        }
+    }
+
+    /// primitive type used to represent the duration in the C API
+    pub(crate) fn primitive(&self) -> Primitive {
+        match self {
+            DurationType::Milliseconds
+            | DurationType::Seconds
+            | DurationType::Microseconds
+            | DurationType::Nanoseconds => Primitive::U64,
+            DurationType::SignedMilliseconds => Primitive::S64,
+            DurationType::FloatSeconds => Primitive::Double,
         }
     }
 
+    /// abbreviated form of a value used in documentation, e.g. "5ms"
+    pub(crate) fn get_value_abbreviation(&self, duration: Duration) -> String {
+        let suffix = match self {
+            DurationType::Milliseconds | DurationType::SignedMilliseconds => "ms",
+            DurationType::Seconds | DurationType::FloatSeconds => "s",
+            DurationType::Microseconds => "us",
+            DurationType::Nanoseconds => "ns",
+        };
+        format!("{}{suffix}", self.get_value_string(duration))
+    }
+
     pub fn get_value_string(&self, duration: Duration) -> String {
         match self {
-            DurationType::Milliseconds => {
+            DurationType::Milliseconds | DurationType::SignedMilliseconds => {
                 format!("{}", duration.as_millis())
             }
             DurationType::Seconds => {
                 format!("{}", duration.as_secs())
             }
+            DurationType::Microseconds => {
+                format!("{}", duration.as_micros())
+            }
+            DurationType::Nanoseconds => {
+                format!("{}", duration.as_nanos())
+            }
+            DurationType::FloatSeconds => {
+                format!("{:?}", duration.as_secs_f64())
+            }
+        }
+    }
+}
+
+/// Absolute points in time may be represented in multiple ways in the underlying C API
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimestampType {
+    /// Timestamp is represented as a signed count of milliseconds since the UNIX epoch (UTC) in an i64 value
+    Milliseconds,
+}
+
+impl TimestampType {
+    pub fn unit(&self) -> &'static str {
+        match self {
+            TimestampType::Milliseconds => "milliseconds since the UNIX epoch (UTC)",
         }
     }
+
+    /// primitive type used to represent the timestamp in the C API
+    pub(crate) fn primitive(&self) -> Primitive {
+        match self {
+            TimestampType::Milliseconds => Primitive::S64,
+        }
+    }
+}
+
+impl From<TimestampType> for BasicType {
+    fn from(x: TimestampType) -> Self {
+        BasicType::Timestamp(x)
+    }
 }
 
 impl From<DurationType> for BasicType {
@@ -70,6 +205,10 @@ where
     pub(crate) arg_type: T,
     pub(crate) name: Name,
     pub(crate) doc: DocString<D>,
+    /// value used when the caller omits the argument in languages that allow it
+    pub(crate) default: Option<ValidatedDefaultValue>,
+    /// class references are passed to the Rust implementation as `&mut` instead of `&`
+    pub(crate) mutable: bool,
 }
 
 impl<T> Arg<T, Unvalidated>
@@ -81,6 +220,8 @@ where
             arg_type: self.arg_type.clone(),
             name: self.name.clone(),
             doc: self.doc.validate(&self.name, lib)?,
+            default: self.default.clone(),
+            mutable: self.mutable,
         })
     }
 }
@@ -95,13 +236,16 @@ where
             arg_type,
             name,
             doc,
+            default: None,
+            mutable: false,
         }
     }
 }
 
 /// primitive types in most languages
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Primitive {
     Bool,
     U8,
@@ -118,7 +262,8 @@ pub enum Primitive {
 
 /// same as primitive, but with a value
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum PrimitiveValue {
     Bool(bool),
     U8(u8),
@@ -158,7 +303,9 @@ impl From<PrimitiveValue> for Primitive {
 pub enum BasicType {
     Primitive(Primitive),
     Duration(DurationType),
+    Timestamp(TimestampType),
     Enum(Handle<Enum<Unvalidated>>),
+    Flags(Handle<Flags<Unvalidated>>),
 }
 
 impl From<Primitive> for BasicType {
@@ -298,13 +445,24 @@ impl InitializerValidator for BasicType {
                 }
                 .into()),
             },
+            // timestamps and flags don't have default values
+            BasicType::Timestamp(_) => Err(BindingErrorVariant::StructInitializerBadValueForType {
+                field_type: "Timestamp".to_string(),
+                value: value.clone(),
+            }
+            .into()),
+            BasicType::Flags(_) => Err(BindingErrorVariant::StructInitializerBadValueForType {
+                field_type: "Flags".to_string(),
+                value: value.clone(),
+            }
+            .into()),
         }
     }
 }
 
 impl Primitive {
     /// get the string representation of the type used in the Rust for the C FFI
-    pub(crate) fn get_c_rust_type(&self) -> &str {
+    pub(crate) fn get_c_rust_type(&self) -> &'static str {
         match self {
             Self::Bool => "bool",
             Self::U8 => "u8",
@@ -322,25 +480,46 @@ impl Primitive {
 }
 
 impl BasicType {
+    /// true if the Rust FFI may receive a value of this type that it can't convert, i.e. an
+    /// integer that isn't a variant of an enum using [UnknownValuePolicy::Error]
+    pub(crate) fn can_be_invalid(&self) -> bool {
+        match self {
+            Self::Enum(x) => x.unknown_value == UnknownValuePolicy::Error,
+            Self::Primitive(_) | Self::Duration(_) | Self::Timestamp(_) | Self::Flags(_) => false,
+        }
+    }
+
+    /// unit of the underlying integer or float value for durations and timestamps
+    pub(crate) fn unit(&self) -> Option<&'static str> {
+        match self {
+            Self::Duration(x) => Some(x.unit()),
+            Self::Timestamp(x) => Some(x.unit()),
+            Self::Primitive(_) | Self::Enum(_) | Self::Flags(_) => None,
+        }
+    }
+
+    /// how values received from the C API are clamped, if at all
+    pub(crate) fn clamping(&self) -> Option<&'static str> {
+        match self {
+            Self::Duration(x) => x.clamping(),
+            Self::Primitive(_) | Self::Enum(_) | Self::Flags(_) | Self::Timestamp(_) => None,
+        }
+    }
+
     /// get the string representation of the type used in the Rust for the C FFI
     pub(crate) fn get_c_rust_type(&self) -> &str {
         match self {
             Self::Primitive(x) => x.get_c_rust_type(),
-            Self::Duration(_) => "u64",
+            Self::Duration(x) => x.primitive().get_c_rust_type(),
+            Self::Timestamp(x) => x.primitive().get_c_rust_type(),
             Self::Enum(_) => "std::os::raw::c_int",
+            Self::Flags(x) => Primitive::from(x.flags_type).get_c_rust_type(),
         }
     }
 }
 
 pub(crate) trait TypeExtractor {
     fn get_basic_type(&self) -> Option<&BasicType>;
-
-    fn get_duration_type(&self) -> Option<DurationType> {
-        match self.get_basic_type() {
-            Some(BasicType::Duration(x)) => Some(*x),
-            _ => None,
-        }
-    }
 }
 
 impl TypeExtractor for FunctionArgStructField {
@@ -378,3 +557,30 @@ impl TypeExtractor for UniversalStructField {
         }
     }
 }
+
+impl TypeExtractor for ListItemType {
+    fn get_basic_type(&self) -> Option<&BasicType> {
+        match self {
+            Self::Basic(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl TypeExtractor for FunctionArgument {
+    fn get_basic_type(&self) -> Option<&BasicType> {
+        match self {
+            Self::Basic(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl TypeExtractor for FunctionReturnValue {
+    fn get_basic_type(&self) -> Option<&BasicType> {
+        match self {
+            Self::Basic(x) => Some(x),
+            _ => None,
+        }
+    }
+}
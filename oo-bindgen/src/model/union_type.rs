@@ -0,0 +1,67 @@
+use std::rc::Rc;
+
+use crate::model::*;
+
+/// Variant of a tagged union that carries a universal struct as its payload
+#[derive(Debug, Clone)]
+pub(crate) struct UnionVariant<T>
+where
+    T: DocReference,
+{
+    pub(crate) name: Name,
+    pub(crate) value: UniversalStructHandle,
+    pub(crate) doc: Doc<T>,
+}
+
+impl UnionVariant<Unvalidated> {
+    pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<UnionVariant<Validated>> {
+        Ok(UnionVariant {
+            name: self.name.clone(),
+            value: self.value.clone(),
+            doc: self.doc.validate(&self.name, lib)?,
+        })
+    }
+}
+
+pub type UnionHandle = Handle<Union<Unvalidated>>;
+
+/// Tagged union (data-carrying enum) where exactly one variant is active at a time
+#[derive(Debug)]
+pub struct Union<T>
+where
+    T: DocReference,
+{
+    pub(crate) name: Name,
+    pub(crate) settings: Rc<LibrarySettings>,
+    pub(crate) variants: Vec<UnionVariant<T>>,
+    pub(crate) doc: Doc<T>,
+}
+
+impl Union<Unvalidated> {
+    pub(crate) fn validate(&self, lib: &LibraryFields) -> BindResult<Handle<Union<Validated>>> {
+        let variants: BindResult<Vec<UnionVariant<Validated>>> =
+            self.variants.iter().map(|x| x.validate(lib)).collect();
+
+        Ok(Handle::new(Union {
+            name: self.name.clone(),
+            settings: self.settings.clone(),
+            variants: variants?,
+            doc: self.doc.validate(&self.name, lib)?,
+        }))
+    }
+}
+
+impl<T> Union<T>
+where
+    T: DocReference,
+{
+    /// name of the enum used to tag the active variant
+    pub(crate) fn tag_name(&self) -> Name {
+        self.name.append(&Name::create("tag").unwrap())
+    }
+
+    /// true if the payload of any variant contains a list
+    pub(crate) fn contains_list(&self) -> bool {
+        self.variants.iter().any(|x| x.value.contains_list())
+    }
+}
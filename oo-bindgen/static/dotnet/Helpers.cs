@@ -33,6 +33,171 @@ namespace Helpers
         }
     }
 
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct ByteSlice
+    {
+        internal IntPtr value;
+        internal UIntPtr len;
+    }
+
+    internal static class RustBytes
+    {
+        internal static ByteSlice ToNative(byte[] value)
+        {
+            var slice = new ByteSlice();
+            slice.value = Marshal.AllocHGlobal(value.Length);
+            slice.len = (UIntPtr)value.Length;
+            Marshal.Copy(value, 0, slice.value, value.Length);
+            return slice;
+        }
+
+        internal static void Destroy(ByteSlice value)
+        {
+            Marshal.FreeHGlobal(value.value);
+        }
+
+        internal static byte[] FromNative(ByteSlice value)
+        {
+            var length = (int)value.len;
+            byte[] buffer = new byte[length];
+            if (length > 0)
+            {
+                Marshal.Copy(value.value, buffer, 0, length);
+            }
+            return buffer;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalByte
+    {
+        internal byte hasValue;
+        internal byte value;
+
+        internal OptionalByte(byte value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalSByte
+    {
+        internal byte hasValue;
+        internal sbyte value;
+
+        internal OptionalSByte(sbyte value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalUShort
+    {
+        internal byte hasValue;
+        internal ushort value;
+
+        internal OptionalUShort(ushort value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalShort
+    {
+        internal byte hasValue;
+        internal short value;
+
+        internal OptionalShort(short value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalUInt
+    {
+        internal byte hasValue;
+        internal uint value;
+
+        internal OptionalUInt(uint value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalInt
+    {
+        internal byte hasValue;
+        internal int value;
+
+        internal OptionalInt(int value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalULong
+    {
+        internal byte hasValue;
+        internal ulong value;
+
+        internal OptionalULong(ulong value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalLong
+    {
+        internal byte hasValue;
+        internal long value;
+
+        internal OptionalLong(long value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalFloat
+    {
+        internal byte hasValue;
+        internal float value;
+
+        internal OptionalFloat(float value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal struct OptionalDouble
+    {
+        internal byte hasValue;
+        internal double value;
+
+        internal OptionalDouble(double value)
+        {
+            this.hasValue = 1;
+            this.value = value;
+        }
+    }
+
     internal static class PrimitivePointer
    {
        internal static bool ReadBool(IntPtr x)
@@ -0,0 +1,157 @@
+using System;
+using System.Collections.Generic;
+using System.Threading;
+using System.Threading.Tasks;
+
+namespace Helpers
+{
+    /// <summary>
+    /// Asynchronous sequence fed by the callbacks of a stream interface
+    /// </summary>
+    /// <remarks>
+    /// Items are queued until they are consumed and a failure of the stream is thrown
+    /// once the items received before it have been consumed. The sequence can only be enumerated once.
+    /// </remarks>
+    internal class StreamChannel<T> : IAsyncEnumerable<T>
+    {
+        private readonly object mutex = new object();
+        private readonly Queue<T> items = new Queue<T>();
+        private bool complete = false;
+        private bool disposed = false;
+        private bool enumerated = false;
+        private Exception error = null;
+        // completed when the state changes while the enumerator is waiting
+        private TaskCompletionSource<bool> signal = null;
+
+        internal void Add(T item)
+        {
+            Notify(() =>
+            {
+                if (!complete && error == null && !disposed)
+                {
+                    items.Enqueue(item);
+                }
+            });
+        }
+
+        internal void Complete()
+        {
+            Notify(() =>
+            {
+                if (error == null)
+                {
+                    complete = true;
+                }
+            });
+        }
+
+        internal void Fail(Exception error)
+        {
+            Notify(() =>
+            {
+                if (!complete && this.error == null)
+                {
+                    this.error = error;
+                }
+            });
+        }
+
+        public IAsyncEnumerator<T> GetAsyncEnumerator(CancellationToken cancellationToken = default)
+        {
+            lock (mutex)
+            {
+                if (enumerated)
+                {
+                    throw new InvalidOperationException("The stream can only be enumerated once");
+                }
+                enumerated = true;
+            }
+            return new Enumerator(this, cancellationToken);
+        }
+
+        private void Notify(Action update)
+        {
+            TaskCompletionSource<bool> waiting;
+            lock (mutex)
+            {
+                update();
+                waiting = signal;
+                signal = null;
+            }
+            // continuations run asynchronously so the native thread is never blocked
+            waiting?.TrySetResult(true);
+        }
+
+        private void Dispose()
+        {
+            lock (mutex)
+            {
+                disposed = true;
+                items.Clear();
+            }
+        }
+
+        private async Task<bool> MoveNext(Enumerator enumerator)
+        {
+            while (true)
+            {
+                enumerator.cancellationToken.ThrowIfCancellationRequested();
+
+                TaskCompletionSource<bool> wait;
+                lock (mutex)
+                {
+                    if (items.Count > 0)
+                    {
+                        enumerator.current = items.Dequeue();
+                        return true;
+                    }
+                    if (error != null)
+                    {
+                        throw error;
+                    }
+                    if (complete)
+                    {
+                        return false;
+                    }
+                    if (signal == null)
+                    {
+                        signal = new TaskCompletionSource<bool>(TaskCreationOptions.RunContinuationsAsynchronously);
+                    }
+                    wait = signal;
+                }
+
+                // cancellation wakes the wait up, the registration is released once the wait completes
+                using (enumerator.cancellationToken.Register(() => wait.TrySetResult(true)))
+                {
+                    await wait.Task.ConfigureAwait(false);
+                }
+            }
+        }
+
+        private class Enumerator : IAsyncEnumerator<T>
+        {
+            private readonly StreamChannel<T> channel;
+            internal readonly CancellationToken cancellationToken;
+            internal T current = default(T);
+
+            internal Enumerator(StreamChannel<T> channel, CancellationToken cancellationToken)
+            {
+                this.channel = channel;
+                this.cancellationToken = cancellationToken;
+            }
+
+            public T Current => current;
+
+            public ValueTask<bool> MoveNextAsync()
+            {
+                return new ValueTask<bool>(channel.MoveNext(this));
+            }
+
+            public ValueTask DisposeAsync()
+            {
+                channel.Dispose();
+                return default(ValueTask);
+            }
+        }
+    }
+}
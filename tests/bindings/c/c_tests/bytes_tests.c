@@ -0,0 +1,79 @@
+#include <assert.h>
+#include <stddef.h>
+#include <string.h>
+
+#include "foo.h"
+
+static const uint8_t BYTES[] = { 0x00, 0x01, 0xFE, 0xFF, 0x7F };
+
+typedef struct data {
+    size_t on_bytes_len;
+    bool on_bytes_called;
+} data_t;
+
+static void on_bytes(oo_bindgen_byte_slice_t value, void* context)
+{
+    data_t* data = (data_t*)context;
+    assert(memcmp(value.value, BYTES, value.len) == 0);
+    data->on_bytes_len = value.len;
+    data->on_bytes_called = true;
+}
+
+static uint32_t on_payload(foo_byte_payload_t value, void* context)
+{
+    uint32_t sum = 0;
+    for (size_t i = 0; i < value.data.len; ++i) {
+        sum += value.data.value[i];
+    }
+    return sum + value.id;
+}
+
+static void test_byte_echo()
+{
+    foo_byte_class_t* byte_class = foo_byte_class_create();
+
+    oo_bindgen_byte_slice_t input = { BYTES, sizeof(BYTES) };
+    oo_bindgen_byte_slice_t output = { NULL, 0 };
+    assert(foo_byte_class_echo(byte_class, input, &output) == FOO_BYTE_ERROR_OK);
+    assert(output.len == sizeof(BYTES));
+    assert(memcmp(output.value, BYTES, sizeof(BYTES)) == 0);
+    assert(foo_byte_length(input) == sizeof(BYTES));
+
+    oo_bindgen_byte_slice_t empty = { NULL, 0 };
+    assert(foo_byte_class_echo(byte_class, empty, &output) == FOO_BYTE_ERROR_OK);
+    assert(output.len == 0);
+    assert(foo_byte_length(empty) == 0);
+
+    // a null instance is reported instead of returning a slice
+    assert(foo_byte_class_echo(NULL, input, &output) == FOO_BYTE_ERROR_NULL_INSTANCE);
+
+    foo_byte_class_destroy(byte_class);
+}
+
+static void test_byte_receiver()
+{
+    data_t data = {
+        .on_bytes_len = 0,
+        .on_bytes_called = false,
+    };
+
+    foo_byte_receiver_t receiver = {
+        .on_bytes = &on_bytes,
+        .on_payload = &on_payload,
+        .ctx = &data,
+    };
+
+    oo_bindgen_byte_slice_t bytes = { BYTES, sizeof(BYTES) };
+    foo_byte_payload_t payload = foo_byte_payload_init(bytes);
+    payload.id = 3;
+
+    assert(foo_invoke_byte_receiver(payload, receiver) == 0x01 + 0xFE + 0xFF + 0x7F + 3);
+    assert(data.on_bytes_called);
+    assert(data.on_bytes_len == sizeof(BYTES));
+}
+
+void bytes_tests()
+{
+    test_byte_echo();
+    test_byte_receiver();
+}
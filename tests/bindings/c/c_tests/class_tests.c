@@ -0,0 +1,33 @@
+#include <assert.h>
+
+#include "foo.h"
+
+static void test_interface_adapter()
+{
+    foo_test_class_t* instance = foo_test_class_create(10);
+    foo_value_adder_t adder = foo_test_class_as_value_adder(instance);
+
+    assert(foo_add_twice(adder, 5) == 20);
+    assert(foo_test_class_get_value(instance) == 20);
+
+    foo_test_class_destroy(instance);
+}
+
+static void test_null_instance()
+{
+    const uint32_t constructed = foo_construction_counter();
+    const uint32_t panics = foo_panic_count();
+
+    assert(foo_test_class_get_value(NULL) == 0);
+    foo_test_class_increment_value(NULL);
+    foo_test_class_destroy(NULL);
+
+    assert(foo_construction_counter() == constructed);
+    assert(foo_panic_count() == panics);
+}
+
+void class_tests()
+{
+    test_interface_adapter();
+    test_null_instance();
+}
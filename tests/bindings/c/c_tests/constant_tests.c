@@ -1,5 +1,6 @@
 #include <assert.h>
 #include <stddef.h>
+#include <string.h>
 
 #include "foo.h"
 
@@ -7,4 +8,23 @@ void constant_tests()
 {
     assert(FOO_SPECIAL_VALUES_ONE == 1);
     assert(FOO_SPECIAL_VALUES_TWO == 2);
+
+    assert(FOO_TYPED_VALUES_ENABLED == true);
+    assert(FOO_TYPED_VALUES_MAX_U8 == UINT8_MAX);
+    assert(FOO_TYPED_VALUES_MIN_S8 == -100);
+    assert(FOO_TYPED_VALUES_MASK_U16 == 10);
+    assert(FOO_TYPED_VALUES_NEGATIVE_S16 == -256);
+    assert(FOO_TYPED_VALUES_MAX_U32 == UINT32_MAX);
+    assert(FOO_TYPED_VALUES_MIN_S32 == -2000000000);
+    assert(FOO_TYPED_VALUES_MAX_U64 == UINT64_MAX);
+    assert(FOO_TYPED_VALUES_MAX_U64_HEX == UINT64_MAX);
+    assert(FOO_TYPED_VALUES_LARGE_S64 == INT64_C(-5000000000));
+    assert(FOO_TYPED_VALUES_HALF == 0.5f);
+    assert(FOO_TYPED_VALUES_PI == 3.25);
+    assert(FOO_TYPED_VALUES_TIMEOUT == 1500);
+    assert(FOO_TYPED_VALUES_PERIOD == 60);
+    assert(FOO_TYPED_VALUES_RESOLUTION == 250);
+    assert(FOO_TYPED_VALUES_SKEW == -200);
+    assert(strcmp(FOO_TYPED_VALUES_GREETING, "Hello \"world\"\n") == 0);
+    assert(FOO_TYPED_VALUES_DEFAULT_PRIORITY == FOO_PRIORITY_HIGH);
 }
@@ -27,8 +27,62 @@ static void test_duration_s()
     assert(result == UINT64_MAX);
 }
 
+static void test_duration_us()
+{
+    assert(foo_duration_us_echo(0) == 0);
+    assert(foo_duration_us_echo(1500) == 1500);
+    assert(foo_duration_us_echo(UINT64_MAX) == UINT64_MAX);
+}
+
+static void test_duration_ns()
+{
+    assert(foo_duration_ns_echo(0) == 0);
+    assert(foo_duration_ns_echo(1500) == 1500);
+    assert(foo_duration_ns_echo(UINT64_MAX) == UINT64_MAX);
+}
+
+static void test_duration_signed_ms()
+{
+    assert(foo_duration_signed_ms_echo(0) == 0);
+    assert(foo_duration_signed_ms_echo(-2000) == -2000);
+    assert(foo_duration_signed_ms_echo(INT64_MIN) == INT64_MIN);
+    assert(foo_duration_signed_ms_echo(INT64_MAX) == INT64_MAX);
+}
+
+static void test_duration_float_s()
+{
+    assert(foo_duration_float_s_echo(0.0) == 0.0);
+    assert(foo_duration_float_s_echo(1.5) == 1.5);
+    // negative and NaN values saturate to zero
+    assert(foo_duration_float_s_echo(-1.0) == 0.0);
+    assert(foo_duration_float_s_echo(NAN) == 0.0);
+    // values that are too large saturate to the maximum duration
+    assert(foo_duration_float_s_echo(INFINITY) > 1.8e19);
+    assert(foo_duration_float_s_echo(1e300) == foo_duration_float_s_echo(INFINITY));
+}
+
+static void test_timestamp()
+{
+    assert(foo_timestamp_echo(0) == 0);
+    assert(foo_timestamp_echo(1700000000123) == 1700000000123);
+    assert(foo_timestamp_echo(-86400000) == -86400000);
+
+    assert(foo_timestamp_offset(1000, 500) == 1500);
+    assert(foo_timestamp_offset(1000, -1500) == -500);
+
+    oo_bindgen_optional_i64_t absent = { .has_value = false };
+    oo_bindgen_optional_i64_t present = { .has_value = true, .value = 42 };
+    assert(foo_timestamp_or_epoch(absent) == 0);
+    assert(foo_timestamp_or_epoch(present) == 42);
+}
+
 void duration_tests()
 {
     test_duration_ms();
     test_duration_s();
+    test_duration_us();
+    test_duration_ns();
+    test_duration_signed_ms();
+    test_duration_float_s();
+    test_timestamp();
 }
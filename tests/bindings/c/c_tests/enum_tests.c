@@ -110,6 +110,24 @@ static void test_enum_single()
     assert(result == 0);
 }
 
+static void test_unknown_values()
+{
+    // clamped to the greatest variant below the value, or the lowest variant
+    assert(foo_enum_disjoint_echo((foo_enum_disjoint_t)3) == FOO_ENUM_DISJOINT_TWO);
+    assert(foo_enum_disjoint_echo((foo_enum_disjoint_t)100) == FOO_ENUM_DISJOINT_TWENTY);
+    assert(foo_enum_disjoint_echo((foo_enum_disjoint_t)0) == FOO_ENUM_DISJOINT_ONE);
+
+    // mapped to a designated variant
+    assert(foo_enum_single_echo((foo_enum_single_t)42) == FOO_ENUM_SINGLE_SINGLE);
+
+    // reported as an error
+    foo_enum_checked_t result = FOO_ENUM_CHECKED_ONE;
+    assert(foo_enum_checked_echo((foo_enum_checked_t)42, &result) == FOO_ENUM_ERROR_BAD_VALUE);
+    assert(result == FOO_ENUM_CHECKED_ONE);
+    assert(foo_enum_checked_echo(FOO_ENUM_CHECKED_THREE, &result) == FOO_ENUM_ERROR_OK);
+    assert(result == FOO_ENUM_CHECKED_THREE);
+}
+
 static void test_enum_to_string()
 {
     assert(strcmp("two", foo_enum_zero_to_five_to_string(FOO_ENUM_ZERO_TO_FIVE_TWO)) == 0);
@@ -124,5 +142,6 @@ void enum_tests()
     test_enum_one_to_six();
     test_enum_disjoint();
     test_enum_single();
+    test_unknown_values();
     test_enum_to_string();
 }
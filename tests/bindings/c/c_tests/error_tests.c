@@ -1,6 +1,7 @@
 #include <assert.h>
 #include <math.h>
 #include <stddef.h>
+#include <string.h>
 
 #include "foo.h"
 
@@ -32,8 +33,48 @@ static void test_allocation_via_out_parameter()
     foo_class_with_password_destroy(instance);
 }
 
+static void test_error_detail()
+{
+    assert(foo_door_unlock(1234) == FOO_LOCK_ERROR_OK);
+
+    assert(foo_door_unlock(0) == FOO_LOCK_ERROR_WRONG_CODE);
+    assert(foo_lock_error_last_message() == NULL);
+    assert(foo_lock_error_last_payload() == NULL);
+
+    assert(foo_door_unlock(42) == FOO_LOCK_ERROR_WRONG_CODE);
+    assert(strcmp(foo_lock_error_last_message(), "42 is not the code") == 0);
+    assert(foo_lock_error_last_payload() == NULL);
+
+    assert(foo_door_unlock(9999) == FOO_LOCK_ERROR_LOCKED_OUT);
+    assert(strcmp(foo_lock_error_last_message(), "too many wrong codes") == 0);
+    const foo_lockout_info_t* info = foo_lock_error_last_payload();
+    assert(info);
+    assert(info->attempts == 3);
+    assert(info->retry_after == 30000);
+}
+
+static void test_panic()
+{
+    const uint32_t panics = foo_panic_count();
+    uint32_t number = 0;
+    assert(foo_panic_with_error(&number) == FOO_MY_ERROR_PANIC);
+    assert(number == 0);
+    assert(foo_panic_without_error(42) == 0);
+    assert(foo_panic_count() == panics + 2);
+}
+
+static void test_null_argument()
+{
+    uint32_t number = 42;
+    assert(foo_class_with_password_get_special_value(NULL, &number) == FOO_MY_ERROR_OK);
+    assert(number == 0);
+}
+
 void error_tests()
 {
     test_integer_out_parameter();
     test_allocation_via_out_parameter();
+    test_error_detail();
+    test_panic();
+    test_null_argument();
 }
@@ -0,0 +1,47 @@
+#include <assert.h>
+
+#include "foo.h"
+
+static void test_flags_values()
+{
+    assert(FOO_PERMISSIONS_READ == 0x01);
+    assert(FOO_PERMISSIONS_WRITE == 0x02);
+    assert(FOO_PERMISSIONS_EXECUTE == 0x04);
+    assert(FOO_PERMISSIONS_STICKY == 0x80);
+}
+
+static void test_flags_echo()
+{
+    foo_permissions_t value = FOO_PERMISSIONS_READ | FOO_PERMISSIONS_STICKY;
+    assert(foo_permissions_echo(value) == value);
+    assert(foo_permissions_echo(0) == 0);
+}
+
+static void test_flags_combine()
+{
+    foo_permissions_t result = foo_permissions_combine(FOO_PERMISSIONS_READ, FOO_PERMISSIONS_WRITE | FOO_PERMISSIONS_EXECUTE);
+    assert(result == (FOO_PERMISSIONS_READ | FOO_PERMISSIONS_WRITE | FOO_PERMISSIONS_EXECUTE));
+}
+
+static void test_optional_flags()
+{
+    oo_bindgen_optional_u8_t absent = { .has_value = false };
+    oo_bindgen_optional_u8_t present = { .has_value = true, .value = FOO_PERMISSIONS_EXECUTE };
+    assert(foo_permissions_or_default(absent, FOO_PERMISSIONS_READ) == FOO_PERMISSIONS_READ);
+    assert(foo_permissions_or_default(present, FOO_PERMISSIONS_READ) == FOO_PERMISSIONS_EXECUTE);
+}
+
+static void test_flags_struct_fields()
+{
+    foo_file_mode_t mode = foo_file_mode_init(FOO_PERMISSIONS_READ | FOO_PERMISSIONS_WRITE, FOO_PERMISSIONS_READ | FOO_PERMISSIONS_EXECUTE);
+    assert(foo_file_mode_common(mode) == FOO_PERMISSIONS_READ);
+}
+
+void flags_tests()
+{
+    test_flags_values();
+    test_flags_echo();
+    test_flags_combine();
+    test_optional_flags();
+    test_flags_struct_fields();
+}
@@ -0,0 +1,49 @@
+#include <assert.h>
+#include <stddef.h>
+
+#include "foo.h"
+
+static void test_list_arguments()
+{
+    foo_waypoint_t waypoints[] = { foo_waypoint_init(1, 2), foo_waypoint_init(-3, 7) };
+    uint16_t readings[] = { 100, 200, 300 };
+
+    foo_waypoint_list_t waypoint_list = { waypoints, 2 };
+    foo_reading_list_t reading_list = { readings, 3 };
+    foo_route_t route = foo_route_init(waypoint_list, reading_list);
+    assert(route.id == 0);
+
+    assert(foo_route_count_waypoints(route) == 2);
+    assert(foo_route_sum_coordinates(route) == 7);
+    assert(foo_route_sum_readings(route) == 600);
+}
+
+static void test_empty_lists()
+{
+    foo_waypoint_list_t waypoint_list = { NULL, 0 };
+    foo_reading_list_t reading_list = { NULL, 0 };
+    foo_route_t route = foo_route_init(waypoint_list, reading_list);
+
+    assert(foo_route_count_waypoints(route) == 0);
+    assert(foo_route_sum_coordinates(route) == 0);
+    assert(foo_route_sum_readings(route) == 0);
+}
+
+static void test_returned_lists()
+{
+    foo_route_t route = foo_get_sample_route();
+
+    assert(route.id == 42);
+    assert(route.waypoints.count == 3);
+    assert(route.waypoints.items[0].x == 1);
+    assert(route.waypoints.items[2].y == 6);
+    assert(route.readings.count == 4);
+    assert(route.readings.items[3] == 40);
+}
+
+void list_tests()
+{
+    test_list_arguments();
+    test_empty_lists();
+    test_returned_lists();
+}
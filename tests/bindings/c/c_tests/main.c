@@ -2,11 +2,19 @@ void version_tests();
 void constant_tests();
 void enum_tests();
 void error_tests();
+void flags_tests();
 void duration_tests();
 void primitive_iterator_tests();
+void owned_iterator_tests();
+void value_iterator_tests();
 void string_tests();
+void bytes_tests();
+void list_tests();
+void optional_tests();
+void union_tests();
 void structure_tests();
 void callback_tests();
+void class_tests();
 void iterator_tests();
 void universal_tests();
 
@@ -16,11 +24,19 @@ int main()
     constant_tests();
     enum_tests();
     error_tests();
+    flags_tests();
     duration_tests();
     primitive_iterator_tests();
+    owned_iterator_tests();
+    value_iterator_tests();
     string_tests();
+    bytes_tests();
+    list_tests();
+    optional_tests();
+    union_tests();
     structure_tests();
     callback_tests();
+    class_tests();
     iterator_tests();
     universal_tests();
 
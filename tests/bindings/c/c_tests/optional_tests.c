@@ -0,0 +1,93 @@
+#include <assert.h>
+#include <stddef.h>
+
+#include "foo.h"
+
+typedef struct data {
+    bool on_number_called;
+    oo_bindgen_optional_u16_t number;
+    uint32_t present_count;
+} data_t;
+
+static void on_number(oo_bindgen_optional_u16_t value, void* context)
+{
+    data_t* data = (data_t*)context;
+    data->on_number_called = true;
+    data->number = value;
+}
+
+static void on_values(foo_optional_values_t values, void* context)
+{
+    data_t* data = (data_t*)context;
+    data->present_count = foo_optional_count_present(values);
+}
+
+static void test_optional_arguments()
+{
+    oo_bindgen_optional_i32_t absent_number = { .has_value = false };
+    oo_bindgen_optional_i32_t present_number = { .has_value = true, .value = -42 };
+    assert(foo_optional_number_or(absent_number, 7) == 7);
+    assert(foo_optional_number_or(present_number, 7) == -42);
+
+    oo_bindgen_optional_u64_t absent_timeout = { .has_value = false };
+    oo_bindgen_optional_u64_t present_timeout = { .has_value = true, .value = 30 };
+    assert(foo_optional_timeout_or(absent_timeout, 5) == 5);
+    assert(foo_optional_timeout_or(present_timeout, 5) == 30);
+
+    foo_optional_optional_level_t absent_level = { .has_value = false };
+    foo_optional_optional_level_t present_level = { .has_value = true, .value = FOO_OPTIONAL_LEVEL_HIGH };
+    assert(foo_optional_level_or(absent_level, FOO_OPTIONAL_LEVEL_LOW) == FOO_OPTIONAL_LEVEL_LOW);
+    assert(foo_optional_level_or(present_level, FOO_OPTIONAL_LEVEL_LOW) == FOO_OPTIONAL_LEVEL_HIGH);
+}
+
+static void test_optional_struct()
+{
+    oo_bindgen_optional_bool_t flag = { .has_value = true, .value = false };
+    oo_bindgen_optional_u16_t number = { .has_value = false };
+    oo_bindgen_optional_f64_t ratio = { .has_value = true, .value = 0.5 };
+    oo_bindgen_optional_u64_t timeout = { .has_value = false };
+    foo_optional_optional_level_t level = { .has_value = true, .value = FOO_OPTIONAL_LEVEL_LOW };
+
+    foo_optional_values_t values = foo_optional_values_init(flag, number, ratio, timeout, level);
+    assert(foo_optional_count_present(values) == 3);
+
+    values.number.has_value = true;
+    values.number.value = 1;
+    values.timeout.has_value = true;
+    values.timeout.value = 1000;
+    assert(foo_optional_count_present(values) == 5);
+}
+
+static void test_optional_receiver()
+{
+    data_t data = {
+        .on_number_called = false,
+        .number = { .has_value = false },
+        .present_count = 0,
+    };
+
+    foo_optional_receiver_t receiver = {
+        .on_number = &on_number,
+        .on_values = &on_values,
+        .ctx = &data,
+    };
+
+    oo_bindgen_optional_bool_t flag = { .has_value = false };
+    oo_bindgen_optional_u16_t number = { .has_value = true, .value = 0xFFFF };
+    oo_bindgen_optional_f64_t ratio = { .has_value = false };
+    oo_bindgen_optional_u64_t timeout = { .has_value = true, .value = 250 };
+    foo_optional_optional_level_t level = { .has_value = false };
+
+    foo_invoke_optional_receiver(foo_optional_values_init(flag, number, ratio, timeout, level), receiver);
+    assert(data.on_number_called);
+    assert(data.number.has_value);
+    assert(data.number.value == 0xFFFF);
+    assert(data.present_count == 2);
+}
+
+void optional_tests()
+{
+    test_optional_arguments();
+    test_optional_struct();
+    test_optional_receiver();
+}
@@ -0,0 +1,46 @@
+#include <assert.h>
+#include <stdlib.h>
+
+#include "foo.h"
+
+static void test_owned_primitive_iterator()
+{
+    foo_number_iterator_t* it = foo_count_up(1, 3);
+    assert(it);
+
+    uint32_t* value = NULL;
+    for (uint32_t i = 1; i <= 3; ++i)
+    {
+        value = foo_number_iterator_next(it);
+        assert(value);
+        assert(*value == i);
+    }
+
+    assert(!foo_number_iterator_next(it));
+
+    foo_number_iterator_destroy(it);
+}
+
+static void test_owned_struct_iterator()
+{
+    foo_character_iterator_t* it = foo_get_characters("abc");
+    assert(it);
+
+    foo_character_value_t* value = NULL;
+    for (char c = 'a'; c <= 'c'; ++c)
+    {
+        value = foo_character_iterator_next(it);
+        assert(value);
+        assert(value->value == c);
+    }
+
+    assert(!foo_character_iterator_next(it));
+
+    foo_character_iterator_destroy(it);
+}
+
+void owned_iterator_tests()
+{
+    test_owned_primitive_iterator();
+    test_owned_struct_iterator();
+}
@@ -0,0 +1,86 @@
+#include <assert.h>
+
+#include "foo.h"
+
+typedef struct data {
+    foo_shape_tag_t tag;
+    double area;
+} data_t;
+
+static void on_shape(foo_shape_t shape, void* context)
+{
+    data_t* data = (data_t*)context;
+    data->tag = shape.tag;
+    assert(foo_shape_area(shape, &data->area) == FOO_SHAPE_ERROR_OK);
+}
+
+static void test_union_arguments()
+{
+    foo_shape_t circle = foo_shape_init_circle(foo_circle_init(2.0));
+    assert(circle.tag == FOO_SHAPE_TAG_CIRCLE);
+    double circle_area = 0.0;
+    assert(foo_shape_area(circle, &circle_area) == FOO_SHAPE_ERROR_OK);
+    assert(circle_area > 12.566 && circle_area < 12.567);
+
+    foo_shape_t rectangle = foo_shape_init_rectangle(foo_rectangle_init(2.0, 3.0));
+    assert(rectangle.tag == FOO_SHAPE_TAG_RECTANGLE);
+    double rectangle_area = 0.0;
+    assert(foo_shape_area(rectangle, &rectangle_area) == FOO_SHAPE_ERROR_OK);
+    assert(rectangle_area == 6.0);
+
+    // square shares the payload type of rectangle but is still a distinct variant
+    foo_shape_t square = foo_shape_init_square(foo_rectangle_init(3.0, 3.0));
+    assert(square.tag == FOO_SHAPE_TAG_SQUARE);
+    double square_area = 0.0;
+    assert(foo_shape_area(square, &square_area) == FOO_SHAPE_ERROR_OK);
+    assert(square_area == 9.0);
+}
+
+static void test_union_return_values()
+{
+    foo_shape_t circle;
+    assert(foo_shape_scale(foo_shape_init_circle(foo_circle_init(2.0)), 2.0, &circle) == FOO_SHAPE_ERROR_OK);
+    assert(circle.tag == FOO_SHAPE_TAG_CIRCLE);
+    assert(circle.value.circle.radius == 4.0);
+
+    foo_shape_t square;
+    assert(foo_shape_scale(foo_shape_init_square(foo_rectangle_init(1.5, 1.5)), 2.0, &square) == FOO_SHAPE_ERROR_OK);
+    assert(square.tag == FOO_SHAPE_TAG_SQUARE);
+    assert(square.value.square.width == 3.0);
+    assert(square.value.square.height == 3.0);
+}
+
+static void test_union_receiver()
+{
+    data_t data = {
+        .tag = FOO_SHAPE_TAG_CIRCLE,
+        .area = 0.0,
+    };
+
+    foo_shape_receiver_t receiver = {
+        .on_shape = &on_shape,
+        .ctx = &data,
+    };
+
+    assert(foo_invoke_shape_receiver(foo_shape_init_rectangle(foo_rectangle_init(4.0, 0.5)), receiver) == FOO_SHAPE_ERROR_OK);
+    assert(data.tag == FOO_SHAPE_TAG_RECTANGLE);
+    assert(data.area == 2.0);
+}
+
+static void test_union_unknown_tag()
+{
+    foo_shape_t shape = foo_shape_init_circle(foo_circle_init(2.0));
+    shape.tag = (foo_shape_tag_t)42;
+
+    double area = -1.0;
+    assert(foo_shape_area(shape, &area) == FOO_SHAPE_ERROR_BAD_SHAPE);
+    assert(area == -1.0);
+}
+
+void union_tests()
+{
+    test_union_arguments();
+    test_union_return_values();
+    test_union_receiver();
+    test_union_unknown_tag();
+}
@@ -0,0 +1,107 @@
+#include <assert.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "foo.h"
+
+static void test_string_iterator()
+{
+    foo_word_iterator_t* it = foo_split_words("hello big  world");
+    assert(it);
+
+    const char* value = foo_word_iterator_next(it);
+    assert(value);
+    assert(strcmp(value, "hello") == 0);
+    value = foo_word_iterator_next(it);
+    assert(value);
+    assert(strcmp(value, "big") == 0);
+    value = foo_word_iterator_next(it);
+    assert(value);
+    assert(strcmp(value, "world") == 0);
+
+    assert(!foo_word_iterator_next(it));
+
+    foo_word_iterator_destroy(it);
+}
+
+static void test_enum_iterator()
+{
+    foo_compass_direction_iterator_t* it = foo_get_directions();
+    assert(it);
+
+    foo_compass_direction_t* value = foo_compass_direction_iterator_next(it);
+    assert(value && *value == FOO_COMPASS_DIRECTION_NORTH);
+    value = foo_compass_direction_iterator_next(it);
+    assert(value && *value == FOO_COMPASS_DIRECTION_EAST);
+    value = foo_compass_direction_iterator_next(it);
+    assert(value && *value == FOO_COMPASS_DIRECTION_SOUTH);
+    value = foo_compass_direction_iterator_next(it);
+    assert(value && *value == FOO_COMPASS_DIRECTION_WEST);
+
+    assert(!foo_compass_direction_iterator_next(it));
+
+    foo_compass_direction_iterator_destroy(it);
+}
+
+static void test_duration_iterator()
+{
+    foo_interval_iterator_t* it = foo_get_intervals(3, 250);
+    assert(it);
+
+    uint64_t* value = NULL;
+    for (uint64_t i = 1; i <= 3; ++i)
+    {
+        value = foo_interval_iterator_next(it);
+        assert(value);
+        assert(*value == i * 250);
+    }
+
+    assert(!foo_interval_iterator_next(it));
+
+    foo_interval_iterator_destroy(it);
+}
+
+static void test_signed_duration_iterator()
+{
+    foo_offset_iterator_t* it = foo_get_offsets(3, 250);
+    assert(it);
+
+    int64_t* value = NULL;
+    for (int64_t i = 1; i <= 3; ++i)
+    {
+        value = foo_offset_iterator_next(it);
+        assert(value);
+        assert(*value == i * -250);
+    }
+
+    assert(!foo_offset_iterator_next(it));
+
+    foo_offset_iterator_destroy(it);
+}
+
+static void test_class_iterator()
+{
+    foo_indexed_item_iterator_t* it = foo_get_indexed_items(3);
+    assert(it);
+
+    foo_indexed_item_t* item = NULL;
+    for (uint32_t i = 0; i < 3; ++i)
+    {
+        item = foo_indexed_item_iterator_next(it);
+        assert(item);
+        assert(foo_indexed_item_get_index(item) == i);
+    }
+
+    assert(!foo_indexed_item_iterator_next(it));
+
+    foo_indexed_item_iterator_destroy(it);
+}
+
+void value_iterator_tests()
+{
+    test_string_iterator();
+    test_enum_iterator();
+    test_duration_iterator();
+    test_signed_duration_iterator();
+    test_class_iterator();
+}
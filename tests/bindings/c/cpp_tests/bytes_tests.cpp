@@ -0,0 +1,48 @@
+#include <cassert>
+#include <numeric>
+
+#include "foo.hpp"
+
+using namespace foo;
+
+static const std::vector<uint8_t> BYTES = { 0x00, 0x01, 0xFE, 0xFF, 0x7F };
+
+class Receiver : public ByteReceiver {
+public:
+    std::vector<uint8_t> received;
+
+    void on_bytes(const std::vector<uint8_t>& value) override {
+        received = value;
+    }
+
+    uint32_t on_payload(const BytePayload& value) override {
+        return std::accumulate(value.data.begin(), value.data.end(), value.id);
+    }
+};
+
+static void test_byte_echo()
+{
+    foo::ByteClass byte_class;
+
+    assert(byte_class.echo(BYTES) == BYTES);
+    assert(byte_class.get_length(BYTES) == BYTES.size());
+
+    assert(byte_class.echo(std::vector<uint8_t>()).empty());
+    assert(byte_class.get_length(std::vector<uint8_t>()) == 0);
+}
+
+static void test_byte_receiver()
+{
+    BytePayload payload(BYTES);
+    payload.id = 3;
+
+    Receiver receiver;
+    assert(ByteReceiverTests::invoke(payload, receiver) == 0x01 + 0xFE + 0xFF + 0x7F + 3);
+    assert(receiver.received == BYTES);
+}
+
+void bytes_tests()
+{
+    test_byte_echo();
+    test_byte_receiver();
+}
@@ -0,0 +1,36 @@
+#include <cassert>
+
+#include "foo.hpp"
+
+static void test_properties()
+{
+    foo::TestClass instance(10);
+
+    assert(instance.step() == 1);
+    assert(instance.increments() == 0);
+
+    instance.set_step(5);
+    instance.increment_value();
+
+    assert(instance.step() == 5);
+    assert(instance.increments() == 1);
+    assert(instance.get_value() == 15);
+}
+
+static void test_default_arguments()
+{
+    foo::TestClass instance(10);
+
+    assert(instance.add_value(5) == 15);
+    assert(instance.add_value(5, 3) == 30);
+
+    assert(foo::TestClass::scale_duration() == std::chrono::milliseconds(500));
+    assert(foo::TestClass::scale_duration(std::chrono::milliseconds(100)) == std::chrono::milliseconds(200));
+    assert(foo::TestClass::scale_duration(std::chrono::milliseconds(100), 3) == std::chrono::milliseconds(300));
+}
+
+void class_tests()
+{
+    test_properties();
+    test_default_arguments();
+}
@@ -1,4 +1,6 @@
 #include <cassert>
+#include <cstring>
+#include <limits>
 
 #include "foo.hpp"
 
@@ -6,4 +8,23 @@ void constant_tests()
 {
     assert(foo::special_values::one == 1);
     assert(foo::special_values::two == 2);
+
+    assert(foo::typed_values::enabled);
+    assert(foo::typed_values::max_u8 == std::numeric_limits<uint8_t>::max());
+    assert(foo::typed_values::min_s8 == -100);
+    assert(foo::typed_values::mask_u16 == 10);
+    assert(foo::typed_values::negative_s16 == -256);
+    assert(foo::typed_values::max_u32 == std::numeric_limits<uint32_t>::max());
+    assert(foo::typed_values::min_s32 == -2000000000);
+    assert(foo::typed_values::max_u64 == std::numeric_limits<uint64_t>::max());
+    assert(foo::typed_values::max_u64_hex == std::numeric_limits<uint64_t>::max());
+    assert(foo::typed_values::large_s64 == -5000000000ll);
+    assert(foo::typed_values::half == 0.5f);
+    assert(foo::typed_values::pi == 3.25);
+    assert(foo::typed_values::timeout == std::chrono::milliseconds(1500));
+    assert(foo::typed_values::period == std::chrono::minutes(1));
+    assert(foo::typed_values::resolution == std::chrono::microseconds(250));
+    assert(foo::typed_values::skew == std::chrono::milliseconds(-200));
+    assert(std::strcmp(foo::typed_values::greeting, "Hello \"world\"\n") == 0);
+    assert(foo::typed_values::default_priority == foo::Priority::high);
 }
@@ -1,4 +1,6 @@
 #include <cassert>
+#include <cmath>
+#include <limits>
 
 #include "foo.hpp"
 
@@ -30,8 +32,60 @@ static void test_duration_seconds()
     test_echo_seconds(UINT64_MAX);
 }
 
+static void test_duration_micro_seconds()
+{
+    const auto input = std::chrono::microseconds(1500);
+    assert(foo::DurationEchoFunctions::microseconds_echo(input) == input);
+    assert(foo::DurationEchoFunctions::microseconds_echo(std::chrono::microseconds(0)) == std::chrono::microseconds(0));
+}
+
+static void test_duration_nano_seconds()
+{
+    const auto input = std::chrono::nanoseconds(1500);
+    assert(foo::DurationEchoFunctions::nanoseconds_echo(input) == input);
+    assert(foo::DurationEchoFunctions::nanoseconds_echo(std::chrono::nanoseconds(0)) == std::chrono::nanoseconds(0));
+}
+
+static void test_duration_signed_milli_seconds()
+{
+    const auto negative = std::chrono::milliseconds(-2000);
+    assert(foo::DurationEchoFunctions::signed_milliseconds_echo(negative) == negative);
+    const auto positive = std::chrono::milliseconds(2000);
+    assert(foo::DurationEchoFunctions::signed_milliseconds_echo(positive) == positive);
+}
+
+static void test_duration_float_seconds()
+{
+    const auto input = std::chrono::duration<double>(1.5);
+    assert(foo::DurationEchoFunctions::float_seconds_echo(input) == input);
+    // negative values saturate to zero
+    assert(foo::DurationEchoFunctions::float_seconds_echo(std::chrono::duration<double>(-1.0)).count() == 0.0);
+}
+
+static void test_timestamp()
+{
+    const auto epoch = std::chrono::system_clock::time_point{};
+    assert(foo::TimestampFunctions::echo(epoch) == epoch);
+
+    const auto value = epoch + std::chrono::milliseconds(1700000000123);
+    assert(foo::TimestampFunctions::echo(value) == value);
+
+    const auto before_epoch = epoch - std::chrono::hours(24);
+    assert(foo::TimestampFunctions::echo(before_epoch) == before_epoch);
+
+    assert(foo::TimestampFunctions::offset(value, std::chrono::milliseconds(-123)) == epoch + std::chrono::milliseconds(1700000000000));
+
+    assert(foo::TimestampFunctions::or_epoch(std::nullopt) == epoch);
+    assert(foo::TimestampFunctions::or_epoch(value) == value);
+}
+
 void duration_tests()
 {
     test_duration_milli_seconds();
     test_duration_seconds();
+    test_duration_micro_seconds();
+    test_duration_nano_seconds();
+    test_duration_signed_milli_seconds();
+    test_duration_float_seconds();
+    test_timestamp();
 }
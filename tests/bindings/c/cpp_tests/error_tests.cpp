@@ -51,9 +51,92 @@ static void test_defensive_exception_after_move()
     assert(other.get_special_value() == MAGIC_NUMBER);
 }
 
+static void test_error_detail()
+{
+    foo::Door::unlock(1234);
+
+    try {
+        foo::Door::unlock(0);
+        assert(false);
+    }
+    catch (const foo::LockException& ex) {
+        assert(ex.error == foo::LockError::wrong_code);
+        assert(ex.detail.empty());
+        assert(!ex.payload);
+        assert(strcmp(ex.what(), "wrong_code") == 0);
+    }
+
+    try {
+        foo::Door::unlock(42);
+        assert(false);
+    }
+    catch (const foo::LockException& ex) {
+        assert(ex.error == foo::LockError::wrong_code);
+        assert(ex.detail == "42 is not the code");
+        assert(!ex.payload);
+        assert(strcmp(ex.what(), "wrong_code: 42 is not the code") == 0);
+    }
+
+    try {
+        foo::Door::unlock(9999);
+        assert(false);
+    }
+    catch (const foo::LockException& ex) {
+        assert(ex.error == foo::LockError::locked_out);
+        assert(ex.detail == "too many wrong codes");
+        assert(ex.payload);
+        assert(ex.payload->attempts == 3);
+        assert(ex.payload->retry_after == std::chrono::seconds(30));
+    }
+}
+
+static void test_exception_hierarchy()
+{
+    try {
+        foo::ClassWithPassword::validate_password(std::string(WRONG_PASSWORD));
+        assert(false);
+    }
+    catch (const foo::FooException& ex) {
+        assert(dynamic_cast<const foo::MyException*>(&ex));
+    }
+
+    try {
+        foo::Door::unlock(42);
+        assert(false);
+    }
+    catch (const foo::DoorException& ex) {
+        assert(!dynamic_cast<const foo::LockedOutException*>(&ex));
+    }
+
+    try {
+        foo::Door::unlock(9999);
+        assert(false);
+    }
+    catch (const foo::LockedOutException& ex) {
+        assert(ex.error == foo::LockError::locked_out);
+        assert(ex.payload->attempts == 3);
+    }
+}
+
+static void test_panic()
+{
+    try {
+        foo::ClassWithPassword::panic_with_error();
+        assert(false);
+    }
+    catch (const foo::MyException& ex) {
+        assert(ex.error == foo::MyError::panic);
+    }
+
+    assert(foo::ClassWithPassword::panic_without_error(42) == 0);
+}
+
 void error_tests()
 {
     test_constructor_that_throws();
     test_static_method_that_throws();
     test_defensive_exception_after_move();
+    test_error_detail();
+    test_exception_hierarchy();
+    test_panic();
 }
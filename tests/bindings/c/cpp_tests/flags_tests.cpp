@@ -0,0 +1,47 @@
+#include <cassert>
+
+#include "foo.hpp"
+
+using namespace foo;
+
+static void test_flags_operators()
+{
+    auto value = Permissions::read | Permissions::write;
+    assert(static_cast<uint8_t>(value) == 0x03);
+    assert(any(value & Permissions::read));
+    assert(!any(value & Permissions::execute));
+
+    value |= Permissions::sticky;
+    assert(static_cast<uint8_t>(value) == 0x83);
+
+    value &= ~Permissions::write;
+    assert(static_cast<uint8_t>(value) == 0x81);
+
+    value ^= Permissions::read;
+    assert(value == Permissions::sticky);
+
+    // the complement only contains defined bits
+    assert(static_cast<uint8_t>(~Permissions::sticky) == 0x07);
+}
+
+static void test_flags_functions()
+{
+    const auto value = Permissions::read | Permissions::sticky;
+    assert(FlagsTestHelper::echo(value) == value);
+    assert(FlagsTestHelper::combine(Permissions::read, Permissions::execute) == (Permissions::read | Permissions::execute));
+    assert(FlagsTestHelper::or_default(std::nullopt, Permissions::read) == Permissions::read);
+    assert(FlagsTestHelper::or_default(Permissions::write, Permissions::read) == Permissions::write);
+}
+
+static void test_flags_struct_fields()
+{
+    const FileMode mode(Permissions::read | Permissions::write, Permissions::read | Permissions::execute);
+    assert(FlagsTestHelper::common(mode) == Permissions::read);
+}
+
+void flags_tests()
+{
+    test_flags_operators();
+    test_flags_functions();
+    test_flags_struct_fields();
+}
@@ -0,0 +1,42 @@
+#include <cassert>
+
+#include "foo.hpp"
+
+static void list_argument_tests()
+{
+    foo::Route route(
+        { foo::Waypoint(1, 2), foo::Waypoint(-3, 7) },
+        { 100, 200, 300 }
+    );
+
+    assert(foo::ListTestHelper::count_waypoints(route) == 2);
+    assert(foo::ListTestHelper::sum_coordinates(route) == 7);
+    assert(foo::ListTestHelper::sum_readings(route) == 600);
+}
+
+static void empty_list_tests()
+{
+    foo::Route route({}, {});
+
+    assert(foo::ListTestHelper::count_waypoints(route) == 0);
+    assert(foo::ListTestHelper::sum_coordinates(route) == 0);
+    assert(foo::ListTestHelper::sum_readings(route) == 0);
+}
+
+static void returned_list_tests()
+{
+    const auto route = foo::ListTestHelper::get_sample_route();
+
+    assert(route.id == 42);
+    assert(route.waypoints.size() == 3);
+    assert(route.waypoints[0].x == 1);
+    assert(route.waypoints[2].y == 6);
+    assert(route.readings == std::vector<uint16_t>({ 10, 20, 30, 40 }));
+}
+
+void list_tests()
+{
+    list_argument_tests();
+    empty_list_tests();
+    returned_list_tests();
+}
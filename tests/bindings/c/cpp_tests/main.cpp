@@ -3,14 +3,23 @@ void constant_tests();
 void defaulted_interface_tests();
 void enum_tests();
 void error_tests();
+void flags_tests();
 void duration_tests();
 void string_tests();
+void bytes_tests();
+void optional_tests();
+void union_tests();
 void structure_tests();
 void callback_tests();
+void class_tests();
 void iterator_tests();
 void primitive_iterator_tests();
+void owned_iterator_tests();
+void value_iterator_tests();
 void universal_tests();
 void collection_tests();
+void list_tests();
+void map_tests();
 void thread_tests();
 
 int main()
@@ -20,14 +29,23 @@ int main()
     defaulted_interface_tests();
     enum_tests();
     error_tests();
+    flags_tests();
     iterator_tests();
     primitive_iterator_tests();
+    owned_iterator_tests();
+    value_iterator_tests();
     duration_tests();
     string_tests();
+    bytes_tests();
+    optional_tests();
+    union_tests();
     structure_tests();
     callback_tests();
+    class_tests();
     universal_tests();
     collection_tests();
+    list_tests();
+    map_tests();
     thread_tests();
 
     return 0;
@@ -0,0 +1,33 @@
+#include <cassert>
+
+#include "foo.hpp"
+
+static void string_map_tests()
+{
+    std::map<std::string, std::string> values = {
+        { "hello", "world" },
+        { "big", "map" },
+    };
+    assert(foo::MapTestMethods::get_string_map_size(values) == 2);
+    assert(foo::MapTestMethods::get_string_map_value(values, "hello") == "world");
+    assert(foo::MapTestMethods::get_string_map_value(values, "big") == "map");
+    assert(foo::MapTestMethods::get_string_map_value(values, "missing") == "");
+}
+
+static void port_map_tests()
+{
+    std::map<uint16_t, foo::PortSettings> values;
+    values.emplace(20000, foo::PortSettings(true, 5));
+    values.emplace(20001, foo::PortSettings(false, 10));
+
+    assert(foo::MapTestMethods::get_port_map_size(values) == 2);
+    assert(foo::MapTestMethods::get_max_connections(values, 20000) == 5);
+    assert(foo::MapTestMethods::get_max_connections(values, 20001) == 0);
+    assert(foo::MapTestMethods::get_max_connections(values, 20002) == 0);
+}
+
+void map_tests()
+{
+    string_map_tests();
+    port_map_tests();
+}
@@ -0,0 +1,58 @@
+#include <cassert>
+
+#include "foo.hpp"
+
+using namespace foo;
+
+class TestOptionalReceiver : public OptionalReceiver {
+public:
+    std::optional<uint16_t> number;
+    uint32_t present_count = 0;
+
+    void on_number(std::optional<uint16_t> value) override {
+        number = value;
+    }
+
+    void on_values(const OptionalValues& values) override {
+        present_count = OptionalTests::count_present(values);
+    }
+};
+
+static void test_optional_arguments()
+{
+    assert(OptionalTests::number_or(std::nullopt, 7) == 7);
+    assert(OptionalTests::number_or(-42, 7) == -42);
+
+    assert(OptionalTests::timeout_or(std::nullopt, std::chrono::seconds(5)) == std::chrono::seconds(5));
+    assert(OptionalTests::timeout_or(std::chrono::seconds(30), std::chrono::seconds(5)) == std::chrono::seconds(30));
+
+    assert(OptionalTests::level_or(std::nullopt, OptionalLevel::low) == OptionalLevel::low);
+    assert(OptionalTests::level_or(OptionalLevel::high, OptionalLevel::low) == OptionalLevel::high);
+}
+
+static void test_optional_struct()
+{
+    OptionalValues values(false, std::nullopt, 0.5, std::nullopt, OptionalLevel::low);
+    assert(OptionalTests::count_present(values) == 3);
+
+    values.number = 1;
+    values.timeout = std::chrono::milliseconds(1000);
+    assert(OptionalTests::count_present(values) == 5);
+}
+
+static void test_optional_receiver()
+{
+    OptionalValues values(std::nullopt, 0xFFFF, std::nullopt, std::chrono::milliseconds(250), std::nullopt);
+
+    TestOptionalReceiver receiver;
+    OptionalTests::invoke(values, receiver);
+    assert(receiver.number == 0xFFFF);
+    assert(receiver.present_count == 2);
+}
+
+void optional_tests()
+{
+    test_optional_arguments();
+    test_optional_struct();
+    test_optional_receiver();
+}
@@ -0,0 +1,63 @@
+#include <assert.h>
+#include <stdlib.h>
+#include <string>
+
+#include "foo.hpp"
+
+static void test_owned_iterator_next()
+{
+    auto values = foo::OwnedIteratorTestHelper::count_up(1, 3);
+    uint32_t expected = 1;
+    while (values.next()) {
+        assert(values.get() == expected);
+        ++expected;
+    }
+    assert(expected == 4);
+}
+
+static void test_owned_iterator_range()
+{
+    uint32_t expected = 1;
+    for (auto value : foo::OwnedIteratorTestHelper::count_up(1, 3)) {
+        assert(value == expected);
+        ++expected;
+    }
+    assert(expected == 4);
+}
+
+static void test_owned_iterator_empty_range()
+{
+    auto values = foo::OwnedIteratorTestHelper::count_up(2, 1);
+    assert(values.begin() == values.end());
+}
+
+static void test_owned_iterator_move()
+{
+    auto values = foo::OwnedIteratorTestHelper::count_up(1, 3);
+    assert(values.next());
+    assert(values.get() == 1);
+
+    // the moved-to instance continues where the original left off
+    auto moved = std::move(values);
+    assert(!values.next());
+    assert(moved.next());
+    assert(moved.get() == 2);
+}
+
+static void test_owned_struct_iterator()
+{
+    std::string result;
+    for (auto character : foo::OwnedIteratorTestHelper::get_characters("abc")) {
+        result.push_back(static_cast<char>(character.value));
+    }
+    assert(result == "abc");
+}
+
+void owned_iterator_tests()
+{
+    test_owned_iterator_next();
+    test_owned_iterator_range();
+    test_owned_iterator_empty_range();
+    test_owned_iterator_move();
+    test_owned_struct_iterator();
+}
@@ -42,6 +42,30 @@ public:
     }
 };
 
+class FlushHandler : public foo::FlushHandler {
+    std::shared_ptr<std::promise<AddResult>> result;
+
+public:
+
+    FlushHandler(std::shared_ptr<std::promise<AddResult>> result) : result(result) {}
+
+    void on_complete() override
+    {
+        this->result->set_value(AddResult{});
+    }
+
+    void on_failure(foo::MathIsBroken error) override
+    {
+        AddResult result = {
+            true,
+            error,
+            0
+        };
+
+        this->result->set_value(result);
+    }
+};
+
 static void test_async_callbacks()
 {
     auto changes = std::make_shared <std::vector<uint32_t>>();
@@ -104,7 +128,95 @@ static void test_async_callbacks()
     assert((*changes)[2] == 94);
 }
 
+static void test_cancellation()
+{
+    foo::ThreadClass tc(42, foo::functional::value_change_listener([](uint32_t) {}));
+
+    {
+        auto promise = std::make_shared<std::promise<AddResult>>();
+        auto future = promise->get_future();
+        tc.next_value(std::make_unique<AddHandler>(promise));
+        tc.update(43);
+        auto result = future.get();
+        assert(!result.is_error);
+        assert(result.value == 43);
+    }
+
+    {
+        auto promise = std::make_shared<std::promise<AddResult>>();
+        auto future = promise->get_future();
+        const auto operation = tc.next_value(std::make_unique<AddHandler>(promise));
+        foo::ThreadClass::next_value_cancel(operation);
+        auto result = future.get();
+        assert(result.is_error);
+        assert(result.error == foo::MathIsBroken::cancelled);
+
+        // the completion of a cancelled operation is ignored
+        tc.update(44);
+        // cancelling a completed operation has no effect
+        foo::ThreadClass::next_value_cancel(operation);
+    }
+}
+
+static void test_void_future()
+{
+    auto changes = std::make_shared<std::vector<uint32_t>>();
+    foo::ThreadClass tc(
+        42,
+        foo::functional::value_change_listener(
+            [changes](uint32_t value) {
+                changes->push_back(value);
+            }
+        )
+    );
+
+    tc.update(43);
+    auto promise = std::make_shared<std::promise<AddResult>>();
+    auto future = promise->get_future();
+    tc.flush(std::make_unique<FlushHandler>(promise));
+    auto result = future.get();
+    assert(!result.is_error);
+    // the update queued before the flush has been processed
+    assert(changes->size() == 1);
+    assert((*changes)[0] == 43);
+}
+
+static void test_stream()
+{
+    foo::ThreadClass tc(42, foo::functional::value_change_listener([](uint32_t) {}));
+
+    {
+        foo::ValueStreamIterator iter;
+        tc.values(3, iter.handler());
+        std::vector<uint32_t> values;
+        while(iter.next()) {
+            values.push_back(iter.get());
+        }
+        assert(values == std::vector<uint32_t>({ 42, 43, 44 }));
+    }
+
+    {
+        foo::ValueStreamIterator iter;
+        tc.queue_error(foo::MathIsBroken::math_is_broke);
+        tc.values(2, iter.handler());
+        assert(iter.next());
+        assert(iter.get() == 42);
+        assert(iter.next());
+        assert(iter.get() == 43);
+        try {
+            iter.next();
+            assert(false);
+        }
+        catch(const foo::BrokenMathException& ex) {
+            assert(ex.error == foo::MathIsBroken::math_is_broke);
+        }
+    }
+}
+
 void thread_tests()
 {
     test_async_callbacks();
+    test_cancellation();
+    test_void_future();
+    test_stream();
 }
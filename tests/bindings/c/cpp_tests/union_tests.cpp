@@ -0,0 +1,52 @@
+#include <cassert>
+
+#include "foo.hpp"
+
+using namespace foo;
+
+class TestShapeReceiver : public ShapeReceiver {
+public:
+    size_t index = 0;
+    double area = 0.0;
+
+    void on_shape(const Shape& shape) override {
+        index = shape.index();
+        area = UnionTests::area(shape);
+    }
+};
+
+static void test_union_arguments()
+{
+    const auto circle_area = UnionTests::area(Shape(std::in_place_index<0>, Circle(2.0)));
+    assert(circle_area > 12.566 && circle_area < 12.567);
+    assert(UnionTests::area(Shape(std::in_place_index<1>, Rectangle(2.0, 3.0))) == 6.0);
+    // square shares the payload type of rectangle but is still a distinct variant
+    assert(UnionTests::area(Shape(std::in_place_index<2>, Rectangle(3.0, 3.0))) == 9.0);
+}
+
+static void test_union_return_values()
+{
+    const auto circle = UnionTests::scale(Shape(std::in_place_index<0>, Circle(2.0)), 2.0);
+    assert(circle.index() == 0);
+    assert(std::get<0>(circle).radius == 4.0);
+
+    const auto square = UnionTests::scale(Shape(std::in_place_index<2>, Rectangle(1.5, 1.5)), 2.0);
+    assert(square.index() == 2);
+    assert(std::get<2>(square).width == 3.0);
+    assert(std::get<2>(square).height == 3.0);
+}
+
+static void test_union_receiver()
+{
+    TestShapeReceiver receiver;
+    UnionTests::invoke(Shape(std::in_place_index<1>, Rectangle(4.0, 0.5)), receiver);
+    assert(receiver.index == 1);
+    assert(receiver.area == 2.0);
+}
+
+void union_tests()
+{
+    test_union_arguments();
+    test_union_return_values();
+    test_union_receiver();
+}
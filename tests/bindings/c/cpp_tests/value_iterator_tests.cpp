@@ -0,0 +1,70 @@
+#include <assert.h>
+#include <stdlib.h>
+#include <string>
+#include <vector>
+
+#include "foo.hpp"
+
+static void test_string_iterator()
+{
+    std::vector<std::string> words;
+    for (auto word : foo::ValueIteratorTestHelper::split_words("hello big  world")) {
+        words.push_back(word);
+    }
+    assert(words == std::vector<std::string>({ "hello", "big", "world" }));
+}
+
+static void test_enum_iterator()
+{
+    std::vector<foo::CompassDirection> directions;
+    for (auto direction : foo::ValueIteratorTestHelper::get_directions()) {
+        directions.push_back(direction);
+    }
+    assert(directions == std::vector<foo::CompassDirection>({
+        foo::CompassDirection::north,
+        foo::CompassDirection::east,
+        foo::CompassDirection::south,
+        foo::CompassDirection::west,
+    }));
+}
+
+static void test_duration_iterator()
+{
+    auto intervals = foo::ValueIteratorTestHelper::get_intervals(3, std::chrono::milliseconds(250));
+    int64_t expected = 250;
+    while (intervals.next()) {
+        assert(intervals.get() == std::chrono::milliseconds(expected));
+        expected += 250;
+    }
+    assert(expected == 1000);
+}
+
+static void test_signed_duration_iterator()
+{
+    auto offsets = foo::ValueIteratorTestHelper::get_offsets(3, std::chrono::milliseconds(250));
+    int64_t expected = -250;
+    while (offsets.next()) {
+        assert(offsets.get() == std::chrono::milliseconds(expected));
+        expected -= 250;
+    }
+    assert(expected == -1000);
+}
+
+static void test_class_iterator()
+{
+    uint32_t expected = 0;
+    for (auto item : foo::ValueIteratorTestHelper::get_indexed_items(3)) {
+        assert(item.get_index() == expected);
+        ++expected;
+    }
+    assert(expected == 3);
+}
+
+void value_iterator_tests()
+{
+    test_string_iterator();
+    test_enum_iterator();
+    test_duration_iterator();
+    test_signed_duration_iterator();
+    test_class_iterator();
+}
@@ -0,0 +1,68 @@
+using System;
+using System.Linq;
+using Xunit;
+using foo;
+
+namespace foo.Tests
+{
+    class Receiver : IByteReceiver
+    {
+        public byte[] received = null;
+
+        public void OnBytes(byte[] value)
+        {
+            this.received = value;
+        }
+
+        public uint OnPayload(BytePayload value)
+        {
+            return value.Data.Aggregate(value.Id, (sum, b) => sum + b);
+        }
+    }
+
+    public class BytesTest
+    {
+        static readonly byte[] BYTES = { 0x00, 0x01, 0xFE, 0xFF, 0x7F };
+
+        [Fact]
+        public void EchoTest()
+        {
+            using (var byteclass = new ByteClass())
+            {
+                Assert.Equal(BYTES, byteclass.Echo(BYTES));
+                Assert.Empty(byteclass.Echo(new byte[0]));
+            }
+        }
+
+        [Fact]
+        public void LengthTest()
+        {
+            Assert.Equal(BYTES.Length, (int)ByteClass.GetLength(BYTES));
+            Assert.Equal(0, (int)ByteClass.GetLength(new byte[0]));
+        }
+
+        [Fact]
+        public void ReceiverTest()
+        {
+            var payload = new BytePayload(BYTES).WithId(3);
+            var receiver = new Receiver();
+
+            Assert.Equal(0x01u + 0xFE + 0xFF + 0x7F + 3, ByteReceiverTests.Invoke(payload, receiver));
+            Assert.Equal(BYTES, receiver.received);
+        }
+
+        [Fact]
+        public void MemoryLeakTest()
+        {
+            const int NUM_ITERATIONS = 100000;
+
+            using (var byteclass = new ByteClass())
+            {
+                for (int i = 0; i < NUM_ITERATIONS; i++)
+                {
+                    Assert.Equal(BYTES, byteclass.Echo(BYTES));
+                }
+            }
+        }
+    }
+}
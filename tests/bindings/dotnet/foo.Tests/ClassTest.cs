@@ -21,6 +21,44 @@ namespace foo.Tests
             testclass.Shutdown();
 
             Assert.Equal(0u, TestClass.ConstructionCounter());
-        }       
+        }
+
+        [Fact]
+        public void PropertyTest()
+        {
+            var testclass = new TestClass(10);
+            Assert.Equal(1u, testclass.Step);
+            Assert.Equal(0u, testclass.Increments);
+
+            testclass.Step = 5;
+            testclass.IncrementValue();
+            Assert.Equal(5u, testclass.Step);
+            Assert.Equal(1u, testclass.Increments);
+            Assert.Equal(15u, testclass.GetValue());
+
+            testclass.Shutdown();
+        }
+
+        [Fact]
+        public void DefaultArgumentsTest()
+        {
+            var testclass = new TestClass(10);
+            Assert.Equal(15u, testclass.AddValue(5));
+            Assert.Equal(30u, testclass.AddValue(5, 3));
+            testclass.Shutdown();
+
+            Assert.Equal(TimeSpan.FromMilliseconds(500), TestClass.ScaleDuration());
+            Assert.Equal(TimeSpan.FromMilliseconds(200), TestClass.ScaleDuration(TimeSpan.FromMilliseconds(100)));
+            Assert.Equal(TimeSpan.FromMilliseconds(300), TestClass.ScaleDuration(TimeSpan.FromMilliseconds(100), 3));
+        }
+
+        [Fact]
+        public void ImplementsInterfaceTest()
+        {
+            var testclass = new TestClass(10);
+            Assert.Equal(20u, TestClass.AddTwice(testclass, 5));
+            Assert.Equal(20u, testclass.GetValue());
+            testclass.Shutdown();
+        }
     }
 }
@@ -12,5 +12,28 @@ namespace foo.Tests
             Assert.Equal(1, SpecialValues.One);
             Assert.Equal(2, SpecialValues.Two);
         }
+
+        [Fact]
+        public void TypedConstantTest()
+        {
+            Assert.True(TypedValues.Enabled);
+            Assert.Equal(byte.MaxValue, TypedValues.MaxU8);
+            Assert.Equal(-100, TypedValues.MinS8);
+            Assert.Equal(10, TypedValues.MaskU16);
+            Assert.Equal(-256, TypedValues.NegativeS16);
+            Assert.Equal(uint.MaxValue, TypedValues.MaxU32);
+            Assert.Equal(-2000000000, TypedValues.MinS32);
+            Assert.Equal(ulong.MaxValue, TypedValues.MaxU64);
+            Assert.Equal(ulong.MaxValue, TypedValues.MaxU64Hex);
+            Assert.Equal(-5000000000L, TypedValues.LargeS64);
+            Assert.Equal(0.5f, TypedValues.Half);
+            Assert.Equal(3.25, TypedValues.Pi);
+            Assert.Equal(TimeSpan.FromMilliseconds(1500), TypedValues.Timeout);
+            Assert.Equal(TimeSpan.FromMinutes(1), TypedValues.Period);
+            Assert.Equal(TimeSpan.FromTicks(2500), TypedValues.Resolution);
+            Assert.Equal(TimeSpan.FromMilliseconds(-200), TypedValues.Skew);
+            Assert.Equal("Hello \"world\"\n", TypedValues.Greeting);
+            Assert.Equal(Priority.High, TypedValues.DefaultPriority);
+        }
     }
 }
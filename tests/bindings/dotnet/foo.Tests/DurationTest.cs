@@ -12,6 +12,10 @@ namespace foo.Tests
             var zero = TimeSpan.Zero;
             Assert.Equal(zero, DurationEchoFunctions.MillisecondsEcho(zero));
             Assert.Equal(zero, DurationEchoFunctions.SecondsEcho(zero));
+            Assert.Equal(zero, DurationEchoFunctions.MicrosecondsEcho(zero));
+            Assert.Equal(zero, DurationEchoFunctions.NanosecondsEcho(zero));
+            Assert.Equal(zero, DurationEchoFunctions.SignedMillisecondsEcho(zero));
+            Assert.Equal(zero, DurationEchoFunctions.FloatSecondsEcho(zero));
         }
 
         [Fact]
@@ -20,6 +24,10 @@ namespace foo.Tests
             var fiveS = TimeSpan.FromSeconds(5);
             Assert.Equal(fiveS, DurationEchoFunctions.MillisecondsEcho(fiveS));
             Assert.Equal(fiveS, DurationEchoFunctions.SecondsEcho(fiveS));
+            Assert.Equal(fiveS, DurationEchoFunctions.MicrosecondsEcho(fiveS));
+            Assert.Equal(fiveS, DurationEchoFunctions.NanosecondsEcho(fiveS));
+            Assert.Equal(fiveS, DurationEchoFunctions.SignedMillisecondsEcho(fiveS));
+            Assert.Equal(fiveS, DurationEchoFunctions.FloatSecondsEcho(fiveS));
         }
 
         [Fact]
@@ -28,6 +36,7 @@ namespace foo.Tests
             var test = TimeSpan.FromMilliseconds(250);
             Assert.Equal(test, DurationEchoFunctions.MillisecondsEcho(test));
             Assert.Equal(TimeSpan.FromSeconds(0), DurationEchoFunctions.SecondsEcho(test));
+            Assert.Equal(test, DurationEchoFunctions.FloatSecondsEcho(test));
         }
 
         [Fact]
@@ -37,5 +46,48 @@ namespace foo.Tests
             Assert.Equal(test, DurationEchoFunctions.MillisecondsEcho(test));
             Assert.Equal(test, DurationEchoFunctions.SecondsEcho(test));
         }
+
+        [Fact]
+        public void DurationSubMillisecondTest()
+        {
+            // 1.5001 ms, i.e. 15001 ticks of 100 ns
+            var test = TimeSpan.FromTicks(15001);
+            Assert.Equal(TimeSpan.FromTicks(15000), DurationEchoFunctions.MicrosecondsEcho(test));
+            Assert.Equal(test, DurationEchoFunctions.NanosecondsEcho(test));
+            Assert.Equal(TimeSpan.FromMilliseconds(1), DurationEchoFunctions.MillisecondsEcho(test));
+        }
+
+        [Fact]
+        public void DurationNegativeTest()
+        {
+            var test = TimeSpan.FromMilliseconds(-2500);
+            Assert.Equal(test, DurationEchoFunctions.SignedMillisecondsEcho(test));
+        }
+
+        [Fact]
+        public void TimestampEchoTest()
+        {
+            Assert.Equal(DateTimeOffset.UnixEpoch, TimestampFunctions.Echo(DateTimeOffset.UnixEpoch));
+            var value = DateTimeOffset.FromUnixTimeMilliseconds(1_700_000_000_123);
+            Assert.Equal(value, TimestampFunctions.Echo(value));
+            var beforeEpoch = DateTimeOffset.FromUnixTimeMilliseconds(-86_400_000);
+            Assert.Equal(beforeEpoch, TimestampFunctions.Echo(beforeEpoch));
+        }
+
+        [Fact]
+        public void TimestampOffsetTest()
+        {
+            var value = DateTimeOffset.FromUnixTimeMilliseconds(1000);
+            Assert.Equal(DateTimeOffset.FromUnixTimeMilliseconds(1500), TimestampFunctions.Offset(value, TimeSpan.FromMilliseconds(500)));
+            Assert.Equal(DateTimeOffset.FromUnixTimeMilliseconds(-500), TimestampFunctions.Offset(value, TimeSpan.FromMilliseconds(-1500)));
+        }
+
+        [Fact]
+        public void TimestampOptionalTest()
+        {
+            var value = DateTimeOffset.FromUnixTimeMilliseconds(42);
+            Assert.Equal(DateTimeOffset.UnixEpoch, TimestampFunctions.OrEpoch(null));
+            Assert.Equal(value, TimestampFunctions.OrEpoch(value));
+        }
     }
 }
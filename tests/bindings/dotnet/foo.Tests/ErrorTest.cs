@@ -51,6 +51,19 @@ namespace foo.Tests
             Assert.Equal("12345", result);
         }
 
+        [Fact]
+        public void PanicIsReportedAsError()
+        {
+            var ex = Assert.Throws<MyException>(() => ClassWithPassword.PanicWithError());
+            Assert.Equal(MyError.Panic, ex.error);
+        }
+
+        [Fact]
+        public void PanicWithoutErrorReturnsDefault()
+        {
+            Assert.Equal(0u, ClassWithPassword.PanicWithoutError(42));
+        }
+
         [Fact]
         public void ConstructorWithError()
         {
@@ -59,5 +72,44 @@ namespace foo.Tests
             var secret = new ClassWithPassword("12345");
             Assert.Equal(42u, secret.GetSpecialValue());
         }
+
+        [Fact]
+        public void ErrorWithoutDetail()
+        {
+            Door.Unlock(1234);
+            var ex = Assert.Throws<LockException>(() => Door.Unlock(0));
+            Assert.Equal(LockError.WrongCode, ex.error);
+            Assert.Null(ex.detail);
+            Assert.Null(ex.payload);
+            Assert.Equal("WrongCode", ex.Message);
+        }
+
+        [Fact]
+        public void ErrorWithMessage()
+        {
+            var ex = Assert.Throws<LockException>(() => Door.Unlock(42));
+            Assert.Equal(LockError.WrongCode, ex.error);
+            Assert.Equal("42 is not the code", ex.detail);
+            Assert.Null(ex.payload);
+            Assert.Equal("WrongCode: 42 is not the code", ex.Message);
+        }
+
+        [Fact]
+        public void ErrorWithPayload()
+        {
+            var ex = Assert.Throws<LockedOutException>(() => Door.Unlock(9999));
+            Assert.Equal(LockError.LockedOut, ex.error);
+            Assert.Equal("too many wrong codes", ex.detail);
+            Assert.Equal(3u, ex.payload.Attempts);
+            Assert.Equal(TimeSpan.FromSeconds(30), ex.payload.RetryAfter);
+        }
+
+        [Fact]
+        public void ExceptionsShareBaseClass()
+        {
+            Assert.IsAssignableFrom<MyException>(Assert.ThrowsAny<FooException>(() => ClassWithPassword.ValidatePassword("hi!")));
+            Assert.IsType<LockException>(Assert.ThrowsAny<DoorException>(() => Door.Unlock(42)));
+            Assert.IsType<LockedOutException>(Assert.ThrowsAny<FooException>(() => Door.Unlock(9999)));
+        }
     }
 }
@@ -0,0 +1,42 @@
+using System;
+using Xunit;
+using foo;
+
+namespace foo.Tests
+{
+    public class FlagsTest
+    {
+        [Fact]
+        public void ValuesTest()
+        {
+            Assert.Equal(0x01, (byte)Permissions.Read);
+            Assert.Equal(0x02, (byte)Permissions.Write);
+            Assert.Equal(0x04, (byte)Permissions.Execute);
+            Assert.Equal(0x80, (byte)Permissions.Sticky);
+            Assert.Equal("Read, Sticky", (Permissions.Read | Permissions.Sticky).ToString());
+        }
+
+        [Fact]
+        public void FunctionsTest()
+        {
+            var value = Permissions.Read | Permissions.Sticky;
+            Assert.Equal(value, FlagsTestHelper.Echo(value));
+            Assert.Equal(Permissions.Read | Permissions.Execute, FlagsTestHelper.Combine(Permissions.Read, Permissions.Execute));
+            Assert.True(FlagsTestHelper.Combine(Permissions.Read, Permissions.Write).HasFlag(Permissions.Write));
+        }
+
+        [Fact]
+        public void OptionalTest()
+        {
+            Assert.Equal(Permissions.Read, FlagsTestHelper.OrDefault(null, Permissions.Read));
+            Assert.Equal(Permissions.Write, FlagsTestHelper.OrDefault(Permissions.Write, Permissions.Read));
+        }
+
+        [Fact]
+        public void StructFieldsTest()
+        {
+            var mode = new FileMode(Permissions.Read | Permissions.Write, Permissions.Read | Permissions.Execute);
+            Assert.Equal(Permissions.Read, FlagsTestHelper.Common(mode));
+        }
+    }
+}
@@ -0,0 +1,44 @@
+using System;
+using Xunit;
+using foo;
+using System.Collections.Generic;
+
+namespace foo.Tests
+{
+    public class ListTest
+    {
+        [Fact]
+        public void ListArgumentsTest()
+        {
+            var waypoints = new List<Waypoint> { new Waypoint(1, 2), new Waypoint(-3, 7) };
+            var readings = new List<ushort> { 100, 200, 300 };
+            var route = new Route(waypoints, readings);
+
+            Assert.Equal(2u, ListTestHelper.CountWaypoints(route));
+            Assert.Equal(7, ListTestHelper.SumCoordinates(route));
+            Assert.Equal(600u, ListTestHelper.SumReadings(route));
+        }
+
+        [Fact]
+        public void EmptyListsTest()
+        {
+            var route = new Route(new List<Waypoint>(), new List<ushort>());
+
+            Assert.Equal(0u, ListTestHelper.CountWaypoints(route));
+            Assert.Equal(0, ListTestHelper.SumCoordinates(route));
+            Assert.Equal(0u, ListTestHelper.SumReadings(route));
+        }
+
+        [Fact]
+        public void ReturnedListsTest()
+        {
+            var route = ListTestHelper.GetSampleRoute();
+
+            Assert.Equal(42u, route.Id);
+            Assert.Equal(3, route.Waypoints.Count);
+            Assert.Equal(1, route.Waypoints[0].X);
+            Assert.Equal(6, route.Waypoints[2].Y);
+            Assert.Equal(new List<ushort> { 10, 20, 30, 40 }, route.Readings);
+        }
+    }
+}
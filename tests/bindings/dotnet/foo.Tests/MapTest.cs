@@ -0,0 +1,36 @@
+using System;
+using Xunit;
+using foo;
+using System.Collections.Generic;
+
+namespace foo.Tests
+{
+    public class MapTest
+    {
+        [Fact]
+        public void StringMapTest()
+        {
+            var values = new Dictionary<string, string>();
+            values.Add("Hello", "World!");
+            values.Add("Émile", "Zola");
+
+            Assert.Equal((uint)values.Count, MapTestMethods.GetStringMapSize(values));
+            Assert.Equal("World!", MapTestMethods.GetStringMapValue(values, "Hello"));
+            Assert.Equal("Zola", MapTestMethods.GetStringMapValue(values, "Émile"));
+            Assert.Equal("", MapTestMethods.GetStringMapValue(values, "missing"));
+        }
+
+        [Fact]
+        public void PortMapTest()
+        {
+            var values = new Dictionary<ushort, PortSettings>();
+            values.Add(20000, new PortSettings(true, 5));
+            values.Add(20001, new PortSettings(false, 10));
+
+            Assert.Equal((uint)values.Count, MapTestMethods.GetPortMapSize(values));
+            Assert.Equal((ushort)5, MapTestMethods.GetMaxConnections(values, 20000));
+            Assert.Equal((ushort)0, MapTestMethods.GetMaxConnections(values, 20001));
+            Assert.Equal((ushort)0, MapTestMethods.GetMaxConnections(values, 20002));
+        }
+    }
+}
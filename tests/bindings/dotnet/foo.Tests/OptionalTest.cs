@@ -0,0 +1,59 @@
+using System;
+using Xunit;
+using foo;
+
+namespace foo.Tests
+{
+    class TestOptionalReceiver : IOptionalReceiver
+    {
+        public ushort? number = null;
+        public uint presentCount = 0;
+
+        public void OnNumber(ushort? value)
+        {
+            this.number = value;
+        }
+
+        public void OnValues(OptionalValues values)
+        {
+            this.presentCount = OptionalTests.CountPresent(values);
+        }
+    }
+
+    public class OptionalTest
+    {
+        [Fact]
+        public void ArgumentsTest()
+        {
+            Assert.Equal(7, OptionalTests.NumberOr(null, 7));
+            Assert.Equal(-42, OptionalTests.NumberOr(-42, 7));
+
+            Assert.Equal(TimeSpan.FromSeconds(5), OptionalTests.TimeoutOr(null, TimeSpan.FromSeconds(5)));
+            Assert.Equal(TimeSpan.FromSeconds(30), OptionalTests.TimeoutOr(TimeSpan.FromSeconds(30), TimeSpan.FromSeconds(5)));
+
+            Assert.Equal(OptionalLevel.Low, OptionalTests.LevelOr(null, OptionalLevel.Low));
+            Assert.Equal(OptionalLevel.High, OptionalTests.LevelOr(OptionalLevel.High, OptionalLevel.Low));
+        }
+
+        [Fact]
+        public void StructTest()
+        {
+            var values = new OptionalValues(false, null, 0.5, null, OptionalLevel.Low);
+            Assert.Equal(3u, OptionalTests.CountPresent(values));
+
+            values.WithNumber(1).WithTimeout(TimeSpan.FromMilliseconds(1000));
+            Assert.Equal(5u, OptionalTests.CountPresent(values));
+        }
+
+        [Fact]
+        public void ReceiverTest()
+        {
+            var values = new OptionalValues(null, 0xFFFF, null, TimeSpan.FromMilliseconds(250), null);
+            var receiver = new TestOptionalReceiver();
+
+            OptionalTests.Invoke(values, receiver);
+            Assert.Equal((ushort?)0xFFFF, receiver.number);
+            Assert.Equal(2u, receiver.presentCount);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+using System;
+using Xunit;
+using foo;
+using System.Linq;
+using System.Collections.Generic;
+
+namespace foo.Tests
+{
+    public class OwnedIteratorTest
+    {
+        [Fact]
+        public void ReturnedPrimitiveIteratorTest()
+        {
+            using (var iter = OwnedIteratorTestHelper.CountUp(1, 3))
+            {
+                var values = iter.ToList();
+
+                Assert.Equal(3, values.Count);
+                Assert.Equal(1u, values[0]);
+                Assert.Equal(2u, values[1]);
+                Assert.Equal(3u, values[2]);
+            }
+        }
+
+        [Fact]
+        public void ReturnedStructIteratorTest()
+        {
+            using (var iter = OwnedIteratorTestHelper.GetCharacters("abc"))
+            {
+                var result = new string(iter.Select(x => (char)x.Value).ToArray());
+                Assert.Equal("abc", result);
+            }
+        }
+
+        [Fact]
+        public void CanOnlyEnumerateOnceTest()
+        {
+            using (var iter = OwnedIteratorTestHelper.CountUp(1, 3))
+            {
+                iter.GetEnumerator();
+                Assert.Throws<InvalidOperationException>(() => iter.GetEnumerator());
+            }
+        }
+
+        [Fact]
+        public void CannotEnumerateAfterDisposeTest()
+        {
+            var iter = OwnedIteratorTestHelper.CountUp(1, 3);
+            var enumerator = iter.GetEnumerator();
+            iter.Dispose();
+            Assert.Throws<ObjectDisposedException>(() => enumerator.MoveNext());
+        }
+    }
+}
@@ -2,6 +2,7 @@ using System;
 using Xunit;
 using foo;
 using System.Collections.Generic;
+using System.Threading;
 
 namespace foo.Tests
 {
@@ -50,6 +51,96 @@ namespace foo.Tests
             Assert.Empty(values);
         }
 
+        [Fact]
+        public async void CancellationTokenCancelsTheOperation()
+        {
+            var tc = new foo.ThreadClass(42, item => { });
+
+            try
+            {
+                var next = tc.NextValue();
+                tc.Update(43);
+                Assert.Equal(43u, await next);
+
+                var source = new CancellationTokenSource();
+                var cancelled = tc.NextValue(source.Token);
+                source.Cancel();
+                var ex = await Assert.ThrowsAsync<BrokenMathException>(() => cancelled);
+                Assert.Equal(MathIsBroken.Cancelled, ex.error);
+                // the completion of the cancelled operation is ignored
+                tc.Update(44);
+            }
+            finally
+            {
+                tc.Shutdown();
+            }
+        }
+
+        [Fact]
+        public async void VoidFutureCompletesWithoutValue()
+        {
+            var values = new List<uint>();
+            var tc = new foo.ThreadClass(42, item => values.Add(item));
+
+            try
+            {
+                tc.Update(43);
+                await tc.Flush();
+                // the update queued before the flush has been processed
+                Assert.Equal(new List<uint> { 43 }, values);
+            }
+            finally
+            {
+                tc.Shutdown();
+            }
+        }
+
+        [Fact]
+        public async void StreamProducesValuesThenCompletes()
+        {
+            var tc = new foo.ThreadClass(42, item => { });
+
+            try
+            {
+                var values = new List<uint>();
+                await foreach (var value in tc.Values(3))
+                {
+                    values.Add(value);
+                }
+                Assert.Equal(new List<uint> { 42, 43, 44 }, values);
+            }
+            finally
+            {
+                tc.Shutdown();
+            }
+        }
+
+        [Fact]
+        public async void StreamFailsAfterValues()
+        {
+            var tc = new foo.ThreadClass(42, item => { });
+
+            try
+            {
+                tc.QueueError(MathIsBroken.MathIsBroke);
+                var values = new List<uint>();
+                var ex = await Assert.ThrowsAsync<BrokenMathException>(async () =>
+                {
+                    await foreach (var value in tc.Values(2))
+                    {
+                        values.Add(value);
+                    }
+                });
+                Assert.Equal(MathIsBroken.MathIsBroke, ex.error);
+                // the values produced before the failure are delivered
+                Assert.Equal(new List<uint> { 42, 43 }, values);
+            }
+            finally
+            {
+                tc.Shutdown();
+            }
+        }
+
         [Fact]
         public async void PromiseCanCompleteIfDropped()
         {
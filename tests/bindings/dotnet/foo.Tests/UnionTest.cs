@@ -0,0 +1,50 @@
+using System;
+using Xunit;
+using foo;
+
+namespace foo.Tests
+{
+    class TestShapeReceiver : IShapeReceiver
+    {
+        public Shape shape = null;
+
+        public void OnShape(Shape shape)
+        {
+            this.shape = shape;
+        }
+    }
+
+    public class UnionTest
+    {
+        [Fact]
+        public void ArgumentTest()
+        {
+            Assert.Equal(4.0 * Math.PI, UnionTests.Area(new Shape.Circle(new Circle(2.0))), 9);
+            Assert.Equal(6.0, UnionTests.Area(new Shape.Rectangle(new Rectangle(2.0, 3.0))));
+            // square shares the payload type of rectangle but is still a distinct variant
+            Assert.Equal(9.0, UnionTests.Area(new Shape.Square(new Rectangle(3.0, 3.0))));
+        }
+
+        [Fact]
+        public void ReturnValueTest()
+        {
+            var circle = Assert.IsType<Shape.Circle>(UnionTests.Scale(new Shape.Circle(new Circle(2.0)), 2.0));
+            Assert.Equal(4.0, circle.Value.Radius);
+
+            var square = Assert.IsType<Shape.Square>(UnionTests.Scale(new Shape.Square(new Rectangle(1.5, 1.5)), 2.0));
+            Assert.Equal(3.0, square.Value.Width);
+            Assert.Equal(3.0, square.Value.Height);
+        }
+
+        [Fact]
+        public void ReceiverTest()
+        {
+            var receiver = new TestShapeReceiver();
+            UnionTests.Invoke(new Shape.Rectangle(new Rectangle(4.0, 0.5)), receiver);
+
+            var rectangle = Assert.IsType<Shape.Rectangle>(receiver.shape);
+            Assert.Equal(4.0, rectangle.Value.Width);
+            Assert.Equal(0.5, rectangle.Value.Height);
+        }
+    }
+}
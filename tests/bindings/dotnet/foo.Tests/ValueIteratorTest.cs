@@ -0,0 +1,76 @@
+using System;
+using Xunit;
+using foo;
+using System.Linq;
+using System.Collections.Generic;
+
+namespace foo.Tests
+{
+    public class ValueIteratorTest
+    {
+        [Fact]
+        public void ReturnedStringIteratorTest()
+        {
+            using (var iter = ValueIteratorTestHelper.SplitWords("hello big  world"))
+            {
+                Assert.Equal(new List<string> { "hello", "big", "world" }, iter.ToList());
+            }
+        }
+
+        [Fact]
+        public void ReturnedEnumIteratorTest()
+        {
+            using (var iter = ValueIteratorTestHelper.GetDirections())
+            {
+                var expected = new List<CompassDirection>
+                {
+                    CompassDirection.North,
+                    CompassDirection.East,
+                    CompassDirection.South,
+                    CompassDirection.West,
+                };
+                Assert.Equal(expected, iter.ToList());
+            }
+        }
+
+        [Fact]
+        public void ReturnedDurationIteratorTest()
+        {
+            using (var iter = ValueIteratorTestHelper.GetIntervals(3, TimeSpan.FromMilliseconds(250)))
+            {
+                var expected = new List<TimeSpan>
+                {
+                    TimeSpan.FromMilliseconds(250),
+                    TimeSpan.FromMilliseconds(500),
+                    TimeSpan.FromMilliseconds(750),
+                };
+                Assert.Equal(expected, iter.ToList());
+            }
+        }
+
+        [Fact]
+        public void ReturnedSignedDurationIteratorTest()
+        {
+            using (var iter = ValueIteratorTestHelper.GetOffsets(3, TimeSpan.FromMilliseconds(250)))
+            {
+                var expected = new List<TimeSpan>
+                {
+                    TimeSpan.FromMilliseconds(-250),
+                    TimeSpan.FromMilliseconds(-500),
+                    TimeSpan.FromMilliseconds(-750),
+                };
+                Assert.Equal(expected, iter.ToList());
+            }
+        }
+
+        [Fact]
+        public void ReturnedClassIteratorTest()
+        {
+            using (var iter = ValueIteratorTestHelper.GetIndexedItems(3))
+            {
+                var indices = iter.Select(x => x.GetIndex()).ToList();
+                Assert.Equal(new List<uint> { 0, 1, 2 }, indices);
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.ByteClass;
+import io.stepfunc.foo.BytePayload;
+import io.stepfunc.foo.ByteReceiver;
+import io.stepfunc.foo.ByteReceiverTests;
+import org.joou.UInteger;
+import org.junit.jupiter.api.Test;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.joou.Unsigned.uint;
+
+public class BytesTest {
+    final byte[] BYTES = { 0x00, 0x01, (byte) 0xFE, (byte) 0xFF, 0x7F };
+
+    static class Receiver implements ByteReceiver {
+        byte[] received = null;
+
+        @Override
+        public void onBytes(byte[] value) {
+            this.received = value;
+        }
+
+        @Override
+        public UInteger onPayload(BytePayload value) {
+            long sum = value.id.longValue();
+            for (byte b : value.data) {
+                sum += b & 0xFF;
+            }
+            return uint(sum);
+        }
+    }
+
+    @Test
+    public void EchoTest() {
+        try(ByteClass byteClass = new ByteClass()) {
+            assertThat(byteClass.echo(BYTES)).isEqualTo(BYTES);
+            assertThat(byteClass.echo(new byte[0])).isEmpty();
+        }
+    }
+
+    @Test
+    public void LengthTest() {
+        assertThat(ByteClass.getLength(BYTES).intValue()).isEqualTo(BYTES.length);
+        assertThat(ByteClass.getLength(new byte[0]).intValue()).isEqualTo(0);
+    }
+
+    @Test
+    public void ReceiverTest() {
+        final BytePayload payload = new BytePayload(BYTES).withId(uint(3));
+        final Receiver receiver = new Receiver();
+
+        assertThat(ByteReceiverTests.invoke(payload, receiver).longValue()).isEqualTo(0x01 + 0xFE + 0xFF + 0x7F + 3);
+        assertThat(receiver.received).isEqualTo(BYTES);
+    }
+}
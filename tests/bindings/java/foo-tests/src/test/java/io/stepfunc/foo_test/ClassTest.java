@@ -3,6 +3,7 @@ package io.stepfunc.foo_test;
 import io.stepfunc.foo.TestClass;
 import org.junit.jupiter.api.Test;
 
+import java.time.Duration;
 import java.util.concurrent.ExecutionException;
 
 import static org.assertj.core.api.Assertions.assertThat;
@@ -24,4 +25,39 @@ public class ClassTest {
 
         assertThat(TestClass.constructionCounter().intValue()).isZero();
     }
+
+    @Test
+    public void PropertyTest() {
+        TestClass testclass = new TestClass(uint(10));
+        assertThat(testclass.getStep()).isEqualTo(uint(1));
+        assertThat(testclass.getIncrements()).isEqualTo(uint(0));
+
+        testclass.setStep(uint(5));
+        testclass.incrementValue();
+        assertThat(testclass.getStep()).isEqualTo(uint(5));
+        assertThat(testclass.getIncrements()).isEqualTo(uint(1));
+        assertThat(testclass.getValue()).isEqualTo(uint(15));
+
+        testclass.shutdown();
+    }
+
+    @Test
+    public void DefaultArgumentsTest() {
+        TestClass testclass = new TestClass(uint(10));
+        assertThat(testclass.addValue(uint(5))).isEqualTo(uint(15));
+        assertThat(testclass.addValue(uint(5), uint(3))).isEqualTo(uint(30));
+        testclass.shutdown();
+
+        assertThat(TestClass.scaleDuration()).isEqualTo(Duration.ofMillis(500));
+        assertThat(TestClass.scaleDuration(Duration.ofMillis(100))).isEqualTo(Duration.ofMillis(200));
+        assertThat(TestClass.scaleDuration(Duration.ofMillis(100), uint(3))).isEqualTo(Duration.ofMillis(300));
+    }
+
+    @Test
+    public void ImplementsInterfaceTest() {
+        TestClass testclass = new TestClass(uint(10));
+        assertThat(TestClass.addTwice(testclass, uint(5))).isEqualTo(uint(20));
+        assertThat(testclass.getValue()).isEqualTo(uint(20));
+        testclass.shutdown();
+    }
 }
@@ -1,8 +1,13 @@
 package io.stepfunc.foo_test;
 
+import io.stepfunc.foo.Priority;
 import io.stepfunc.foo.SpecialValues;
+import io.stepfunc.foo.TypedValues;
+import org.joou.ULong;
 import org.junit.jupiter.api.Test;
 
+import java.time.Duration;
+
 import static org.assertj.core.api.Assertions.assertThat;
 
 class ConstantTest {
@@ -11,4 +16,26 @@ class ConstantTest {
         assertThat(SpecialValues.ONE.byteValue()).isEqualTo((byte) 0x01);
         assertThat(SpecialValues.TWO.byteValue()).isEqualTo((byte) 0x02);
     }
+
+    @Test
+    void typedValues() {
+        assertThat(TypedValues.ENABLED).isTrue();
+        assertThat(TypedValues.MAX_U8.intValue()).isEqualTo(255);
+        assertThat(TypedValues.MIN_S8).isEqualTo((byte) -100);
+        assertThat(TypedValues.MASK_U16.intValue()).isEqualTo(10);
+        assertThat(TypedValues.NEGATIVE_S16).isEqualTo((short) -256);
+        assertThat(TypedValues.MAX_U32.longValue()).isEqualTo(4294967295L);
+        assertThat(TypedValues.MIN_S32).isEqualTo(-2000000000);
+        assertThat(TypedValues.MAX_U64).isEqualTo(ULong.MAX);
+        assertThat(TypedValues.MAX_U64_HEX).isEqualTo(ULong.MAX);
+        assertThat(TypedValues.LARGE_S64).isEqualTo(-5000000000L);
+        assertThat(TypedValues.HALF).isEqualTo(0.5f);
+        assertThat(TypedValues.PI).isEqualTo(3.25);
+        assertThat(TypedValues.TIMEOUT).isEqualTo(Duration.ofMillis(1500));
+        assertThat(TypedValues.PERIOD).isEqualTo(Duration.ofMinutes(1));
+        assertThat(TypedValues.RESOLUTION).isEqualTo(Duration.ofNanos(250_000));
+        assertThat(TypedValues.SKEW).isEqualTo(Duration.ofMillis(-200));
+        assertThat(TypedValues.GREETING).isEqualTo("Hello \"world\"\n");
+        assertThat(TypedValues.DEFAULT_PRIORITY).isEqualTo(Priority.HIGH);
+    }
 }
@@ -1,9 +1,11 @@
 package io.stepfunc.foo_test;
 
 import io.stepfunc.foo.DurationEchoFunctions;
+import io.stepfunc.foo.TimestampFunctions;
 import org.junit.jupiter.api.Test;
 
 import java.time.Duration;
+import java.time.Instant;
 
 import static org.assertj.core.api.Assertions.assertThat;
 
@@ -13,6 +15,10 @@ public class DurationTest {
         Duration zero = Duration.ZERO;
         assertThat(DurationEchoFunctions.millisecondsEcho(zero)).isEqualTo(zero);
         assertThat(DurationEchoFunctions.secondsEcho(zero)).isEqualTo(zero);
+        assertThat(DurationEchoFunctions.microsecondsEcho(zero)).isEqualTo(zero);
+        assertThat(DurationEchoFunctions.nanosecondsEcho(zero)).isEqualTo(zero);
+        assertThat(DurationEchoFunctions.signedMillisecondsEcho(zero)).isEqualTo(zero);
+        assertThat(DurationEchoFunctions.floatSecondsEcho(zero)).isEqualTo(zero);
     }
 
     @Test
@@ -20,6 +26,10 @@ public class DurationTest {
         Duration test = Duration.ofSeconds(5);
         assertThat(DurationEchoFunctions.millisecondsEcho(test)).isEqualTo(test);
         assertThat(DurationEchoFunctions.secondsEcho(test)).isEqualTo(test);
+        assertThat(DurationEchoFunctions.microsecondsEcho(test)).isEqualTo(test);
+        assertThat(DurationEchoFunctions.nanosecondsEcho(test)).isEqualTo(test);
+        assertThat(DurationEchoFunctions.signedMillisecondsEcho(test)).isEqualTo(test);
+        assertThat(DurationEchoFunctions.floatSecondsEcho(test)).isEqualTo(test);
     }
 
     @Test
@@ -27,6 +37,7 @@ public class DurationTest {
         Duration test = Duration.ofMillis(250);
         assertThat(DurationEchoFunctions.millisecondsEcho(test)).isEqualTo(test);
         assertThat(DurationEchoFunctions.secondsEcho(test)).isEqualTo(Duration.ZERO);
+        assertThat(DurationEchoFunctions.floatSecondsEcho(test)).isEqualTo(test);
     }
 
     @Test
@@ -35,4 +46,41 @@ public class DurationTest {
         assertThat(DurationEchoFunctions.millisecondsEcho(test)).isEqualTo(test);
         assertThat(DurationEchoFunctions.secondsEcho(test)).isEqualTo(test);
     }
+
+    @Test
+    public void DurationSubMillisecondTest() {
+        Duration test = Duration.ofNanos(1_500_250);
+        assertThat(DurationEchoFunctions.microsecondsEcho(test)).isEqualTo(Duration.ofNanos(1_500_000));
+        assertThat(DurationEchoFunctions.nanosecondsEcho(test)).isEqualTo(test);
+        assertThat(DurationEchoFunctions.millisecondsEcho(test)).isEqualTo(Duration.ofMillis(1));
+    }
+
+    @Test
+    public void DurationNegativeTest() {
+        Duration test = Duration.ofMillis(-2500);
+        assertThat(DurationEchoFunctions.signedMillisecondsEcho(test)).isEqualTo(test);
+    }
+
+    @Test
+    public void TimestampEchoTest() {
+        assertThat(TimestampFunctions.echo(Instant.EPOCH)).isEqualTo(Instant.EPOCH);
+        Instant value = Instant.ofEpochMilli(1_700_000_000_123L);
+        assertThat(TimestampFunctions.echo(value)).isEqualTo(value);
+        Instant beforeEpoch = Instant.ofEpochMilli(-86_400_000L);
+        assertThat(TimestampFunctions.echo(beforeEpoch)).isEqualTo(beforeEpoch);
+    }
+
+    @Test
+    public void TimestampOffsetTest() {
+        Instant value = Instant.ofEpochMilli(1000);
+        assertThat(TimestampFunctions.offset(value, Duration.ofMillis(500))).isEqualTo(Instant.ofEpochMilli(1500));
+        assertThat(TimestampFunctions.offset(value, Duration.ofMillis(-1500))).isEqualTo(Instant.ofEpochMilli(-500));
+    }
+
+    @Test
+    public void TimestampOptionalTest() {
+        Instant value = Instant.ofEpochMilli(42);
+        assertThat(TimestampFunctions.orEpoch(null)).isEqualTo(Instant.EPOCH);
+        assertThat(TimestampFunctions.orEpoch(value)).isEqualTo(value);
+    }
 }
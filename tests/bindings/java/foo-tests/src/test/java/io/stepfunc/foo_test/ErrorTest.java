@@ -3,6 +3,8 @@ package io.stepfunc.foo_test;
 import io.stepfunc.foo.*;
 import org.junit.jupiter.api.Test;
 
+import java.time.Duration;
+
 import static org.assertj.core.api.Assertions.assertThat;
 import static org.assertj.core.api.Assertions.assertThatThrownBy;
 import static org.joou.Unsigned.*;
@@ -45,6 +47,21 @@ public class ErrorTest {
         assertThat(result).isEqualTo("12345");
     }
 
+    @Test
+    public void PanicIsReportedAsError() {
+        try {
+            ClassWithPassword.panicWithError();
+            throw new AssertionError("expected an exception");
+        } catch (MyException ex) {
+            assertThat(ex.error).isEqualTo(MyError.PANIC);
+        }
+    }
+
+    @Test
+    public void PanicWithoutErrorReturnsDefault() {
+        assertThat(ClassWithPassword.panicWithoutError(uint(42))).isEqualTo(uint(0));
+    }
+
     @Test
     public void ConstructorWithError() {
         assertThatThrownBy(() -> {
@@ -54,4 +71,57 @@ public class ErrorTest {
         ClassWithPassword secret = new ClassWithPassword("12345");
         assertThat(secret.getSpecialValue()).isEqualTo(uint(42));
     }
+
+    @Test
+    public void ErrorWithoutDetail() {
+        Door.unlock(uint(1234));
+        try {
+            Door.unlock(uint(0));
+            throw new AssertionError("expected an exception");
+        } catch (LockException ex) {
+            assertThat(ex.error).isEqualTo(LockError.WRONG_CODE);
+            assertThat(ex.detail).isNull();
+            assertThat(ex.payload).isNull();
+            assertThat(ex.getMessage()).isEqualTo("WRONG_CODE");
+        }
+    }
+
+    @Test
+    public void ErrorWithMessage() {
+        try {
+            Door.unlock(uint(42));
+            throw new AssertionError("expected an exception");
+        } catch (LockException ex) {
+            assertThat(ex.error).isEqualTo(LockError.WRONG_CODE);
+            assertThat(ex.detail).isEqualTo("42 is not the code");
+            assertThat(ex.payload).isNull();
+            assertThat(ex.getMessage()).isEqualTo("WRONG_CODE: 42 is not the code");
+        }
+    }
+
+    @Test
+    public void ErrorWithPayload() {
+        try {
+            Door.unlock(uint(9999));
+            throw new AssertionError("expected an exception");
+        } catch (LockException ex) {
+            assertThat(ex.error).isEqualTo(LockError.LOCKED_OUT);
+            assertThat(ex.detail).isEqualTo("too many wrong codes");
+            assertThat(ex.payload.attempts).isEqualTo(uint(3));
+            assertThat(ex.payload.retryAfter).isEqualTo(Duration.ofSeconds(30));
+        }
+    }
+
+    @Test
+    public void ExceptionsShareBaseClass() {
+        assertThatThrownBy(() -> {
+            ClassWithPassword.validatePassword("hi!");
+        }).isInstanceOf(FooException.class);
+        assertThatThrownBy(() -> {
+            Door.unlock(uint(42));
+        }).isInstanceOf(DoorException.class).isNotInstanceOf(LockedOutException.class);
+        assertThatThrownBy(() -> {
+            Door.unlock(uint(9999));
+        }).isInstanceOf(LockedOutException.class).isInstanceOf(FooException.class);
+    }
 }
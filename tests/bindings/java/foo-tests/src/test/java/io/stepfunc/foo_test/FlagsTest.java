@@ -0,0 +1,59 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.FileMode;
+import io.stepfunc.foo.FlagsTestHelper;
+import io.stepfunc.foo.Permissions;
+import org.junit.jupiter.api.Test;
+
+import java.util.Arrays;
+import java.util.EnumSet;
+
+import static org.assertj.core.api.Assertions.assertThat;
+
+public class FlagsTest {
+
+    @Test
+    public void SetSemanticsTest() {
+        final Permissions none = Permissions.none();
+        assertThat(none.isEmpty()).isTrue();
+        assertThat(none.contains(Permissions.Flag.READ)).isFalse();
+
+        final Permissions readWrite = none.with(Permissions.Flag.READ).with(Permissions.Flag.WRITE);
+        assertThat(readWrite).isEqualTo(Permissions.of(Permissions.Flag.READ, Permissions.Flag.WRITE));
+        assertThat(readWrite.contains(Permissions.Flag.WRITE)).isTrue();
+        assertThat(readWrite.without(Permissions.Flag.WRITE)).isEqualTo(Permissions.of(Permissions.Flag.READ));
+
+        // the original set is unmodified
+        assertThat(none.isEmpty()).isTrue();
+
+        assertThat(Permissions.all().toEnumSet()).isEqualTo(EnumSet.allOf(Permissions.Flag.class));
+        assertThat(Permissions.of(Arrays.asList(Permissions.Flag.EXECUTE, Permissions.Flag.STICKY)))
+                .isEqualTo(Permissions.of(Permissions.Flag.EXECUTE, Permissions.Flag.STICKY));
+    }
+
+    @Test
+    public void FunctionsTest() {
+        final Permissions value = Permissions.of(Permissions.Flag.READ, Permissions.Flag.STICKY);
+        assertThat(FlagsTestHelper.echo(value)).isEqualTo(value);
+        assertThat(FlagsTestHelper.echo(Permissions.none())).isEqualTo(Permissions.none());
+        assertThat(FlagsTestHelper.combine(Permissions.of(Permissions.Flag.READ), Permissions.of(Permissions.Flag.EXECUTE)))
+                .isEqualTo(Permissions.of(Permissions.Flag.READ, Permissions.Flag.EXECUTE));
+    }
+
+    @Test
+    public void OptionalTest() {
+        final Permissions read = Permissions.of(Permissions.Flag.READ);
+        final Permissions write = Permissions.of(Permissions.Flag.WRITE);
+        assertThat(FlagsTestHelper.orDefault(null, read)).isEqualTo(read);
+        assertThat(FlagsTestHelper.orDefault(write, read)).isEqualTo(write);
+    }
+
+    @Test
+    public void StructFieldsTest() {
+        final FileMode mode = new FileMode(
+                Permissions.of(Permissions.Flag.READ, Permissions.Flag.WRITE),
+                Permissions.of(Permissions.Flag.READ, Permissions.Flag.EXECUTE)
+        );
+        assertThat(FlagsTestHelper.common(mode)).isEqualTo(Permissions.of(Permissions.Flag.READ));
+    }
+}
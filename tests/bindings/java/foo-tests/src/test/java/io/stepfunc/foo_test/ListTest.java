@@ -0,0 +1,57 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.ListTestHelper;
+import io.stepfunc.foo.Route;
+import io.stepfunc.foo.Waypoint;
+import org.joou.UShort;
+import org.junit.jupiter.api.Test;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.joou.Unsigned.uint;
+import static org.joou.Unsigned.ushort;
+
+public class ListTest {
+    @Test
+    public void ListArgumentsTest() {
+        List<Waypoint> waypoints = Arrays.asList(new Waypoint(1, 2), new Waypoint(-3, 7));
+        List<UShort> readings = Arrays.asList(ushort(100), ushort(200), ushort(300));
+        Route route = new Route(waypoints, readings);
+
+        assertThat(ListTestHelper.countWaypoints(route)).isEqualTo(uint(2));
+        assertThat(ListTestHelper.sumCoordinates(route)).isEqualTo(7);
+        assertThat(ListTestHelper.sumReadings(route)).isEqualTo(uint(600));
+    }
+
+    @Test
+    public void EmptyListsTest() {
+        Route route = new Route(new ArrayList<>(), Collections.emptyList());
+
+        assertThat(ListTestHelper.countWaypoints(route)).isEqualTo(uint(0));
+        assertThat(ListTestHelper.sumCoordinates(route)).isEqualTo(0);
+        assertThat(ListTestHelper.sumReadings(route)).isEqualTo(uint(0));
+    }
+
+    @Test
+    public void ReturnedListsTest() {
+        Route route = ListTestHelper.getSampleRoute();
+
+        assertThat(route.id).isEqualTo(uint(42));
+        assertThat(route.waypoints).hasSize(3);
+        assertThat(route.waypoints.get(0).x).isEqualTo(1);
+        assertThat(route.waypoints.get(2).y).isEqualTo(6);
+        assertThat(route.readings).containsExactly(ushort(10), ushort(20), ushort(30), ushort(40));
+    }
+
+    @Test
+    public void NullListIsRejected() {
+        Route route = new Route(null, Collections.emptyList());
+
+        assertThatThrownBy(() -> ListTestHelper.countWaypoints(route)).isInstanceOf(NullPointerException.class);
+    }
+}
@@ -0,0 +1,48 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.MapTestMethods;
+import io.stepfunc.foo.PortSettings;
+import org.joou.UShort;
+import org.junit.jupiter.api.Test;
+
+import java.util.HashMap;
+import java.util.Map;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.joou.Unsigned.uint;
+import static org.joou.Unsigned.ushort;
+
+public class MapTest {
+    @Test
+    public void StringMapTest() {
+        Map<String, String> values = new HashMap<>();
+        values.put("Hello", "World!");
+        values.put("Émile", "Zola");
+
+        assertThat(MapTestMethods.getStringMapSize(values)).isEqualTo(uint(2));
+        assertThat(MapTestMethods.getStringMapValue(values, "Hello")).isEqualTo("World!");
+        assertThat(MapTestMethods.getStringMapValue(values, "Émile")).isEqualTo("Zola");
+        assertThat(MapTestMethods.getStringMapValue(values, "missing")).isEqualTo("");
+    }
+
+    @Test
+    public void PortMapTest() {
+        Map<UShort, PortSettings> values = new HashMap<>();
+        values.put(ushort(20000), new PortSettings(true, ushort(5)));
+        values.put(ushort(20001), new PortSettings(false, ushort(10)));
+
+        assertThat(MapTestMethods.getPortMapSize(values)).isEqualTo(uint(2));
+        assertThat(MapTestMethods.getMaxConnections(values, ushort(20000))).isEqualTo(ushort(5));
+        assertThat(MapTestMethods.getMaxConnections(values, ushort(20001))).isEqualTo(ushort(0));
+        assertThat(MapTestMethods.getMaxConnections(values, ushort(20002))).isEqualTo(ushort(0));
+    }
+
+    @Test
+    public void NullValuesAreRejected() {
+        Map<String, String> values = new HashMap<>();
+        values.put("Hello", null);
+
+        assertThatThrownBy(() -> MapTestMethods.getStringMapSize(values)).isInstanceOf(NullPointerException.class);
+    }
+}
@@ -0,0 +1,62 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.OptionalLevel;
+import io.stepfunc.foo.OptionalReceiver;
+import io.stepfunc.foo.OptionalTests;
+import io.stepfunc.foo.OptionalValues;
+import org.joou.UShort;
+import org.junit.jupiter.api.Test;
+
+import java.time.Duration;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.joou.Unsigned.ushort;
+
+public class OptionalTest {
+
+    static class Receiver implements OptionalReceiver {
+        UShort number = null;
+        long presentCount = 0;
+
+        @Override
+        public void onNumber(UShort value) {
+            this.number = value;
+        }
+
+        @Override
+        public void onValues(OptionalValues values) {
+            this.presentCount = OptionalTests.countPresent(values).longValue();
+        }
+    }
+
+    @Test
+    public void ArgumentsTest() {
+        assertThat(OptionalTests.numberOr(null, 7)).isEqualTo(7);
+        assertThat(OptionalTests.numberOr(-42, 7)).isEqualTo(-42);
+
+        assertThat(OptionalTests.timeoutOr(null, Duration.ofSeconds(5))).isEqualTo(Duration.ofSeconds(5));
+        assertThat(OptionalTests.timeoutOr(Duration.ofSeconds(30), Duration.ofSeconds(5))).isEqualTo(Duration.ofSeconds(30));
+
+        assertThat(OptionalTests.levelOr(null, OptionalLevel.LOW)).isEqualTo(OptionalLevel.LOW);
+        assertThat(OptionalTests.levelOr(OptionalLevel.HIGH, OptionalLevel.LOW)).isEqualTo(OptionalLevel.HIGH);
+    }
+
+    @Test
+    public void StructTest() {
+        final OptionalValues values = new OptionalValues(false, null, 0.5, null, OptionalLevel.LOW);
+        assertThat(OptionalTests.countPresent(values).longValue()).isEqualTo(3);
+
+        values.withNumber(ushort(1)).withTimeout(Duration.ofMillis(1000));
+        assertThat(OptionalTests.countPresent(values).longValue()).isEqualTo(5);
+    }
+
+    @Test
+    public void ReceiverTest() {
+        final OptionalValues values = new OptionalValues(null, ushort(0xFFFF), null, Duration.ofMillis(250), null);
+        final Receiver receiver = new Receiver();
+
+        OptionalTests.invoke(values, receiver);
+        assertThat(receiver.number).isEqualTo(ushort(0xFFFF));
+        assertThat(receiver.presentCount).isEqualTo(2);
+    }
+}
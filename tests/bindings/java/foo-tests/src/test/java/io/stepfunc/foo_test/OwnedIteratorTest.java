@@ -0,0 +1,70 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.*;
+import org.joou.UInteger;
+import org.junit.jupiter.api.Test;
+
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.assertThatThrownBy;
+import static org.joou.Unsigned.ubyte;
+import static org.joou.Unsigned.uint;
+
+class OwnedIteratorTest {
+
+    @Test
+    void canIterateReturnedPrimitives() {
+        List<UInteger> values = new ArrayList<>();
+        try (NumberIterator iter = OwnedIteratorTestHelper.countUp(uint(1), uint(3))) {
+            for (UInteger value : iter) {
+                values.add(value);
+            }
+        }
+        assertThat(values.size()).isEqualTo(3);
+        assertThat(values.get(0)).isEqualTo(uint(1));
+        assertThat(values.get(1)).isEqualTo(uint(2));
+        assertThat(values.get(2)).isEqualTo(uint(3));
+    }
+
+    @Test
+    void canIterateReturnedStructs() {
+        StringBuilder builder = new StringBuilder();
+        try (CharacterIterator iter = OwnedIteratorTestHelper.getCharacters("abc")) {
+            for (CharacterValue character : iter) {
+                builder.append((char) character.value.intValue());
+            }
+        }
+        assertThat(builder.toString()).isEqualTo("abc");
+    }
+
+    @Test
+    void hasNextDoesNotSkipValues() {
+        try (NumberIterator iter = OwnedIteratorTestHelper.countUp(uint(1), uint(2))) {
+            Iterator<UInteger> it = iter.iterator();
+            assertThat(it.hasNext()).isTrue();
+            assertThat(it.hasNext()).isTrue();
+            assertThat(it.next()).isEqualTo(uint(1));
+            assertThat(it.next()).isEqualTo(uint(2));
+            assertThat(it.hasNext()).isFalse();
+        }
+    }
+
+    @Test
+    void canOnlyIterateOnce() {
+        try (NumberIterator iter = OwnedIteratorTestHelper.countUp(uint(1), uint(3))) {
+            iter.iterator();
+            assertThatThrownBy(iter::iterator).isInstanceOf(IllegalStateException.class);
+        }
+    }
+
+    @Test
+    void cannotIterateAfterClose() {
+        NumberIterator iter = OwnedIteratorTestHelper.countUp(uint(1), uint(3));
+        Iterator<UInteger> it = iter.iterator();
+        iter.close();
+        assertThatThrownBy(it::hasNext).isInstanceOf(IllegalStateException.class);
+    }
+}
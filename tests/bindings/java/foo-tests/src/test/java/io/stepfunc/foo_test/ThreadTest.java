@@ -11,7 +11,9 @@ import org.junit.jupiter.api.Disabled;
 
 import java.util.ArrayList;
 import java.util.List;
+import java.util.concurrent.CompletableFuture;
 import java.util.concurrent.ExecutionException;
+import java.util.concurrent.Flow;
 
 import static org.assertj.core.api.Assertions.*;
 import static org.joou.Unsigned.uint;
@@ -58,6 +60,112 @@ class ThreadTest {
         }
     }
 
+    @Test
+    void cancellingTheFutureCancelsTheOperation() throws Exception {
+
+        ThreadClass tc = new ThreadClass(uint(42), v -> {});
+
+        try {
+            CompletableFuture<UInteger> next = tc.nextValue().toCompletableFuture();
+            tc.update(uint(43));
+            assertThat(next.get()).isEqualTo(uint(43));
+
+            CompletableFuture<UInteger> cancelled = tc.nextValue().toCompletableFuture();
+            assertThat(cancelled.cancel(true)).isTrue();
+            assertThat(cancelled.isCancelled()).isTrue();
+            // the completion of the cancelled operation is ignored
+            tc.update(uint(44));
+        }
+        finally {
+            // explicitly shutdown the thread so that we can test post conditions
+            tc.shutdown();
+        }
+    }
+
+    @Test
+    void voidFutureCompletesWithoutValue() throws Exception {
+        List<UInteger> values = new ArrayList<>();
+        ThreadClass tc = new ThreadClass(uint(42), v -> values.add(v));
+
+        try {
+            tc.update(uint(43));
+            Void result = tc.flush().toCompletableFuture().get();
+            assertThat(result).isNull();
+            // the update queued before the flush has been processed
+            assertThat(values.size()).isEqualTo(1);
+            assertThat(values.get(0)).isEqualTo(uint(43));
+        }
+        finally {
+            // explicitly shutdown the thread so that we can test post conditions
+            tc.shutdown();
+        }
+    }
+
+    // requests the items one at a time and collects them
+    static class CollectingSubscriber implements Flow.Subscriber<UInteger> {
+        final List<UInteger> items = new ArrayList<>();
+        final CompletableFuture<List<UInteger>> result = new CompletableFuture<>();
+        Flow.Subscription subscription;
+
+        @Override
+        public void onSubscribe(Flow.Subscription subscription) {
+            this.subscription = subscription;
+            subscription.request(1);
+        }
+
+        @Override
+        public void onNext(UInteger item) {
+            items.add(item);
+            subscription.request(1);
+        }
+
+        @Override
+        public void onError(Throwable throwable) {
+            result.completeExceptionally(throwable);
+        }
+
+        @Override
+        public void onComplete() {
+            result.complete(items);
+        }
+    }
+
+    @Test
+    void streamPublishesValuesThenCompletes() throws Exception {
+        ThreadClass tc = new ThreadClass(uint(42), v -> {});
+
+        try {
+            CollectingSubscriber subscriber = new CollectingSubscriber();
+            tc.values(uint(3)).subscribe(subscriber);
+            assertThat(subscriber.result.get()).containsExactly(uint(42), uint(43), uint(44));
+        }
+        finally {
+            tc.shutdown();
+        }
+    }
+
+    @Test
+    void streamSignalsFailureAfterValues() throws Exception {
+        ThreadClass tc = new ThreadClass(uint(42), v -> {});
+
+        CollectingSubscriber subscriber = new CollectingSubscriber();
+        try {
+            tc.queueError(MathIsBroken.MATH_IS_BROKE);
+            tc.values(uint(2)).subscribe(subscriber);
+            subscriber.result.get();
+            fail("Exception not thrown");
+        }
+        catch(ExecutionException ex) {
+            BrokenMathException cause = (BrokenMathException) ex.getCause();
+            assertThat(cause.error).isEqualTo(MathIsBroken.MATH_IS_BROKE);
+            // the values produced before the failure are delivered
+            assertThat(subscriber.items).containsExactly(uint(42), uint(43));
+        }
+        finally {
+            tc.shutdown();
+        }
+    }
+
     @Test
     void promiseStillCompletesIfDropped() throws Exception {
 
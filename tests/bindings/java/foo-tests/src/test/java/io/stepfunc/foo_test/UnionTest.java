@@ -0,0 +1,52 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.Circle;
+import io.stepfunc.foo.Rectangle;
+import io.stepfunc.foo.Shape;
+import io.stepfunc.foo.ShapeReceiver;
+import io.stepfunc.foo.UnionTests;
+import org.junit.jupiter.api.Test;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.assertj.core.api.Assertions.within;
+
+public class UnionTest {
+
+    static class Receiver implements ShapeReceiver {
+        Shape shape = null;
+
+        @Override
+        public void onShape(Shape shape) {
+            this.shape = shape;
+        }
+    }
+
+    @Test
+    public void ArgumentTest() {
+        assertThat(UnionTests.area(new Shape.Circle(new Circle(2.0)))).isCloseTo(4.0 * Math.PI, within(1e-9));
+        assertThat(UnionTests.area(new Shape.Rectangle(new Rectangle(2.0, 3.0)))).isEqualTo(6.0);
+        // square shares the payload type of rectangle but is still a distinct variant
+        assertThat(UnionTests.area(new Shape.Square(new Rectangle(3.0, 3.0)))).isEqualTo(9.0);
+    }
+
+    @Test
+    public void ReturnValueTest() {
+        final Shape circle = UnionTests.scale(new Shape.Circle(new Circle(2.0)), 2.0);
+        assertThat(circle).isInstanceOf(Shape.Circle.class);
+        assertThat(((Shape.Circle) circle).value.radius).isEqualTo(4.0);
+
+        final Shape square = UnionTests.scale(new Shape.Square(new Rectangle(1.5, 1.5)), 2.0);
+        assertThat(square).isInstanceOf(Shape.Square.class);
+        assertThat(((Shape.Square) square).value.width).isEqualTo(3.0);
+        assertThat(((Shape.Square) square).value.height).isEqualTo(3.0);
+    }
+
+    @Test
+    public void ReceiverTest() {
+        final Receiver receiver = new Receiver();
+        UnionTests.invoke(new Shape.Rectangle(new Rectangle(4.0, 0.5)), receiver);
+        assertThat(receiver.shape).isInstanceOf(Shape.Rectangle.class);
+        assertThat(((Shape.Rectangle) receiver.shape).value.width).isEqualTo(4.0);
+        assertThat(((Shape.Rectangle) receiver.shape).value.height).isEqualTo(0.5);
+    }
+}
@@ -0,0 +1,75 @@
+package io.stepfunc.foo_test;
+
+import io.stepfunc.foo.*;
+import org.joou.UInteger;
+import org.junit.jupiter.api.Test;
+
+import java.time.Duration;
+import java.util.ArrayList;
+import java.util.List;
+
+import static org.assertj.core.api.Assertions.assertThat;
+import static org.joou.Unsigned.uint;
+
+class ValueIteratorTest {
+
+    @Test
+    void canIterateStrings() {
+        List<String> words = new ArrayList<>();
+        try (WordIterator iter = ValueIteratorTestHelper.splitWords("hello big  world")) {
+            for (String word : iter) {
+                words.add(word);
+            }
+        }
+        assertThat(words).containsExactly("hello", "big", "world");
+    }
+
+    @Test
+    void canIterateEnums() {
+        List<CompassDirection> directions = new ArrayList<>();
+        try (CompassDirectionIterator iter = ValueIteratorTestHelper.getDirections()) {
+            for (CompassDirection direction : iter) {
+                directions.add(direction);
+            }
+        }
+        assertThat(directions).containsExactly(
+                CompassDirection.NORTH,
+                CompassDirection.EAST,
+                CompassDirection.SOUTH,
+                CompassDirection.WEST
+        );
+    }
+
+    @Test
+    void canIterateDurations() {
+        List<Duration> intervals = new ArrayList<>();
+        try (IntervalIterator iter = ValueIteratorTestHelper.getIntervals(uint(3), Duration.ofMillis(250))) {
+            for (Duration interval : iter) {
+                intervals.add(interval);
+            }
+        }
+        assertThat(intervals).containsExactly(Duration.ofMillis(250), Duration.ofMillis(500), Duration.ofMillis(750));
+    }
+
+    @Test
+    void canIterateSignedDurations() {
+        List<Duration> offsets = new ArrayList<>();
+        try (OffsetIterator iter = ValueIteratorTestHelper.getOffsets(uint(3), Duration.ofMillis(250))) {
+            for (Duration offset : iter) {
+                offsets.add(offset);
+            }
+        }
+        assertThat(offsets).containsExactly(Duration.ofMillis(-250), Duration.ofMillis(-500), Duration.ofMillis(-750));
+    }
+
+    @Test
+    void canIterateClasses() {
+        List<UInteger> indices = new ArrayList<>();
+        try (IndexedItemIterator iter = ValueIteratorTestHelper.getIndexedItems(uint(3))) {
+            for (IndexedItem item : iter) {
+                indices.add(item.getIndex());
+            }
+        }
+        assertThat(indices).containsExactly(uint(0), uint(1), uint(2));
+    }
+}
@@ -0,0 +1,40 @@
+pub struct ByteClass {
+    value: Vec<u8>,
+}
+
+impl ByteClass {
+    fn new() -> Self {
+        Self { value: Vec::new() }
+    }
+}
+
+pub unsafe fn byte_class_create() -> *mut ByteClass {
+    let byte_class = Box::new(ByteClass::new());
+    Box::into_raw(byte_class)
+}
+
+pub unsafe fn byte_class_destroy(byte_class: *mut ByteClass) {
+    if !byte_class.is_null() {
+        drop(Box::from_raw(byte_class));
+    }
+}
+
+pub fn byte_class_echo<'a>(
+    byte_class: &'a mut ByteClass,
+    value: &[u8],
+) -> Result<&'a [u8], crate::ffi::ByteError> {
+    byte_class.value = value.to_vec();
+    Ok(&byte_class.value)
+}
+
+pub unsafe fn byte_length(value: &[u8]) -> u32 {
+    value.len() as u32
+}
+
+pub(crate) fn invoke_byte_receiver(
+    value: crate::ffi::BytePayload,
+    receiver: crate::ffi::ByteReceiver,
+) -> u32 {
+    receiver.on_bytes(value.data());
+    receiver.on_payload(value).unwrap_or(0)
+}
@@ -49,33 +49,22 @@ pub unsafe fn callback_source_destroy(cb_source: *mut CallbackSource) {
     }
 }
 
-pub unsafe fn callback_source_set_interface(
-    cb_source: *mut CallbackSource,
-    cb: ffi::CallbackInterface,
-) {
-    let cb_source = cb_source.as_mut().unwrap();
+pub fn callback_source_set_interface(cb_source: &mut CallbackSource, cb: ffi::CallbackInterface) {
     cb_source.set(cb);
 }
 
-pub unsafe fn callback_source_set_value(cb_source: *mut CallbackSource, value: u32) -> u32 {
-    let cb_source = cb_source.as_mut().unwrap();
+pub fn callback_source_set_value(cb_source: &mut CallbackSource, value: u32) -> u32 {
     cb_source.set_value(value)
 }
 
-pub unsafe fn callback_source_set_duration(
-    cb_source: *mut CallbackSource,
-    value: Duration,
-) -> Duration {
-    let cb_source = cb_source.as_mut().unwrap();
+pub fn callback_source_set_duration(cb_source: &mut CallbackSource, value: Duration) -> Duration {
     cb_source.set_duration(value)
 }
 
 pub unsafe fn callback_source_invoke_on_names(
-    cb_source: *mut crate::CallbackSource,
+    cb_source: &crate::CallbackSource,
     names: ffi::Names,
 ) {
-    let cb_source = cb_source.as_mut().unwrap();
-
     let names = ffi::NamesFields {
         first_name: names.first_name(),
         last_name: names.last_name(),
@@ -92,8 +81,7 @@ pub struct NamesIter {
     names: ffi::Names,
 }
 
-pub(crate) unsafe fn names_iter_next<'a>(iter: *mut crate::NamesIter) -> Option<&'a ffi::Names> {
-    let iter = iter.as_mut().unwrap();
+pub(crate) fn names_iter_next(iter: &mut crate::NamesIter) -> Option<&ffi::Names> {
     let (first, last) = match iter.pos {
         0 => ("jane", "doe"),
         1 => ("jake", "sully"),
@@ -108,10 +96,7 @@ pub(crate) unsafe fn names_iter_next<'a>(iter: *mut crate::NamesIter) -> Option<
     Some(&iter.names)
 }
 
-pub(crate) unsafe fn callback_source_invoke_on_several_names(
-    cb_source: *mut crate::CallbackSource,
-) {
-    let cb_source = cb_source.as_mut().unwrap();
+pub(crate) fn callback_source_invoke_on_several_names(cb_source: &crate::CallbackSource) {
     let mut iter = NamesIter {
         pos: 0,
         current: (Default::default(), Default::default()),
@@ -1,32 +1,64 @@
+use std::time::Duration;
+
 static mut CONSTRUCTION_COUNTER: u32 = 0;
 
 pub struct TestClass {
     value: u32,
+    step: u32,
+    increments: u32,
 }
 
 pub unsafe fn test_class_create(value: u32) -> *mut TestClass {
     CONSTRUCTION_COUNTER += 1;
-    let testclass = Box::new(TestClass { value });
+    let testclass = Box::new(TestClass {
+        value,
+        step: 1,
+        increments: 0,
+    });
     Box::into_raw(testclass)
 }
 
 pub unsafe fn test_class_destroy(testclass: *mut TestClass) {
-    CONSTRUCTION_COUNTER -= 1;
     if !testclass.is_null() {
+        CONSTRUCTION_COUNTER -= 1;
         drop(Box::from_raw(testclass));
     };
 }
 
-pub unsafe fn test_class_get_value(testclass: *const TestClass) -> u32 {
-    let testclass = testclass.as_ref().unwrap();
+pub fn test_class_get_value(testclass: &TestClass) -> u32 {
     testclass.value
 }
 
-pub unsafe fn test_class_increment_value(testclass: *mut TestClass) {
-    let testclass = testclass.as_mut().unwrap();
-    testclass.value += 1;
+pub fn test_class_increment_value(testclass: &mut TestClass) {
+    testclass.value += testclass.step;
+    testclass.increments += 1;
+}
+
+pub fn test_class_add_value(testclass: &mut TestClass, amount: u32, repeat: u32) -> u32 {
+    testclass.value += amount * repeat;
+    testclass.value
+}
+
+pub fn test_class_get_step(testclass: &TestClass) -> u32 {
+    testclass.step
+}
+
+pub fn test_class_set_step(testclass: &mut TestClass, step: u32) {
+    testclass.step = step;
+}
+
+pub fn test_class_get_increments(testclass: &TestClass) -> u32 {
+    testclass.increments
 }
 
 pub unsafe fn construction_counter() -> u32 {
     CONSTRUCTION_COUNTER
 }
+
+pub fn add_twice(adder: crate::ffi::ValueAdder, amount: u32) -> u32 {
+    adder.add_value(amount, 2).unwrap_or(0)
+}
+
+pub fn scale_duration(duration: Duration, factor: u32) -> Duration {
+    duration * factor
+}
@@ -41,11 +41,8 @@ pub unsafe fn string_collection_destroy(col: *mut StringCollection) {
     }
 }
 
-pub unsafe fn string_collection_add(col: *mut StringCollection, value: &CStr) {
-    if let Some(col) = col.as_mut() {
-        let value = value.to_owned();
-        col.add(value);
-    }
+pub fn string_collection_add(col: &mut StringCollection, value: &CStr) {
+    col.add(value.to_owned());
 }
 
 pub unsafe fn string_collection_with_reserve_create(
@@ -61,14 +58,8 @@ pub unsafe fn string_collection_with_reserve_destroy(col: *mut StringCollectionW
     }
 }
 
-pub unsafe fn string_collection_with_reserve_add(
-    col: *mut StringCollectionWithReserve,
-    value: &CStr,
-) {
-    if let Some(col) = col.as_mut() {
-        let value = value.to_owned();
-        col.add(value);
-    }
+pub fn string_collection_with_reserve_add(col: &mut StringCollectionWithReserve, value: &CStr) {
+    col.add(value.to_owned());
 }
 
 pub unsafe fn collection_size(col: *mut StringCollection) -> u32 {
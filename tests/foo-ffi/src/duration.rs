@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 pub fn duration_ms_echo(value: Duration) -> Duration {
     value
@@ -7,3 +7,36 @@ pub fn duration_ms_echo(value: Duration) -> Duration {
 pub fn duration_s_echo(value: Duration) -> Duration {
     value
 }
+
+pub fn duration_us_echo(value: Duration) -> Duration {
+    value
+}
+
+pub fn duration_ns_echo(value: Duration) -> Duration {
+    value
+}
+
+pub fn duration_signed_ms_echo(value: i64) -> i64 {
+    value
+}
+
+pub fn duration_float_s_echo(value: Duration) -> Duration {
+    value
+}
+
+pub fn timestamp_echo(value: SystemTime) -> SystemTime {
+    value
+}
+
+pub fn timestamp_offset(value: SystemTime, offset: i64) -> SystemTime {
+    let magnitude = Duration::from_millis(offset.unsigned_abs());
+    if offset >= 0 {
+        value + magnitude
+    } else {
+        value - magnitude
+    }
+}
+
+pub fn timestamp_or_epoch(value: Option<SystemTime>) -> SystemTime {
+    value.unwrap_or(SystemTime::UNIX_EPOCH)
+}
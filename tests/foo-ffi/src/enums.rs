@@ -1,4 +1,4 @@
-use crate::ffi::{EnumDisjoint, EnumOneToSix, EnumSingle, EnumZeroToFive};
+use crate::ffi::{EnumChecked, EnumDisjoint, EnumOneToSix, EnumSingle, EnumZeroToFive};
 
 pub fn enum_zero_to_five_echo(value: EnumZeroToFive) -> EnumZeroToFive {
     value
@@ -15,3 +15,7 @@ pub fn enum_disjoint_echo(value: EnumDisjoint) -> EnumDisjoint {
 pub fn enum_single_echo(value: EnumSingle) -> EnumSingle {
     value
 }
+
+pub fn enum_checked_echo(value: EnumChecked) -> Result<EnumChecked, crate::ffi::EnumError> {
+    Ok(value)
+}
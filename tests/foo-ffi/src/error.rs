@@ -1,5 +1,6 @@
 use std::ffi::CStr;
 use std::str::Utf8Error;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 pub struct ClassWithPassword {
     value: u32,
@@ -32,6 +33,25 @@ pub(crate) fn echo_password(password: &CStr) -> std::result::Result<&CStr, crate
     }
 }
 
+pub(crate) fn panic_with_error() -> std::result::Result<u32, crate::ffi::MyError> {
+    panic!("panic with an error type")
+}
+
+pub(crate) fn panic_without_error(value: u32) -> u32 {
+    panic!("panic without an error type: {value}")
+}
+
+static PANIC_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+pub(crate) fn log_panic(message: &str) {
+    PANIC_COUNTER.fetch_add(1, Ordering::SeqCst);
+    eprintln!("{message}");
+}
+
+pub(crate) fn panic_count() -> u32 {
+    PANIC_COUNTER.load(Ordering::SeqCst)
+}
+
 pub(crate) fn class_with_password_create(
     password: &CStr,
 ) -> std::result::Result<*mut crate::ClassWithPassword, crate::ffi::MyError> {
@@ -44,13 +64,10 @@ pub(crate) fn class_with_password_create(
     }
 }
 
-pub(crate) unsafe fn class_with_password_get_special_value(
-    instance: *mut crate::ClassWithPassword,
+pub(crate) fn class_with_password_get_special_value(
+    instance: &crate::ClassWithPassword,
 ) -> Result<u32, crate::ffi::MyError> {
-    match instance.as_ref() {
-        Some(x) => Ok(x.value),
-        None => Err(crate::ffi::MyError::NullArgument),
-    }
+    Ok(instance.value)
 }
 
 pub(crate) unsafe fn class_with_password_destroy(instance: *mut crate::ClassWithPassword) {
@@ -64,3 +81,25 @@ impl From<Utf8Error> for crate::ffi::MyError {
         crate::ffi::MyError::BadPassword
     }
 }
+
+const DOOR_CODE: u32 = 1234;
+const LOCKOUT_CODE: u32 = 9999;
+
+pub(crate) fn door_unlock(code: u32) -> Result<(), crate::ffi::LockErrorDetail> {
+    match code {
+        DOOR_CODE => Ok(()),
+        0 => Err(crate::ffi::LockError::WrongCode.into()),
+        LOCKOUT_CODE => Err(
+            crate::ffi::LockErrorDetail::new(crate::ffi::LockError::LockedOut)
+                .with_message("too many wrong codes")
+                .with_payload(crate::ffi::LockoutInfoFields {
+                    attempts: 3,
+                    retry_after: std::time::Duration::from_secs(30),
+                }),
+        ),
+        _ => Err(
+            crate::ffi::LockErrorDetail::new(crate::ffi::LockError::WrongCode)
+                .with_message(format!("{code} is not the code")),
+        ),
+    }
+}
@@ -0,0 +1,20 @@
+use crate::ffi::{FileMode, Permissions};
+
+pub fn permissions_echo(value: Permissions) -> Permissions {
+    value
+}
+
+pub fn permissions_combine(first: Permissions, second: Permissions) -> Permissions {
+    first | second
+}
+
+pub fn permissions_or_default(
+    value: Option<Permissions>,
+    default_value: Permissions,
+) -> Permissions {
+    value.unwrap_or(default_value)
+}
+
+pub fn file_mode_common(mode: FileMode) -> Permissions {
+    mode.owner() & mode.other()
+}
@@ -28,16 +28,7 @@ pub unsafe fn invoke_callback(values: &CStr, callback: ffi::ValuesReceiver) {
     callback.on_characters(&mut iter)
 }
 
-pub unsafe fn string_iterator_next<'a>(
-    value: *mut StringIterator,
-) -> Option<&'a ffi::StringIteratorItem> {
-    if let Some(it) = value.as_mut() {
-        it.next();
-        match &it.current {
-            Some(val) => Some(val),
-            None => None,
-        }
-    } else {
-        None
-    }
+pub fn string_iterator_next(value: &mut StringIterator) -> Option<&ffi::StringIteratorItem> {
+    value.next();
+    value.current.as_ref()
 }
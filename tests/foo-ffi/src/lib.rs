@@ -2,39 +2,55 @@
 
 extern crate alloc;
 
+pub use bytes::*;
 pub use callback::*;
 pub use class::*;
 pub use collection::*;
 pub use duration::*;
 pub use enums::*;
 pub use error::*;
+pub use flags::*;
 pub use integer::*;
 pub(crate) use interface_defaults::*;
 pub use iterator::*;
 pub use lifetime::*;
+pub(crate) use list::*;
+pub use map::*;
 pub use opaque_struct::*;
+pub(crate) use optional::*;
+pub use owned_iterator::*;
 pub use primitive_iterator::*;
 pub use primitive_pointers::*;
 pub use strings::*;
 pub use thread_class::*;
+pub(crate) use unions::*;
 use universal::*;
+pub use value_iterator::*;
 
+mod bytes;
 mod callback;
 mod class;
 mod collection;
 mod duration;
 mod enums;
 mod error;
+mod flags;
 mod integer;
 mod interface_defaults;
 mod iterator;
 mod lifetime;
+mod list;
+mod map;
 mod opaque_struct;
+mod optional;
+mod owned_iterator;
 mod primitive_iterator;
 mod primitive_pointers;
 mod strings;
 mod thread_class;
+mod unions;
 mod universal;
+mod value_iterator;
 
 #[allow(clippy::extra_unused_lifetimes)]
 #[allow(clippy::needless_lifetimes)]
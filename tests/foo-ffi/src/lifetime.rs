@@ -5,15 +5,9 @@ pub struct InnerByteIterator<'a> {
     values: &'a [u8],
 }
 
-pub unsafe fn inner_byte_iterator_next(
-    it: *mut crate::InnerByteIterator,
-) -> Option<&crate::ffi::ByteValue> {
-    if it.is_null() {
-        return None;
-    }
-
-    let it = &mut *it;
-
+pub fn inner_byte_iterator_next<'a>(
+    it: &'a mut crate::InnerByteIterator,
+) -> Option<&'a crate::ffi::ByteValue> {
     match it.values {
         [] => None,
         [x, tail @ ..] => {
@@ -30,11 +24,9 @@ pub struct ChunkIterator<'a> {
     chunk_size: usize,
 }
 
-pub unsafe fn chunk_iterator_next(it: *mut crate::ChunkIterator) -> Option<&crate::ffi::Chunk> {
-    if it.is_null() {
-        return None;
-    }
-    let it = &mut (*it);
+pub unsafe fn chunk_iterator_next<'a, 'b>(
+    it: &'a mut crate::ChunkIterator<'b>,
+) -> Option<&'a crate::ffi::Chunk<'b>> {
     match it.bytes.get(..it.chunk_size) {
         None => None,
         Some(chunk) => {
@@ -0,0 +1,30 @@
+use crate::ffi;
+
+static SAMPLE_WAYPOINTS: [ffi::Waypoint; 3] = [
+    ffi::Waypoint { x: 1, y: 2 },
+    ffi::Waypoint { x: 3, y: 4 },
+    ffi::Waypoint { x: 5, y: 6 },
+];
+
+static SAMPLE_READINGS: [u16; 4] = [10, 20, 30, 40];
+
+pub(crate) fn route_count_waypoints(route: ffi::Route) -> u32 {
+    route.waypoints().len() as u32
+}
+
+pub(crate) fn route_sum_coordinates(route: ffi::Route) -> i32 {
+    route.waypoints().iter().map(|w| w.x + w.y).sum()
+}
+
+pub(crate) fn route_sum_readings(route: ffi::Route) -> u32 {
+    route.readings().iter().map(|x| *x as u32).sum()
+}
+
+pub(crate) fn get_sample_route() -> ffi::Route {
+    ffi::RouteFields {
+        id: 42,
+        waypoints: &SAMPLE_WAYPOINTS,
+        readings: &SAMPLE_READINGS,
+    }
+    .into()
+}
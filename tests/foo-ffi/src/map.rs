@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+
+use crate::ffi;
+
+pub struct StringMap {
+    values: HashMap<CString, CString>,
+}
+
+pub struct PortMap {
+    values: HashMap<u16, ffi::PortSettings>,
+}
+
+pub unsafe fn string_map_create() -> *mut StringMap {
+    Box::into_raw(Box::new(StringMap {
+        values: HashMap::new(),
+    }))
+}
+
+pub unsafe fn string_map_destroy(map: *mut StringMap) {
+    if !map.is_null() {
+        drop(Box::from_raw(map));
+    }
+}
+
+pub fn string_map_insert(map: &mut StringMap, key: &CStr, value: &CStr) {
+    map.values.insert(key.to_owned(), value.to_owned());
+}
+
+pub unsafe fn port_map_create() -> *mut PortMap {
+    Box::into_raw(Box::new(PortMap {
+        values: HashMap::new(),
+    }))
+}
+
+pub unsafe fn port_map_destroy(map: *mut PortMap) {
+    if !map.is_null() {
+        drop(Box::from_raw(map));
+    }
+}
+
+pub fn port_map_insert(map: &mut PortMap, key: u16, value: ffi::PortSettings) {
+    map.values.insert(key, value);
+}
+
+pub unsafe fn string_map_size(map: *mut StringMap) -> u32 {
+    match map.as_ref() {
+        Some(map) => map.values.len() as u32,
+        None => 0,
+    }
+}
+
+pub unsafe fn string_map_get<'a>(map: *mut StringMap, key: &CStr) -> &'a CStr {
+    static EMPTY: &[u8] = b"\0";
+    match map.as_ref().and_then(|map| map.values.get(key)) {
+        Some(value) => value,
+        None => CStr::from_bytes_with_nul_unchecked(EMPTY),
+    }
+}
+
+pub unsafe fn port_map_size(map: *mut PortMap) -> u32 {
+    match map.as_ref() {
+        Some(map) => map.values.len() as u32,
+        None => 0,
+    }
+}
+
+pub unsafe fn port_map_get_max_connections(map: *mut PortMap, port: u16) -> u16 {
+    match map.as_ref().and_then(|map| map.values.get(&port)) {
+        Some(settings) if settings.enabled => settings.max_connections,
+        _ => 0,
+    }
+}
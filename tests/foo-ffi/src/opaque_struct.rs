@@ -1,5 +1,5 @@
-pub fn opaque_struct_get_id(value: Option<&crate::ffi::OpaqueStruct>) -> u64 {
-    value.unwrap().id
+pub fn opaque_struct_get_id(value: &crate::ffi::OpaqueStruct) -> u64 {
+    value.id
 }
 
 pub fn opaque_struct_magic_init() -> crate::ffi::OpaqueStruct {
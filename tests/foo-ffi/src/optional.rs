@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use crate::ffi;
+
+pub(crate) fn optional_count_present(values: ffi::OptionalValues) -> u32 {
+    [
+        values.flag().is_some(),
+        values.number().is_some(),
+        values.ratio().is_some(),
+        values.timeout().is_some(),
+        values.level().is_some(),
+    ]
+    .iter()
+    .filter(|x| **x)
+    .count() as u32
+}
+
+pub(crate) fn optional_number_or(value: Option<i32>, default_value: i32) -> i32 {
+    value.unwrap_or(default_value)
+}
+
+pub(crate) fn optional_timeout_or(value: Option<Duration>, default_value: Duration) -> Duration {
+    value.unwrap_or(default_value)
+}
+
+pub(crate) fn optional_level_or(
+    value: Option<ffi::OptionalLevel>,
+    default_value: ffi::OptionalLevel,
+) -> ffi::OptionalLevel {
+    value.unwrap_or(default_value)
+}
+
+pub(crate) fn invoke_optional_receiver(
+    values: ffi::OptionalValues,
+    receiver: ffi::OptionalReceiver,
+) {
+    receiver.on_number(values.number());
+    receiver.on_values(values);
+}
@@ -0,0 +1,58 @@
+use std::ffi::CStr;
+
+use crate::ffi;
+
+pub struct NumberIterator {
+    current: u32,
+    next: u32,
+    max: u32,
+}
+
+pub struct CharacterIterator {
+    iter: std::vec::IntoIter<u8>,
+    current: Option<ffi::CharacterValue>,
+}
+
+pub(crate) unsafe fn count_up(min: u32, max: u32) -> *mut NumberIterator {
+    Box::into_raw(Box::new(NumberIterator {
+        current: min,
+        next: min,
+        max,
+    }))
+}
+
+pub(crate) fn number_iterator_next(it: &mut NumberIterator) -> *const u32 {
+    if it.next > it.max {
+        return std::ptr::null();
+    }
+    it.current = it.next;
+    it.next += 1;
+    &it.current
+}
+
+pub(crate) unsafe fn number_iterator_destroy(it: *mut NumberIterator) {
+    if !it.is_null() {
+        drop(Box::from_raw(it));
+    }
+}
+
+pub(crate) unsafe fn get_characters(value: &CStr) -> *mut CharacterIterator {
+    Box::into_raw(Box::new(CharacterIterator {
+        iter: value.to_bytes().to_vec().into_iter(),
+        current: None,
+    }))
+}
+
+pub(crate) fn character_iterator_next(it: &mut CharacterIterator) -> Option<&ffi::CharacterValue> {
+    it.current = it
+        .iter
+        .next()
+        .map(|value| ffi::CharacterValueFields { value });
+    it.current.as_ref()
+}
+
+pub(crate) unsafe fn character_iterator_destroy(it: *mut CharacterIterator) {
+    if !it.is_null() {
+        drop(Box::from_raw(it));
+    }
+}
@@ -14,8 +14,7 @@ impl RangeIterator {
     }
 }
 
-pub(crate) fn range_iterator_next(it: *mut RangeIterator) -> *const u32 {
-    let it = unsafe { it.as_mut().unwrap() };
+pub(crate) fn range_iterator_next(it: &mut RangeIterator) -> *const u32 {
     if it.next > it.max {
         return std::ptr::null();
     }
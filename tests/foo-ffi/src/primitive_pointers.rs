@@ -24,38 +24,34 @@ pub(crate) unsafe fn primitive_pointers_destroy(instance: *mut crate::PrimitiveP
     drop(Box::from_raw(instance));
 }
 
-pub(crate) unsafe fn primitive_pointers_get_bool(
-    instance: *mut crate::PrimitivePointers,
+pub(crate) fn primitive_pointers_get_bool(
+    instance: &mut crate::PrimitivePointers,
     value: bool,
 ) -> *const bool {
-    let instance = instance.as_mut().unwrap();
     instance.bool_value = value;
     &instance.bool_value
 }
 
-pub(crate) unsafe fn primitive_pointers_get_u8(
-    instance: *mut crate::PrimitivePointers,
+pub(crate) fn primitive_pointers_get_u8(
+    instance: &mut crate::PrimitivePointers,
     value: u8,
 ) -> *const u8 {
-    let instance = instance.as_mut().unwrap();
     instance.u8_value = value;
     &instance.u8_value
 }
 
-pub(crate) unsafe fn primitive_pointers_get_float(
-    instance: *mut crate::PrimitivePointers,
+pub(crate) fn primitive_pointers_get_float(
+    instance: &mut crate::PrimitivePointers,
     value: f32,
 ) -> *const f32 {
-    let instance = instance.as_mut().unwrap();
     instance.float_value = value;
     &instance.float_value
 }
 
-pub(crate) unsafe fn primitive_pointers_get_double(
-    instance: *mut crate::PrimitivePointers,
+pub(crate) fn primitive_pointers_get_double(
+    instance: &mut crate::PrimitivePointers,
     value: f64,
 ) -> *const f64 {
-    let instance = instance.as_mut().unwrap();
     instance.double_value = value;
     &instance.double_value
 }
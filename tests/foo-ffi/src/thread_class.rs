@@ -1,6 +1,8 @@
-use crate::ffi::{AddHandler, MathIsBroken};
+use crate::ffi::{AddHandler, FlushHandler, ValueStream};
 use std::thread::JoinHandle;
 
+type Reply = Box<dyn FnOnce(Result<u32, crate::ffi::MathIsBroken>) + Send + Sync + 'static>;
+
 enum Message {
     Update(u32),
     Add(
@@ -9,6 +11,9 @@ enum Message {
     ),
     QueueAddError(crate::ffi::MathIsBroken),
     DropAdd,
+    NextValue(Reply),
+    Flush(Box<dyn FnOnce(Result<(), crate::ffi::MathIsBroken>) + Send + Sync + 'static>),
+    Values(u32, ValueStream),
     Operation(crate::ffi::Operation),
     Stop,
 }
@@ -17,6 +22,7 @@ struct ThreadData {
     value: u32,
     error_queue: Vec<crate::ffi::MathIsBroken>,
     drop_add: bool,
+    next_values: Vec<Reply>,
     receiver: crate::ffi::ValueChangeListener,
     rx: std::sync::mpsc::Receiver<Message>,
 }
@@ -46,6 +52,9 @@ fn run(mut data: ThreadData) {
             Message::Update(x) => {
                 data.value = x;
                 data.receiver.on_value_change(x);
+                for reply in data.next_values.drain(..) {
+                    reply(Ok(x));
+                }
             }
             Message::Add(x, reply) => {
                 if data.drop_add {
@@ -70,6 +79,17 @@ fn run(mut data: ThreadData) {
             Message::DropAdd => {
                 data.drop_add = true;
             }
+            Message::NextValue(reply) => data.next_values.push(reply),
+            Message::Flush(reply) => reply(Ok(())),
+            Message::Values(count, stream) => {
+                for x in 0..count {
+                    stream.on_item(data.value + x);
+                }
+                match data.error_queue.pop() {
+                    Some(err) => stream.on_failure(err),
+                    None => stream.on_complete(),
+                }
+            }
         }
     }
 }
@@ -83,6 +103,7 @@ pub(crate) fn thread_class_create(
         value,
         error_queue: Default::default(),
         drop_add: false,
+        next_values: Vec::new(),
         receiver,
         rx,
     };
@@ -97,54 +118,46 @@ pub(crate) unsafe fn thread_class_destroy(instance: *mut ThreadClass) {
     }
 }
 
-pub(crate) unsafe fn thread_class_update(instance: *mut ThreadClass, value: u32) {
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::Update(value)).unwrap()
-    }
+pub(crate) fn thread_class_update(instance: &ThreadClass, value: u32) {
+    instance.tx.send(Message::Update(value)).unwrap()
 }
 
-impl sfio_promise::FutureType<Result<u32, crate::ffi::MathIsBroken>> for AddHandler {
-    fn on_drop() -> Result<u32, MathIsBroken> {
-        Err(MathIsBroken::Dropped)
-    }
+pub(crate) fn thread_class_add(instance: &ThreadClass, value: u32, handler: AddHandler) {
+    let promise = handler.into_promise();
+    instance
+        .tx
+        .send(Message::Add(value, Box::new(|res| promise.complete(res))))
+        .unwrap()
+}
 
-    fn complete(self, result: Result<u32, MathIsBroken>) {
-        match result {
-            Ok(x) => self.on_complete(x),
-            Err(err) => self.on_failure(err),
-        }
-    }
+pub(crate) fn thread_class_next_value(instance: &ThreadClass, handler: AddHandler) {
+    let promise = handler.into_promise();
+    instance
+        .tx
+        .send(Message::NextValue(Box::new(|res| promise.complete(res))))
+        .unwrap()
 }
 
-pub(crate) unsafe fn thread_class_add(instance: *mut ThreadClass, value: u32, handler: AddHandler) {
-    let promise = sfio_promise::wrap(handler);
+pub(crate) fn thread_class_flush(instance: &ThreadClass, handler: FlushHandler) {
+    let promise = handler.into_promise();
+    instance
+        .tx
+        .send(Message::Flush(Box::new(|res| promise.complete(res))))
+        .unwrap()
+}
 
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::Add(value, Box::new(|res| promise.complete(res))))
-            .unwrap()
-    }
+pub(crate) fn thread_class_values(instance: &ThreadClass, count: u32, callback: ValueStream) {
+    instance.tx.send(Message::Values(count, callback)).unwrap()
 }
 
-pub(crate) unsafe fn thread_class_execute(
-    instance: *mut ThreadClass,
-    operation: crate::ffi::Operation,
-) {
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::Operation(operation)).unwrap()
-    }
+pub(crate) fn thread_class_execute(instance: &ThreadClass, operation: crate::ffi::Operation) {
+    instance.tx.send(Message::Operation(operation)).unwrap()
 }
 
-pub(crate) unsafe fn thread_class_queue_error(
-    instance: *mut ThreadClass,
-    err: crate::ffi::MathIsBroken,
-) {
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::QueueAddError(err)).unwrap()
-    }
+pub(crate) fn thread_class_queue_error(instance: &ThreadClass, err: crate::ffi::MathIsBroken) {
+    instance.tx.send(Message::QueueAddError(err)).unwrap()
 }
 
-pub(crate) unsafe fn thread_class_drop_next_add(instance: *mut crate::ThreadClass) {
-    if let Some(x) = instance.as_ref() {
-        x.tx.send(Message::DropAdd).unwrap()
-    }
+pub(crate) fn thread_class_drop_next_add(instance: &crate::ThreadClass) {
+    instance.tx.send(Message::DropAdd).unwrap()
 }
@@ -0,0 +1,39 @@
+use crate::ffi;
+
+pub(crate) fn shape_area(shape: ffi::ShapeVariant) -> Result<f64, ffi::ShapeError> {
+    let area = match shape {
+        ffi::ShapeVariant::Circle(x) => std::f64::consts::PI * x.radius() * x.radius(),
+        ffi::ShapeVariant::Rectangle(x) => x.width() * x.height(),
+        ffi::ShapeVariant::Square(x) => x.width() * x.width(),
+    };
+    Ok(area)
+}
+
+pub(crate) fn shape_scale(
+    shape: ffi::ShapeVariant,
+    factor: f64,
+) -> Result<ffi::ShapeVariant, ffi::ShapeError> {
+    let shape = match shape {
+        ffi::ShapeVariant::Circle(x) => ffi::ShapeVariant::Circle(ffi::Circle {
+            radius: x.radius() * factor,
+        }),
+        ffi::ShapeVariant::Rectangle(x) => ffi::ShapeVariant::Rectangle(scale_rectangle(x, factor)),
+        ffi::ShapeVariant::Square(x) => ffi::ShapeVariant::Square(scale_rectangle(x, factor)),
+    };
+    Ok(shape)
+}
+
+fn scale_rectangle(x: ffi::Rectangle, factor: f64) -> ffi::Rectangle {
+    ffi::Rectangle {
+        width: x.width() * factor,
+        height: x.height() * factor,
+    }
+}
+
+pub(crate) fn invoke_shape_receiver(
+    shape: ffi::ShapeVariant,
+    receiver: ffi::ShapeReceiver,
+) -> Result<(), ffi::ShapeError> {
+    receiver.on_shape(shape);
+    Ok(())
+}
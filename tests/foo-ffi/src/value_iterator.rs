@@ -0,0 +1,165 @@
+use std::ffi::{CStr, CString};
+use std::time::Duration;
+
+use crate::ffi;
+
+pub struct WordIterator {
+    iter: std::vec::IntoIter<CString>,
+    current: Option<CString>,
+}
+
+pub struct CompassDirectionIterator {
+    iter: std::vec::IntoIter<ffi::CompassDirection>,
+    current: Option<ffi::CompassDirection>,
+}
+
+pub struct IntervalIterator {
+    current: u64,
+    step: u64,
+    remaining: u32,
+}
+
+pub struct OffsetIterator {
+    current: i64,
+    step: i64,
+    remaining: u32,
+}
+
+pub struct IndexedItem {
+    index: u32,
+}
+
+pub struct IndexedItemIterator {
+    items: Vec<IndexedItem>,
+    next: usize,
+}
+
+pub(crate) unsafe fn split_words(value: &CStr) -> *mut WordIterator {
+    let words: Vec<CString> = value
+        .to_string_lossy()
+        .split_whitespace()
+        .map(|x| CString::new(x).unwrap())
+        .collect();
+
+    Box::into_raw(Box::new(WordIterator {
+        iter: words.into_iter(),
+        current: None,
+    }))
+}
+
+pub(crate) fn word_iterator_next(it: &mut WordIterator) -> *const std::os::raw::c_char {
+    it.current = it.iter.next();
+    match &it.current {
+        None => std::ptr::null(),
+        Some(x) => x.as_ptr(),
+    }
+}
+
+pub(crate) unsafe fn word_iterator_destroy(it: *mut WordIterator) {
+    if !it.is_null() {
+        drop(Box::from_raw(it));
+    }
+}
+
+pub(crate) unsafe fn get_directions() -> *mut CompassDirectionIterator {
+    let directions = vec![
+        ffi::CompassDirection::North,
+        ffi::CompassDirection::East,
+        ffi::CompassDirection::South,
+        ffi::CompassDirection::West,
+    ];
+
+    Box::into_raw(Box::new(CompassDirectionIterator {
+        iter: directions.into_iter(),
+        current: None,
+    }))
+}
+
+pub(crate) fn compass_direction_iterator_next(
+    it: &mut CompassDirectionIterator,
+) -> *const ffi::CompassDirection {
+    it.current = it.iter.next();
+    match &it.current {
+        None => std::ptr::null(),
+        Some(x) => x,
+    }
+}
+
+pub(crate) unsafe fn compass_direction_iterator_destroy(it: *mut CompassDirectionIterator) {
+    if !it.is_null() {
+        drop(Box::from_raw(it));
+    }
+}
+
+pub(crate) unsafe fn get_intervals(count: u32, step: Duration) -> *mut IntervalIterator {
+    Box::into_raw(Box::new(IntervalIterator {
+        current: 0,
+        step: step.as_millis() as u64,
+        remaining: count,
+    }))
+}
+
+pub(crate) fn interval_iterator_next(it: &mut IntervalIterator) -> *const u64 {
+    if it.remaining == 0 {
+        return std::ptr::null();
+    }
+    it.remaining -= 1;
+    it.current += it.step;
+    &it.current
+}
+
+pub(crate) unsafe fn interval_iterator_destroy(it: *mut IntervalIterator) {
+    if !it.is_null() {
+        drop(Box::from_raw(it));
+    }
+}
+
+pub(crate) unsafe fn get_offsets(count: u32, step: i64) -> *mut OffsetIterator {
+    Box::into_raw(Box::new(OffsetIterator {
+        current: 0,
+        step,
+        remaining: count,
+    }))
+}
+
+pub(crate) fn offset_iterator_next(it: &mut OffsetIterator) -> *const i64 {
+    if it.remaining == 0 {
+        return std::ptr::null();
+    }
+    it.remaining -= 1;
+    it.current -= it.step;
+    &it.current
+}
+
+pub(crate) unsafe fn offset_iterator_destroy(it: *mut OffsetIterator) {
+    if !it.is_null() {
+        drop(Box::from_raw(it));
+    }
+}
+
+pub(crate) fn indexed_item_get_index(item: &IndexedItem) -> u32 {
+    item.index
+}
+
+pub(crate) unsafe fn get_indexed_items(count: u32) -> *mut IndexedItemIterator {
+    Box::into_raw(Box::new(IndexedItemIterator {
+        items: (0..count).map(|index| IndexedItem { index }).collect(),
+        next: 0,
+    }))
+}
+
+pub(crate) fn indexed_item_iterator_next(it: &mut IndexedItemIterator) -> *mut IndexedItem {
+    match it.items.get_mut(it.next) {
+        None => std::ptr::null_mut(),
+        Some(x) => {
+            it.next += 1;
+            x
+        }
+    }
+}
+
+pub(crate) unsafe fn indexed_item_iterator_destroy(it: *mut IndexedItemIterator) {
+    if !it.is_null() {
+        drop(Box::from_raw(it));
+    }
+}
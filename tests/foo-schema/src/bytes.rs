@@ -0,0 +1,105 @@
+use oo_bindgen::model::*;
+
+fn define_byte_payload(lib: &mut LibraryBuilder) -> BackTraced<UniversalStructHandle> {
+    let id_field = Name::create("id")?;
+    let data_field = Name::create("data")?;
+
+    let payload = lib.declare_universal_struct("byte_payload")?;
+    let payload = lib
+        .define_universal_struct(payload)?
+        .doc("Universal struct that contains a sequence of bytes")?
+        .add(
+            id_field.clone(),
+            Primitive::U32,
+            "identifier of the payload",
+        )?
+        .add(data_field, BytesType, "bytes of the payload")?
+        .end_fields()?
+        .begin_initializer(
+            "init",
+            InitializerType::Normal,
+            "Construct a {struct:byte_payload} with a default identifier",
+        )?
+        .default(&id_field, NumberValue::U32(0))?
+        .end_initializer()?
+        .build()?;
+
+    Ok(payload)
+}
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    // Declare the class
+    let byte_class = lib.declare_class("byte_class")?;
+
+    // Declare each native function
+    let constructor = lib
+        .define_constructor(byte_class.clone())?
+        .doc("Create a new ByteClass")?
+        .build()?;
+
+    let destructor = lib.define_destructor(byte_class.clone(), "Destroy a ByteClass")?;
+
+    let byte_error = lib
+        .define_error_type(
+            "byte_error",
+            "byte_exception",
+            ExceptionType::UncheckedException,
+        )?
+        .add_bad_argument_error("null_instance", "The instance is null")?
+        .doc("Errors returned by {class:byte_class.echo()}")?
+        .build()?;
+
+    let echo = lib
+        .define_method("echo", byte_class.clone())?
+        .mutable_instance()?
+        .param("value", BytesType, "Bytes to echo")?
+        .returns(BytesType, "Echoed bytes")?
+        .fails_with(byte_error)?
+        .doc("Echo a sequence of bytes")?
+        .build()?;
+
+    let byte_length = lib
+        .define_function("byte_length")?
+        .param("value", BytesType, "Bytes")?
+        .returns(Primitive::U32, "Number of bytes")?
+        .doc("Get the number of bytes in a sequence")?
+        .build_static("get_length")?;
+
+    // Define the class
+    lib.define_class(&byte_class)?
+        .constructor(constructor)?
+        .destructor(destructor)?
+        .method(echo)?
+        .static_method(byte_length)?
+        .disposable_destroy()?
+        .doc("ByteClass")?
+        .build()?;
+
+    let payload = define_byte_payload(lib)?;
+
+    let receiver = lib
+        .define_interface("byte_receiver", "Interface that receives bytes")?
+        .begin_callback("on_bytes", "callback that receives a sequence of bytes")?
+        .param("value", BytesType, "bytes received")?
+        .end_callback()?
+        .begin_callback("on_payload", "callback that receives a payload")?
+        .param("value", payload.clone(), "payload received")?
+        .returns(Primitive::U32, "sum of the bytes in the payload")?
+        .end_callback()?
+        .build_sync()?;
+
+    let invoke = lib
+        .define_function("invoke_byte_receiver")?
+        .doc("invokes {interface:byte_receiver.on_bytes()} with the payload data and then {interface:byte_receiver.on_payload()} with the payload")?
+        .param("value", payload, "payload to pass to the receiver")?
+        .param("receiver", receiver, "receiver on which to invoke the callbacks")?
+        .returns(Primitive::U32, "value returned by {interface:byte_receiver.on_payload()}")?
+        .build_static("invoke")?;
+
+    lib.define_static_class("byte_receiver_tests")?
+        .doc("test methods for byte receivers")?
+        .static_method(invoke)?
+        .build()?;
+
+    Ok(())
+}
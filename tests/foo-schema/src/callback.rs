@@ -49,12 +49,14 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     let set_interface = lib
         .define_method("set_interface", callback_source.clone())?
+        .mutable_instance()?
         .param("cb", interface, "Callback to add")?
         .doc("Add a callback")?
         .build()?;
 
     let set_value = lib
         .define_method("set_value", callback_source.clone())?
+        .mutable_instance()?
         .param("value", Primitive::U32, "New value")?
         .returns(Primitive::U32, "Value returned by the callback")?
         .doc("Set the value and call all the callbacks")?
@@ -62,6 +64,7 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     let set_duration = lib
         .define_method("set_duration", callback_source.clone())?
+        .mutable_instance()?
         .param("value", DurationType::Milliseconds, "New duration")?
         .returns(DurationType::Milliseconds, "Some value")?
         .doc("Set the duration and call all the callbacks")?
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use oo_bindgen::model::*;
 
 pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
@@ -29,7 +31,76 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     let increment_value = lib
         .define_method("increment_value", test_class.clone())?
-        .doc("Increment value")?
+        .mutable_instance()?
+        .doc("Increment value by the step")?
+        .build()?;
+
+    let add_value = lib
+        .define_method("add_value", test_class.clone())?
+        .mutable_instance()?
+        .param("amount", Primitive::U32, "Amount added on each repetition")?
+        .param_with_default(
+            "repeat",
+            Primitive::U32,
+            NumberValue::U32(1),
+            "Number of times the amount is added",
+        )?
+        .returns(Primitive::U32, "Value after the addition")?
+        .doc("Add an amount to the value a number of times")?
+        .build()?;
+
+    let value_adder = lib
+        .define_interface("value_adder", "Interface that adds amounts to a value")?
+        .begin_callback("add_value", "Add an amount to the value a number of times")?
+        .param("amount", Primitive::U32, "Amount added on each repetition")?
+        .param(
+            "repeat",
+            Primitive::U32,
+            "Number of times the amount is added",
+        )?
+        .returns(Primitive::U32, "Value after the addition")?
+        .end_callback()?
+        .build_sync()?;
+
+    let add_twice = lib
+        .define_function("add_twice")?
+        .param(
+            "adder",
+            value_adder.clone(),
+            "Adder on which to invoke {interface:value_adder.add_value()}",
+        )?
+        .param("amount", Primitive::U32, "Amount to add")?
+        .returns(
+            Primitive::U32,
+            "Value returned by {interface:value_adder.add_value()}",
+        )?
+        .doc("Invoke {interface:value_adder.add_value()} with a repeat count of 2")?
+        .build_static_with_same_name()?;
+
+    let get_step = lib
+        .define_method("get_step", test_class.clone())?
+        .returns(
+            Primitive::U32,
+            "Amount added to the value on each increment",
+        )?
+        .doc("Get the amount added to the value on each increment")?
+        .build()?;
+
+    let set_step = lib
+        .define_method("set_step", test_class.clone())?
+        .mutable_instance()?
+        .param(
+            "step",
+            Primitive::U32,
+            "Amount added to the value on each increment",
+        )?
+        .doc("Set the amount added to the value on each increment")?
+        .build()?;
+
+    let get_increments = lib
+        .define_method("get_increments", test_class.clone())?
+        .returns(Primitive::U32, "Number of increments")?
+        .doc("Get the number of times the value was incremented")?
         .build()?;
 
     let construction_counter = lib
@@ -38,6 +109,24 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .doc("Get number of calls to the constructor")?
         .build_static("construction_counter")?;
 
+    let scale_duration = lib
+        .define_function("scale_duration")?
+        .param_with_default(
+            "duration",
+            DurationType::Milliseconds,
+            Duration::from_millis(250),
+            "Duration to scale",
+        )?
+        .param_with_default(
+            "factor",
+            Primitive::U32,
+            NumberValue::U32(2),
+            "Scaling factor",
+        )?
+        .returns(DurationType::Milliseconds, "Scaled duration")?
+        .doc("Multiply a duration by a factor")?
+        .build_static_with_same_name()?;
+
     // Define the class
     let _test_class = lib
         .define_class(&test_class)?
@@ -45,7 +134,13 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .destructor(destructor)?
         .method(get_value)?
         .method(increment_value)?
+        .method(add_value)?
+        .define_property("step", get_step, Some(set_step))?
+        .define_property("increments", get_increments, None)?
         .static_method(construction_counter)?
+        .static_method(scale_duration)?
+        .static_method(add_twice)?
+        .implements(&value_adder)?
         .custom_destroy("shutdown")?
         .doc("A test class")?
         .build()?;
@@ -4,16 +4,85 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
     lib.define_constants("special_values")?
         .add(
             "one",
-            ConstantValue::U8(1, Representation::Hex),
+            ConstantValue::Primitive(PrimitiveValue::U8(1), Representation::Hex),
             "the value 1",
         )?
         .add(
             "two",
-            ConstantValue::U8(2, Representation::Hex),
+            ConstantValue::Primitive(PrimitiveValue::U8(2), Representation::Hex),
             "the value 2",
         )?
         .doc("some special values")?
         .build()?;
 
+    let priority = lib
+        .define_enum("priority")?
+        .push("low", "Low priority")?
+        .push("high", "High priority")?
+        .doc("Priority used to test enum constants")?
+        .build()?;
+
+    lib.define_constants("typed_values")?
+        .add("enabled", PrimitiveValue::Bool(true), "a boolean")?
+        .add(
+            "max_u8",
+            ConstantValue::Primitive(PrimitiveValue::U8(u8::MAX), Representation::Hex),
+            "largest u8",
+        )?
+        .add("min_s8", PrimitiveValue::S8(-100), "a negative s8")?
+        .add(
+            "mask_u16",
+            ConstantValue::Primitive(PrimitiveValue::U16(0b1010), Representation::Binary),
+            "a u16 mask",
+        )?
+        .add(
+            "negative_s16",
+            ConstantValue::Primitive(PrimitiveValue::S16(-0x100), Representation::Hex),
+            "a negative s16",
+        )?
+        .add("max_u32", PrimitiveValue::U32(u32::MAX), "largest u32")?
+        .add(
+            "min_s32",
+            PrimitiveValue::S32(-2_000_000_000),
+            "a negative s32",
+        )?
+        .add("max_u64", PrimitiveValue::U64(u64::MAX), "largest u64")?
+        .add(
+            "max_u64_hex",
+            ConstantValue::Primitive(PrimitiveValue::U64(u64::MAX), Representation::Hex),
+            "largest u64 in hex",
+        )?
+        .add(
+            "large_s64",
+            PrimitiveValue::S64(-5_000_000_000),
+            "a negative s64",
+        )?
+        .add("half", PrimitiveValue::Float(0.5), "a float")?
+        .add("pi", PrimitiveValue::Double(3.25), "a double")?
+        .add(
+            "timeout",
+            DurationValue::Milliseconds(1500),
+            "a duration in milliseconds",
+        )?
+        .add(
+            "period",
+            DurationValue::Seconds(60),
+            "a duration in seconds",
+        )?
+        .add(
+            "resolution",
+            DurationValue::Microseconds(250),
+            "a duration in microseconds",
+        )?
+        .add(
+            "skew",
+            DurationValue::SignedMilliseconds(-200),
+            "a negative duration in milliseconds",
+        )?
+        .add("greeting", "Hello \"world\"\n", "a string")?
+        .add("default_priority", priority.value("high")?, "an enum")?
+        .doc("constants of every supported type")?
+        .build()?;
+
     Ok(())
 }
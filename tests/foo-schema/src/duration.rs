@@ -16,12 +16,84 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .doc("Echo duration as count of seconds")?
         .build_static("seconds_echo")?;
 
+    let duration_us_echo_func = lib
+        .define_function("duration_us_echo")?
+        .param("value", DurationType::Microseconds, "Duration")?
+        .returns(DurationType::Microseconds, "Duration")?
+        .doc("Echo duration as count of microseconds")?
+        .build_static("microseconds_echo")?;
+
+    let duration_ns_echo_func = lib
+        .define_function("duration_ns_echo")?
+        .param("value", DurationType::Nanoseconds, "Duration")?
+        .returns(DurationType::Nanoseconds, "Duration")?
+        .doc("Echo duration as count of nanoseconds")?
+        .build_static("nanoseconds_echo")?;
+
+    let duration_signed_ms_echo_func = lib
+        .define_function("duration_signed_ms_echo")?
+        .param("value", DurationType::SignedMilliseconds, "Duration")?
+        .returns(DurationType::SignedMilliseconds, "Duration")?
+        .doc("Echo duration as signed count of milliseconds")?
+        .build_static("signed_milliseconds_echo")?;
+
+    let duration_float_s_echo_func = lib
+        .define_function("duration_float_s_echo")?
+        .param("value", DurationType::FloatSeconds, "Duration")?
+        .returns(DurationType::FloatSeconds, "Duration")?
+        .doc("Echo duration as fractional count of seconds")?
+        .build_static("float_seconds_echo")?;
+
     // Declare static class
     lib.define_static_class("duration_echo_functions")?
         .static_method(duration_ms_echo_func)?
         .static_method(duration_s_echo_func)?
+        .static_method(duration_us_echo_func)?
+        .static_method(duration_ns_echo_func)?
+        .static_method(duration_signed_ms_echo_func)?
+        .static_method(duration_float_s_echo_func)?
         .doc("Duration echos functions")?
         .build()?;
 
+    let timestamp_echo_func = lib
+        .define_function("timestamp_echo")?
+        .param("value", TimestampType::Milliseconds, "Timestamp")?
+        .returns(TimestampType::Milliseconds, "Timestamp")?
+        .doc("Echo a timestamp")?
+        .build_static("echo")?;
+
+    let timestamp_offset_func = lib
+        .define_function("timestamp_offset")?
+        .param("value", TimestampType::Milliseconds, "Timestamp")?
+        .param(
+            "offset",
+            DurationType::SignedMilliseconds,
+            "Offset to apply, may be negative",
+        )?
+        .returns(TimestampType::Milliseconds, "Offset timestamp")?
+        .doc("Offset a timestamp forwards or backwards in time")?
+        .build_static("offset")?;
+
+    let timestamp_or_epoch_func = lib
+        .define_function("timestamp_or_epoch")?
+        .param(
+            "value",
+            Optional::new(TimestampType::Milliseconds),
+            "Optional timestamp",
+        )?
+        .returns(
+            TimestampType::Milliseconds,
+            "the timestamp if present, otherwise the UNIX epoch",
+        )?
+        .doc("Get an optional timestamp or the UNIX epoch")?
+        .build_static("or_epoch")?;
+
+    lib.define_static_class("timestamp_functions")?
+        .static_method(timestamp_echo_func)?
+        .static_method(timestamp_offset_func)?
+        .static_method(timestamp_or_epoch_func)?
+        .doc("Timestamp functions")?
+        .build()?;
+
     Ok(())
 }
@@ -32,15 +32,36 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .variant("four", 4, "Four")?
         .variant("seven", 7, "Seven")?
         .variant("two", 2, "Two")?
+        .unknown_value_policy(UnknownValuePolicy::Clamp)?
         .doc("Disjoint")?
         .build()?;
 
     let enum_single = lib
         .define_enum("enum_single")?
         .push("single", "Single")?
+        .unknown_value_policy(UnknownValuePolicy::MapTo(Name::create("single")?))?
         .doc("Single")?
         .build()?;
 
+    let enum_checked = lib
+        .define_enum("enum_checked")?
+        .push("one", "One")?
+        .push("two", "Two")?
+        .push("three", "Three")?
+        .unknown_value_policy(UnknownValuePolicy::Error)?
+        .doc("Enum whose unknown values are reported as errors")?
+        .build()?;
+
+    let enum_error = lib
+        .define_error_type(
+            "enum_error",
+            "enum_exception",
+            ExceptionType::UncheckedException,
+        )?
+        .add_bad_argument_error("bad_value", "The value is not a variant of the enum")?
+        .doc("Errors returned by the checked echo function")?
+        .build()?;
+
     // Declare each echo function
     let enum_zero_to_five_echo = lib
         .define_function("enum_zero_to_five_echo")?
@@ -70,12 +91,21 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .doc("Echo a EnumSingle enum")?
         .build_static_with_same_name()?;
 
+    let enum_checked_echo = lib
+        .define_function("enum_checked_echo")?
+        .param("value", enum_checked.clone(), "Enum value")?
+        .returns(enum_checked, "Enum value")?
+        .fails_with(enum_error)?
+        .doc("Echo a EnumChecked enum, failing if the value isn't a variant")?
+        .build_static_with_same_name()?;
+
     // Declare static class
     lib.define_static_class("enum_echo_functions")?
         .static_method(enum_zero_to_five_echo)?
         .static_method(enum_one_to_six_echo)?
         .static_method(enum_disjoint_echo)?
         .static_method(enum_single_echo)?
+        .static_method(enum_checked_echo)?
         .doc("Enum echo functions")?
         .build()?;
 
@@ -1,12 +1,18 @@
 use oo_bindgen::model::*;
 
 pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let foo_exception = lib
+        .define_base_exception("foo_exception", ExceptionType::UncheckedException)?
+        .doc("Base class of the exceptions thrown by the library")?
+        .build()?;
+
     let error_type = lib
         .define_error_type(
             "my_error",
             "my_exception",
             ExceptionType::UncheckedException,
         )?
+        .extends(&foo_exception)?
         .add_error("bad_password", "Wrong password!")?
         .add_error("null_argument", "Provided argument was NULL")?
         .doc("Errors returned by the various functions")?
@@ -37,6 +43,26 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .doc("Use a password and echoes it if it's valid")?
         .build_static_with_same_name()?;
 
+    let panic_with_error_fn = lib
+        .define_function("panic_with_error")?
+        .returns(Primitive::U32, "never returned")?
+        .fails_with(error_type.clone())?
+        .doc("Always panics, which is reported as the panic error")?
+        .build_static_with_same_name()?;
+
+    let panic_without_error_fn = lib
+        .define_function("panic_without_error")?
+        .param("value", Primitive::U32, "ignored value")?
+        .returns(Primitive::U32, "0 because the implementation panics")?
+        .doc("Always panics, which returns the default value")?
+        .build_static_with_same_name()?;
+
+    let panic_count_fn = lib
+        .define_function("panic_count")?
+        .returns(Primitive::U32, "Number of panics logged")?
+        .doc("Get the number of panics logged by the FFI")?
+        .build_static_with_same_name()?;
+
     let constructor = lib
         .define_constructor(my_class.clone())?
         .param("password", StringType, "secret password")?
@@ -60,8 +86,73 @@ pub(crate) fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .static_method(get_special_number_fb)?
         .static_method(get_struct_fn)?
         .static_method(echo_password_fn)?
+        .static_method(panic_with_error_fn)?
+        .static_method(panic_without_error_fn)?
+        .static_method(panic_count_fn)?
         .doc("A very special class")?
         .build()?;
 
+    define_detailed_error(lib, &foo_exception)?;
+
+    Ok(())
+}
+
+fn define_detailed_error(
+    lib: &mut LibraryBuilder,
+    foo_exception: &BaseExceptionHandle,
+) -> BackTraced<()> {
+    let door_exception = lib
+        .define_base_exception("door_exception", ExceptionType::UncheckedException)?
+        .extends(foo_exception)?
+        .doc("Base class of the exceptions thrown by the door")?
+        .build()?;
+
+    let lockout_info = lib.declare_universal_struct("lockout_info")?;
+    let lockout_info = lib
+        .define_universal_struct(lockout_info)?
+        .add("attempts", Primitive::U32, "number of failed attempts")?
+        .add(
+            "retry_after",
+            DurationType::Milliseconds,
+            "time after which another attempt may be made",
+        )?
+        .doc("Information about a lockout")?
+        .end_fields()?
+        .add_full_initializer("init")?
+        .build()?;
+
+    let lock_error = lib
+        .define_error_type(
+            "lock_error",
+            "lock_exception",
+            ExceptionType::UncheckedException,
+        )?
+        .extends(&door_exception)?
+        .add_error("wrong_code", "The code was wrong")?
+        .add_error_with_exception(
+            "locked_out",
+            "locked_out_exception",
+            "Too many wrong codes were entered",
+        )?
+        .with_message()?
+        .with_payload(lockout_info)?
+        .doc("Errors that carry a message and a payload")?
+        .build()?;
+
+    let unlock = lib
+        .define_function("door_unlock")?
+        .param("code", Primitive::U32, "code that unlocks the door")?
+        .fails_with(lock_error)?
+        .doc(
+            doc("Unlock the door")
+                .details("A code of 0 fails without any detail, 9999 causes a lockout with a payload, and any other wrong code fails with a message"),
+        )?
+        .build_static("unlock")?;
+
+    lib.define_static_class("door")?
+        .static_method(unlock)?
+        .doc("Door used to test errors with a message and a payload")?
+        .build()?;
+
     Ok(())
 }
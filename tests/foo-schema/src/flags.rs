@@ -0,0 +1,76 @@
+use oo_bindgen::model::*;
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let permissions = lib
+        .define_flags("permissions", FlagsType::U8)?
+        .push("read", "Read access")?
+        .push("write", "Write access")?
+        .push("execute", "Execute access")?
+        .bit("sticky", 0x80, "Sticky bit")?
+        .doc("Permissions used to test bit-flag types")?
+        .build()?;
+
+    let file_mode = lib.declare_universal_struct("file_mode")?;
+    let file_mode = lib
+        .define_universal_struct(file_mode)?
+        .add("owner", permissions.clone(), "owner permissions")?
+        .add("other", permissions.clone(), "permissions of everyone else")?
+        .doc("Struct used to test bit-flags as fields")?
+        .end_fields()?
+        .add_full_initializer("init")?
+        .build()?;
+
+    let echo = lib
+        .define_function("permissions_echo")?
+        .param("value", permissions.clone(), "Permissions")?
+        .returns(permissions.clone(), "Permissions")?
+        .doc("Echo a set of permissions")?
+        .build_static("echo")?;
+
+    let combine = lib
+        .define_function("permissions_combine")?
+        .param("first", permissions.clone(), "first set of permissions")?
+        .param("second", permissions.clone(), "second set of permissions")?
+        .returns(permissions.clone(), "union of the two sets")?
+        .doc("Compute the union of two sets of permissions")?
+        .build_static("combine")?;
+
+    let or_default = lib
+        .define_function("permissions_or_default")?
+        .param(
+            "value",
+            Optional::new(permissions.clone()),
+            "optional permissions",
+        )?
+        .param(
+            "default_value",
+            permissions.clone(),
+            "permissions used when absent",
+        )?
+        .returns(
+            permissions.clone(),
+            "the permissions if present, otherwise the default",
+        )?
+        .doc("Get optional permissions or a default value")?
+        .build_static("or_default")?;
+
+    let common = lib
+        .define_function("file_mode_common")?
+        .param("mode", file_mode, "file mode to inspect")?
+        .returns(
+            permissions,
+            "permissions shared by {struct:file_mode.owner} and {struct:file_mode.other}",
+        )?
+        .doc("Compute the permissions shared by the owner and everyone else")?
+        .build_static("common")?;
+
+    lib.define_static_class("flags_test_helper")?
+        .static_method(echo)?
+        .static_method(combine)?
+        .static_method(or_default)?
+        .static_method(common)?
+        .doc("Helper methods for testing bit-flag types")?
+        .build()?;
+
+    Ok(())
+}
@@ -17,7 +17,7 @@ fn define_iterator(lib: &mut LibraryBuilder) -> BackTraced<AbstractIteratorHandl
 }
 
 pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
-    // iterators can only be used in callback arguments, so we need an interface
+    // borrowed iterators can only be used in callback arguments, so we need an interface
     let iterator = define_iterator(lib)?;
 
     let interface = lib
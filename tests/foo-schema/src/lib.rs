@@ -2,6 +2,7 @@ use std::path::PathBuf;
 
 use oo_bindgen::model::*;
 
+mod bytes;
 mod callback;
 mod class;
 mod collection;
@@ -9,17 +10,24 @@ mod constants;
 mod duration;
 mod enums;
 mod error;
+mod flags;
 mod integer;
 mod interface_defaults;
 mod iterator;
 mod lifetime;
+mod list;
+mod map;
 mod opaque_struct;
+mod optional;
+mod owned_iterator;
 mod primitive_iterator;
 mod primitive_pointer;
 mod strings;
 mod structure;
 mod thread_class;
+mod unions;
 mod universal_struct;
+mod value_iterator;
 
 pub fn build_lib() -> BackTraced<Library> {
     let lib_info = LibraryInfo {
@@ -56,7 +64,12 @@ pub fn build_lib() -> BackTraced<Library> {
         CollectionSettings::default(),
         FutureSettings::default(),
         InterfaceSettings::default(),
-    )?;
+    )?
+    .with_panic(PanicSettings::new(
+        true,
+        Name::create("panic")?,
+        Some("crate::error::log_panic".to_string()),
+    ));
 
     let mut builder = LibraryBuilder::new(Version::parse("1.2.3").unwrap(), lib_info, settings);
 
@@ -67,17 +80,25 @@ pub fn build_lib() -> BackTraced<Library> {
     duration::define(&mut builder)?;
     enums::define(&mut builder)?;
     error::define(&mut builder)?;
+    flags::define(&mut builder)?;
     integer::define(&mut builder)?;
     interface_defaults::define(&mut builder)?;
     iterator::define(&mut builder)?;
     opaque_struct::define(&mut builder)?;
+    optional::define(&mut builder)?;
+    owned_iterator::define(&mut builder)?;
     primitive_iterator::define(&mut builder)?;
     primitive_pointer::define(&mut builder)?;
     strings::define(&mut builder)?;
+    bytes::define(&mut builder)?;
     lifetime::define(&mut builder)?;
     collection::define(&mut builder)?;
+    map::define(&mut builder)?;
+    list::define(&mut builder)?;
     universal_struct::define(&mut builder)?;
     thread_class::define(&mut builder)?;
+    unions::define(&mut builder)?;
+    value_iterator::define(&mut builder)?;
 
     let library = builder.build()?;
 
@@ -92,4 +113,157 @@ mod tests {
     fn test_build_lib() {
         build_lib().unwrap();
     }
+
+    fn name(x: &Option<Name>) -> Option<&str> {
+        x.as_ref().map(|x| x.as_ref())
+    }
+
+    #[test]
+    fn test_ir_round_trip() {
+        use oo_bindgen::ir::*;
+
+        let json = serde_json::to_string(&build_lib().unwrap().to_ir()).unwrap();
+        let ir: Document = serde_json::from_str(&json).unwrap();
+        assert_eq!(ir.format_version, FORMAT_VERSION);
+        assert_eq!(ir.version, "1.2.3");
+        assert!(ir
+            .functions
+            .iter()
+            .any(|x| x.name == "callback_source_set_value"));
+
+        // classes reference their methods and properties by the name of the native functions
+        let class = ir.classes.iter().find(|x| x.name == "test_class").unwrap();
+        assert_eq!(name(&class.constructor), Some("test_class_create"));
+        assert_eq!(name(&class.destructor), Some("test_class_destroy"));
+        assert!(matches!(&class.destruction_mode, DestructionMode::Custom(x) if x == "shutdown"));
+        let method = class
+            .methods
+            .iter()
+            .find(|x| x.name == "add_value")
+            .unwrap();
+        assert_eq!(method.function, "test_class_add_value");
+        let function = ir
+            .functions
+            .iter()
+            .find(|x| x.name == "test_class_add_value")
+            .unwrap();
+        assert_eq!(function.arguments.len(), 3);
+        assert!(function.arguments[2].default.is_some());
+        assert!(matches!(
+            function.return_type.as_ref().map(|x| &x.value_type),
+            Some(TypeRef::Primitive {
+                primitive: Primitive::U32
+            })
+        ));
+        let step = class.properties.iter().find(|x| x.name == "step").unwrap();
+        assert_eq!(step.getter, "test_class_get_step");
+        assert_eq!(name(&step.setter), Some("test_class_set_step"));
+        let increments = class
+            .properties
+            .iter()
+            .find(|x| x.name == "increments")
+            .unwrap();
+        assert_eq!(increments.getter, "test_class_get_increments");
+        assert!(increments.setter.is_none());
+        assert!(class.interfaces.iter().any(|x| x == "value_adder"));
+
+        // error types reference their enum, which lists the added variants
+        let error = ir
+            .error_types
+            .iter()
+            .find(|x| x.name == "broken_math_exception")
+            .unwrap();
+        assert_eq!(error.exception_type, ExceptionType::CheckedException);
+        assert_eq!(error.error_enum, "math_is_broken");
+        assert_eq!(name(&error.drop_variant), Some("dropped"));
+        assert!(error.bad_argument_variant.is_none());
+        let error_enum = ir
+            .enums
+            .iter()
+            .find(|x| x.name == "math_is_broken")
+            .unwrap();
+        let variants: Vec<&str> = error_enum
+            .variants
+            .iter()
+            .map(|x| x.name.as_ref())
+            .collect();
+        assert_eq!(
+            variants,
+            ["ok", "math_is_broke", "dropped", "cancelled", "panic"]
+        );
+        let error = ir
+            .error_types
+            .iter()
+            .find(|x| x.error_enum == "enum_error")
+            .unwrap();
+        assert_eq!(name(&error.bad_argument_variant), Some("bad_value"));
+        assert!(error.drop_variant.is_none());
+
+        // settings
+        assert_eq!(ir.settings.c_ffi_prefix, "foo");
+        assert!(ir.settings.panic.catch_unwind);
+        assert_eq!(ir.settings.panic.error_variant_name, "panic");
+        assert_eq!(
+            ir.settings.panic.log_function.as_deref(),
+            Some("crate::error::log_panic")
+        );
+        assert_eq!(ir.settings.panic.fallback, PanicFallback::DefaultOrAbort);
+        assert_eq!(ir.settings.null_argument, NullArgumentFallback::Default);
+
+        // unknown value policies of the enums
+        let policy = |enum_name: &str| {
+            ir.enums
+                .iter()
+                .find(|x| x.name == enum_name)
+                .unwrap()
+                .unknown_value
+                .clone()
+        };
+        assert_eq!(policy("enum_zero_to_five"), UnknownValuePolicy::Panic);
+        assert_eq!(policy("enum_checked"), UnknownValuePolicy::Error);
+        assert_eq!(policy("enum_disjoint"), UnknownValuePolicy::Clamp);
+        assert_eq!(
+            policy("enum_single"),
+            UnknownValuePolicy::MapTo(Name::create("single").unwrap())
+        );
+
+        // durations and timestamps keep their representation
+        let arg = |function_name: &str, index: usize| {
+            ir.functions
+                .iter()
+                .find(|x| x.name == function_name)
+                .unwrap()
+                .arguments[index]
+                .clone()
+        };
+        let arg_type = |function_name: &str, index: usize| arg(function_name, index).arg_type;
+        assert!(matches!(
+            arg_type("duration_signed_ms_echo", 0),
+            TypeRef::Duration {
+                duration: DurationType::SignedMilliseconds
+            }
+        ));
+        assert!(matches!(
+            arg_type("duration_float_s_echo", 0),
+            TypeRef::Duration {
+                duration: DurationType::FloatSeconds
+            }
+        ));
+        assert!(matches!(
+            arg_type("timestamp_offset", 0),
+            TypeRef::Timestamp {
+                timestamp: TimestampType::Milliseconds
+            }
+        ));
+        assert!(matches!(
+            arg_type("timestamp_offset", 1),
+            TypeRef::Duration {
+                duration: DurationType::SignedMilliseconds
+            }
+        ));
+
+        // only class references that opted in are passed as `&mut`
+        assert!(arg("test_class_increment_value", 0).mutable);
+        assert!(!arg("test_class_get_value", 0).mutable);
+    }
 }
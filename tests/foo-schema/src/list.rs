@@ -0,0 +1,97 @@
+use oo_bindgen::model::*;
+
+fn define_waypoint(lib: &mut LibraryBuilder) -> BackTraced<UniversalStructHandle> {
+    let x_field = Name::create("x")?;
+    let y_field = Name::create("y")?;
+
+    let waypoint = lib.declare_universal_struct("waypoint")?;
+    let waypoint = lib
+        .define_universal_struct(waypoint)?
+        .doc("Position on a {struct:route}")?
+        .add(x_field, Primitive::S32, "horizontal position")?
+        .add(y_field, Primitive::S32, "vertical position")?
+        .end_fields()?
+        .begin_initializer(
+            "init",
+            InitializerType::Normal,
+            "Construct a {struct:waypoint} from its coordinates",
+        )?
+        .end_initializer()?
+        .build()?;
+
+    Ok(waypoint)
+}
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let waypoint = define_waypoint(lib)?;
+    let waypoint_list = lib.define_list("waypoint_list", waypoint)?;
+    let reading_list = lib.define_list("reading_list", Primitive::U16)?;
+
+    let id_field = Name::create("id")?;
+    let waypoints_field = Name::create("waypoints")?;
+    let readings_field = Name::create("readings")?;
+
+    let route = lib.declare_universal_struct("route")?;
+    let route = lib
+        .define_universal_struct(route)?
+        .doc("Universal struct that contains lists")?
+        .add(id_field.clone(), Primitive::U32, "identifier of the route")?
+        .add(waypoints_field, waypoint_list, "waypoints along the route")?
+        .add(
+            readings_field,
+            reading_list,
+            "readings taken along the route",
+        )?
+        .end_fields()?
+        .begin_initializer(
+            "init",
+            InitializerType::Normal,
+            "Construct a {struct:route} with a default identifier",
+        )?
+        .default(&id_field, NumberValue::U32(0))?
+        .end_initializer()?
+        .build()?;
+
+    let count_waypoints_fn = lib
+        .define_function("route_count_waypoints")?
+        .param("route", route.clone(), "route to inspect")?
+        .returns(Primitive::U32, "number of waypoints in the route")?
+        .doc("Count the waypoints of a {struct:route}")?
+        .build_static("count_waypoints")?;
+
+    let sum_coordinates_fn = lib
+        .define_function("route_sum_coordinates")?
+        .param("route", route.clone(), "route to inspect")?
+        .returns(
+            Primitive::S32,
+            "sum of the x and y coordinates of every waypoint",
+        )?
+        .doc("Sum the coordinates of the waypoints of a {struct:route}")?
+        .build_static("sum_coordinates")?;
+
+    let sum_readings_fn = lib
+        .define_function("route_sum_readings")?
+        .param("route", route.clone(), "route to inspect")?
+        .returns(Primitive::U32, "sum of the readings")?
+        .doc("Sum the readings of a {struct:route}")?
+        .build_static("sum_readings")?;
+
+    let get_sample_route_fn = lib
+        .define_function("get_sample_route")?
+        .returns(
+            route,
+            "route with three waypoints and readings that lives for the duration of the program",
+        )?
+        .doc("Retrieve a sample {struct:route}")?
+        .build_static("get_sample_route")?;
+
+    lib.define_static_class("list_test_helper")?
+        .doc("Helper methods for the tests of lists")?
+        .static_method(count_waypoints_fn)?
+        .static_method(sum_coordinates_fn)?
+        .static_method(sum_readings_fn)?
+        .static_method(get_sample_route_fn)?
+        .build()?;
+
+    Ok(())
+}
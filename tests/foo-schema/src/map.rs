@@ -0,0 +1,79 @@
+use oo_bindgen::model::*;
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let string_map = lib.define_map("string_map", StringType, StringType)?;
+
+    let enabled_field = Name::create("enabled")?;
+    let max_connections_field = Name::create("max_connections")?;
+
+    let port_settings = lib.declare_universal_struct("port_settings")?;
+    let port_settings = lib
+        .define_universal_struct(port_settings)?
+        .doc("Settings associated with a port in a port map")?
+        .add(
+            enabled_field,
+            Primitive::Bool,
+            "true if the port is enabled",
+        )?
+        .add(
+            max_connections_field,
+            Primitive::U16,
+            "maximum number of connections on the port",
+        )?
+        .end_fields()?
+        .begin_initializer(
+            "init",
+            InitializerType::Normal,
+            "initializes {struct:port_settings} with the specified values",
+        )?
+        .end_initializer()?
+        .build()?;
+
+    let port_map = lib.define_map("port_map", Primitive::U16, port_settings)?;
+
+    let string_map_size_method = lib
+        .define_function("string_map_size")?
+        .param("map", string_map.clone(), "Map")?
+        .returns(Primitive::U32, "Number of entries in the map")?
+        .doc("Get the number of entries in a map")?
+        .build_static("get_string_map_size")?;
+
+    let string_map_get_method = lib
+        .define_function("string_map_get")?
+        .param("map", string_map, "Map")?
+        .param("key", StringType, "Key to look up")?
+        .returns(
+            StringType,
+            "Value associated with the key, or an empty string if the key is not present",
+        )?
+        .doc("Get the value associated with a key in the map")?
+        .build_static("get_string_map_value")?;
+
+    let port_map_size_method = lib
+        .define_function("port_map_size")?
+        .param("map", port_map.clone(), "Map")?
+        .returns(Primitive::U32, "Number of entries in the map")?
+        .doc("Get the number of entries in a map")?
+        .build_static("get_port_map_size")?;
+
+    let port_map_get_max_connections_method = lib
+        .define_function("port_map_get_max_connections")?
+        .param("map", port_map, "Map")?
+        .param("port", Primitive::U16, "Port to look up")?
+        .returns(
+            Primitive::U16,
+            "Maximum number of connections if the port is present and enabled, 0 otherwise",
+        )?
+        .doc("Get the maximum number of connections of a port in the map")?
+        .build_static("get_max_connections")?;
+
+    lib.define_static_class("map_test_methods")?
+        .static_method(string_map_size_method)?
+        .static_method(string_map_get_method)?
+        .static_method(port_map_size_method)?
+        .static_method(port_map_get_max_connections_method)?
+        .doc("Map helper functions")?
+        .build()?;
+
+    Ok(())
+}
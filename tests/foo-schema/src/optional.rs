@@ -0,0 +1,116 @@
+use oo_bindgen::model::*;
+
+fn define_optional_values(
+    lib: &mut LibraryBuilder,
+    level: EnumHandle,
+) -> BackTraced<UniversalStructHandle> {
+    let values = lib.declare_universal_struct("optional_values")?;
+    let values = lib
+        .define_universal_struct(values)?
+        .doc("Universal struct that contains optional values")?
+        .add(
+            "flag",
+            Optional::new(Primitive::Bool),
+            "optional boolean value",
+        )?
+        .add("number", Optional::new(Primitive::U16), "optional number")?
+        .add("ratio", Optional::new(Primitive::Double), "optional ratio")?
+        .add(
+            "timeout",
+            Optional::new(DurationType::Milliseconds),
+            "optional timeout",
+        )?
+        .add("level", Optional::new(level), "optional level")?
+        .end_fields()?
+        .add_full_initializer("init")?
+        .build()?;
+
+    Ok(values)
+}
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let level = lib
+        .define_enum("optional_level")?
+        .push("low", "Low level")?
+        .push("high", "High level")?
+        .doc("Level used to test optional enums")?
+        .build()?;
+
+    let values = define_optional_values(lib, level.clone())?;
+
+    let count_present = lib
+        .define_function("optional_count_present")?
+        .param("values", values.clone(), "values to inspect")?
+        .returns(Primitive::U32, "number of fields that have a value")?
+        .doc("Count the number of fields in {struct:optional_values} that have a value")?
+        .build_static("count_present")?;
+
+    let number_or = lib
+        .define_function("optional_number_or")?
+        .param("value", Optional::new(Primitive::S32), "optional value")?
+        .param("default_value", Primitive::S32, "value used when absent")?
+        .returns(
+            Primitive::S32,
+            "the value if present, otherwise the default",
+        )?
+        .doc("Get an optional number or a default value")?
+        .build_static("number_or")?;
+
+    let timeout_or = lib
+        .define_function("optional_timeout_or")?
+        .param(
+            "value",
+            Optional::new(DurationType::Seconds),
+            "optional timeout",
+        )?
+        .param(
+            "default_value",
+            DurationType::Seconds,
+            "timeout used when absent",
+        )?
+        .returns(
+            DurationType::Seconds,
+            "the timeout if present, otherwise the default",
+        )?
+        .doc("Get an optional timeout or a default value")?
+        .build_static("timeout_or")?;
+
+    let level_or = lib
+        .define_function("optional_level_or")?
+        .param("value", Optional::new(level.clone()), "optional level")?
+        .param("default_value", level.clone(), "level used when absent")?
+        .returns(level, "the level if present, otherwise the default")?
+        .doc("Get an optional level or a default value")?
+        .build_static("level_or")?;
+
+    let receiver = lib
+        .define_interface(
+            "optional_receiver",
+            "Interface that receives optional values",
+        )?
+        .begin_callback("on_number", "callback that receives an optional number")?
+        .param("value", Optional::new(Primitive::U16), "number received")?
+        .end_callback()?
+        .begin_callback("on_values", "callback that receives optional values")?
+        .param("values", values.clone(), "values received")?
+        .end_callback()?
+        .build_sync()?;
+
+    let invoke = lib
+        .define_function("invoke_optional_receiver")?
+        .doc("invokes {interface:optional_receiver.on_number()} with the number field and then {interface:optional_receiver.on_values()} with the values")?
+        .param("values", values, "values to pass to the receiver")?
+        .param("receiver", receiver, "receiver on which to invoke the callbacks")?
+        .build_static("invoke")?;
+
+    lib.define_static_class("optional_tests")?
+        .doc("test methods for optional values")?
+        .static_method(count_present)?
+        .static_method(number_or)?
+        .static_method(timeout_or)?
+        .static_method(level_or)?
+        .static_method(invoke)?
+        .build()?;
+
+    Ok(())
+}
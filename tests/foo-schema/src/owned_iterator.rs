@@ -0,0 +1,39 @@
+use oo_bindgen::model::*;
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    // owned iterators can be returned directly from functions
+    let number_iterator = lib.define_owned_iterator("number_iterator", Primitive::U32)?;
+
+    let character = lib.declare_function_return_struct("character_value")?;
+    let character = lib
+        .define_function_return_struct(character)?
+        .add("value", Primitive::U8, "Character value")?
+        .doc("Single character of a string")?
+        .end_fields()?
+        .build()?;
+
+    let character_iterator = lib.define_owned_iterator("character_iterator", character)?;
+
+    let count_fn = lib
+        .define_function("count_up")?
+        .doc("returns an iterator over a range of values")?
+        .param("min", Primitive::U32, "minimum value of the range")?
+        .param("max", Primitive::U32, "maximum value of the range")?
+        .returns(number_iterator, "iterator over the values in the range")?
+        .build_static_with_same_name()?;
+
+    let characters_fn = lib
+        .define_function("get_characters")?
+        .doc("returns an iterator over the characters of a string")?
+        .param("value", StringType, "string to iterate over")?
+        .returns(character_iterator, "iterator over the characters")?
+        .build_static_with_same_name()?;
+
+    lib.define_static_class("owned_iterator_test_helper")?
+        .doc("Helper methods for the owned iterator tests")?
+        .static_method(count_fn)?
+        .static_method(characters_fn)?
+        .build()?;
+
+    Ok(())
+}
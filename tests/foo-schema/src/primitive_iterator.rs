@@ -1,7 +1,7 @@
 use oo_bindgen::model::*;
 
 pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
-    // iterators can only be used in callback arguments, so we need an interface
+    // borrowed iterators can only be used in callback arguments, so we need an interface
     let iterator = lib.define_iterator("range_iterator", Primitive::U32)?;
 
     let interface = lib
@@ -12,6 +12,7 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     let get_bool = lib
         .define_method("get_bool", class.clone())?
+        .mutable_instance()?
         .param("value", Primitive::Bool, "value")?
         .returns(
             PrimitiveRef::new(Primitive::Bool),
@@ -22,6 +23,7 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     let get_u8 = lib
         .define_method("get_u8", class.clone())?
+        .mutable_instance()?
         .param("value", Primitive::U8, "value")?
         .returns(
             PrimitiveRef::new(Primitive::U8),
@@ -32,6 +34,7 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     let get_float = lib
         .define_method("get_float", class.clone())?
+        .mutable_instance()?
         .param("value", Primitive::Float, "value")?
         .returns(
             PrimitiveRef::new(Primitive::Float),
@@ -42,6 +45,7 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     let get_double = lib
         .define_method("get_double", class.clone())?
+        .mutable_instance()?
         .param("value", Primitive::Double, "value")?
         .returns(
             PrimitiveRef::new(Primitive::Double),
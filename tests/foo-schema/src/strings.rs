@@ -14,6 +14,7 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
 
     let echo = lib
         .define_method("echo", string_class.clone())?
+        .mutable_instance()?
         .param("value", StringType, "String to echo")?
         .returns(StringType, "Echoed string")?
         .doc("Echo a string")?
@@ -11,7 +11,8 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
             ExceptionType::CheckedException,
         )?
         .add_error("math_is_broke", "hey, sometime is happens")?
-        .add_error("dropped", "callback was dropped")?
+        .add_drop_error("dropped", "callback was dropped")?
+        .add_error("cancelled", "operation was cancelled")?
         .doc("sometime math just doesn't work")?
         .build()?;
 
@@ -93,11 +94,25 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         "receives a single value from an add operation",
         Primitive::U32,
         "result of the add operation",
+        error_type.clone(),
+    )?;
+
+    let flush_handler = lib.define_void_future_interface(
+        "flush_handler",
+        "notified once the queued operations have been processed",
+        error_type.clone(),
+    )?;
+
+    let value_stream = lib.define_stream_interface(
+        "value_stream",
+        "receives a sequence of values from the thread",
+        Primitive::U32,
+        "next value of the sequence",
         error_type,
     )?;
 
     let add_async = lib
-        .define_future_method("add", thread_class.clone(), add_handler)?
+        .define_future_method("add", thread_class.clone(), add_handler.clone())?
         .param(
             "value",
             Primitive::U32,
@@ -106,6 +121,26 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .doc("adds a supplied value to an internal value")?
         .build()?;
 
+    let next_value = lib
+        .define_future_method("next_value", thread_class.clone(), add_handler)?
+        .cancellable("cancelled")?
+        .doc("Wait for the value set by the next call to {class:thread_class.update()}")?
+        .build()?;
+
+    let flush = lib
+        .define_future_method("flush", thread_class.clone(), flush_handler)?
+        .doc("Complete once the operations queued before this call have been processed")?
+        .build()?;
+
+    let values = lib
+        .define_stream_method("values", thread_class.clone(), value_stream)?
+        .param("count", Primitive::U32, "number of values to produce")?
+        .doc(
+            doc("Produce a sequence of values starting at the internal value")
+                .details("The stream fails with the error queued by {class:thread_class.queue_error()} if there is one, otherwise it completes after the last value."),
+        )?
+        .build()?;
+
     // Define the class
     lib.define_class(&thread_class)?
         .constructor(constructor)?
@@ -115,6 +150,9 @@ pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
         .method(queue_error)?
         .method(drop_next_add)?
         .async_method(add_async)?
+        .async_method(next_value)?
+        .async_method(flush)?
+        .stream_method(values)?
         .custom_destroy("shutdown")?
         .doc("A class that manipulations integers on a Rust thread")?
         .build()?;
@@ -0,0 +1,108 @@
+use oo_bindgen::model::*;
+
+fn define_circle(lib: &mut LibraryBuilder) -> BackTraced<UniversalStructHandle> {
+    let circle = lib.declare_universal_struct("circle")?;
+    let circle = lib
+        .define_universal_struct(circle)?
+        .doc("Circle defined by its radius")?
+        .add("radius", Primitive::Double, "radius of the circle")?
+        .end_fields()?
+        .add_full_initializer("init")?
+        .build()?;
+
+    Ok(circle)
+}
+
+fn define_rectangle(lib: &mut LibraryBuilder) -> BackTraced<UniversalStructHandle> {
+    let rectangle = lib.declare_universal_struct("rectangle")?;
+    let rectangle = lib
+        .define_universal_struct(rectangle)?
+        .doc("Rectangle defined by its width and height")?
+        .add("width", Primitive::Double, "width of the rectangle")?
+        .add("height", Primitive::Double, "height of the rectangle")?
+        .end_fields()?
+        .add_full_initializer("init")?
+        .build()?;
+
+    Ok(rectangle)
+}
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let circle = define_circle(lib)?;
+    let rectangle = define_rectangle(lib)?;
+
+    let shape = lib
+        .define_union("shape")?
+        .variant("circle", circle, "shape is a {struct:circle}")?
+        .variant(
+            "rectangle",
+            rectangle.clone(),
+            "shape is a {struct:rectangle}",
+        )?
+        .variant(
+            "square",
+            rectangle,
+            "shape is a square stored as a {struct:rectangle} with equal sides",
+        )?
+        .doc("Tagged union used to test union conversions")?
+        .build()?;
+
+    let shape_error = lib
+        .define_error_type(
+            "shape_error",
+            "shape_exception",
+            ExceptionType::UncheckedException,
+        )?
+        .add_bad_argument_error("bad_shape", "The tag of the shape is not a variant")?
+        .doc("Errors returned by the functions that receive shapes")?
+        .build()?;
+
+    let area = lib
+        .define_function("shape_area")?
+        .param("shape", shape.clone(), "shape to measure")?
+        .returns(Primitive::Double, "area of the shape")?
+        .fails_with(shape_error.clone())?
+        .doc("Compute the area of a shape")?
+        .build_static("area")?;
+
+    let scale = lib
+        .define_function("shape_scale")?
+        .param("shape", shape.clone(), "shape to scale")?
+        .param(
+            "factor",
+            Primitive::Double,
+            "factor applied to every dimension",
+        )?
+        .returns(shape.clone(), "scaled shape of the same variant")?
+        .fails_with(shape_error.clone())?
+        .doc("Scale every dimension of a shape")?
+        .build_static("scale")?;
+
+    let receiver = lib
+        .define_interface("shape_receiver", "Interface that receives shapes")?
+        .begin_callback("on_shape", "callback that receives a shape")?
+        .param("shape", shape.clone(), "shape received")?
+        .end_callback()?
+        .build_sync()?;
+
+    let invoke = lib
+        .define_function("invoke_shape_receiver")?
+        .doc("invokes {interface:shape_receiver.on_shape()} with the shape")?
+        .param("shape", shape, "shape to pass to the receiver")?
+        .param(
+            "receiver",
+            receiver,
+            "receiver on which to invoke the callback",
+        )?
+        .fails_with(shape_error)?
+        .build_static("invoke")?;
+
+    lib.define_static_class("union_tests")?
+        .doc("test methods for tagged unions")?
+        .static_method(area)?
+        .static_method(scale)?
+        .static_method(invoke)?
+        .build()?;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use oo_bindgen::model::*;
+
+pub fn define(lib: &mut LibraryBuilder) -> BackTraced<()> {
+    let word_iterator = lib.define_owned_iterator("word_iterator", StringType)?;
+
+    let direction = lib
+        .define_enum("compass_direction")?
+        .push("north", "North")?
+        .push("east", "East")?
+        .push("south", "South")?
+        .push("west", "West")?
+        .doc("Direction used to test iterators of enums")?
+        .build()?;
+
+    let direction_iterator = lib.define_owned_iterator("compass_direction_iterator", direction)?;
+
+    let interval_iterator =
+        lib.define_owned_iterator("interval_iterator", DurationType::Milliseconds)?;
+
+    let offset_iterator =
+        lib.define_owned_iterator("offset_iterator", DurationType::SignedMilliseconds)?;
+
+    let indexed_item = lib.declare_class("indexed_item")?;
+
+    let get_index = lib
+        .define_method("get_index", indexed_item.clone())?
+        .returns(Primitive::U32, "index of the item")?
+        .doc("Get the index of the item in the sequence")?
+        .build()?;
+
+    lib.define_class(&indexed_item)?
+        .method(get_index)?
+        .doc("Item borrowed from the iterator that returned it")?
+        .build()?;
+
+    let indexed_item_iterator = lib.define_owned_iterator("indexed_item_iterator", indexed_item)?;
+
+    let split_words_fn = lib
+        .define_function("split_words")?
+        .doc("returns an iterator over the whitespace separated words of a string")?
+        .param("value", StringType, "string to split")?
+        .returns(word_iterator, "iterator over the words")?
+        .build_static_with_same_name()?;
+
+    let get_directions_fn = lib
+        .define_function("get_directions")?
+        .doc("returns an iterator over every {enum:compass_direction}, clockwise from north")?
+        .returns(direction_iterator, "iterator over the directions")?
+        .build_static_with_same_name()?;
+
+    let get_intervals_fn = lib
+        .define_function("get_intervals")?
+        .doc("returns an iterator over multiples of an interval")?
+        .param("count", Primitive::U32, "number of intervals")?
+        .param(
+            "step",
+            DurationType::Milliseconds,
+            "duration between the intervals",
+        )?
+        .returns(interval_iterator, "iterator over the intervals")?
+        .build_static_with_same_name()?;
+
+    let get_offsets_fn = lib
+        .define_function("get_offsets")?
+        .doc("returns an iterator over negative multiples of an interval")?
+        .param("count", Primitive::U32, "number of offsets")?
+        .param(
+            "step",
+            DurationType::SignedMilliseconds,
+            "duration between the offsets",
+        )?
+        .returns(offset_iterator, "iterator over the offsets")?
+        .build_static_with_same_name()?;
+
+    let get_indexed_items_fn = lib
+        .define_function("get_indexed_items")?
+        .doc("returns an iterator over {class:indexed_item} instances")?
+        .param("count", Primitive::U32, "number of items")?
+        .returns(indexed_item_iterator, "iterator over the items")?
+        .build_static_with_same_name()?;
+
+    lib.define_static_class("value_iterator_test_helper")?
+        .doc(
+            "Helper methods for the tests of iterators over strings, enums, durations and classes",
+        )?
+        .static_method(split_words_fn)?
+        .static_method(get_directions_fn)?
+        .static_method(get_intervals_fn)?
+        .static_method(get_offsets_fn)?
+        .static_method(get_indexed_items_fn)?
+        .build()?;
+
+    Ok(())
+}
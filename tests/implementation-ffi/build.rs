@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+use oo_bindgen::model::*;
+
+/// small library implemented entirely through the generated trait and macro
+fn build_lib() -> BackTraced<Library> {
+    let lib_info = LibraryInfo {
+        description: "Calculator implemented through the generated trait".to_string(),
+        project_url: "https://stepfunc.io/".to_string(),
+        repository: "stepfunc/oo_bindgen".to_string(),
+        license_name: "MIT".to_string(),
+        license_description: ["calc v1.2.3", "Copyright (C) 2020-2021 Step Function I/O"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+        license_path: PathBuf::from("LICENSE-MIT"),
+        developers: vec![DeveloperInfo {
+            name: "Émile Grégoire".to_string(),
+            email: "info@stepfunc.io".to_string(),
+            organization: "Step Function I/O".to_string(),
+            organization_url: "https://stepfunc.io/".to_string(),
+        }],
+        logo_png: include_bytes!("../../sfio_logo.png"),
+    };
+
+    let settings = LibrarySettings::create(
+        "calc",
+        "calc",
+        ClassSettings::default(),
+        IteratorSettings::default(),
+        CollectionSettings::default(),
+        FutureSettings::default(),
+        InterfaceSettings::default(),
+    )?;
+
+    let mut lib = LibraryBuilder::new(Version::parse("1.2.3").unwrap(), lib_info, settings);
+
+    let error_type = lib
+        .define_error_type(
+            "calculator_error",
+            "calculator_exception",
+            ExceptionType::UncheckedException,
+        )?
+        .add_error("divide_by_zero", "Division by zero")?
+        .add_bad_argument_error("null_argument", "Provided argument was NULL")?
+        .doc("Errors returned by the {class:accumulator}")?
+        .build()?;
+
+    let accumulator = lib.declare_class("accumulator")?;
+
+    let constructor = lib
+        .define_constructor(accumulator.clone())?
+        .param("value", Primitive::U32, "Initial value")?
+        .doc("Create a new {class:accumulator}")?
+        .build()?;
+
+    let destructor =
+        lib.define_destructor(accumulator.clone(), "Destroy an {class:accumulator}")?;
+
+    let add = lib
+        .define_method("add", accumulator.clone())?
+        .mutable_instance()?
+        .param("amount", Primitive::U32, "Amount added to the value")?
+        .doc("Add an amount to the value")?
+        .build()?;
+
+    let divide = lib
+        .define_method("divide", accumulator.clone())?
+        .mutable_instance()?
+        .param("divisor", Primitive::U32, "Divisor of the value")?
+        .returns(Primitive::U32, "Value after the division")?
+        .fails_with(error_type)?
+        .doc("Divide the value")?
+        .build()?;
+
+    let get_value = lib
+        .define_method("get_value", accumulator.clone())?
+        .returns(Primitive::U32, "Current value")?
+        .doc("Get the value")?
+        .build()?;
+
+    lib.define_class(&accumulator)?
+        .constructor(constructor)?
+        .destructor(destructor)?
+        .method(add)?
+        .method(divide)?
+        .method(get_value)?
+        .doc("Accumulates a value")?
+        .build()?;
+
+    Ok(lib.build()?)
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    match build_lib() {
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(-1);
+        }
+        Ok(lib) => {
+            oo_bindgen::backend::rust::generate_ffi(&lib).unwrap();
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! FFI implemented through the generated `Implementation` trait and `implement_ffi!` macro
+
+#[allow(clippy::extra_unused_lifetimes)]
+#[allow(clippy::needless_lifetimes)]
+pub mod ffi;
+
+static VERSION: &str = concat!("1.2.3", "\0");
+
+pub struct Accumulator {
+    value: u32,
+}
+
+struct Calculator;
+
+impl ffi::Implementation for Calculator {
+    unsafe fn accumulator_create(value: u32) -> *mut Accumulator {
+        Box::into_raw(Box::new(Accumulator { value }))
+    }
+
+    unsafe fn accumulator_destroy(instance: *mut Accumulator) {
+        if !instance.is_null() {
+            drop(Box::from_raw(instance));
+        }
+    }
+
+    unsafe fn accumulator_add(instance: &mut Accumulator, amount: u32) {
+        instance.value += amount;
+    }
+
+    unsafe fn accumulator_divide(
+        instance: &mut Accumulator,
+        divisor: u32,
+    ) -> Result<u32, ffi::CalculatorError> {
+        match instance.value.checked_div(divisor) {
+            Some(value) => {
+                instance.value = value;
+                Ok(value)
+            }
+            None => Err(ffi::CalculatorError::DivideByZero),
+        }
+    }
+
+    unsafe fn accumulator_get_value(instance: &Accumulator) -> u32 {
+        instance.value
+    }
+
+    unsafe fn version<'a>() -> &'a std::ffi::CStr {
+        std::ffi::CStr::from_bytes_with_nul_unchecked(VERSION.as_bytes())
+    }
+}
+
+ffi::implement_ffi!(ffi, Calculator);
+
+#[cfg(test)]
+mod tests {
+    use super::ffi::*;
+
+    #[test]
+    fn calls_the_implementation() {
+        unsafe {
+            let instance = calc_accumulator_create(10);
+            calc_accumulator_add(instance, 5);
+            assert_eq!(calc_accumulator_get_value(instance), 15);
+
+            let mut result = 0;
+            assert_eq!(
+                calc_accumulator_divide(instance, 3, &mut result),
+                CalculatorError::Ok as std::os::raw::c_int
+            );
+            assert_eq!(result, 5);
+            assert_eq!(
+                calc_accumulator_divide(instance, 0, &mut result),
+                CalculatorError::DivideByZero as std::os::raw::c_int
+            );
+            assert_eq!(calc_accumulator_get_value(instance), 5);
+
+            calc_accumulator_destroy(instance);
+        }
+    }
+
+    #[test]
+    fn null_instance_does_not_call_the_implementation() {
+        unsafe {
+            let mut result = 0;
+            assert_eq!(
+                calc_accumulator_divide(std::ptr::null_mut(), 3, &mut result),
+                CalculatorError::NullArgument as std::os::raw::c_int
+            );
+            assert_eq!(calc_accumulator_get_value(std::ptr::null_mut()), 0);
+        }
+    }
+
+    #[test]
+    fn version_is_returned_by_the_implementation() {
+        let version = unsafe { std::ffi::CStr::from_ptr(calc_version()) };
+        assert_eq!(version.to_str().unwrap(), "1.2.3");
+    }
+}